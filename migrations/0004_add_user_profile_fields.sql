@@ -0,0 +1,8 @@
+-- Self-service profile settings and pending email changes
+ALTER TABLE users
+    ADD COLUMN preferred_language VARCHAR(10) NOT NULL DEFAULT 'en',
+    ADD COLUMN time_zone VARCHAR(64) NOT NULL DEFAULT 'UTC',
+    ADD COLUMN avatar_url TEXT,
+    ADD COLUMN pending_email VARCHAR(255),
+    ADD COLUMN email_verification_token_hash VARCHAR(255),
+    ADD COLUMN email_verification_expires_at TIMESTAMP WITH TIME ZONE;
@@ -0,0 +1,10 @@
+-- Track XP earned on the learner's current local day
+ALTER TABLE user_progress
+    ADD COLUMN xp_earned_today INTEGER NOT NULL DEFAULT 0;
+
+-- daily_xp_cap becomes a per-user override; NULL falls back to the global setting
+ALTER TABLE user_progress
+    ALTER COLUMN daily_xp_cap DROP NOT NULL,
+    ALTER COLUMN daily_xp_cap DROP DEFAULT;
+
+UPDATE user_progress SET daily_xp_cap = NULL WHERE daily_xp_cap = 1000;
@@ -0,0 +1,5 @@
+-- Streak freeze inventory and the repair offer left after a broken streak
+ALTER TABLE user_progress
+    ADD COLUMN streak_freezes INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN repairable_streak_days INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN streak_repair_expires_at TIMESTAMPTZ;
@@ -0,0 +1,34 @@
+-- Achievement catalogue; `rule` holds the unlock condition, e.g. {"type": "streak_days", "threshold": 7}
+CREATE TABLE achievements (
+    id VARCHAR(100) PRIMARY KEY,
+    title_en VARCHAR(255) NOT NULL,
+    title_id VARCHAR(255) NOT NULL,
+    description_en TEXT NOT NULL,
+    description_id TEXT NOT NULL,
+    icon VARCHAR(50) NOT NULL,
+    rule JSONB NOT NULL,
+    xp_bonus INTEGER NOT NULL DEFAULT 0 CHECK (xp_bonus >= 0),
+    sort_order INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE user_achievements (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    achievement_id VARCHAR(100) NOT NULL REFERENCES achievements(id) ON DELETE CASCADE,
+    unlocked_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, achievement_id)
+);
+
+INSERT INTO achievements (id, title_en, title_id, description_en, description_id, icon, rule, xp_bonus, sort_order) VALUES
+    ('xp_100', 'First Steps', 'Langkah Pertama', 'Earn 100 XP.', 'Kumpulkan 100 XP.', '🌱', '{"type": "total_xp", "threshold": 100}', 10, 10),
+    ('xp_1000', 'Rising Rustacean', 'Rustacean Berkembang', 'Earn 1,000 XP.', 'Kumpulkan 1.000 XP.', '🦀', '{"type": "total_xp", "threshold": 1000}', 50, 20),
+    ('xp_5000', 'Seasoned Rustacean', 'Rustacean Berpengalaman', 'Earn 5,000 XP.', 'Kumpulkan 5.000 XP.', '🏅', '{"type": "total_xp", "threshold": 5000}', 100, 30),
+    ('streak_3', 'Warming Up', 'Pemanasan', 'Keep a 3-day streak.', 'Pertahankan runtutan 3 hari.', '🔥', '{"type": "streak_days", "threshold": 3}', 15, 40),
+    ('streak_7', 'Week Warrior', 'Pejuang Sepekan', 'Keep a 7-day streak.', 'Pertahankan runtutan 7 hari.', '📅', '{"type": "streak_days", "threshold": 7}', 30, 50),
+    ('streak_30', 'Unstoppable', 'Tak Terhentikan', 'Keep a 30-day streak.', 'Pertahankan runtutan 30 hari.', '⚡', '{"type": "streak_days", "threshold": 30}', 150, 60),
+    ('perfect_lesson_1', 'Flawless', 'Sempurna', 'Finish a lesson with 3 stars.', 'Selesaikan pelajaran dengan 3 bintang.', '⭐', '{"type": "perfect_lessons", "threshold": 1}', 20, 70),
+    ('perfect_lesson_10', 'Perfectionist', 'Perfeksionis', 'Finish 10 lessons with 3 stars.', 'Selesaikan 10 pelajaran dengan 3 bintang.', '🌟', '{"type": "perfect_lessons", "threshold": 10}', 100, 80),
+    ('code_practice_1', 'Hello, World', 'Halo, Dunia', 'Solve your first code practice.', 'Selesaikan latihan kode pertamamu.', '💻', '{"type": "code_practices_completed", "threshold": 1}', 20, 90),
+    ('code_practice_25', 'Code Crafter', 'Perajin Kode', 'Solve 25 code practices.', 'Selesaikan 25 latihan kode.', '🛠️', '{"type": "code_practices_completed", "threshold": 25}', 100, 100),
+    ('topic_master_1', 'Topic Master', 'Penguasa Topik', 'Earn 3 stars on every lesson of a topic.', 'Raih 3 bintang di semua pelajaran dalam satu topik.', '🎓', '{"type": "topics_mastered", "threshold": 1}', 150, 110);
@@ -0,0 +1,22 @@
+-- Ordered questions of each lesson (Lesson.questions)
+CREATE TABLE lesson_questions (
+    lesson_id UUID NOT NULL REFERENCES lessons(id) ON DELETE CASCADE,
+    question_id UUID NOT NULL REFERENCES questions(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (lesson_id, question_id)
+);
+
+CREATE INDEX idx_lesson_questions_lesson_id ON lesson_questions(lesson_id, position);
+
+-- A learner's run through a lesson, from start to finish
+CREATE TABLE lesson_sessions (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    lesson_id UUID NOT NULL REFERENCES lessons(id) ON DELETE CASCADE,
+    question_ids JSONB NOT NULL DEFAULT '[]',
+    answers JSONB NOT NULL DEFAULT '[]',
+    started_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    finished_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_lesson_sessions_user_id ON lesson_sessions(user_id);
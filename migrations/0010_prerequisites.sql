@@ -0,0 +1,23 @@
+-- Prerequisite graph between topics and between lessons. Both must stay acyclic;
+-- cycles are rejected by the application when an edge is saved.
+CREATE TABLE topic_prerequisites (
+    topic_id UUID NOT NULL REFERENCES topics(id) ON DELETE CASCADE,
+    prerequisite_topic_id UUID NOT NULL REFERENCES topics(id) ON DELETE CASCADE,
+    -- Stars needed on every lesson of the prerequisite topic
+    min_stars INTEGER NOT NULL DEFAULT 2 CHECK (min_stars >= 0 AND min_stars <= 3),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (topic_id, prerequisite_topic_id),
+    CHECK (topic_id <> prerequisite_topic_id)
+);
+
+CREATE TABLE lesson_prerequisites (
+    lesson_id UUID NOT NULL REFERENCES lessons(id) ON DELETE CASCADE,
+    prerequisite_lesson_id UUID NOT NULL REFERENCES lessons(id) ON DELETE CASCADE,
+    min_stars INTEGER NOT NULL DEFAULT 1 CHECK (min_stars >= 0 AND min_stars <= 3),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (lesson_id, prerequisite_lesson_id),
+    CHECK (lesson_id <> prerequisite_lesson_id)
+);
+
+CREATE INDEX idx_topic_prerequisites_prerequisite ON topic_prerequisites(prerequisite_topic_id);
+CREATE INDEX idx_lesson_prerequisites_prerequisite ON lesson_prerequisites(prerequisite_lesson_id);
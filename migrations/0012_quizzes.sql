@@ -0,0 +1,30 @@
+-- Timed assessments drawing a fixed number of questions from a pool
+CREATE TABLE quizzes (
+    id UUID PRIMARY KEY,
+    title_en VARCHAR(500) NOT NULL,
+    title_id VARCHAR(500) NOT NULL,
+    description_en TEXT NOT NULL,
+    description_id TEXT NOT NULL,
+    topic_ids JSONB NOT NULL DEFAULT '[]',
+    difficulties JSONB NOT NULL DEFAULT '[]',
+    question_count INTEGER NOT NULL CHECK (question_count > 0),
+    time_limit_secs INTEGER NOT NULL CHECK (time_limit_secs > 0),
+    passing_score INTEGER NOT NULL CHECK (passing_score BETWEEN 0 AND 100),
+    max_attempts INTEGER CHECK (max_attempts > 0),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- A learner's attempt at a quiz, with the questions and option order drawn for it
+CREATE TABLE quiz_attempts (
+    id UUID PRIMARY KEY,
+    quiz_id UUID NOT NULL REFERENCES quizzes(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    items JSONB NOT NULL DEFAULT '[]',
+    answers JSONB NOT NULL DEFAULT '[]',
+    started_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    deadline TIMESTAMP WITH TIME ZONE NOT NULL,
+    submitted_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_quiz_attempts_user_quiz ON quiz_attempts(user_id, quiz_id, started_at DESC);
@@ -0,0 +1,8 @@
+-- Hints each learner has revealed per code practice; each one lowers the completion XP
+CREATE TABLE code_practice_hint_usage (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    code_practice_id UUID NOT NULL REFERENCES code_practices(id) ON DELETE CASCADE,
+    hints_revealed INTEGER NOT NULL DEFAULT 0 CHECK (hints_revealed >= 0),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, code_practice_id)
+);
@@ -0,0 +1,2 @@
+-- Content editors may read full content, including answers and solutions, through the API
+ALTER TABLE users ADD COLUMN is_content_editor BOOLEAN NOT NULL DEFAULT FALSE;
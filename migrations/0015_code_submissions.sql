@@ -0,0 +1,25 @@
+-- Every code practice attempt, not just the latest completion
+CREATE TABLE code_submissions (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    code_practice_id UUID NOT NULL REFERENCES code_practices(id) ON DELETE CASCADE,
+    code TEXT NOT NULL,
+    verdict VARCHAR(32) NOT NULL,
+    runner_output TEXT,
+    submitted_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_code_submissions_user_practice
+    ON code_submissions(user_id, code_practice_id, submitted_at DESC);
+
+-- Keep the completions recorded so far as the first entries of each history
+INSERT INTO code_submissions (user_id, code_practice_id, code, verdict, submitted_at)
+SELECT
+    p.user_id,
+    (c->>'id')::uuid,
+    c->>'user_code',
+    CASE WHEN (c->>'is_correct')::boolean THEN 'accepted' ELSE 'wrong_answer' END,
+    (c->>'completed_at')::timestamptz
+FROM user_progress p
+CROSS JOIN LATERAL jsonb_array_elements(p.completed_code_practices) AS c
+WHERE EXISTS (SELECT 1 FROM code_practices cp WHERE cp.id = (c->>'id')::uuid);
@@ -0,0 +1,11 @@
+-- Generated questions waiting for review before they become real questions
+CREATE TABLE question_drafts (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    question JSONB NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending',
+    source VARCHAR(100) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    reviewed_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_question_drafts_status ON question_drafts(status, created_at);
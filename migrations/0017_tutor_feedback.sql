@@ -0,0 +1,17 @@
+-- AI tutor hints for wrong answers, shared between identical (question, answer) pairs
+CREATE TABLE tutor_feedback_cache (
+    cache_key TEXT PRIMARY KEY,
+    language TEXT NOT NULL,
+    hint TEXT NOT NULL,
+    provider TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- Tutor hints generated per learner per local day, checked against the daily quota
+CREATE TABLE tutor_usage (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    usage_date DATE NOT NULL,
+    requests INTEGER NOT NULL DEFAULT 0 CHECK (requests >= 0),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, usage_date)
+);
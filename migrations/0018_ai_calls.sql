@@ -0,0 +1,16 @@
+-- Every AI request with its token usage and cost, for the admin usage report
+CREATE TABLE ai_calls (
+    id UUID PRIMARY KEY,
+    feature TEXT NOT NULL,
+    user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    provider TEXT NOT NULL,
+    cached BOOLEAN NOT NULL DEFAULT FALSE,
+    succeeded BOOLEAN NOT NULL DEFAULT TRUE,
+    input_tokens INTEGER NOT NULL DEFAULT 0 CHECK (input_tokens >= 0),
+    output_tokens INTEGER NOT NULL DEFAULT 0 CHECK (output_tokens >= 0),
+    cost_micros BIGINT NOT NULL DEFAULT 0 CHECK (cost_micros >= 0),
+    latency_ms INTEGER NOT NULL DEFAULT 0 CHECK (latency_ms >= 0),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_ai_calls_created_at ON ai_calls(created_at);
@@ -0,0 +1,21 @@
+-- Machine translations of localized content fields, applied once an editor approves them
+CREATE TABLE machine_translations (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    content_kind VARCHAR(20) NOT NULL,
+    content_id UUID NOT NULL,
+    field VARCHAR(50) NOT NULL,
+    language VARCHAR(10) NOT NULL,
+    source_text TEXT NOT NULL,
+    translated_text TEXT NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'needs_review',
+    provider VARCHAR(100) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    reviewed_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_machine_translations_status ON machine_translations(status, created_at);
+
+-- At most one translation of a field awaits review at a time
+CREATE UNIQUE INDEX idx_machine_translations_pending_field
+    ON machine_translations(content_kind, content_id, field, language)
+    WHERE status = 'needs_review';
@@ -0,0 +1,108 @@
+-- Localized text moves from one column per language to a JSONB object keyed by
+-- locale tag, e.g. {"en": "Ownership", "id": "Kepemilikan", "pt-BR": "Posse"}.
+-- Empty translations are left out.
+
+CREATE FUNCTION pg_temp.localized(en TEXT, id TEXT) RETURNS JSONB AS $$
+    SELECT jsonb_strip_nulls(jsonb_build_object('en', NULLIF(en, ''), 'id', NULLIF(id, '')))
+$$ LANGUAGE SQL IMMUTABLE;
+
+ALTER TABLE topics
+    ADD COLUMN title JSONB,
+    ADD COLUMN description JSONB,
+    ADD COLUMN required_skills JSONB;
+UPDATE topics SET
+    title = pg_temp.localized(title_en, title_id),
+    description = pg_temp.localized(description_en, description_id),
+    required_skills = pg_temp.localized(required_skills_en, required_skills_id);
+ALTER TABLE topics
+    ALTER COLUMN title SET NOT NULL,
+    ALTER COLUMN description SET NOT NULL,
+    ALTER COLUMN required_skills SET NOT NULL,
+    DROP COLUMN title_en,
+    DROP COLUMN title_id,
+    DROP COLUMN description_en,
+    DROP COLUMN description_id,
+    DROP COLUMN required_skills_en,
+    DROP COLUMN required_skills_id;
+
+ALTER TABLE lessons
+    ADD COLUMN title JSONB,
+    ADD COLUMN summary JSONB;
+UPDATE lessons SET
+    title = pg_temp.localized(title_en, title_id),
+    summary = pg_temp.localized(summary_en, summary_id);
+ALTER TABLE lessons
+    ALTER COLUMN title SET NOT NULL,
+    ALTER COLUMN summary SET NOT NULL,
+    DROP COLUMN title_en,
+    DROP COLUMN title_id,
+    DROP COLUMN summary_en,
+    DROP COLUMN summary_id;
+
+-- Questions without an explanation keep NULL
+ALTER TABLE questions
+    ADD COLUMN prompt JSONB,
+    ADD COLUMN explanation JSONB;
+UPDATE questions SET
+    prompt = pg_temp.localized(prompt_en, prompt_id),
+    explanation = CASE
+        WHEN explanation_en IS NULL AND explanation_id IS NULL THEN NULL
+        ELSE pg_temp.localized(explanation_en, explanation_id)
+    END;
+ALTER TABLE questions
+    ALTER COLUMN prompt SET NOT NULL,
+    DROP COLUMN prompt_en,
+    DROP COLUMN prompt_id,
+    DROP COLUMN explanation_en,
+    DROP COLUMN explanation_id;
+
+ALTER TABLE code_practices
+    ADD COLUMN title JSONB,
+    ADD COLUMN description JSONB;
+UPDATE code_practices SET
+    title = pg_temp.localized(title_en, title_id),
+    description = pg_temp.localized(description_en, description_id);
+ALTER TABLE code_practices
+    ALTER COLUMN title SET NOT NULL,
+    ALTER COLUMN description SET NOT NULL,
+    DROP COLUMN title_en,
+    DROP COLUMN title_id,
+    DROP COLUMN description_en,
+    DROP COLUMN description_id;
+
+ALTER TABLE achievements
+    ADD COLUMN title JSONB,
+    ADD COLUMN description JSONB;
+UPDATE achievements SET
+    title = pg_temp.localized(title_en, title_id),
+    description = pg_temp.localized(description_en, description_id);
+ALTER TABLE achievements
+    ALTER COLUMN title SET NOT NULL,
+    ALTER COLUMN description SET NOT NULL,
+    DROP COLUMN title_en,
+    DROP COLUMN title_id,
+    DROP COLUMN description_en,
+    DROP COLUMN description_id;
+
+ALTER TABLE quizzes
+    ADD COLUMN title JSONB,
+    ADD COLUMN description JSONB;
+UPDATE quizzes SET
+    title = pg_temp.localized(title_en, title_id),
+    description = pg_temp.localized(description_en, description_id);
+ALTER TABLE quizzes
+    ALTER COLUMN title SET NOT NULL,
+    ALTER COLUMN description SET NOT NULL,
+    DROP COLUMN title_en,
+    DROP COLUMN title_id,
+    DROP COLUMN description_en,
+    DROP COLUMN description_id;
+
+-- Hints were already stored as {"en": ..., "id": ...}; drop the empty ones
+UPDATE code_practices SET hints = COALESCE(
+    (SELECT jsonb_agg(
+        (SELECT COALESCE(jsonb_object_agg(key, value), '{}'::jsonb)
+         FROM jsonb_each(hint) WHERE value <> '""'::jsonb)
+        ORDER BY position)
+     FROM jsonb_array_elements(hints) WITH ORDINALITY AS h(hint, position)),
+    '[]'::jsonb);
@@ -0,0 +1,2 @@
+-- Lesson content: an ordered array of typed blocks (markdown, code, callout, image, question)
+ALTER TABLE lessons ADD COLUMN blocks JSONB NOT NULL DEFAULT '[]';
@@ -0,0 +1,20 @@
+-- Uploaded media. Content lives in the configured asset storage under a key
+-- derived from its SHA-256; identical uploads share one row.
+CREATE TABLE assets (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    sha256 CHAR(64) NOT NULL UNIQUE,
+    content_type VARCHAR(100) NOT NULL,
+    size_bytes BIGINT NOT NULL CHECK (size_bytes > 0),
+    original_filename VARCHAR(255) NOT NULL,
+    storage_key VARCHAR(255) NOT NULL,
+    thumbnail_key VARCHAR(255),
+    width INTEGER,
+    height INTEGER,
+    uploaded_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_assets_created_at ON assets(created_at DESC);
+
+-- Optional image shown with a question
+ALTER TABLE questions ADD COLUMN image_asset_id UUID REFERENCES assets(id) ON DELETE SET NULL;
@@ -0,0 +1,13 @@
+-- Previous states of topics, lessons, questions and code practices, one row per change
+CREATE TABLE content_versions (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    content_kind VARCHAR(20) NOT NULL,
+    content_id UUID NOT NULL,
+    version INTEGER NOT NULL CHECK (version > 0),
+    snapshot JSONB NOT NULL,
+    author_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    author_name VARCHAR(255) NOT NULL,
+    change_note TEXT NOT NULL DEFAULT '',
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (content_kind, content_id, version)
+);
@@ -0,0 +1,2 @@
+-- Bumped on every save so concurrent answers to one session cannot overwrite each other
+ALTER TABLE lesson_sessions ADD COLUMN revision INTEGER NOT NULL DEFAULT 0;
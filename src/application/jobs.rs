@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use crate::application::state::AppState;
+
+const ACCOUNT_PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+/// Hourly, so every time zone is checked shortly after its local midnight.
+const STREAK_EXPIRY_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Starts periodic maintenance tasks. They run for the lifetime of the process.
+pub fn spawn_background_jobs(state: AppState) {
+    let streak_state = state.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(STREAK_EXPIRY_INTERVAL);
+        loop {
+            interval.tick().await;
+            match streak_state
+                .progress_use_cases
+                .expire_broken_streaks()
+                .await
+            {
+                Ok(0) => {}
+                Ok(expired) => tracing::info!("Reset {} broken streaks", expired),
+                Err(e) => tracing::error!("Streak expiry failed: {}", e),
+            }
+        }
+    });
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(ACCOUNT_PURGE_INTERVAL);
+        loop {
+            interval.tick().await;
+            match state.privacy_use_cases.purge_due_accounts().await {
+                Ok(report) => {
+                    if report.purged > 0 {
+                        tracing::info!("Purged {} accounts past their grace period", report.purged);
+                    }
+                    if report.failed > 0 {
+                        tracing::error!("Failed to purge {} accounts", report.failed);
+                    }
+                }
+                Err(e) => tracing::error!("Account purge failed: {}", e),
+            }
+        }
+    });
+}
@@ -1,5 +1,6 @@
 pub mod dtos;
 pub mod interfaces;
+pub mod jobs;
 pub mod state;
 pub mod use_cases;
 
@@ -1,21 +1,58 @@
 use axum::extract::FromRef;
 use std::sync::Arc;
 
-use crate::application::use_cases::{AuthUseCases, ContentUseCases, ProgressUseCases};
+use crate::application::use_cases::{
+    AccountUseCases, AchievementUseCases, AdaptiveUseCases, ApiKeyUseCases, AuthUseCases,
+    CodePracticeUseCases, ContentUseCases, LearningPathUseCases, LessonSessionUseCases,
+    PrivacyUseCases, ProgressUseCases, QuizUseCases, ReviewUseCases, TranslationUseCases,
+    TutorUseCases,
+};
 use crate::domain::repositories::{
-    CodePracticeRepository, LeaderboardRepository, LessonRepository, NotificationRepository,
-    QuestionRepository, TopicRepository, UserProgressRepository, UserRepository,
+    AccountAuditRepository, AchievementRepository, AiUsageRepository, ApiKeyRepository,
+    AssetRepository, CodePracticeRepository, CodeSubmissionRepository, ContentVersionRepository,
+    HintUsageRepository, LeaderboardRepository, LessonRepository, LessonSessionRepository,
+    NotificationRepository, PrerequisiteRepository, QuestionDraftRepository, QuestionRepository,
+    QuizAttemptRepository, QuizRepository, ReviewCardRepository, TopicRepository,
+    TranslationRepository, TutorFeedbackRepository, UserProgressRepository, UserRepository,
 };
 use crate::domain::services::{
-    auth_service::JwtAuthService, leaderboard_service::LeaderboardServiceImpl,
+    account_service::AccountServiceImpl,
+    achievement_service::AchievementServiceImpl,
+    adaptive_learning_service::AdaptiveLearningServiceImpl,
+    api_key_service::ApiKeyServiceImpl,
+    auth_service::JwtAuthService,
+    code_practice_service::CodePracticeServiceImpl,
+    content_version_service::ContentVersionServiceImpl,
+    data_privacy_service::{DataPrivacyServiceImpl, LearnerActivityRepositories},
+    leaderboard_service::LeaderboardServiceImpl,
+    learning_path_service::LearningPathServiceImpl,
+    lesson_session_service::LessonSessionServiceImpl,
+    notification_service::NotificationServiceImpl,
+    plagiarism_service::PlagiarismServiceImpl,
     progress_service::ProgressServiceImpl,
+    question_draft_service::QuestionDraftServiceImpl,
+    quiz_service::QuizServiceImpl,
+    review_service::ReviewServiceImpl,
+};
+use crate::domain::services::{
+    AccountService, AchievementService, AdaptiveLearningService, ApiKeyService, AuthService,
+    CodePracticeService, ContentVersionService, DataPrivacyService, LeaderboardService,
+    LearningPathService, LessonSessionService, NotificationService, PlagiarismService,
+    ProgressService, QuestionDraftService, QuizService, ReviewService,
+};
+use crate::infrastructure::external_services::{
+    asset_storage_from_config, llm_provider_from_config, AiService, AiSettings, AssetService,
+    AssetSettings, EmailService,
 };
-use crate::domain::services::{AuthService, LeaderboardService, ProgressService};
-use crate::infrastructure::external_services::{EmailService, GeminiService};
 use crate::infrastructure::repositories::{
-    CodePracticeRepositoryImpl, LeaderboardRepositoryImpl, LessonRepositoryImpl,
-    NotificationRepositoryImpl, QuestionRepositoryImpl, TopicRepositoryImpl,
-    UserProgressRepositoryImpl, UserRepositoryImpl,
+    AccountAuditRepositoryImpl, AchievementRepositoryImpl, AiUsageRepositoryImpl,
+    ApiKeyRepositoryImpl, AssetRepositoryImpl, CodePracticeRepositoryImpl,
+    CodeSubmissionRepositoryImpl, ContentVersionRepositoryImpl, HintUsageRepositoryImpl,
+    LeaderboardRepositoryImpl, LessonRepositoryImpl, LessonSessionRepositoryImpl,
+    NotificationRepositoryImpl, PrerequisiteRepositoryImpl, QuestionDraftRepositoryImpl,
+    QuestionRepositoryImpl, QuizAttemptRepositoryImpl, QuizRepositoryImpl,
+    ReviewCardRepositoryImpl, TopicRepositoryImpl, TranslationRepositoryImpl,
+    TutorFeedbackRepositoryImpl, UserProgressRepositoryImpl, UserRepositoryImpl,
 };
 use crate::shared::config::Config;
 use crate::shared::errors::AppError;
@@ -31,38 +68,179 @@ pub struct AppState {
     pub user_progress_repository: Arc<dyn UserProgressRepository>,
     pub leaderboard_repository: Arc<dyn LeaderboardRepository>,
     pub notification_repository: Arc<dyn NotificationRepository>,
+    pub api_key_repository: Arc<dyn ApiKeyRepository>,
+    pub account_audit_repository: Arc<dyn AccountAuditRepository>,
+    pub achievement_repository: Arc<dyn AchievementRepository>,
+    pub lesson_session_repository: Arc<dyn LessonSessionRepository>,
+    pub prerequisite_repository: Arc<dyn PrerequisiteRepository>,
+    pub review_card_repository: Arc<dyn ReviewCardRepository>,
+    pub quiz_repository: Arc<dyn QuizRepository>,
+    pub quiz_attempt_repository: Arc<dyn QuizAttemptRepository>,
+    pub hint_usage_repository: Arc<dyn HintUsageRepository>,
+    pub code_submission_repository: Arc<dyn CodeSubmissionRepository>,
+    pub question_draft_repository: Arc<dyn QuestionDraftRepository>,
+    pub tutor_feedback_repository: Arc<dyn TutorFeedbackRepository>,
+    pub ai_usage_repository: Arc<dyn AiUsageRepository>,
+    pub translation_repository: Arc<dyn TranslationRepository>,
+    pub asset_repository: Arc<dyn AssetRepository>,
+    pub content_version_repository: Arc<dyn ContentVersionRepository>,
 
     // Services
     pub auth_service: Arc<dyn AuthService>,
     pub progress_service: Arc<dyn ProgressService>,
     pub leaderboard_service: Arc<dyn LeaderboardService>,
+    pub api_key_service: Arc<dyn ApiKeyService>,
+    pub account_service: Arc<dyn AccountService>,
+    pub data_privacy_service: Arc<dyn DataPrivacyService>,
+    pub notification_service: Arc<dyn NotificationService>,
+    pub achievement_service: Arc<dyn AchievementService>,
+    pub lesson_session_service: Arc<dyn LessonSessionService>,
+    pub learning_path_service: Arc<dyn LearningPathService>,
+    pub review_service: Arc<dyn ReviewService>,
+    pub adaptive_learning_service: Arc<dyn AdaptiveLearningService>,
+    pub quiz_service: Arc<dyn QuizService>,
+    pub code_practice_service: Arc<dyn CodePracticeService>,
+    pub plagiarism_service: Arc<dyn PlagiarismService>,
+    pub question_draft_service: Arc<dyn QuestionDraftService>,
+    pub content_version_service: Arc<dyn ContentVersionService>,
 
     // External Services
-    pub gemini_service: GeminiService,
+    pub ai_service: AiService,
     pub email_service: EmailService,
+    pub asset_service: AssetService,
 
     // Use Cases
     pub auth_use_cases: AuthUseCases,
     pub content_use_cases: ContentUseCases,
     pub progress_use_cases: ProgressUseCases,
+    pub api_key_use_cases: ApiKeyUseCases,
+    pub account_use_cases: AccountUseCases,
+    pub privacy_use_cases: PrivacyUseCases,
+    pub achievement_use_cases: AchievementUseCases,
+    pub lesson_session_use_cases: LessonSessionUseCases,
+    pub learning_path_use_cases: LearningPathUseCases,
+    pub review_use_cases: ReviewUseCases,
+    pub adaptive_use_cases: AdaptiveUseCases,
+    pub quiz_use_cases: QuizUseCases,
+    pub code_practice_use_cases: CodePracticeUseCases,
+    pub tutor_use_cases: TutorUseCases,
+    pub translation_use_cases: TranslationUseCases,
+}
+
+/// Repositories the application state is built from.
+pub struct AppRepositories {
+    pub user_repository: Arc<dyn UserRepository>,
+    pub topic_repository: Arc<dyn TopicRepository>,
+    pub lesson_repository: Arc<dyn LessonRepository>,
+    pub question_repository: Arc<dyn QuestionRepository>,
+    pub code_practice_repository: Arc<dyn CodePracticeRepository>,
+    pub user_progress_repository: Arc<dyn UserProgressRepository>,
+    pub leaderboard_repository: Arc<dyn LeaderboardRepository>,
+    pub notification_repository: Arc<dyn NotificationRepository>,
+    pub api_key_repository: Arc<dyn ApiKeyRepository>,
+    pub account_audit_repository: Arc<dyn AccountAuditRepository>,
+    pub achievement_repository: Arc<dyn AchievementRepository>,
+    pub lesson_session_repository: Arc<dyn LessonSessionRepository>,
+    pub prerequisite_repository: Arc<dyn PrerequisiteRepository>,
+    pub review_card_repository: Arc<dyn ReviewCardRepository>,
+    pub quiz_repository: Arc<dyn QuizRepository>,
+    pub quiz_attempt_repository: Arc<dyn QuizAttemptRepository>,
+    pub hint_usage_repository: Arc<dyn HintUsageRepository>,
+    pub code_submission_repository: Arc<dyn CodeSubmissionRepository>,
+    pub question_draft_repository: Arc<dyn QuestionDraftRepository>,
+    pub tutor_feedback_repository: Arc<dyn TutorFeedbackRepository>,
+    pub ai_usage_repository: Arc<dyn AiUsageRepository>,
+    pub translation_repository: Arc<dyn TranslationRepository>,
+    pub asset_repository: Arc<dyn AssetRepository>,
+    pub content_version_repository: Arc<dyn ContentVersionRepository>,
+}
+
+/// Domain services the application state is built from.
+pub struct AppServices {
+    pub auth_service: Arc<dyn AuthService>,
+    pub progress_service: Arc<dyn ProgressService>,
+    pub leaderboard_service: Arc<dyn LeaderboardService>,
+    pub api_key_service: Arc<dyn ApiKeyService>,
+    pub account_service: Arc<dyn AccountService>,
+    pub data_privacy_service: Arc<dyn DataPrivacyService>,
+    pub notification_service: Arc<dyn NotificationService>,
+    pub achievement_service: Arc<dyn AchievementService>,
+    pub lesson_session_service: Arc<dyn LessonSessionService>,
+    pub learning_path_service: Arc<dyn LearningPathService>,
+    pub review_service: Arc<dyn ReviewService>,
+    pub adaptive_learning_service: Arc<dyn AdaptiveLearningService>,
+    pub quiz_service: Arc<dyn QuizService>,
+    pub code_practice_service: Arc<dyn CodePracticeService>,
+    pub plagiarism_service: Arc<dyn PlagiarismService>,
+    pub question_draft_service: Arc<dyn QuestionDraftService>,
+    pub content_version_service: Arc<dyn ContentVersionService>,
+}
+
+/// Services backed by systems outside the database.
+pub struct AppExternalServices {
+    pub ai_service: AiService,
+    pub email_service: EmailService,
+    pub asset_service: AssetService,
 }
 
 impl AppState {
     pub fn new(
-        user_repository: Arc<dyn UserRepository>,
-        topic_repository: Arc<dyn TopicRepository>,
-        lesson_repository: Arc<dyn LessonRepository>,
-        question_repository: Arc<dyn QuestionRepository>,
-        code_practice_repository: Arc<dyn CodePracticeRepository>,
-        user_progress_repository: Arc<dyn UserProgressRepository>,
-        leaderboard_repository: Arc<dyn LeaderboardRepository>,
-        notification_repository: Arc<dyn NotificationRepository>,
-        auth_service: Arc<dyn AuthService>,
-        progress_service: Arc<dyn ProgressService>,
-        leaderboard_service: Arc<dyn LeaderboardService>,
-        gemini_service: GeminiService,
-        email_service: EmailService,
+        repositories: AppRepositories,
+        services: AppServices,
+        external_services: AppExternalServices,
+        tutor_daily_quota: u32,
     ) -> Self {
+        let AppRepositories {
+            user_repository,
+            topic_repository,
+            lesson_repository,
+            question_repository,
+            code_practice_repository,
+            user_progress_repository,
+            leaderboard_repository,
+            notification_repository,
+            api_key_repository,
+            account_audit_repository,
+            achievement_repository,
+            lesson_session_repository,
+            prerequisite_repository,
+            review_card_repository,
+            quiz_repository,
+            quiz_attempt_repository,
+            hint_usage_repository,
+            code_submission_repository,
+            question_draft_repository,
+            tutor_feedback_repository,
+            ai_usage_repository,
+            translation_repository,
+            asset_repository,
+            content_version_repository,
+        } = repositories;
+        let AppServices {
+            auth_service,
+            progress_service,
+            leaderboard_service,
+            api_key_service,
+            account_service,
+            data_privacy_service,
+            notification_service,
+            achievement_service,
+            lesson_session_service,
+            learning_path_service,
+            review_service,
+            adaptive_learning_service,
+            quiz_service,
+            code_practice_service,
+            plagiarism_service,
+            question_draft_service,
+            content_version_service,
+        } = services;
+        let AppExternalServices {
+            ai_service,
+            email_service,
+            asset_service,
+        } = external_services;
+
         let auth_use_cases = AuthUseCases::new(auth_service.clone());
         let content_use_cases = ContentUseCases::new(
             topic_repository.clone(),
@@ -70,7 +248,41 @@ impl AppState {
             question_repository.clone(),
             code_practice_repository.clone(),
         );
-        let progress_use_cases = ProgressUseCases::new(leaderboard_repository.clone());
+        let progress_use_cases =
+            ProgressUseCases::new(leaderboard_repository.clone(), progress_service.clone());
+        let api_key_use_cases = ApiKeyUseCases::new(api_key_service.clone());
+        let account_use_cases = AccountUseCases::new(
+            account_service.clone(),
+            adaptive_learning_service.clone(),
+            email_service.clone(),
+        );
+        let privacy_use_cases = PrivacyUseCases::new(data_privacy_service.clone());
+        let achievement_use_cases = AchievementUseCases::new(achievement_service.clone());
+        let lesson_session_use_cases = LessonSessionUseCases::new(lesson_session_service.clone());
+        let learning_path_use_cases = LearningPathUseCases::new(learning_path_service.clone());
+        let review_use_cases = ReviewUseCases::new(review_service.clone());
+        let adaptive_use_cases = AdaptiveUseCases::new(adaptive_learning_service.clone());
+        let quiz_use_cases = QuizUseCases::new(quiz_service.clone());
+        let code_practice_use_cases = CodePracticeUseCases::new(code_practice_service.clone());
+        let tutor_use_cases = TutorUseCases::new(
+            user_repository.clone(),
+            question_repository.clone(),
+            quiz_attempt_repository.clone(),
+            code_practice_repository.clone(),
+            code_submission_repository.clone(),
+            tutor_feedback_repository.clone(),
+            ai_service.clone(),
+            tutor_daily_quota,
+        );
+        let translation_use_cases = TranslationUseCases::new(
+            topic_repository.clone(),
+            lesson_repository.clone(),
+            question_repository.clone(),
+            code_practice_repository.clone(),
+            translation_repository.clone(),
+            content_version_service.clone(),
+            ai_service.clone(),
+        );
 
         Self {
             user_repository,
@@ -81,14 +293,57 @@ impl AppState {
             user_progress_repository,
             leaderboard_repository,
             notification_repository,
+            api_key_repository,
+            account_audit_repository,
+            achievement_repository,
+            lesson_session_repository,
+            prerequisite_repository,
+            review_card_repository,
+            quiz_repository,
+            quiz_attempt_repository,
+            hint_usage_repository,
+            code_submission_repository,
+            question_draft_repository,
+            tutor_feedback_repository,
+            ai_usage_repository,
+            translation_repository,
+            asset_repository,
+            content_version_repository,
             auth_service,
             progress_service,
             leaderboard_service,
-            gemini_service,
+            api_key_service,
+            account_service,
+            data_privacy_service,
+            notification_service,
+            achievement_service,
+            lesson_session_service,
+            learning_path_service,
+            review_service,
+            adaptive_learning_service,
+            quiz_service,
+            code_practice_service,
+            plagiarism_service,
+            question_draft_service,
+            content_version_service,
+            ai_service,
             email_service,
+            asset_service,
             auth_use_cases,
             content_use_cases,
             progress_use_cases,
+            api_key_use_cases,
+            account_use_cases,
+            privacy_use_cases,
+            achievement_use_cases,
+            lesson_session_use_cases,
+            learning_path_use_cases,
+            review_use_cases,
+            adaptive_use_cases,
+            quiz_use_cases,
+            code_practice_use_cases,
+            tutor_use_cases,
+            translation_use_cases,
         }
     }
 
@@ -108,19 +363,131 @@ impl AppState {
         let user_progress_repository = Arc::new(UserProgressRepositoryImpl::new(pool.clone()));
         let leaderboard_repository = Arc::new(LeaderboardRepositoryImpl::new(pool.clone()));
         let notification_repository = Arc::new(NotificationRepositoryImpl::new());
+        let api_key_repository = Arc::new(ApiKeyRepositoryImpl::new(pool.clone()));
+        let account_audit_repository = Arc::new(AccountAuditRepositoryImpl::new(pool.clone()));
+        let achievement_repository = Arc::new(AchievementRepositoryImpl::new(pool.clone()));
+        let lesson_session_repository = Arc::new(LessonSessionRepositoryImpl::new(pool.clone()));
+        let prerequisite_repository = Arc::new(PrerequisiteRepositoryImpl::new(pool.clone()));
+        let review_card_repository = Arc::new(ReviewCardRepositoryImpl::new(pool.clone()));
+        let quiz_repository = Arc::new(QuizRepositoryImpl::new(pool.clone()));
+        let quiz_attempt_repository = Arc::new(QuizAttemptRepositoryImpl::new(pool.clone()));
+        let hint_usage_repository = Arc::new(HintUsageRepositoryImpl::new(pool.clone()));
+        let code_submission_repository = Arc::new(CodeSubmissionRepositoryImpl::new(pool.clone()));
+        let question_draft_repository = Arc::new(QuestionDraftRepositoryImpl::new(pool.clone()));
+        let tutor_feedback_repository = Arc::new(TutorFeedbackRepositoryImpl::new(pool.clone()));
+        let ai_usage_repository = Arc::new(AiUsageRepositoryImpl::new(pool.clone()));
+        let translation_repository = Arc::new(TranslationRepositoryImpl::new(pool.clone()));
+        let asset_repository = Arc::new(AssetRepositoryImpl::new(pool.clone()));
+        let content_version_repository = Arc::new(ContentVersionRepositoryImpl::new(pool.clone()));
 
         // Create services
+        let notification_service = Arc::new(NotificationServiceImpl::new(
+            notification_repository.clone(),
+            user_repository.clone(),
+        ));
+        let achievement_service = Arc::new(AchievementServiceImpl::new(
+            achievement_repository.clone(),
+            user_progress_repository.clone(),
+            user_repository.clone(),
+            lesson_repository.clone(),
+            notification_service.clone(),
+        ));
         let auth_service = Arc::new(JwtAuthService::new(
             config.jwt_secret.clone(),
             user_repository.clone(),
         ));
-        let progress_service = Arc::new(ProgressServiceImpl::new(user_progress_repository.clone()));
+        let progress_service = Arc::new(ProgressServiceImpl::new(
+            user_progress_repository.clone(),
+            user_repository.clone(),
+            notification_service.clone(),
+            achievement_service.clone(),
+            review_card_repository.clone(),
+            config.daily_xp_cap,
+        ));
+        let learning_path_service = Arc::new(LearningPathServiceImpl::new(
+            topic_repository.clone(),
+            lesson_repository.clone(),
+            prerequisite_repository.clone(),
+            user_progress_repository.clone(),
+        ));
+        let lesson_session_service = Arc::new(LessonSessionServiceImpl::new(
+            lesson_session_repository.clone(),
+            lesson_repository.clone(),
+            question_repository.clone(),
+            user_repository.clone(),
+            progress_service.clone(),
+            notification_service.clone(),
+            learning_path_service.clone(),
+        ));
+        let review_service = Arc::new(ReviewServiceImpl::new(
+            review_card_repository.clone(),
+            question_repository.clone(),
+            user_repository.clone(),
+            progress_service.clone(),
+        ));
+        let adaptive_learning_service = Arc::new(AdaptiveLearningServiceImpl::new(
+            question_repository.clone(),
+            topic_repository.clone(),
+            user_progress_repository.clone(),
+        ));
+        let quiz_service = Arc::new(QuizServiceImpl::new(
+            quiz_repository.clone(),
+            quiz_attempt_repository.clone(),
+            question_repository.clone(),
+        ));
+        let code_practice_service = Arc::new(CodePracticeServiceImpl::new(
+            code_practice_repository.clone(),
+            hint_usage_repository.clone(),
+            code_submission_repository.clone(),
+            user_progress_repository.clone(),
+            progress_service.clone(),
+        ));
+        let plagiarism_service = Arc::new(PlagiarismServiceImpl::new(
+            code_practice_repository.clone(),
+            code_submission_repository.clone(),
+        ));
+        let question_draft_service = Arc::new(QuestionDraftServiceImpl::new(
+            question_draft_repository.clone(),
+            question_repository.clone(),
+        ));
+        let content_version_service = Arc::new(ContentVersionServiceImpl::new(
+            content_version_repository.clone(),
+            topic_repository.clone(),
+            lesson_repository.clone(),
+            question_repository.clone(),
+            code_practice_repository.clone(),
+        ));
         let leaderboard_service =
             Arc::new(LeaderboardServiceImpl::new(leaderboard_repository.clone()));
+        let api_key_service = Arc::new(ApiKeyServiceImpl::new(api_key_repository.clone()));
+        let account_service = Arc::new(AccountServiceImpl::new(user_repository.clone()));
+        let data_privacy_service = Arc::new(DataPrivacyServiceImpl::new(
+            user_repository.clone(),
+            user_progress_repository.clone(),
+            notification_repository.clone(),
+            api_key_repository.clone(),
+            account_audit_repository.clone(),
+            LearnerActivityRepositories {
+                lesson_session_repository: lesson_session_repository.clone(),
+                quiz_attempt_repository: quiz_attempt_repository.clone(),
+                code_submission_repository: code_submission_repository.clone(),
+                hint_usage_repository: hint_usage_repository.clone(),
+                review_card_repository: review_card_repository.clone(),
+                achievement_repository: achievement_repository.clone(),
+                tutor_feedback_repository: tutor_feedback_repository.clone(),
+                ai_usage_repository: ai_usage_repository.clone(),
+            },
+            config.account_deletion_grace_days,
+        ));
 
         // Create external services
-        let gemini_service =
-            GeminiService::new(config.gemini_api_key.clone(), config.gemini_api_url.clone());
+        let llm_provider = llm_provider_from_config(config)
+            .map_err(|e| AppError::Internal(format!("Failed to create LLM provider: {e}")))?;
+        let ai_service = AiService::new(
+            llm_provider,
+            ai_usage_repository.clone(),
+            AiSettings::from_config(config),
+        );
         let email_service = EmailService::new(
             config.smtp_host.clone(),
             config.smtp_port,
@@ -130,22 +497,67 @@ impl AppState {
             config.from_name.clone(),
         )
         .map_err(|e| AppError::Internal(format!("Failed to create email service: {e}")))?;
+        let asset_storage = asset_storage_from_config(config)
+            .map_err(|e| AppError::Internal(format!("Failed to create asset storage: {e}")))?;
+        let asset_service = AssetService::new(
+            asset_storage,
+            asset_repository.clone(),
+            AssetSettings::from_config(config),
+        );
 
         // Create AppState
         Ok(Self::new(
-            user_repository,
-            topic_repository,
-            lesson_repository,
-            question_repository,
-            code_practice_repository,
-            user_progress_repository,
-            leaderboard_repository,
-            notification_repository,
-            auth_service,
-            progress_service,
-            leaderboard_service,
-            gemini_service,
-            email_service,
+            AppRepositories {
+                user_repository,
+                topic_repository,
+                lesson_repository,
+                question_repository,
+                code_practice_repository,
+                user_progress_repository,
+                leaderboard_repository,
+                notification_repository,
+                api_key_repository,
+                account_audit_repository,
+                achievement_repository,
+                lesson_session_repository,
+                prerequisite_repository,
+                review_card_repository,
+                quiz_repository,
+                quiz_attempt_repository,
+                hint_usage_repository,
+                code_submission_repository,
+                question_draft_repository,
+                tutor_feedback_repository,
+                ai_usage_repository,
+                translation_repository,
+                asset_repository,
+                content_version_repository,
+            },
+            AppServices {
+                auth_service,
+                progress_service,
+                leaderboard_service,
+                api_key_service,
+                account_service,
+                data_privacy_service,
+                notification_service,
+                achievement_service,
+                lesson_session_service,
+                learning_path_service,
+                review_service,
+                adaptive_learning_service,
+                quiz_service,
+                code_practice_service,
+                plagiarism_service,
+                question_draft_service,
+                content_version_service,
+            },
+            AppExternalServices {
+                ai_service,
+                email_service,
+                asset_service,
+            },
+            config.tutor_daily_quota,
         ))
     }
 }
@@ -199,6 +611,102 @@ impl FromRef<AppState> for Arc<dyn NotificationRepository> {
     }
 }
 
+impl FromRef<AppState> for Arc<dyn ApiKeyRepository> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.api_key_repository)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn AccountAuditRepository> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.account_audit_repository)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn AchievementRepository> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.achievement_repository)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn LessonSessionRepository> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.lesson_session_repository)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn PrerequisiteRepository> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.prerequisite_repository)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn ReviewCardRepository> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.review_card_repository)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn QuizRepository> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.quiz_repository)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn QuizAttemptRepository> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.quiz_attempt_repository)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn HintUsageRepository> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.hint_usage_repository)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn CodeSubmissionRepository> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.code_submission_repository)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn QuestionDraftRepository> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.question_draft_repository)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn TutorFeedbackRepository> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.tutor_feedback_repository)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn AiUsageRepository> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.ai_usage_repository)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn TranslationRepository> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.translation_repository)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn AssetRepository> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.asset_repository)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn ContentVersionRepository> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.content_version_repository)
+    }
+}
+
 impl FromRef<AppState> for Arc<dyn AuthService> {
     fn from_ref(app_state: &AppState) -> Self {
         Arc::clone(&app_state.auth_service)
@@ -217,6 +725,90 @@ impl FromRef<AppState> for Arc<dyn LeaderboardService> {
     }
 }
 
+impl FromRef<AppState> for Arc<dyn ApiKeyService> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.api_key_service)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn AccountService> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.account_service)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn DataPrivacyService> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.data_privacy_service)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn NotificationService> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.notification_service)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn AchievementService> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.achievement_service)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn LessonSessionService> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.lesson_session_service)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn LearningPathService> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.learning_path_service)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn ReviewService> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.review_service)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn AdaptiveLearningService> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.adaptive_learning_service)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn QuizService> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.quiz_service)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn CodePracticeService> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.code_practice_service)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn PlagiarismService> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.plagiarism_service)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn QuestionDraftService> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.question_draft_service)
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn ContentVersionService> {
+    fn from_ref(app_state: &AppState) -> Self {
+        Arc::clone(&app_state.content_version_service)
+    }
+}
+
 impl FromRef<AppState> for AuthUseCases {
     fn from_ref(app_state: &AppState) -> Self {
         app_state.auth_use_cases.clone()
@@ -235,9 +827,81 @@ impl FromRef<AppState> for ProgressUseCases {
     }
 }
 
-impl FromRef<AppState> for GeminiService {
+impl FromRef<AppState> for AccountUseCases {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.account_use_cases.clone()
+    }
+}
+
+impl FromRef<AppState> for PrivacyUseCases {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.privacy_use_cases.clone()
+    }
+}
+
+impl FromRef<AppState> for AchievementUseCases {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.achievement_use_cases.clone()
+    }
+}
+
+impl FromRef<AppState> for LessonSessionUseCases {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.lesson_session_use_cases.clone()
+    }
+}
+
+impl FromRef<AppState> for LearningPathUseCases {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.learning_path_use_cases.clone()
+    }
+}
+
+impl FromRef<AppState> for ReviewUseCases {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.review_use_cases.clone()
+    }
+}
+
+impl FromRef<AppState> for AdaptiveUseCases {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.adaptive_use_cases.clone()
+    }
+}
+
+impl FromRef<AppState> for QuizUseCases {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.quiz_use_cases.clone()
+    }
+}
+
+impl FromRef<AppState> for CodePracticeUseCases {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.code_practice_use_cases.clone()
+    }
+}
+
+impl FromRef<AppState> for TutorUseCases {
     fn from_ref(app_state: &AppState) -> Self {
-        app_state.gemini_service.clone()
+        app_state.tutor_use_cases.clone()
+    }
+}
+
+impl FromRef<AppState> for TranslationUseCases {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.translation_use_cases.clone()
+    }
+}
+
+impl FromRef<AppState> for ApiKeyUseCases {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.api_key_use_cases.clone()
+    }
+}
+
+impl FromRef<AppState> for AiService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.ai_service.clone()
     }
 }
 
@@ -246,3 +910,9 @@ impl FromRef<AppState> for EmailService {
         app_state.email_service.clone()
     }
 }
+
+impl FromRef<AppState> for AssetService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.asset_service.clone()
+    }
+}
@@ -0,0 +1,190 @@
+use anyhow::Result;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::sync::Arc;
+
+use crate::application::use_cases::adaptive_use_cases::TopicAbilityResponse;
+use crate::domain::entities::User;
+use crate::domain::services::account_service::ProfileUpdate;
+use crate::domain::services::{AccountService, AdaptiveLearningService};
+use crate::domain::value_objects::UserId;
+use crate::infrastructure::external_services::EmailService;
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateProfileRequest {
+    pub display_name: Option<String>,
+    pub preferred_language: Option<String>,
+    pub time_zone: Option<String>,
+    /// Absent leaves the avatar alone, `null` removes it.
+    #[serde(default, deserialize_with = "present_or_null")]
+    pub avatar_url: Option<Option<String>>,
+}
+
+fn present_or_null<'de, D>(deserializer: D) -> std::result::Result<Option<Option<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer).map(Some)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChangePasswordRequest {
+    pub current_password: String,
+    pub new_password: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChangeEmailRequest {
+    pub new_email: String,
+    pub current_password: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VerifyEmailRequest {
+    pub token: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProfileResponse {
+    pub id: String,
+    pub email: String,
+    pub pending_email: Option<String>,
+    pub display_name: String,
+    pub preferred_language: String,
+    pub time_zone: String,
+    pub avatar_url: Option<String>,
+    pub total_xp: u32,
+    pub current_streak_days: u32,
+    pub highest_streak_days: u32,
+    pub deletion_scheduled_for: Option<String>,
+    pub created_at: String,
+    /// Per-topic ability estimates, only included when the profile is fetched.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abilities: Option<Vec<TopicAbilityResponse>>,
+}
+
+impl From<User> for ProfileResponse {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id.to_string(),
+            email: user.email.as_str().to_string(),
+            pending_email: user.pending_email.map(|email| email.as_str().to_string()),
+            display_name: user.display_name,
+            preferred_language: user.preferred_language,
+            time_zone: user.time_zone,
+            avatar_url: user.avatar_url,
+            total_xp: user.total_xp.value(),
+            current_streak_days: user.current_streak_days,
+            highest_streak_days: user.highest_streak_days,
+            deletion_scheduled_for: user.deletion_scheduled_for.map(|t| t.to_rfc3339()),
+            created_at: user.created_at.to_rfc3339(),
+            abilities: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct EmailChangeResponse {
+    pub pending_email: String,
+    pub expires_at: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct AccountUseCases {
+    account_service: Arc<dyn AccountService>,
+    adaptive_learning_service: Arc<dyn AdaptiveLearningService>,
+    email_service: EmailService,
+}
+
+impl AccountUseCases {
+    pub fn new(
+        account_service: Arc<dyn AccountService>,
+        adaptive_learning_service: Arc<dyn AdaptiveLearningService>,
+        email_service: EmailService,
+    ) -> Self {
+        Self {
+            account_service,
+            adaptive_learning_service,
+            email_service,
+        }
+    }
+
+    pub async fn get_profile(&self, user_id: &UserId) -> Result<ProfileResponse> {
+        let user = self.account_service.get_profile(user_id).await?;
+        let abilities = self
+            .adaptive_learning_service
+            .get_abilities(user_id)
+            .await?
+            .into_iter()
+            .map(TopicAbilityResponse::from)
+            .collect();
+
+        let mut response = ProfileResponse::from(user);
+        response.abilities = Some(abilities);
+        Ok(response)
+    }
+
+    pub async fn update_profile(
+        &self,
+        user_id: &UserId,
+        request: UpdateProfileRequest,
+    ) -> Result<ProfileResponse> {
+        let update = ProfileUpdate {
+            display_name: request.display_name,
+            preferred_language: request.preferred_language,
+            time_zone: request.time_zone,
+            avatar_url: request.avatar_url,
+        };
+        let user = self.account_service.update_profile(user_id, update).await?;
+        Ok(ProfileResponse::from(user))
+    }
+
+    pub async fn change_password(
+        &self,
+        user_id: &UserId,
+        request: ChangePasswordRequest,
+    ) -> Result<()> {
+        self.account_service
+            .change_password(user_id, &request.current_password, &request.new_password)
+            .await
+    }
+
+    pub async fn request_email_change(
+        &self,
+        user_id: &UserId,
+        request: ChangeEmailRequest,
+    ) -> Result<EmailChangeResponse> {
+        let ticket = self
+            .account_service
+            .request_email_change(user_id, &request.new_email, &request.current_password)
+            .await?;
+
+        // The link goes to the new address, proving the user controls it
+        self.email_service
+            .send_email_verification_email(
+                ticket.new_email.as_str(),
+                &ticket.user.display_name,
+                &ticket.token,
+            )
+            .await?;
+
+        Ok(EmailChangeResponse {
+            pending_email: ticket.new_email.as_str().to_string(),
+            expires_at: ticket
+                .user
+                .email_verification_expires_at
+                .map(|t| t.to_rfc3339()),
+        })
+    }
+
+    pub async fn confirm_email_change(
+        &self,
+        user_id: &UserId,
+        request: VerifyEmailRequest,
+    ) -> Result<ProfileResponse> {
+        let user = self
+            .account_service
+            .confirm_email_change(user_id, &request.token)
+            .await?;
+        Ok(ProfileResponse::from(user))
+    }
+}
@@ -0,0 +1,77 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::application::use_cases::content_use_cases::LocalizedTextResponse;
+use crate::domain::services::achievement_service::AchievementProgress;
+use crate::domain::services::AchievementService;
+use crate::domain::value_objects::UserId;
+
+#[derive(Debug, Serialize)]
+pub struct AchievementResponse {
+    pub id: String,
+    pub title: LocalizedTextResponse,
+    pub description: LocalizedTextResponse,
+    pub icon: String,
+    pub xp_bonus: u32,
+    pub unlocked: bool,
+    pub unlocked_at: Option<String>,
+    pub progress: u32,
+    pub target: u32,
+}
+
+impl AchievementResponse {
+    pub fn new(entry: AchievementProgress, locales: &[&str]) -> Self {
+        Self {
+            id: entry.achievement.id,
+            title: LocalizedTextResponse::new(entry.achievement.title, locales),
+            description: LocalizedTextResponse::new(entry.achievement.description, locales),
+            icon: entry.achievement.icon,
+            xp_bonus: entry.achievement.xp_bonus,
+            unlocked: entry.unlocked_at.is_some(),
+            unlocked_at: entry.unlocked_at.map(|t| t.to_rfc3339()),
+            progress: entry.current,
+            target: entry.target,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AchievementsResponse {
+    pub unlocked_count: usize,
+    pub total: usize,
+    pub achievements: Vec<AchievementResponse>,
+}
+
+#[derive(Clone)]
+pub struct AchievementUseCases {
+    achievement_service: Arc<dyn AchievementService>,
+}
+
+impl AchievementUseCases {
+    pub fn new(achievement_service: Arc<dyn AchievementService>) -> Self {
+        Self {
+            achievement_service,
+        }
+    }
+
+    pub async fn list_achievements(
+        &self,
+        user_id: &UserId,
+        locales: &[&str],
+    ) -> Result<AchievementsResponse> {
+        let achievements: Vec<AchievementResponse> = self
+            .achievement_service
+            .list_for_user(user_id)
+            .await?
+            .into_iter()
+            .map(|entry| AchievementResponse::new(entry, locales))
+            .collect();
+
+        Ok(AchievementsResponse {
+            unlocked_count: achievements.iter().filter(|a| a.unlocked).count(),
+            total: achievements.len(),
+            achievements,
+        })
+    }
+}
@@ -0,0 +1,78 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::application::use_cases::lesson_session_use_cases::SessionQuestionResponse;
+use crate::domain::entities::TopicAbility;
+use crate::domain::services::adaptive_learning_service::NextQuestion;
+use crate::domain::services::AdaptiveLearningService;
+use crate::domain::value_objects::{TopicId, UserId};
+use crate::shared::errors::DomainError;
+
+#[derive(Debug, Serialize)]
+pub struct TopicAbilityResponse {
+    pub topic_id: String,
+    /// Elo-style rating; 1000 is level with intermediate questions.
+    pub rating: i32,
+    pub answered: u32,
+    pub correct: u32,
+    pub accuracy: Option<f64>,
+    pub recommended_difficulty: String,
+}
+
+impl From<TopicAbility> for TopicAbilityResponse {
+    fn from(ability: TopicAbility) -> Self {
+        Self {
+            topic_id: ability.topic_id.to_string(),
+            rating: ability.rating.round() as i32,
+            answered: ability.answered,
+            correct: ability.correct,
+            accuracy: ability.accuracy(),
+            recommended_difficulty: ability.recommended_difficulty().as_str().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct NextQuestionResponse {
+    pub question: SessionQuestionResponse,
+    pub expected_success: f64,
+    pub ability: TopicAbilityResponse,
+}
+
+impl NextQuestionResponse {
+    pub fn new(next: NextQuestion, locales: &[&str]) -> Self {
+        Self {
+            question: SessionQuestionResponse::new(next.question, locales),
+            expected_success: next.expected_success,
+            ability: TopicAbilityResponse::from(next.ability),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AdaptiveUseCases {
+    adaptive_learning_service: Arc<dyn AdaptiveLearningService>,
+}
+
+impl AdaptiveUseCases {
+    pub fn new(adaptive_learning_service: Arc<dyn AdaptiveLearningService>) -> Self {
+        Self {
+            adaptive_learning_service,
+        }
+    }
+
+    pub async fn next_question(
+        &self,
+        user_id: &UserId,
+        topic_id: &str,
+        locales: &[&str],
+    ) -> Result<NextQuestionResponse> {
+        let topic_id = TopicId::from_str(topic_id).map_err(DomainError::NotFound)?;
+        let next = self
+            .adaptive_learning_service
+            .next_question(user_id, &topic_id)
+            .await?;
+        Ok(NextQuestionResponse::new(next, locales))
+    }
+}
@@ -0,0 +1,99 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::domain::entities::ApiKey;
+use crate::domain::services::api_key_service::API_KEY_MARKER;
+use crate::domain::services::ApiKeyService;
+use crate::domain::value_objects::{ApiKeyId, ApiScope, UserId};
+
+#[derive(Debug, Deserialize)]
+pub struct CreateApiKeyRequest {
+    pub name: String,
+    pub scopes: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApiKeyResponse {
+    pub id: String,
+    pub name: String,
+    pub prefix: String,
+    pub scopes: Vec<String>,
+    pub last_used_at: Option<String>,
+    pub revoked_at: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreatedApiKeyResponse {
+    /// Plain text key, returned only once.
+    pub key: String,
+    pub api_key: ApiKeyResponse,
+}
+
+impl From<ApiKey> for ApiKeyResponse {
+    fn from(api_key: ApiKey) -> Self {
+        Self {
+            id: api_key.id.to_string(),
+            name: api_key.name,
+            prefix: format!("{API_KEY_MARKER}_{}", api_key.prefix),
+            scopes: api_key
+                .scopes
+                .iter()
+                .map(|scope| scope.as_str().to_string())
+                .collect(),
+            last_used_at: api_key.last_used_at.map(|t| t.to_rfc3339()),
+            revoked_at: api_key.revoked_at.map(|t| t.to_rfc3339()),
+            created_at: api_key.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ApiKeyUseCases {
+    api_key_service: Arc<dyn ApiKeyService>,
+}
+
+impl ApiKeyUseCases {
+    pub fn new(api_key_service: Arc<dyn ApiKeyService>) -> Self {
+        Self { api_key_service }
+    }
+
+    pub async fn create_api_key(
+        &self,
+        user_id: &UserId,
+        request: CreateApiKeyRequest,
+    ) -> Result<CreatedApiKeyResponse> {
+        let scopes = request
+            .scopes
+            .iter()
+            .map(|scope| ApiScope::from_str(scope))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        let issued = self
+            .api_key_service
+            .create_api_key(user_id, request.name, scopes)
+            .await?;
+
+        Ok(CreatedApiKeyResponse {
+            key: issued.plain_key,
+            api_key: ApiKeyResponse::from(issued.api_key),
+        })
+    }
+
+    pub async fn list_api_keys(&self, user_id: &UserId) -> Result<Vec<ApiKeyResponse>> {
+        let api_keys = self.api_key_service.list_api_keys(user_id).await?;
+        Ok(api_keys.into_iter().map(ApiKeyResponse::from).collect())
+    }
+
+    pub async fn revoke_api_key(&self, user_id: &UserId, key_id: &str) -> Result<ApiKeyResponse> {
+        let key_id = ApiKeyId::from_str(key_id).map_err(|e| anyhow::anyhow!(e))?;
+        let api_key = self
+            .api_key_service
+            .revoke_api_key(user_id, &key_id)
+            .await?;
+        Ok(ApiKeyResponse::from(api_key))
+    }
+}
@@ -0,0 +1,176 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::application::use_cases::content_use_cases::LocalizedTextResponse;
+use crate::domain::entities::hint_usage::HINT_XP_COST_PERCENT;
+use crate::domain::entities::CodeSubmission;
+use crate::domain::services::code_practice_service::{HintStatus, SubmissionDiff};
+use crate::domain::services::CodePracticeService;
+use crate::domain::value_objects::{CodePracticeId, CodeSubmissionId, UserId};
+use crate::shared::errors::DomainError;
+
+#[derive(Debug, Deserialize)]
+pub struct SubmissionDiffQuery {
+    /// Submission to diff from; the practice's initial code when omitted.
+    pub from: Option<String>,
+    pub to: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HintStatusResponse {
+    pub code_practice_id: String,
+    /// Hints revealed so far, in order.
+    pub hints: Vec<LocalizedTextResponse>,
+    pub hints_revealed: u32,
+    pub total_hints: u32,
+    /// Share of the practice's points each revealed hint costs.
+    pub xp_cost_percent_per_hint: u32,
+    pub xp_available: u32,
+    pub completed: bool,
+    pub solution: Option<String>,
+}
+
+impl HintStatusResponse {
+    pub fn new(status: HintStatus, locales: &[&str]) -> Self {
+        Self {
+            code_practice_id: status.code_practice_id.to_string(),
+            hints_revealed: status.revealed_hints.len() as u32,
+            hints: status
+                .revealed_hints
+                .into_iter()
+                .map(|text| LocalizedTextResponse::new(text, locales))
+                .collect(),
+            total_hints: status.total_hints,
+            xp_cost_percent_per_hint: HINT_XP_COST_PERCENT,
+            xp_available: status.xp_available.value(),
+            completed: status.completed,
+            solution: status.solution,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CodeSubmissionResponse {
+    pub id: String,
+    pub code_practice_id: String,
+    pub code: String,
+    pub verdict: String,
+    pub runner_output: Option<String>,
+    pub submitted_at: String,
+}
+
+impl From<CodeSubmission> for CodeSubmissionResponse {
+    fn from(submission: CodeSubmission) -> Self {
+        Self {
+            id: submission.id.to_string(),
+            code_practice_id: submission.code_practice_id.to_string(),
+            code: submission.code,
+            verdict: submission.verdict.as_str().to_string(),
+            runner_output: submission.runner_output,
+            submitted_at: submission.submitted_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SubmissionDiffResponse {
+    /// `None` when diffing against the practice's initial code.
+    pub from_submission_id: Option<String>,
+    pub to_submission_id: String,
+    /// Unified diff; empty when the code is unchanged.
+    pub diff: String,
+}
+
+impl From<SubmissionDiff> for SubmissionDiffResponse {
+    fn from(diff: SubmissionDiff) -> Self {
+        Self {
+            from_submission_id: diff.from.map(|from| from.id.to_string()),
+            to_submission_id: diff.to.id.to_string(),
+            diff: diff.diff,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct CodePracticeUseCases {
+    code_practice_service: Arc<dyn CodePracticeService>,
+}
+
+impl CodePracticeUseCases {
+    pub fn new(code_practice_service: Arc<dyn CodePracticeService>) -> Self {
+        Self {
+            code_practice_service,
+        }
+    }
+
+    pub async fn get_hints(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &str,
+        locales: &[&str],
+    ) -> Result<HintStatusResponse> {
+        let code_practice_id =
+            CodePracticeId::from_string(code_practice_id).map_err(DomainError::NotFound)?;
+        let status = self
+            .code_practice_service
+            .get_hint_status(user_id, &code_practice_id)
+            .await?;
+        Ok(HintStatusResponse::new(status, locales))
+    }
+
+    pub async fn reveal_next_hint(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &str,
+        locales: &[&str],
+    ) -> Result<HintStatusResponse> {
+        let code_practice_id =
+            CodePracticeId::from_string(code_practice_id).map_err(DomainError::NotFound)?;
+        let status = self
+            .code_practice_service
+            .reveal_next_hint(user_id, &code_practice_id)
+            .await?;
+        Ok(HintStatusResponse::new(status, locales))
+    }
+
+    pub async fn list_submissions(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &str,
+    ) -> Result<Vec<CodeSubmissionResponse>> {
+        let code_practice_id =
+            CodePracticeId::from_string(code_practice_id).map_err(DomainError::NotFound)?;
+        let submissions = self
+            .code_practice_service
+            .list_submissions(user_id, &code_practice_id)
+            .await?;
+        Ok(submissions
+            .into_iter()
+            .map(CodeSubmissionResponse::from)
+            .collect())
+    }
+
+    pub async fn diff_submissions(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &str,
+        query: SubmissionDiffQuery,
+    ) -> Result<SubmissionDiffResponse> {
+        let code_practice_id =
+            CodePracticeId::from_string(code_practice_id).map_err(DomainError::NotFound)?;
+        let from = query
+            .from
+            .as_deref()
+            .map(CodeSubmissionId::from_str)
+            .transpose()
+            .map_err(DomainError::Invalid)?;
+        let to = CodeSubmissionId::from_str(&query.to).map_err(DomainError::Invalid)?;
+        let diff = self
+            .code_practice_service
+            .diff_submissions(user_id, &code_practice_id, from.as_ref(), &to)
+            .await?;
+        Ok(SubmissionDiffResponse::from(diff))
+    }
+}
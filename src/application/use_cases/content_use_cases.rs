@@ -1,12 +1,17 @@
 use anyhow::Result;
 use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
-use crate::domain::entities::{CodePractice, Lesson, Question, Topic};
+use crate::domain::entities::{
+    CalloutStyle, CodePractice, Lesson, LessonBlock, Question, QuestionType, Topic,
+};
 use crate::domain::repositories::{
     CodePracticeRepository, LessonRepository, QuestionRepository, TopicRepository,
 };
-use crate::domain::value_objects::{Difficulty, LessonId, TopicId};
+use crate::domain::value_objects::{Difficulty, LessonId, LocalizedText, TopicId};
+use crate::shared::errors::DomainError;
+use crate::shared::utils::DEFAULT_LOCALE;
 
 #[derive(Debug, Serialize)]
 pub struct TopicResponse {
@@ -27,8 +32,64 @@ pub struct LessonResponse {
     pub order: u32,
 }
 
+/// A lesson with its content, as structured blocks and as one HTML document.
+#[derive(Debug, Serialize)]
+pub struct LessonDetailResponse {
+    #[serde(flatten)]
+    pub lesson: LessonResponse,
+    pub blocks: Vec<LessonBlockResponse>,
+    /// The blocks rendered in order, sanitized. Embedded questions are empty
+    /// `div.lesson-question` placeholders, to be filled from `blocks`.
+    pub html: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LessonBlockResponse {
+    Markdown {
+        text: LocalizedTextResponse,
+    },
+    Code {
+        code: String,
+        runnable: bool,
+        caption: LocalizedTextResponse,
+    },
+    Callout {
+        style: CalloutStyle,
+        text: LocalizedTextResponse,
+    },
+    Image {
+        url: String,
+        alt: LocalizedTextResponse,
+        caption: LocalizedTextResponse,
+    },
+    Question {
+        question_id: String,
+        /// Absent when the question has been deleted since.
+        question: Option<QuestionResponse>,
+    },
+}
+
+/// A question as learners see it: enough to render it, nothing that gives the
+/// answer away. The explanation is only shown after answering.
 #[derive(Debug, Serialize)]
 pub struct QuestionResponse {
+    pub id: String,
+    pub prompt: LocalizedTextResponse,
+    pub topic_id: String,
+    pub difficulty: String,
+    pub points: u32,
+    pub question_type: String,
+    pub choices: Vec<LocalizedTextResponse>,
+    /// Snippet to read, fix or start from, for code questions.
+    pub code: Option<String>,
+    /// Image shown with the prompt.
+    pub image_url: Option<String>,
+}
+
+/// Full question data, answers included, for content editors.
+#[derive(Debug, Serialize)]
+pub struct EditorQuestionResponse {
     pub id: String,
     pub prompt: LocalizedTextResponse,
     pub explanation: Option<LocalizedTextResponse>,
@@ -36,10 +97,32 @@ pub struct QuestionResponse {
     pub difficulty: String,
     pub points: u32,
     pub question_type: String,
+    pub definition: QuestionType,
+    pub image_asset_id: Option<String>,
+    pub image_url: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
 }
 
 #[derive(Debug, Serialize)]
 pub struct CodePracticeResponse {
+    pub id: String,
+    pub title: LocalizedTextResponse,
+    pub description: LocalizedTextResponse,
+    pub initial_code: String,
+    /// Hints are revealed one at a time through the hints endpoint; the solution
+    /// comes with them once unlocked.
+    pub hint_count: usize,
+    pub difficulty: String,
+    pub category: String,
+    pub lesson_id: String,
+    pub topic_id: String,
+    pub points: u32,
+}
+
+/// Full code practice data, solution included, for content editors.
+#[derive(Debug, Serialize)]
+pub struct EditorCodePracticeResponse {
     pub id: String,
     pub title: LocalizedTextResponse,
     pub description: LocalizedTextResponse,
@@ -52,81 +135,200 @@ pub struct CodePracticeResponse {
     pub lesson_id: String,
     pub topic_id: String,
     pub points: u32,
+    pub created_at: String,
+    pub updated_at: String,
 }
 
+/// Localized text resolved for the requested locale. Every translation is
+/// included as well, keyed by locale tag (`"en"`, `"id"`, ...), for clients that
+/// switch languages without refetching.
 #[derive(Debug, Serialize)]
 pub struct LocalizedTextResponse {
-    pub en: String,
-    pub id: String,
+    pub text: String,
+    /// Locale `text` is in; a fallback when the requested one has no translation.
+    pub locale: String,
+    #[serde(flatten)]
+    pub translations: BTreeMap<String, String>,
 }
 
-impl From<crate::domain::value_objects::LocalizedText> for LocalizedTextResponse {
-    fn from(text: crate::domain::value_objects::LocalizedText) -> Self {
+impl LocalizedTextResponse {
+    pub fn new(text: LocalizedText, locales: &[&str]) -> Self {
+        let (resolved_locale, resolved_text) = text
+            .resolve(locales)
+            .map(|(locale, text)| (locale.to_string(), text.to_string()))
+            .unwrap_or_else(|| {
+                let preferred = locales.first().copied().unwrap_or(DEFAULT_LOCALE);
+                (preferred.to_string(), String::new())
+            });
         Self {
-            en: text.en,
-            id: text.id,
+            text: resolved_text,
+            locale: resolved_locale,
+            translations: text.into(),
         }
     }
 }
 
-impl From<Topic> for TopicResponse {
-    fn from(topic: Topic) -> Self {
+impl TopicResponse {
+    pub fn new(topic: Topic, locales: &[&str]) -> Self {
         Self {
             id: topic.id.to_string(),
-            title: LocalizedTextResponse::from(topic.title),
-            description: LocalizedTextResponse::from(topic.description),
+            title: LocalizedTextResponse::new(topic.title, locales),
+            description: LocalizedTextResponse::new(topic.description, locales),
             order: topic.order,
-            required_skills: LocalizedTextResponse::from(topic.required_skills),
+            required_skills: LocalizedTextResponse::new(topic.required_skills, locales),
         }
     }
 }
 
-impl From<Lesson> for LessonResponse {
-    fn from(lesson: Lesson) -> Self {
+impl LessonResponse {
+    pub fn new(lesson: Lesson, locales: &[&str]) -> Self {
         Self {
             id: lesson.id.to_string(),
-            title: LocalizedTextResponse::from(lesson.title),
+            title: LocalizedTextResponse::new(lesson.title, locales),
             topic_id: lesson.topic_id.to_string(),
-            summary: LocalizedTextResponse::from(lesson.summary),
+            summary: LocalizedTextResponse::new(lesson.summary, locales),
             attribution_url: lesson.attribution_url,
             order: lesson.order,
         }
     }
 }
 
-impl From<Question> for QuestionResponse {
-    fn from(question: Question) -> Self {
+impl LessonDetailResponse {
+    /// `questions` are the lesson's embedded questions that still exist.
+    pub fn new(lesson: Lesson, questions: Vec<Question>, locales: &[&str]) -> Self {
+        let html = lesson.render_html(locales);
+        let blocks = lesson
+            .blocks
+            .clone()
+            .into_iter()
+            .map(|block| LessonBlockResponse::new(block, &questions, locales))
+            .collect();
+        Self {
+            lesson: LessonResponse::new(lesson, locales),
+            blocks,
+            html,
+        }
+    }
+}
+
+impl LessonBlockResponse {
+    pub fn new(block: LessonBlock, questions: &[Question], locales: &[&str]) -> Self {
+        match block {
+            LessonBlock::Markdown { text } => LessonBlockResponse::Markdown {
+                text: LocalizedTextResponse::new(text, locales),
+            },
+            LessonBlock::Code {
+                code,
+                runnable,
+                caption,
+            } => LessonBlockResponse::Code {
+                code,
+                runnable,
+                caption: LocalizedTextResponse::new(caption, locales),
+            },
+            LessonBlock::Callout { style, text } => LessonBlockResponse::Callout {
+                style,
+                text: LocalizedTextResponse::new(text, locales),
+            },
+            LessonBlock::Image { url, alt, caption } => LessonBlockResponse::Image {
+                url,
+                alt: LocalizedTextResponse::new(alt, locales),
+                caption: LocalizedTextResponse::new(caption, locales),
+            },
+            LessonBlock::Question { question_id } => LessonBlockResponse::Question {
+                question: questions
+                    .iter()
+                    .find(|question| question.id == question_id)
+                    .map(|question| QuestionResponse::new(question.clone(), locales)),
+                question_id: question_id.to_string(),
+            },
+        }
+    }
+}
+
+impl QuestionResponse {
+    pub fn new(question: Question, locales: &[&str]) -> Self {
+        let code = question.display_code().map(str::to_string);
+        let image_url = question.image_url();
+
         Self {
             id: question.id.to_string(),
-            prompt: LocalizedTextResponse::from(question.prompt),
-            explanation: question.explanation.map(LocalizedTextResponse::from),
+            choices: question
+                .choice_options()
+                .into_iter()
+                .map(|text| LocalizedTextResponse::new(text, locales))
+                .collect(),
+            prompt: LocalizedTextResponse::new(question.prompt, locales),
             topic_id: question.topic_id.to_string(),
             difficulty: question.difficulty.to_string(),
             points: question.points.value(),
-            question_type: format!("{:?}", question.question_type),
+            question_type: question.question_type.code().to_string(),
+            code,
+            image_url,
+        }
+    }
+}
+
+impl EditorQuestionResponse {
+    pub fn new(question: Question, locales: &[&str]) -> Self {
+        let image_url = question.image_url();
+        Self {
+            id: question.id.to_string(),
+            prompt: LocalizedTextResponse::new(question.prompt, locales),
+            explanation: question
+                .explanation
+                .map(|text| LocalizedTextResponse::new(text, locales)),
+            topic_id: question.topic_id.to_string(),
+            difficulty: question.difficulty.to_string(),
+            points: question.points.value(),
+            question_type: question.question_type.code().to_string(),
+            image_asset_id: question.image.as_ref().map(|id| id.to_string()),
+            image_url,
+            definition: question.question_type,
+            created_at: question.created_at.to_rfc3339(),
+            updated_at: question.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+impl CodePracticeResponse {
+    pub fn new(code_practice: CodePractice, locales: &[&str]) -> Self {
+        Self {
+            id: code_practice.id.to_string(),
+            title: LocalizedTextResponse::new(code_practice.title, locales),
+            description: LocalizedTextResponse::new(code_practice.description, locales),
+            initial_code: code_practice.initial_code,
+            hint_count: code_practice.hints.len(),
+            difficulty: code_practice.difficulty.to_string(),
+            category: code_practice.category,
+            lesson_id: code_practice.lesson_id.to_string(),
+            topic_id: code_practice.topic_id.to_string(),
+            points: code_practice.points.value(),
         }
     }
 }
 
-impl From<CodePractice> for CodePracticeResponse {
-    fn from(code_practice: CodePractice) -> Self {
+impl EditorCodePracticeResponse {
+    pub fn new(code_practice: CodePractice, locales: &[&str]) -> Self {
         Self {
             id: code_practice.id.to_string(),
-            title: LocalizedTextResponse::from(code_practice.title),
-            description: LocalizedTextResponse::from(code_practice.description),
+            title: LocalizedTextResponse::new(code_practice.title, locales),
+            description: LocalizedTextResponse::new(code_practice.description, locales),
             initial_code: code_practice.initial_code,
             expected_output: code_practice.expected_output,
             solution: code_practice.solution,
             hints: code_practice
                 .hints
                 .into_iter()
-                .map(LocalizedTextResponse::from)
+                .map(|text| LocalizedTextResponse::new(text, locales))
                 .collect(),
             difficulty: code_practice.difficulty.to_string(),
             category: code_practice.category,
             lesson_id: code_practice.lesson_id.to_string(),
             topic_id: code_practice.topic_id.to_string(),
             points: code_practice.points.value(),
+            created_at: code_practice.created_at.to_rfc3339(),
+            updated_at: code_practice.updated_at.to_rfc3339(),
         }
     }
 }
@@ -154,26 +356,94 @@ impl ContentUseCases {
         }
     }
 
-    pub async fn get_topics(&self) -> Result<Vec<TopicResponse>> {
+    pub async fn get_topics(&self, locales: &[&str]) -> Result<Vec<TopicResponse>> {
         let topics = self.topic_repository.list(50, 0).await?;
-        Ok(topics.into_iter().map(TopicResponse::from).collect())
+        Ok(topics
+            .into_iter()
+            .map(|topic| TopicResponse::new(topic, locales))
+            .collect())
     }
 
-    pub async fn get_lessons(&self, topic_id: Option<String>) -> Result<Vec<LessonResponse>> {
+    pub async fn get_lessons(
+        &self,
+        topic_id: Option<String>,
+        locales: &[&str],
+    ) -> Result<Vec<LessonResponse>> {
         let lessons = if let Some(topic_id_str) = topic_id {
             let topic_id = TopicId::from_str(&topic_id_str).map_err(|e| anyhow::anyhow!(e))?;
             self.lesson_repository.find_by_topic_id(&topic_id).await?
         } else {
             self.lesson_repository.list(50, 0).await?
         };
-        Ok(lessons.into_iter().map(LessonResponse::from).collect())
+        Ok(lessons
+            .into_iter()
+            .map(|lesson| LessonResponse::new(lesson, locales))
+            .collect())
+    }
+
+    pub async fn get_lesson(
+        &self,
+        lesson_id: &str,
+        locales: &[&str],
+    ) -> Result<LessonDetailResponse> {
+        let lesson_id = LessonId::from_str(lesson_id).map_err(DomainError::NotFound)?;
+        let lesson = self
+            .lesson_repository
+            .find_by_id(&lesson_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Lesson not found".to_string()))?;
+
+        let embedded: Vec<_> = lesson
+            .embedded_question_ids()
+            .into_iter()
+            .cloned()
+            .collect();
+        let found: HashMap<_, _> = self
+            .question_repository
+            .find_by_ids(&embedded)
+            .await?
+            .into_iter()
+            .map(|question| (question.id.clone(), question))
+            .collect();
+        // Keep the order the blocks embed them in
+        let questions = embedded
+            .iter()
+            .filter_map(|question_id| found.get(question_id).cloned())
+            .collect();
+        Ok(LessonDetailResponse::new(lesson, questions, locales))
     }
 
     pub async fn get_questions(
         &self,
         topic_id: Option<String>,
         difficulty: Option<String>,
+        locales: &[&str],
     ) -> Result<Vec<QuestionResponse>> {
+        let questions = self.find_questions(topic_id, difficulty).await?;
+        Ok(questions
+            .into_iter()
+            .map(|question| QuestionResponse::new(question, locales))
+            .collect())
+    }
+
+    pub async fn get_editor_questions(
+        &self,
+        topic_id: Option<String>,
+        difficulty: Option<String>,
+        locales: &[&str],
+    ) -> Result<Vec<EditorQuestionResponse>> {
+        let questions = self.find_questions(topic_id, difficulty).await?;
+        Ok(questions
+            .into_iter()
+            .map(|question| EditorQuestionResponse::new(question, locales))
+            .collect())
+    }
+
+    async fn find_questions(
+        &self,
+        topic_id: Option<String>,
+        difficulty: Option<String>,
+    ) -> Result<Vec<Question>> {
         let questions = if let Some(topic_id_str) = topic_id {
             let topic_id = TopicId::from_str(&topic_id_str).map_err(|e| anyhow::anyhow!(e))?;
             self.question_repository.find_by_topic_id(&topic_id).await?
@@ -186,14 +456,40 @@ impl ContentUseCases {
         } else {
             self.question_repository.list(50, 0).await?
         };
-        Ok(questions.into_iter().map(QuestionResponse::from).collect())
+        Ok(questions)
     }
 
     pub async fn get_code_practices(
         &self,
         topic_id: Option<String>,
         lesson_id: Option<String>,
+        locales: &[&str],
     ) -> Result<Vec<CodePracticeResponse>> {
+        let code_practices = self.find_code_practices(topic_id, lesson_id).await?;
+        Ok(code_practices
+            .into_iter()
+            .map(|code_practice| CodePracticeResponse::new(code_practice, locales))
+            .collect())
+    }
+
+    pub async fn get_editor_code_practices(
+        &self,
+        topic_id: Option<String>,
+        lesson_id: Option<String>,
+        locales: &[&str],
+    ) -> Result<Vec<EditorCodePracticeResponse>> {
+        let code_practices = self.find_code_practices(topic_id, lesson_id).await?;
+        Ok(code_practices
+            .into_iter()
+            .map(|code_practice| EditorCodePracticeResponse::new(code_practice, locales))
+            .collect())
+    }
+
+    async fn find_code_practices(
+        &self,
+        topic_id: Option<String>,
+        lesson_id: Option<String>,
+    ) -> Result<Vec<CodePractice>> {
         let code_practices = if let Some(topic_id_str) = topic_id {
             let topic_id = TopicId::from_str(&topic_id_str).map_err(|e| anyhow::anyhow!(e))?;
             self.code_practice_repository
@@ -207,9 +503,6 @@ impl ContentUseCases {
         } else {
             self.code_practice_repository.list(50, 0).await?
         };
-        Ok(code_practices
-            .into_iter()
-            .map(CodePracticeResponse::from)
-            .collect())
+        Ok(code_practices)
     }
 }
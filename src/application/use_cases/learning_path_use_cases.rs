@@ -0,0 +1,120 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::application::use_cases::content_use_cases::LocalizedTextResponse;
+use crate::domain::entities::UnlockState;
+use crate::domain::services::learning_path_service::{LessonNode, TopicNode};
+use crate::domain::services::LearningPathService;
+use crate::domain::value_objects::UserId;
+
+#[derive(Debug, Serialize)]
+pub struct PrerequisiteResponse {
+    pub id: String,
+    pub min_stars: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PathLessonResponse {
+    pub id: String,
+    pub title: LocalizedTextResponse,
+    pub order: u32,
+    pub state: UnlockState,
+    pub stars: Option<u32>,
+    pub prerequisites: Vec<PrerequisiteResponse>,
+}
+
+impl PathLessonResponse {
+    pub fn new(node: LessonNode, locales: &[&str]) -> Self {
+        Self {
+            id: node.lesson.id.to_string(),
+            title: LocalizedTextResponse::new(node.lesson.title, locales),
+            order: node.lesson.order,
+            state: node.state,
+            stars: node.stars,
+            prerequisites: node
+                .prerequisites
+                .into_iter()
+                .map(|edge| PrerequisiteResponse {
+                    id: edge.prerequisite_lesson_id.to_string(),
+                    min_stars: edge.min_stars,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PathTopicResponse {
+    pub id: String,
+    pub title: LocalizedTextResponse,
+    pub order: u32,
+    pub state: UnlockState,
+    /// Every lesson of each listed topic needs at least `min_stars`.
+    pub prerequisites: Vec<PrerequisiteResponse>,
+    pub lessons: Vec<PathLessonResponse>,
+}
+
+impl PathTopicResponse {
+    pub fn new(node: TopicNode, locales: &[&str]) -> Self {
+        Self {
+            id: node.topic.id.to_string(),
+            title: LocalizedTextResponse::new(node.topic.title, locales),
+            order: node.topic.order,
+            state: node.state,
+            prerequisites: node
+                .prerequisites
+                .into_iter()
+                .map(|edge| PrerequisiteResponse {
+                    id: edge.prerequisite_topic_id.to_string(),
+                    min_stars: edge.min_stars,
+                })
+                .collect(),
+            lessons: node
+                .lessons
+                .into_iter()
+                .map(|lesson| PathLessonResponse::new(lesson, locales))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PathResponse {
+    pub completed_topics: usize,
+    pub total_topics: usize,
+    pub topics: Vec<PathTopicResponse>,
+}
+
+#[derive(Clone)]
+pub struct LearningPathUseCases {
+    learning_path_service: Arc<dyn LearningPathService>,
+}
+
+impl LearningPathUseCases {
+    pub fn new(learning_path_service: Arc<dyn LearningPathService>) -> Self {
+        Self {
+            learning_path_service,
+        }
+    }
+
+    pub async fn get_path(&self, user_id: &UserId, locales: &[&str]) -> Result<PathResponse> {
+        let topics: Vec<PathTopicResponse> = self
+            .learning_path_service
+            .get_path(user_id)
+            .await?
+            .topics
+            .into_iter()
+            .map(|topic| PathTopicResponse::new(topic, locales))
+            .collect();
+
+        Ok(PathResponse {
+            completed_topics: topics
+                .iter()
+                .filter(|t| t.state == UnlockState::Completed)
+                .count(),
+            total_topics: topics.len(),
+            topics,
+        })
+    }
+}
@@ -0,0 +1,193 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::application::use_cases::content_use_cases::LocalizedTextResponse;
+use crate::domain::entities::Question;
+use crate::domain::services::lesson_session_service::{
+    AnswerOutcome, LessonCompletion, StartedLessonSession,
+};
+use crate::domain::services::LessonSessionService;
+use crate::domain::value_objects::{LessonId, LessonSessionId, QuestionId, UserId};
+
+#[derive(Debug, Deserialize)]
+pub struct SubmitAnswerRequest {
+    pub question_id: String,
+    /// Option index for choice questions, `true`/`false`, or free text.
+    pub answer: String,
+    #[serde(default)]
+    pub time_spent_ms: u64,
+}
+
+/// A question as shown to a learner during a session, without its answer.
+#[derive(Debug, Serialize)]
+pub struct SessionQuestionResponse {
+    pub id: String,
+    pub prompt: LocalizedTextResponse,
+    pub question_type: String,
+    pub difficulty: String,
+    pub points: u32,
+    pub choices: Vec<LocalizedTextResponse>,
+    /// Snippet to read, fix or start from, for code questions.
+    pub code: Option<String>,
+}
+
+impl SessionQuestionResponse {
+    pub fn new(question: Question, locales: &[&str]) -> Self {
+        let code = question.display_code().map(str::to_string);
+
+        Self {
+            id: question.id.to_string(),
+            choices: question
+                .choice_options()
+                .into_iter()
+                .map(|text| LocalizedTextResponse::new(text, locales))
+                .collect(),
+            prompt: LocalizedTextResponse::new(question.prompt, locales),
+            question_type: question.question_type.code().to_string(),
+            difficulty: question.difficulty.as_str().to_string(),
+            points: question.points.value(),
+            code,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct LessonSessionResponse {
+    pub session_id: String,
+    pub lesson_id: String,
+    pub lesson_title: LocalizedTextResponse,
+    pub started_at: String,
+    pub questions: Vec<SessionQuestionResponse>,
+}
+
+impl LessonSessionResponse {
+    pub fn new(started: StartedLessonSession, locales: &[&str]) -> Self {
+        Self {
+            session_id: started.session.id.to_string(),
+            lesson_id: started.lesson.id.to_string(),
+            lesson_title: LocalizedTextResponse::new(started.lesson.title, locales),
+            started_at: started.session.started_at.to_rfc3339(),
+            questions: started
+                .questions
+                .into_iter()
+                .map(|question| SessionQuestionResponse::new(question, locales))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AnswerResponse {
+    pub correct: bool,
+    pub attempts: u32,
+    pub explanation: Option<LocalizedTextResponse>,
+}
+
+impl AnswerResponse {
+    pub fn new(outcome: AnswerOutcome, locales: &[&str]) -> Self {
+        Self {
+            correct: outcome.correct,
+            attempts: outcome.attempts,
+            explanation: outcome
+                .explanation
+                .map(|text| LocalizedTextResponse::new(text, locales)),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct LessonCompletionResponse {
+    pub session_id: String,
+    pub lesson_id: String,
+    pub total_questions: usize,
+    pub correct_answers: u32,
+    pub retries: u32,
+    pub stars: u32,
+    pub perfect_score: bool,
+    pub xp_earned: u32,
+    /// XP held back by the daily cap.
+    pub xp_withheld: u32,
+    pub completed_at: String,
+}
+
+impl From<LessonCompletion> for LessonCompletionResponse {
+    fn from(completion: LessonCompletion) -> Self {
+        Self {
+            session_id: completion.session.id.to_string(),
+            lesson_id: completion.result.lesson_id.to_string(),
+            total_questions: completion.session.question_ids.len(),
+            correct_answers: completion.session.correct_answers(),
+            retries: completion.session.retries(),
+            stars: completion.stars,
+            perfect_score: completion.result.perfect_score,
+            xp_earned: completion.result.xp_earned.value(),
+            xp_withheld: completion.award.withheld.value(),
+            completed_at: completion.result.completed_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct LessonSessionUseCases {
+    lesson_session_service: Arc<dyn LessonSessionService>,
+}
+
+impl LessonSessionUseCases {
+    pub fn new(lesson_session_service: Arc<dyn LessonSessionService>) -> Self {
+        Self {
+            lesson_session_service,
+        }
+    }
+
+    pub async fn start_session(
+        &self,
+        user_id: &UserId,
+        lesson_id: &str,
+        locales: &[&str],
+    ) -> Result<LessonSessionResponse> {
+        let lesson_id = LessonId::from_str(lesson_id).map_err(|e| anyhow::anyhow!(e))?;
+        let started = self
+            .lesson_session_service
+            .start(user_id, &lesson_id)
+            .await?;
+        Ok(LessonSessionResponse::new(started, locales))
+    }
+
+    pub async fn submit_answer(
+        &self,
+        user_id: &UserId,
+        session_id: &str,
+        request: SubmitAnswerRequest,
+        locales: &[&str],
+    ) -> Result<AnswerResponse> {
+        let session_id = LessonSessionId::from_str(session_id).map_err(|e| anyhow::anyhow!(e))?;
+        let question_id =
+            QuestionId::from_string(&request.question_id).map_err(|e| anyhow::anyhow!(e))?;
+        let outcome = self
+            .lesson_session_service
+            .submit_answer(
+                user_id,
+                &session_id,
+                &question_id,
+                &request.answer,
+                request.time_spent_ms,
+            )
+            .await?;
+        Ok(AnswerResponse::new(outcome, locales))
+    }
+
+    pub async fn finish_session(
+        &self,
+        user_id: &UserId,
+        session_id: &str,
+    ) -> Result<LessonCompletionResponse> {
+        let session_id = LessonSessionId::from_str(session_id).map_err(|e| anyhow::anyhow!(e))?;
+        let completion = self
+            .lesson_session_service
+            .finish(user_id, &session_id)
+            .await?;
+        Ok(LessonCompletionResponse::from(completion))
+    }
+}
@@ -1,10 +1,34 @@
+pub mod account_use_cases;
+pub mod achievement_use_cases;
+pub mod adaptive_use_cases;
+pub mod api_key_use_cases;
 pub mod auth_use_cases;
+pub mod code_practice_use_cases;
 pub mod content_use_cases;
+pub mod learning_path_use_cases;
+pub mod lesson_session_use_cases;
+pub mod privacy_use_cases;
 pub mod progress_use_cases;
+pub mod quiz_use_cases;
+pub mod review_use_cases;
+pub mod translation_use_cases;
+pub mod tutor_use_cases;
 
 #[cfg(test)]
 mod simple_tests;
 
+pub use account_use_cases::*;
+pub use achievement_use_cases::*;
+pub use adaptive_use_cases::*;
+pub use api_key_use_cases::*;
 pub use auth_use_cases::*;
+pub use code_practice_use_cases::*;
 pub use content_use_cases::*;
+pub use learning_path_use_cases::*;
+pub use lesson_session_use_cases::*;
+pub use privacy_use_cases::*;
 pub use progress_use_cases::*;
+pub use quiz_use_cases::*;
+pub use review_use_cases::*;
+pub use translation_use_cases::*;
+pub use tutor_use_cases::*;
@@ -0,0 +1,113 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::application::use_cases::account_use_cases::ProfileResponse;
+use crate::application::use_cases::api_key_use_cases::ApiKeyResponse;
+use crate::domain::entities::user_progress::LessonResult;
+use crate::domain::entities::{
+    AccountAuditEvent, AiCallRecord, CodeSubmission, HintUsage, LessonSession, Notification,
+    QuizAttempt, ReviewCard, TutorUsage, UserAchievement, UserProgress,
+};
+use crate::domain::services::{DataPrivacyService, PurgeReport};
+use crate::domain::value_objects::UserId;
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteAccountRequest {
+    pub current_password: String,
+}
+
+/// Downloadable archive of a learner's data. Password, API key and token
+/// hashes are deliberately left out.
+#[derive(Debug, Serialize)]
+pub struct DataExportResponse {
+    pub exported_at: String,
+    pub profile: ProfileResponse,
+    pub progress: Option<UserProgress>,
+    pub lesson_results: Vec<LessonResult>,
+    pub lesson_sessions: Vec<LessonSession>,
+    pub quiz_attempts: Vec<QuizAttempt>,
+    pub code_submissions: Vec<CodeSubmission>,
+    pub hint_usage: Vec<HintUsage>,
+    pub review_cards: Vec<ReviewCard>,
+    pub achievements: Vec<UserAchievement>,
+    pub tutor_usage: Vec<TutorUsage>,
+    pub ai_calls: Vec<AiCallRecord>,
+    pub notifications: Vec<Notification>,
+    pub api_keys: Vec<ApiKeyResponse>,
+    pub account_events: Vec<AccountAuditEvent>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DeletionStatusResponse {
+    pub deletion_requested_at: Option<String>,
+    pub deletion_scheduled_for: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct PrivacyUseCases {
+    data_privacy_service: Arc<dyn DataPrivacyService>,
+}
+
+impl PrivacyUseCases {
+    pub fn new(data_privacy_service: Arc<dyn DataPrivacyService>) -> Self {
+        Self {
+            data_privacy_service,
+        }
+    }
+
+    pub async fn export_data(&self, user_id: &UserId) -> Result<DataExportResponse> {
+        let export = self.data_privacy_service.export_user_data(user_id).await?;
+
+        Ok(DataExportResponse {
+            exported_at: export.exported_at.to_rfc3339(),
+            profile: ProfileResponse::from(export.user),
+            progress: export.progress,
+            lesson_results: export.lesson_results,
+            lesson_sessions: export.lesson_sessions,
+            quiz_attempts: export.quiz_attempts,
+            code_submissions: export.code_submissions,
+            hint_usage: export.hint_usage,
+            review_cards: export.review_cards,
+            achievements: export.achievements,
+            tutor_usage: export.tutor_usage,
+            ai_calls: export.ai_calls,
+            notifications: export.notifications,
+            api_keys: export
+                .api_keys
+                .into_iter()
+                .map(ApiKeyResponse::from)
+                .collect(),
+            account_events: export.account_events,
+        })
+    }
+
+    pub async fn request_deletion(
+        &self,
+        user_id: &UserId,
+        request: DeleteAccountRequest,
+    ) -> Result<DeletionStatusResponse> {
+        let user = self
+            .data_privacy_service
+            .request_deletion(user_id, &request.current_password)
+            .await?;
+
+        Ok(DeletionStatusResponse {
+            deletion_requested_at: user.deletion_requested_at.map(|t| t.to_rfc3339()),
+            deletion_scheduled_for: user.deletion_scheduled_for.map(|t| t.to_rfc3339()),
+        })
+    }
+
+    pub async fn cancel_deletion(&self, user_id: &UserId) -> Result<DeletionStatusResponse> {
+        let user = self.data_privacy_service.cancel_deletion(user_id).await?;
+
+        Ok(DeletionStatusResponse {
+            deletion_requested_at: user.deletion_requested_at.map(|t| t.to_rfc3339()),
+            deletion_scheduled_for: user.deletion_scheduled_for.map(|t| t.to_rfc3339()),
+        })
+    }
+
+    pub async fn purge_due_accounts(&self) -> Result<PurgeReport> {
+        self.data_privacy_service.purge_due_accounts().await
+    }
+}
@@ -2,8 +2,10 @@ use anyhow::Result;
 use serde::Serialize;
 use std::sync::Arc;
 
-use crate::domain::entities::LeaderboardEntry;
+use crate::domain::entities::{LeaderboardEntry, UserProgress};
 use crate::domain::repositories::LeaderboardRepository;
+use crate::domain::services::progress_service::{DailyXpStatus, StreakStatus};
+use crate::domain::services::ProgressService;
 use crate::domain::value_objects::UserId;
 
 #[derive(Debug, Serialize)]
@@ -32,15 +34,117 @@ impl From<LeaderboardEntry> for LeaderboardEntryResponse {
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct UserProgressResponse {
+    pub user_id: String,
+    pub total_xp: u32,
+    pub current_streak_days: u32,
+    pub highest_streak_days: u32,
+    pub last_active_date: String,
+    pub questions_completed: usize,
+    pub code_practices_completed: usize,
+    pub lesson_stars: Vec<LessonStarsResponse>,
+    pub daily_xp: Option<DailyXpResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DailyXpResponse {
+    pub cap: u32,
+    pub earned_today: u32,
+    pub remaining: u32,
+    pub resets_at: String,
+}
+
+impl From<DailyXpStatus> for DailyXpResponse {
+    fn from(status: DailyXpStatus) -> Self {
+        Self {
+            cap: status.cap,
+            earned_today: status.earned_today,
+            remaining: status.remaining,
+            resets_at: status.resets_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct StreakStatusResponse {
+    pub current_streak_days: u32,
+    pub highest_streak_days: u32,
+    pub last_active_date: String,
+    pub freezes: u32,
+    pub max_freezes: u32,
+    pub freeze_price: u32,
+    pub repair: Option<StreakRepairResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StreakRepairResponse {
+    pub streak_days: u32,
+    pub price: u32,
+    pub expires_at: String,
+}
+
+impl From<StreakStatus> for StreakStatusResponse {
+    fn from(status: StreakStatus) -> Self {
+        Self {
+            current_streak_days: status.current_streak_days,
+            highest_streak_days: status.highest_streak_days,
+            last_active_date: status.last_active_date.to_string(),
+            freezes: status.freezes,
+            max_freezes: status.max_freezes,
+            freeze_price: status.freeze_price,
+            repair: status.repair.map(|offer| StreakRepairResponse {
+                streak_days: offer.streak_days,
+                price: offer.price,
+                expires_at: offer.expires_at.to_rfc3339(),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct LessonStarsResponse {
+    pub lesson_id: String,
+    pub stars: u32,
+}
+
+impl From<UserProgress> for UserProgressResponse {
+    fn from(progress: UserProgress) -> Self {
+        Self {
+            user_id: progress.user_id.to_string(),
+            total_xp: progress.total_xp.value(),
+            current_streak_days: progress.current_streak_days,
+            highest_streak_days: progress.highest_streak_days,
+            last_active_date: progress.last_active_date.to_string(),
+            questions_completed: progress.completed_questions.len(),
+            code_practices_completed: progress.completed_code_practices.len(),
+            lesson_stars: progress
+                .lesson_stars
+                .into_iter()
+                .map(|(lesson_id, stars)| LessonStarsResponse {
+                    lesson_id: lesson_id.to_string(),
+                    stars,
+                })
+                .collect(),
+            daily_xp: None,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct ProgressUseCases {
     leaderboard_repository: Arc<dyn LeaderboardRepository>,
+    progress_service: Arc<dyn ProgressService>,
 }
 
 impl ProgressUseCases {
-    pub fn new(leaderboard_repository: Arc<dyn LeaderboardRepository>) -> Self {
+    pub fn new(
+        leaderboard_repository: Arc<dyn LeaderboardRepository>,
+        progress_service: Arc<dyn ProgressService>,
+    ) -> Self {
         Self {
             leaderboard_repository,
+            progress_service,
         }
     }
 
@@ -61,4 +165,36 @@ impl ProgressUseCases {
         let user_id = UserId::from_str(user_id).map_err(|e| anyhow::anyhow!(e))?;
         self.leaderboard_repository.get_user_rank(&user_id).await
     }
+
+    pub async fn get_user_progress(&self, user_id: &UserId) -> Result<UserProgressResponse> {
+        let progress = self
+            .progress_service
+            .get_user_progress(user_id)
+            .await?
+            .unwrap_or_else(|| UserProgress::new(user_id.clone()));
+        let daily_xp = self.progress_service.get_daily_xp_status(user_id).await?;
+
+        let mut response = UserProgressResponse::from(progress);
+        response.daily_xp = Some(DailyXpResponse::from(daily_xp));
+        Ok(response)
+    }
+
+    pub async fn expire_broken_streaks(&self) -> Result<u32> {
+        self.progress_service.expire_broken_streaks().await
+    }
+
+    pub async fn get_streak_status(&self, user_id: &UserId) -> Result<StreakStatusResponse> {
+        let status = self.progress_service.get_streak_status(user_id).await?;
+        Ok(StreakStatusResponse::from(status))
+    }
+
+    pub async fn buy_streak_freeze(&self, user_id: &UserId) -> Result<StreakStatusResponse> {
+        let status = self.progress_service.buy_streak_freeze(user_id).await?;
+        Ok(StreakStatusResponse::from(status))
+    }
+
+    pub async fn repair_streak(&self, user_id: &UserId) -> Result<StreakStatusResponse> {
+        let status = self.progress_service.repair_streak(user_id).await?;
+        Ok(StreakStatusResponse::from(status))
+    }
 }
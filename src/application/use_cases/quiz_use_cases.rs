@@ -0,0 +1,281 @@
+use anyhow::Result;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::application::use_cases::content_use_cases::LocalizedTextResponse;
+use crate::application::use_cases::lesson_session_use_cases::SessionQuestionResponse;
+use crate::domain::entities::{Quiz, QuizAttempt};
+use crate::domain::services::quiz_service::QuizAttemptDetails;
+use crate::domain::services::QuizService;
+use crate::domain::value_objects::{QuestionId, QuizAttemptId, QuizId, UserId};
+use crate::shared::errors::DomainError;
+
+#[derive(Debug, Deserialize)]
+pub struct SubmitQuizAnswerRequest {
+    pub question_id: String,
+    /// Option index as shown in this attempt, `true`/`false`, or free text.
+    pub answer: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct QuizResponse {
+    pub id: String,
+    pub title: LocalizedTextResponse,
+    pub description: LocalizedTextResponse,
+    pub topic_ids: Vec<String>,
+    pub difficulties: Vec<String>,
+    pub question_count: u32,
+    pub time_limit_secs: u32,
+    pub passing_score: u32,
+    pub max_attempts: Option<u32>,
+}
+
+impl QuizResponse {
+    pub fn new(quiz: Quiz, locales: &[&str]) -> Self {
+        Self {
+            id: quiz.id.to_string(),
+            title: LocalizedTextResponse::new(quiz.title, locales),
+            description: LocalizedTextResponse::new(quiz.description, locales),
+            topic_ids: quiz.topic_ids.iter().map(|id| id.to_string()).collect(),
+            difficulties: quiz
+                .difficulties
+                .iter()
+                .map(|d| d.as_str().to_string())
+                .collect(),
+            question_count: quiz.question_count,
+            time_limit_secs: quiz.time_limit_secs,
+            passing_score: quiz.passing_score,
+            max_attempts: quiz.max_attempts,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct QuizAnswerEntryResponse {
+    pub question_id: String,
+    pub answer: String,
+}
+
+/// Per-question outcome, only shown once the attempt is closed.
+#[derive(Debug, Serialize)]
+pub struct QuizQuestionResultResponse {
+    pub question_id: String,
+    pub your_answer: Option<String>,
+    pub correct: bool,
+    /// In the same format as answers, against this attempt's option order.
+    pub expected_answer: Option<String>,
+    pub points_earned: u32,
+    pub points: u32,
+    pub explanation: Option<LocalizedTextResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct QuizResultResponse {
+    /// Percentage of the available points earned.
+    pub score: u32,
+    pub passing_score: u32,
+    pub passed: bool,
+    pub correct_answers: u32,
+    pub total_questions: usize,
+    pub earned_points: u32,
+    pub total_points: u32,
+    pub time_taken_secs: i64,
+    pub questions: Vec<QuizQuestionResultResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct QuizAttemptResponse {
+    pub attempt_id: String,
+    pub quiz_id: String,
+    pub quiz_title: LocalizedTextResponse,
+    pub started_at: String,
+    pub deadline: String,
+    pub remaining_secs: i64,
+    pub submitted_at: Option<String>,
+    pub questions: Vec<SessionQuestionResponse>,
+    pub answers: Vec<QuizAnswerEntryResponse>,
+    pub result: Option<QuizResultResponse>,
+}
+
+impl QuizAttemptResponse {
+    pub fn new(details: QuizAttemptDetails, locales: &[&str]) -> Self {
+        let QuizAttemptDetails {
+            attempt,
+            quiz,
+            questions,
+        } = details;
+
+        let result = attempt.submitted_at.map(|submitted_at| QuizResultResponse {
+            score: attempt.score(),
+            passing_score: quiz.passing_score,
+            passed: attempt.passed(quiz.passing_score),
+            correct_answers: attempt.correct_answers(),
+            total_questions: attempt.items.len(),
+            earned_points: attempt.earned_points(),
+            total_points: attempt.total_points(),
+            time_taken_secs: (submitted_at - attempt.started_at).num_seconds(),
+            questions: questions
+                .iter()
+                .map(|question| {
+                    let answer = attempt.answer_for(&question.id);
+                    let correct = answer.is_some_and(|a| a.correct);
+                    let points = attempt
+                        .item(&question.id)
+                        .map_or(0, |item| item.points.value());
+                    QuizQuestionResultResponse {
+                        question_id: question.id.to_string(),
+                        your_answer: answer.map(|a| a.user_answer.clone()),
+                        correct,
+                        expected_answer: question.expected_answer(),
+                        points_earned: if correct { points } else { 0 },
+                        points,
+                        explanation: question
+                            .explanation
+                            .clone()
+                            .map(|text| LocalizedTextResponse::new(text, locales)),
+                    }
+                })
+                .collect(),
+        });
+
+        Self {
+            attempt_id: attempt.id.to_string(),
+            quiz_id: quiz.id.to_string(),
+            quiz_title: LocalizedTextResponse::new(quiz.title, locales),
+            started_at: attempt.started_at.to_rfc3339(),
+            deadline: attempt.deadline.to_rfc3339(),
+            remaining_secs: attempt.remaining_secs(Utc::now()),
+            submitted_at: attempt.submitted_at.map(|at| at.to_rfc3339()),
+            questions: questions
+                .into_iter()
+                .map(|question| SessionQuestionResponse::new(question, locales))
+                .collect(),
+            answers: attempt
+                .answers
+                .iter()
+                .map(|a| QuizAnswerEntryResponse {
+                    question_id: a.question_id.to_string(),
+                    answer: a.user_answer.clone(),
+                })
+                .collect(),
+            result,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct QuizAnswerSavedResponse {
+    pub answered: usize,
+    pub total_questions: usize,
+    pub remaining_secs: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct QuizAttemptSummaryResponse {
+    pub attempt_id: String,
+    pub started_at: String,
+    pub submitted_at: Option<String>,
+    /// Only known once the attempt is closed.
+    pub score: Option<u32>,
+}
+
+impl From<QuizAttempt> for QuizAttemptSummaryResponse {
+    fn from(attempt: QuizAttempt) -> Self {
+        Self {
+            attempt_id: attempt.id.to_string(),
+            started_at: attempt.started_at.to_rfc3339(),
+            submitted_at: attempt.submitted_at.map(|at| at.to_rfc3339()),
+            score: attempt.is_submitted().then(|| attempt.score()),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct QuizUseCases {
+    quiz_service: Arc<dyn QuizService>,
+}
+
+impl QuizUseCases {
+    pub fn new(quiz_service: Arc<dyn QuizService>) -> Self {
+        Self { quiz_service }
+    }
+
+    pub async fn list_quizzes(&self, locales: &[&str]) -> Result<Vec<QuizResponse>> {
+        let quizzes = self.quiz_service.list_quizzes().await?;
+        Ok(quizzes
+            .into_iter()
+            .map(|quiz| QuizResponse::new(quiz, locales))
+            .collect())
+    }
+
+    pub async fn start_attempt(
+        &self,
+        user_id: &UserId,
+        quiz_id: &str,
+        locales: &[&str],
+    ) -> Result<QuizAttemptResponse> {
+        let quiz_id = QuizId::from_str(quiz_id).map_err(DomainError::NotFound)?;
+        let details = self.quiz_service.start_attempt(user_id, &quiz_id).await?;
+        Ok(QuizAttemptResponse::new(details, locales))
+    }
+
+    pub async fn submit_answer(
+        &self,
+        user_id: &UserId,
+        attempt_id: &str,
+        request: SubmitQuizAnswerRequest,
+    ) -> Result<QuizAnswerSavedResponse> {
+        let attempt_id = QuizAttemptId::from_str(attempt_id).map_err(DomainError::NotFound)?;
+        let question_id =
+            QuestionId::from_string(&request.question_id).map_err(DomainError::Invalid)?;
+        let attempt = self
+            .quiz_service
+            .submit_answer(user_id, &attempt_id, &question_id, &request.answer)
+            .await?;
+        Ok(QuizAnswerSavedResponse {
+            answered: attempt.answers.len(),
+            total_questions: attempt.items.len(),
+            remaining_secs: attempt.remaining_secs(Utc::now()),
+        })
+    }
+
+    pub async fn submit_attempt(
+        &self,
+        user_id: &UserId,
+        attempt_id: &str,
+        locales: &[&str],
+    ) -> Result<QuizAttemptResponse> {
+        let attempt_id = QuizAttemptId::from_str(attempt_id).map_err(DomainError::NotFound)?;
+        let details = self
+            .quiz_service
+            .submit_attempt(user_id, &attempt_id)
+            .await?;
+        Ok(QuizAttemptResponse::new(details, locales))
+    }
+
+    pub async fn get_attempt(
+        &self,
+        user_id: &UserId,
+        attempt_id: &str,
+        locales: &[&str],
+    ) -> Result<QuizAttemptResponse> {
+        let attempt_id = QuizAttemptId::from_str(attempt_id).map_err(DomainError::NotFound)?;
+        let details = self.quiz_service.get_attempt(user_id, &attempt_id).await?;
+        Ok(QuizAttemptResponse::new(details, locales))
+    }
+
+    pub async fn list_attempts(
+        &self,
+        user_id: &UserId,
+        quiz_id: &str,
+    ) -> Result<Vec<QuizAttemptSummaryResponse>> {
+        let quiz_id = QuizId::from_str(quiz_id).map_err(DomainError::NotFound)?;
+        let attempts = self.quiz_service.list_attempts(user_id, &quiz_id).await?;
+        Ok(attempts
+            .into_iter()
+            .map(QuizAttemptSummaryResponse::from)
+            .collect())
+    }
+}
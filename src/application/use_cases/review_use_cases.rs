@@ -0,0 +1,128 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::application::use_cases::content_use_cases::LocalizedTextResponse;
+use crate::application::use_cases::lesson_session_use_cases::SessionQuestionResponse;
+use crate::domain::entities::review_card::REVIEW_XP;
+use crate::domain::services::review_service::{ReviewItem, ReviewOutcome, ReviewSet};
+use crate::domain::services::ReviewService;
+use crate::domain::value_objects::{QuestionId, UserId};
+
+#[derive(Debug, Deserialize)]
+pub struct SubmitReviewRequest {
+    pub question_id: String,
+    /// Option index for choice questions, `true`/`false`, or free text.
+    pub answer: String,
+    #[serde(default)]
+    pub time_spent_ms: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReviewQuestionResponse {
+    #[serde(flatten)]
+    pub question: SessionQuestionResponse,
+    pub due_date: String,
+    pub interval_days: u32,
+    pub repetitions: u32,
+}
+
+impl ReviewQuestionResponse {
+    pub fn new(item: ReviewItem, locales: &[&str]) -> Self {
+        Self {
+            question: SessionQuestionResponse::new(item.question, locales),
+            due_date: item.card.due_date.to_string(),
+            interval_days: item.card.interval_days,
+            repetitions: item.card.repetitions,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReviewSetResponse {
+    pub date: String,
+    pub due_total: u64,
+    pub xp_per_review: u32,
+    pub questions: Vec<ReviewQuestionResponse>,
+}
+
+impl ReviewSetResponse {
+    pub fn new(set: ReviewSet, locales: &[&str]) -> Self {
+        Self {
+            date: set.date.to_string(),
+            due_total: set.due_total,
+            xp_per_review: REVIEW_XP,
+            questions: set
+                .items
+                .into_iter()
+                .map(|item| ReviewQuestionResponse::new(item, locales))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReviewAnswerResponse {
+    pub correct: bool,
+    pub xp_earned: u32,
+    /// XP held back by the daily cap.
+    pub xp_withheld: u32,
+    pub next_due_date: String,
+    pub interval_days: u32,
+    pub explanation: Option<LocalizedTextResponse>,
+}
+
+impl ReviewAnswerResponse {
+    pub fn new(outcome: ReviewOutcome, locales: &[&str]) -> Self {
+        Self {
+            correct: outcome.correct,
+            xp_earned: outcome.award.granted.value(),
+            xp_withheld: outcome.award.withheld.value(),
+            next_due_date: outcome.card.due_date.to_string(),
+            interval_days: outcome.card.interval_days,
+            explanation: outcome
+                .explanation
+                .map(|text| LocalizedTextResponse::new(text, locales)),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ReviewUseCases {
+    review_service: Arc<dyn ReviewService>,
+}
+
+impl ReviewUseCases {
+    pub fn new(review_service: Arc<dyn ReviewService>) -> Self {
+        Self { review_service }
+    }
+
+    pub async fn get_review_set(
+        &self,
+        user_id: &UserId,
+        locales: &[&str],
+    ) -> Result<ReviewSetResponse> {
+        let set = self.review_service.get_review_set(user_id).await?;
+        Ok(ReviewSetResponse::new(set, locales))
+    }
+
+    pub async fn submit_review(
+        &self,
+        user_id: &UserId,
+        request: SubmitReviewRequest,
+        locales: &[&str],
+    ) -> Result<ReviewAnswerResponse> {
+        let question_id =
+            QuestionId::from_string(&request.question_id).map_err(|e| anyhow::anyhow!(e))?;
+        let outcome = self
+            .review_service
+            .submit_review(
+                user_id,
+                &question_id,
+                &request.answer,
+                request.time_spent_ms,
+            )
+            .await?;
+        Ok(ReviewAnswerResponse::new(outcome, locales))
+    }
+}
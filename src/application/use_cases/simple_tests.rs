@@ -1,8 +1,11 @@
 #[cfg(test)]
 mod tests {
     use crate::application::TopicResponse;
+    use crate::domain::entities::user_progress::StreakCheck;
     use crate::domain::entities::*;
     use crate::domain::value_objects::*;
+    use chrono::Utc;
+    use std::str::FromStr;
 
     #[test]
     fn test_topic_response_from_topic() {
@@ -18,10 +21,11 @@ mod tests {
                 "Pengetahuan pemrograman".to_string(),
             ),
         );
-        let response = TopicResponse::from(topic);
+        let response = TopicResponse::new(topic, &["id"]);
 
-        assert_eq!(response.title.en, "Rust Basics");
-        assert_eq!(response.title.id, "Dasar Rust");
+        assert_eq!(response.title.text, "Dasar Rust");
+        assert_eq!(response.title.locale, "id");
+        assert_eq!(response.title.translations["en"], "Rust Basics");
         assert_eq!(response.order, 1);
     }
 
@@ -63,4 +67,1275 @@ mod tests {
         assert!(Password::new("Password123").is_err()); // No special char
         assert!(Password::new("Password123!").is_err()); // Contains common pattern
     }
+
+    #[test]
+    fn test_api_scope_parsing() {
+        assert_eq!(
+            ApiScope::from_str("content:read").unwrap(),
+            ApiScope::ContentRead
+        );
+        assert_eq!(
+            ApiScope::from_str("progress:read").unwrap(),
+            ApiScope::ProgressRead
+        );
+        assert!(ApiScope::from_str("admin").is_err());
+    }
+
+    #[test]
+    fn test_daily_xp_cap_and_reset() {
+        use chrono::NaiveDate;
+
+        let day_one = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let day_two = NaiveDate::from_ymd_opt(2024, 3, 2).unwrap();
+        let mut progress = UserProgress::new(UserId::new());
+        progress.last_xp_reset_date = day_one;
+
+        let award = progress.award_xp(Points::new(80), 100, day_one);
+        assert_eq!(award.granted, Points::new(80));
+        assert_eq!(award.withheld, Points::new(0));
+
+        let award = progress.award_xp(Points::new(50), 100, day_one);
+        assert_eq!(award.granted, Points::new(20));
+        assert_eq!(award.withheld, Points::new(30));
+        assert_eq!(progress.remaining_daily_xp(100, day_one), 0);
+        assert_eq!(progress.total_xp, Points::new(100));
+
+        // A per-user override wins over the global cap, and the budget resets the next day
+        progress.daily_xp_cap = Some(40);
+        assert_eq!(progress.remaining_daily_xp(100, day_two), 40);
+        let award = progress.award_xp(Points::new(50), 100, day_two);
+        assert_eq!(award.granted, Points::new(40));
+        assert_eq!(progress.xp_earned_today, 40);
+    }
+
+    #[test]
+    fn test_streak_follows_local_activity_days() {
+        use chrono::NaiveDate;
+
+        let day = |d| NaiveDate::from_ymd_opt(2024, 3, d).unwrap();
+        let mut progress = UserProgress::new(UserId::new());
+        progress.last_active_date = day(1);
+        progress.current_streak_days = 0;
+
+        progress.record_activity(day(1));
+        assert_eq!(progress.current_streak_days, 1);
+        progress.record_activity(day(1));
+        assert_eq!(progress.current_streak_days, 1);
+        progress.record_activity(day(2));
+        progress.record_activity(day(3));
+        assert_eq!(progress.current_streak_days, 3);
+
+        // Still alive the day after the last activity, broken once a full day is missed
+        let now = Utc::now();
+        assert_eq!(progress.check_streak(day(4), now), StreakCheck::Intact);
+        assert!(matches!(
+            progress.check_streak(day(5), now),
+            StreakCheck::Broken {
+                lost_streak_days: 3,
+                ..
+            }
+        ));
+        assert_eq!(progress.current_streak_days, 0);
+        assert_eq!(progress.highest_streak_days, 3);
+
+        progress.record_activity(day(6));
+        assert_eq!(progress.current_streak_days, 1);
+    }
+
+    #[test]
+    fn test_streak_freezes_and_repair() {
+        use crate::domain::entities::user_progress::{
+            MAX_STREAK_FREEZES, STREAK_FREEZE_PRICE, STREAK_REPAIR_PRICE,
+        };
+        use chrono::{Duration, NaiveDate};
+
+        let day = |d| NaiveDate::from_ymd_opt(2024, 3, d).unwrap();
+        let now = Utc::now();
+        let mut progress = UserProgress::new(UserId::new());
+        progress.last_active_date = day(1);
+        progress.current_streak_days = 0;
+        progress.total_xp = Points::new(1000);
+
+        // A week of activity earns a freeze
+        for d in 1..=7 {
+            progress.record_activity(day(d));
+        }
+        assert_eq!(progress.streak_freezes, 1);
+
+        assert!(progress
+            .buy_streak_freeze(Points::new(STREAK_FREEZE_PRICE))
+            .is_ok());
+        assert_eq!(progress.streak_freezes, MAX_STREAK_FREEZES);
+        assert_eq!(progress.total_xp, Points::new(1000 - STREAK_FREEZE_PRICE));
+        assert!(progress
+            .buy_streak_freeze(Points::new(STREAK_FREEZE_PRICE))
+            .is_err());
+
+        // Two missed days are covered by both freezes
+        assert_eq!(
+            progress.check_streak(day(10), now),
+            StreakCheck::Frozen { freezes_used: 2 }
+        );
+        progress.record_activity(day(10));
+        assert_eq!(progress.current_streak_days, 8);
+        assert_eq!(progress.streak_freezes, 0);
+
+        // Without freezes the streak breaks, and can be repaired within the window
+        assert!(matches!(
+            progress.check_streak(day(13), now),
+            StreakCheck::Broken { .. }
+        ));
+        assert!(progress
+            .repair_streak(
+                Points::new(STREAK_REPAIR_PRICE),
+                day(13),
+                now + Duration::hours(72)
+            )
+            .is_err());
+        let restored = progress
+            .repair_streak(Points::new(STREAK_REPAIR_PRICE), day(13), now)
+            .unwrap();
+        assert_eq!(restored, 8);
+        progress.record_activity(day(13));
+        assert_eq!(progress.current_streak_days, 9);
+        assert!(!progress.can_repair_streak(now));
+
+        // Purchases cannot overdraw XP
+        assert!(progress
+            .buy_streak_freeze(Points::new(STREAK_FREEZE_PRICE))
+            .is_ok());
+        assert!(progress
+            .buy_streak_freeze(Points::new(STREAK_FREEZE_PRICE))
+            .is_err());
+        assert_eq!(progress.total_xp, Points::new(100));
+    }
+    #[test]
+    fn test_question_answer_checking() {
+        let topic_id = TopicId::new();
+        let question = |question_type| {
+            Question::new(
+                LocalizedText::from_english("Prompt".to_string()),
+                topic_id.clone(),
+                Difficulty::Beginner,
+                Points::new(10),
+                question_type,
+            )
+        };
+
+        let mcq = question(QuestionType::MultipleChoice {
+            choices: LocalizedText::new("a\nb\nc".to_string(), "x\ny\nz".to_string()),
+            correct_index: 1,
+        });
+        assert!(mcq.check_answer(" 1 "));
+        assert!(!mcq.check_answer("0"));
+        let options = mcq.choice_options();
+        assert_eq!(options.len(), 3);
+        assert_eq!((options[1].get("en"), options[1].get("id")), ("b", "y"));
+
+        let tf = question(QuestionType::TrueFalse { answer: false });
+        assert!(tf.check_answer("False"));
+        assert!(!tf.check_answer("yes"));
+
+        let fib = question(QuestionType::FillInBlank {
+            acceptable_answers: vec!["Vec".to_string()],
+        });
+        assert!(fib.check_answer("vec"));
+
+        let predict = question(QuestionType::CodeOutputPrediction {
+            code: "fn main() {}".to_string(),
+            expected_stdout: "1\n2\n".to_string(),
+        });
+        assert!(predict.check_answer("1  \r\n2"));
+
+        let code_writing = |must_include: Option<Vec<String>>| {
+            question(QuestionType::CodeWriting {
+                scaffold: "fn main() {}".to_string(),
+                validators: crate::domain::entities::question::CodeValidators {
+                    must_include,
+                    must_not_include: Some(Vec::new()),
+                    test_cases: None,
+                },
+            })
+        };
+        assert!(code_writing(Some(vec!["println!".to_string()])).check_answer("println!(\"hi\");"));
+        // Without static checks there is nothing to mark an answer correct against
+        assert!(!code_writing(None).check_answer("x"));
+        assert!(!code_writing(Some(Vec::new())).check_answer("fn main() {}"));
+    }
+
+    #[test]
+    fn test_lesson_session_stars_and_result() {
+        use crate::domain::entities::lesson_session::PERFECT_LESSON_XP_BONUS;
+
+        let questions: Vec<QuestionId> = (0..5).map(|_| QuestionId::new()).collect();
+        let new_session = || LessonSession::new(UserId::new(), LessonId::new(), questions.clone());
+        let answer = |session: &mut LessonSession, i: usize, correct: bool| {
+            session
+                .record_answer(
+                    questions[i].clone(),
+                    "answer".to_string(),
+                    correct,
+                    1000,
+                    Points::new(10),
+                )
+                .map(|a| a.attempts)
+        };
+
+        let mut perfect = new_session();
+        for i in 0..5 {
+            answer(&mut perfect, i, true).unwrap();
+        }
+        assert_eq!(perfect.stars(), 3);
+        let result = perfect.finish().unwrap();
+        assert!(result.perfect_score);
+        assert_eq!(result.xp_earned, Points::new(50 + PERFECT_LESSON_XP_BONUS));
+        assert!(perfect.finish().is_err());
+        assert!(answer(&mut perfect, 0, true).is_err());
+
+        // One retry costs half a question: 4.5 / 5 = 0.9
+        let mut retried = new_session();
+        assert_eq!(answer(&mut retried, 0, false), Ok(1));
+        assert_eq!(answer(&mut retried, 0, true), Ok(2));
+        assert!(answer(&mut retried, 0, true).is_err());
+        for i in 1..5 {
+            answer(&mut retried, i, true).unwrap();
+        }
+        assert_eq!(retried.stars(), 2);
+        let result = retried.finish().unwrap();
+        assert!(!result.perfect_score);
+        assert_eq!(result.xp_earned, Points::new(50));
+
+        // Three of five with unanswered questions counting as wrong
+        let mut partial = new_session();
+        for i in 0..3 {
+            answer(&mut partial, i, true).unwrap();
+        }
+        assert_eq!(partial.stars(), 1);
+        answer(&mut partial, 3, false).unwrap();
+        answer(&mut partial, 3, false).unwrap();
+        assert_eq!(partial.stars(), 1);
+        answer(&mut partial, 4, false).unwrap();
+        answer(&mut partial, 4, false).unwrap();
+        assert_eq!(partial.stars(), 0);
+
+        // Best stars are kept when a lesson is repeated
+        let mut progress = UserProgress::new(UserId::new());
+        let today = Utc::now().date_naive();
+        let mut repeat = result.clone();
+        progress.complete_lesson(&mut result.clone(), 3, 1000, today);
+        let award = progress.complete_lesson(&mut repeat, 1, 1000, today);
+        assert_eq!(progress.lesson_stars, vec![(repeat.lesson_id.clone(), 3)]);
+        assert_eq!(award.granted, Points::new(50));
+        assert_eq!(progress.total_xp, Points::new(100));
+    }
+
+    #[test]
+    fn test_dependency_graph_reachability() {
+        use crate::domain::entities::prerequisite::DependencyGraph;
+
+        // c requires b, b requires a
+        let mut graph = DependencyGraph::new();
+        graph.add_edge("b", "a");
+        graph.add_edge("c", "b");
+
+        // Adding "a requires c" would close the loop, "c requires a" would not
+        assert!(graph.reaches(&["c"], &["a"]));
+        assert!(!graph.reaches(&["a"], &["c"]));
+        assert!(!graph.reaches(&["d"], &["a"]));
+        assert!(graph.reaches(&["a", "c"], &["b"]));
+        assert_eq!(UnlockState::Completed.as_str(), "completed");
+    }
+
+    #[test]
+    fn test_review_card_sm2_schedule() {
+        use crate::domain::entities::review_card::{interleave_by_key, MIN_EASE_FACTOR};
+        use chrono::Duration;
+
+        let today = Utc::now().date_naive();
+        let mut card = ReviewCard::new(UserId::new(), QuestionId::new(), today);
+        assert!(card.is_due(today));
+
+        assert_eq!(ReviewCard::recall_quality(true, 2_000), 5);
+        assert_eq!(ReviewCard::recall_quality(true, 0), 4);
+        assert_eq!(ReviewCard::recall_quality(true, 60_000), 3);
+        assert_eq!(ReviewCard::recall_quality(false, 2_000), 1);
+
+        // 1 day, 6 days, then interval times ease
+        card.review(4, today);
+        assert_eq!(
+            (card.interval_days, card.due_date),
+            (1, today + Duration::days(1))
+        );
+        card.review(4, today);
+        assert_eq!(card.interval_days, 6);
+        card.review(5, today);
+        assert_eq!(card.interval_days, 15);
+        assert!(!card.is_due(today));
+        assert!((card.ease_factor - 2.6).abs() < 1e-9);
+
+        // Forgetting starts over and makes the card harder
+        card.review(1, today);
+        assert_eq!(
+            (card.repetitions, card.interval_days, card.lapses),
+            (0, 1, 1)
+        );
+        assert!(card.ease_factor < 2.6);
+        for _ in 0..10 {
+            card.review(0, today);
+        }
+        assert_eq!(card.ease_factor, MIN_EASE_FACTOR);
+
+        let mixed = interleave_by_key(
+            vec![("a", 1), ("a", 2), ("a", 3), ("b", 4), ("c", 5)],
+            |x| x.0,
+        );
+        let order: Vec<i32> = mixed.iter().map(|x| x.1).collect();
+        assert_eq!(order, vec![1, 4, 5, 2, 3]);
+    }
+
+    #[test]
+    fn test_topic_ability_elo_updates() {
+        use crate::domain::entities::topic_ability::INITIAL_ABILITY;
+
+        let mut ability = TopicAbility::new(TopicId::new());
+        assert_eq!(ability.rating, INITIAL_ABILITY);
+        assert!((ability.expected_success(Difficulty::Intermediate) - 0.5).abs() < 1e-9);
+        assert!(ability.expected_success(Difficulty::Beginner) > 0.7);
+        assert!(ability.accuracy().is_none());
+
+        // Even odds move the rating by half the K factor
+        ability.record(Difficulty::Intermediate, true);
+        assert!((ability.rating - 1016.0).abs() < 1e-9);
+        ability.record(Difficulty::Intermediate, false);
+        assert!(ability.rating < 1016.0);
+        assert_eq!(ability.accuracy(), Some(0.5));
+
+        let mut struggling = TopicAbility::new(TopicId::new());
+        for _ in 0..20 {
+            struggling.record(Difficulty::Beginner, false);
+        }
+        assert_eq!(struggling.recommended_difficulty(), Difficulty::Beginner);
+
+        let mut strong = TopicAbility::new(TopicId::new());
+        for _ in 0..40 {
+            strong.record(Difficulty::Advanced, true);
+        }
+        assert_eq!(strong.recommended_difficulty(), Difficulty::Advanced);
+    }
+
+    #[test]
+    fn test_quiz_attempt_deadline_and_choice_shuffle() {
+        use chrono::Duration;
+
+        let question = Question::new(
+            LocalizedText::new("Pick B".to_string(), "Pilih B".to_string()),
+            TopicId::new(),
+            Difficulty::Beginner,
+            Points::new(10),
+            QuestionType::MultipleChoice {
+                choices: LocalizedText::new("A\nB\nC".to_string(), "A\nB\nC".to_string()),
+                correct_index: 1,
+            },
+        );
+        // Shown as C, A, B: the correct option moves to position 2
+        let shuffled = question.with_choice_order(&[2, 0, 1]);
+        assert_eq!(shuffled.choice_options()[0].english(), "C");
+        assert!(shuffled.check_answer("2"));
+        assert!(!shuffled.check_answer("1"));
+        assert_eq!(shuffled.expected_answer().as_deref(), Some("2"));
+        // Orders that are not a permutation leave the question as stored
+        assert!(question.with_choice_order(&[0, 0, 1]).check_answer("1"));
+
+        let quiz = Quiz::new(
+            LocalizedText::from_english("Monthly check".to_string()),
+            LocalizedText::from_english(String::new()),
+            vec![question.topic_id.clone()],
+            2,
+            600,
+        );
+        assert!(quiz.validate().is_ok());
+        assert!(quiz.includes(&question));
+
+        let started = Utc::now();
+        let other = QuestionId::new();
+        let mut attempt = QuizAttempt::new(
+            &quiz,
+            UserId::new(),
+            vec![
+                QuizAttemptItem {
+                    question_id: question.id.clone(),
+                    choice_order: vec![2, 0, 1],
+                    points: Points::new(10),
+                },
+                QuizAttemptItem {
+                    question_id: other.clone(),
+                    choice_order: Vec::new(),
+                    points: Points::new(30),
+                },
+            ],
+            started,
+        );
+        assert_eq!(attempt.deadline, started + Duration::seconds(600));
+        assert!(attempt.is_open(started));
+
+        // Answers can be changed until the deadline
+        attempt
+            .record_answer(question.id.clone(), "0".to_string(), false, started)
+            .unwrap();
+        attempt
+            .record_answer(question.id.clone(), "2".to_string(), true, started)
+            .unwrap();
+        assert_eq!(attempt.answers.len(), 1);
+        assert!(attempt
+            .record_answer(QuestionId::new(), "1".to_string(), true, started)
+            .is_err());
+        let late = started + Duration::seconds(601);
+        assert!(attempt
+            .record_answer(other.clone(), "x".to_string(), true, late)
+            .is_err());
+        assert_eq!(attempt.remaining_secs(late), 0);
+
+        // A late submission closes at the deadline
+        attempt.submit(late).unwrap();
+        assert_eq!(attempt.submitted_at, Some(attempt.deadline));
+        assert!(attempt.submit(late).is_err());
+        assert_eq!(attempt.earned_points(), 10);
+        assert_eq!(attempt.score(), 25);
+        assert!(attempt.passed(25));
+        assert!(!attempt.passed(quiz.passing_score));
+    }
+
+    #[test]
+    fn test_hint_usage_lowers_code_practice_xp() {
+        let mut practice = CodePractice::new(
+            LocalizedText::from_english("Sum".to_string()),
+            LocalizedText::from_english("Add two numbers".to_string()),
+            "fn main() {}".to_string(),
+            "fn main() { println!(\"3\"); }".to_string(),
+            Difficulty::Beginner,
+            "basics".to_string(),
+            LessonId::new(),
+            TopicId::new(),
+            Points::new(50),
+        );
+        practice.add_hint(LocalizedText::from_english("Use +".to_string()));
+        practice.add_hint(LocalizedText::from_english("Print it".to_string()));
+
+        assert_eq!(practice.xp_with_hints(0).value(), 50);
+        assert_eq!(practice.xp_with_hints(1).value(), 40);
+        assert_eq!(practice.xp_with_hints(2).value(), 30);
+        // The cost never goes below zero XP
+        assert_eq!(practice.xp_with_hints(6).value(), 0);
+
+        let mut usage = HintUsage::new(UserId::new(), practice.id.clone());
+        assert_eq!(usage.reveal_next(2), Ok(0));
+        assert_eq!(usage.reveal_next(2), Ok(1));
+        assert!(usage.reveal_next(2).is_err());
+        assert_eq!(usage.hints_revealed, 2);
+    }
+
+    #[test]
+    fn test_learner_content_responses_hide_answers() {
+        use crate::application::{
+            CodePracticeResponse, EditorCodePracticeResponse, EditorQuestionResponse,
+            QuestionResponse,
+        };
+
+        let question = |question_type: QuestionType| {
+            let mut question = Question::new(
+                LocalizedText::from_english("Prompt".to_string()),
+                TopicId::new(),
+                Difficulty::Beginner,
+                Points::new(10),
+                question_type,
+            );
+            question.set_explanation(LocalizedText::from_english("Because".to_string()));
+            question
+        };
+        let questions = vec![
+            question(QuestionType::MultipleChoice {
+                choices: LocalizedText::from_english("a\nb".to_string()),
+                correct_index: 1,
+            }),
+            question(QuestionType::TrueFalse { answer: true }),
+            question(QuestionType::FillInBlank {
+                acceptable_answers: vec!["let".to_string()],
+            }),
+            question(QuestionType::CodeOutputPrediction {
+                code: "println!(\"hi\");".to_string(),
+                expected_stdout: "hi".to_string(),
+            }),
+            question(QuestionType::CodeFix {
+                code: "let x = 1; x = 2;".to_string(),
+                choices: vec!["let mut x".to_string(), "const x".to_string()],
+                correct_index: 0,
+            }),
+            question(QuestionType::CodeWriting {
+                scaffold: "fn main() {}".to_string(),
+                validators: crate::domain::entities::question::CodeValidators {
+                    must_include: Some(vec!["println!".to_string()]),
+                    must_not_include: None,
+                    test_cases: None,
+                },
+            }),
+        ];
+        let answer_fields = [
+            "correct_index",
+            "answer",
+            "acceptable_answers",
+            "expected_stdout",
+            "validators",
+            "explanation",
+            "definition",
+        ];
+
+        for question in questions {
+            let learner =
+                serde_json::to_value(QuestionResponse::new(question.clone(), &["en"])).unwrap();
+            let learner_object = learner.as_object().unwrap();
+            for field in answer_fields {
+                assert!(
+                    !learner_object.contains_key(field),
+                    "{field} leaked for {}",
+                    question.question_type.code()
+                );
+            }
+            let serialized = learner.to_string();
+            assert!(!serialized.contains("correct_index"));
+            assert!(!serialized.contains("expected_stdout"));
+            assert!(!serialized.contains("must_include"));
+
+            // Choices and code are still there to render the question
+            assert_eq!(
+                learner["choices"].as_array().unwrap().len(),
+                question.choice_options().len()
+            );
+            assert_eq!(learner["code"].as_str(), question.display_code());
+
+            let editor =
+                serde_json::to_value(EditorQuestionResponse::new(question, &["en"])).unwrap();
+            assert!(editor["definition"].is_object());
+            assert!(editor["explanation"].is_object());
+        }
+
+        let mut practice = CodePractice::new(
+            LocalizedText::from_english("Sum".to_string()),
+            LocalizedText::from_english("Add two numbers".to_string()),
+            "fn main() {}".to_string(),
+            "fn main() { println!(\"3\"); }".to_string(),
+            Difficulty::Beginner,
+            "basics".to_string(),
+            LessonId::new(),
+            TopicId::new(),
+            Points::new(50),
+        );
+        practice.set_expected_output("3".to_string());
+        practice.add_hint(LocalizedText::from_english("Use +".to_string()));
+
+        let learner =
+            serde_json::to_value(CodePracticeResponse::new(practice.clone(), &["en"])).unwrap();
+        for field in ["solution", "expected_output", "hints"] {
+            assert!(learner.get(field).is_none(), "{field} leaked");
+        }
+        assert_eq!(learner["hint_count"], 1);
+
+        let editor =
+            serde_json::to_value(EditorCodePracticeResponse::new(practice, &["en"])).unwrap();
+        assert_eq!(editor["solution"], "fn main() { println!(\"3\"); }");
+        assert_eq!(editor["expected_output"], "3");
+        assert_eq!(editor["hints"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_unified_diff_between_code_versions() {
+        use crate::shared::utils::unified_diff;
+
+        let old = "fn main() {\n    let x = 1;\n    println!(\"{}\", x);\n}\n";
+        let new = "fn main() {\n    let x = 2;\n    println!(\"{}\", x);\n}\n";
+        assert_eq!(
+            unified_diff(old, new, "a", "b").unwrap(),
+            "--- a\n+++ b\n@@ -1,4 +1,4 @@\n fn main() {\n-    let x = 1;\n+    let x = 2;\n     println!(\"{}\", x);\n }\n"
+        );
+        assert_eq!(unified_diff(old, old, "a", "b").unwrap(), "");
+
+        // Distant changes get separate hunks with three lines of context
+        let old: String = (1..=20).map(|i| format!("line {i}\n")).collect();
+        let new = old
+            .replace("line 2\n", "line two\n")
+            .replace("line 19\n", "");
+        let diff = unified_diff(&old, &new, "a", "b").unwrap();
+        let headers: Vec<&str> = diff.lines().filter(|l| l.starts_with("@@")).collect();
+        assert_eq!(headers, vec!["@@ -1,5 +1,5 @@", "@@ -16,5 +16,4 @@"]);
+
+        // Starting from nothing adds every line
+        assert_eq!(
+            unified_diff("", "a\nb\n", "a", "b").unwrap(),
+            "--- a\n+++ b\n@@ -0,0 +1,2 @@\n+a\n+b\n"
+        );
+
+        // Large inputs are diffed only when the changed region is small
+        let big: String = (0..5_000).map(|i| format!("line {i}\n")).collect();
+        let edited = big.replace("line 2500\n", "line two thousand five hundred\n");
+        let diff = unified_diff(&big, &edited, "a", "b").unwrap();
+        assert!(diff.contains("@@ -2498,7 +2498,7 @@"));
+        let rewritten: String = (0..5_000).map(|i| format!("other {i}\n")).collect();
+        assert_eq!(unified_diff(&big, &rewritten, "a", "b"), None);
+    }
+
+    #[test]
+    fn test_code_fingerprints_ignore_renaming_and_comments() {
+        use crate::shared::utils::{code_similarity, normalized_tokens};
+
+        assert_eq!(
+            normalized_tokens("let total: i32 = 42; // answer\nprintln!(\"{}\", total);"),
+            vec![
+                "let", "ID", ":", "ID", "=", "NUM", ";", "println", "!", "(", "STR", ",", "ID",
+                ")", ";"
+            ]
+        );
+        assert_eq!(
+            normalized_tokens("/* outer /* inner */ still comment */ fn f<'a>(c: char) { 'x' }"),
+            vec!["fn", "ID", "<", "LIFETIME", ">", "(", "ID", ":", "ID", ")", "{", "CHAR", "}"]
+        );
+
+        let original = r#"
+fn sum_even(numbers: &[i32]) -> i32 {
+    let mut total = 0;
+    for n in numbers {
+        if n % 2 == 0 {
+            total += n;
+        }
+    }
+    total
+}
+"#;
+        // Same code with renamed identifiers, changed literals and added comments
+        let disguised = r#"
+// My own solution
+fn add_evens(values: &[i32]) -> i32 {
+    let mut acc = 0; // accumulator
+    for v in values {
+        if v % 2 == 0 { acc += v; }
+    }
+    acc
+}
+"#;
+        let different = r#"
+fn sum_even(numbers: &[i32]) -> i32 {
+    numbers.iter().filter(|n| *n % 2 == 0).sum()
+}
+"#;
+        assert_eq!(code_similarity(original, disguised), 100);
+        assert!(code_similarity(original, different) < 50);
+        assert_eq!(code_similarity("", ""), 0);
+    }
+
+    #[test]
+    fn test_generated_questions_are_validated_into_drafts() {
+        use crate::domain::entities::question_draft::parse_generated_questions;
+
+        let reply = r#"```json
+{"questions": [
+  {"type": "mcq", "prompt_en": "Which keyword makes a binding mutable?", "prompt_id": "Kata kunci mana yang membuat binding dapat diubah?",
+   "explanation_en": "Bindings are immutable unless marked mut.", "explanation_id": "Binding tidak dapat diubah kecuali ditandai mut.",
+   "choices_en": ["let", "mut", "ref"], "choices_id": ["let", "mut", "ref"], "correct_index": 1},
+  {"type": "code_write", "prompt_en": "Return the sum", "prompt_id": "Kembalikan jumlahnya",
+   "explanation_en": "", "explanation_id": "", "scaffold": "fn sum(a: i32, b: i32) -> i32 {}", "must_include": ["a + b"]}
+]}
+```"#;
+        let questions = parse_generated_questions(reply).unwrap();
+        assert_eq!(questions.len(), 2);
+        assert_eq!(questions[0].type_code(), "mcq");
+
+        let topic_id = TopicId::new();
+        let question = questions[0]
+            .clone()
+            .into_question(topic_id.clone(), Difficulty::Beginner, Points::new(10))
+            .unwrap();
+        assert_eq!(
+            question.prompt.get("id"),
+            "Kata kunci mana yang membuat binding dapat diubah?"
+        );
+        assert_eq!(question.choice_options()[1].english(), "mut");
+        assert!(question.explanation.is_some());
+        // Editing a draft goes back through the generated form unchanged
+        let round_trip = GeneratedQuestion::from(&question)
+            .into_question(topic_id.clone(), Difficulty::Beginner, Points::new(10))
+            .unwrap();
+        assert_eq!(round_trip.choice_options(), question.choice_options());
+
+        let code = questions[1]
+            .clone()
+            .into_question(topic_id, Difficulty::Beginner, Points::new(10))
+            .unwrap();
+        assert!(code.explanation.is_none());
+        assert_eq!(code.question_type.code(), "code_write");
+
+        // Malformed or unanswerable output is rejected with a reason
+        assert!(parse_generated_questions("Here are your questions: Q1 ...").is_err());
+        assert!(parse_generated_questions("[]").is_err());
+        assert!(parse_generated_questions(r#"[{"type": "essay", "prompt_en": "x"}]"#).is_err());
+        let out_of_range = parse_generated_questions(
+            r#"[{"type": "mcq", "prompt_en": "a", "prompt_id": "b", "explanation_en": "", "explanation_id": "",
+                 "choices_en": ["x", "y"], "choices_id": ["x", "y"], "correct_index": 2}]"#,
+        )
+        .unwrap();
+        assert!(out_of_range[0].validate().is_err());
+        for (choices_en, choices_id) in [
+            (r#"["x", "  "]"#, r#"["x", "y"]"#),
+            (r#"["x", "y"]"#, r#"["", "y"]"#),
+        ] {
+            let blank = parse_generated_questions(&format!(
+                r#"[{{"type": "mcq", "prompt_en": "a", "prompt_id": "b", "explanation_en": "", "explanation_id": "",
+                     "choices_en": {choices_en}, "choices_id": {choices_id}, "correct_index": 1}}]"#
+            ))
+            .unwrap();
+            assert_eq!(
+                blank[0].validate(),
+                Err("Choices must not be blank".to_string())
+            );
+        }
+        let english_only = parse_generated_questions(
+            r#"[{"type": "tf", "prompt_en": "a", "prompt_id": " ", "explanation_en": "", "explanation_id": "", "answer": true}]"#,
+        )
+        .unwrap();
+        assert!(english_only[0].validate().is_err());
+    }
+
+    #[test]
+    fn test_mistake_context_describes_answers_and_detects_leaks() {
+        let mut question = Question::new(
+            LocalizedText::new(
+                "Which type owns its heap buffer?".to_string(),
+                "Tipe mana yang memiliki buffer heap-nya?".to_string(),
+            ),
+            TopicId::new(),
+            Difficulty::Beginner,
+            Points::new(10),
+            QuestionType::MultipleChoice {
+                choices: LocalizedText::new(
+                    "&str\nString\n&[u8]".to_string(),
+                    "&str\nString\n&[u8]".to_string(),
+                ),
+                correct_index: 1,
+            },
+        );
+        question.set_explanation(LocalizedText::new(
+            "String owns its data.".to_string(),
+            "String memiliki datanya.".to_string(),
+        ));
+
+        // Indices are shown to the tutor with the option text they stand for
+        let context = MistakeContext::for_question(&question, "0", "id");
+        assert_eq!(context.task, "Tipe mana yang memiliki buffer heap-nya?");
+        assert_eq!(context.learner_answer, "0: &str");
+        assert_eq!(context.correct_answer.as_deref(), Some("1: String"));
+        assert_eq!(
+            context.explanation.as_deref(),
+            Some("String memiliki datanya.")
+        );
+
+        // Identical pairs share a cache entry; a different answer or language does not
+        let same = MistakeContext::for_question(&question, " 0 ", "id");
+        assert_eq!(context.cache_key("id"), same.cache_key("id"));
+        assert_ne!(context.cache_key("id"), context.cache_key("en"));
+        let other = MistakeContext::for_question(&question, "2", "id");
+        assert_ne!(context.cache_key("id"), other.cache_key("id"));
+
+        let solution = "fn total(values: &[i32]) -> i32 {\n    values.iter().sum()\n}";
+        let code_context = MistakeContext {
+            task: "Sum the values".to_string(),
+            code: None,
+            correct_answer: Some(solution.to_string()),
+            explanation: None,
+            learner_answer: "fn total(values: &[i32]) -> i32 { 0 }".to_string(),
+            diagnostics: Some("Verdict: wrong_answer".to_string()),
+        };
+        assert!(!code_context
+            .reveals_answer("Your function ignores its input; look at iterator adapters."));
+        assert!(code_context.reveals_answer(&format!("Try this:\n```rust\n{solution}\n```")));
+        let renamed = "fn total(xs: &[i32]) -> i32 {\n    xs.iter().sum()\n}";
+        assert!(code_context.reveals_answer(&format!("```\n{renamed}\n```")));
+        // Short answers are not looked for in prose
+        assert!(!context.reveals_answer("Think about which of these owns a String."));
+
+        let mut usage = TutorUsage::new(UserId::new(), Utc::now().date_naive());
+        assert!(usage.record_request(2).is_ok());
+        assert!(usage.record_request(2).is_ok());
+        assert_eq!(usage.remaining(2), 0);
+        assert_eq!(
+            usage.record_request(2),
+            Err(TutorError::QuotaExceeded { quota: 2 })
+        );
+    }
+
+    #[test]
+    fn test_ai_call_costs_and_token_bucket() {
+        use crate::domain::entities::ai_usage::{call_cost_micros, model_pricing};
+        use crate::shared::utils::TokenBucket;
+        use std::time::{Duration, Instant};
+
+        // 1000 input tokens at $0.15/M and 500 output tokens at $0.60/M
+        assert_eq!(call_cost_micros("openai/gpt-4o-mini", 1000, 500), 450);
+        assert!(model_pricing("gemini/gemini-1.5-flash-latest").is_some());
+        assert_eq!(call_cost_micros("mock", 1000, 1000), 0);
+        assert_eq!(call_cost_micros("openai/llama3", 1000, 1000), 0);
+
+        let mut day = AiUsageSummary::empty(Utc::now().date_naive(), AiFeature::CodeReview);
+        let call = AiCallRecord::new(
+            AiFeature::CodeReview,
+            None,
+            "gemini/gemini-1.5-pro".to_string(),
+            false,
+            true,
+            2000,
+            1000,
+            800,
+        );
+        assert_eq!(call.cost_micros, 7500);
+        day.add(&call);
+        day.add(&AiCallRecord::new(
+            AiFeature::CodeReview,
+            None,
+            "gemini/gemini-1.5-pro".to_string(),
+            true,
+            true,
+            0,
+            0,
+            0,
+        ));
+        assert_eq!((day.calls, day.cached_calls, day.failed_calls), (2, 1, 0));
+        assert_eq!(day.cost_micros, 7500);
+
+        // Two requests a minute: a burst of two, then one every 30 seconds
+        let start = Instant::now();
+        let mut bucket = TokenBucket::per_minute(2, start);
+        for _ in 0..2 {
+            assert!(bucket.check(start).is_ok());
+            bucket.take();
+        }
+        let wait = bucket.check(start).unwrap_err();
+        assert!(wait > Duration::from_secs(29) && wait <= Duration::from_secs(30));
+        assert!(bucket.check(start + Duration::from_secs(30)).is_ok());
+        assert!(!bucket.is_full(start + Duration::from_secs(30)));
+        assert!(bucket.is_full(start + Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn test_translatable_fields_and_code_protection() {
+        use crate::shared::utils::protect_code;
+
+        let text =
+            "Call `iter()` then:\n```rust\nlet total: i32 = v.iter().sum();\n```\nDone `unclosed";
+        let protected = protect_code(text);
+        assert_eq!(protected.text, "Call ⟦C0⟧ then:\n⟦C1⟧\nDone `unclosed");
+        let translated = "Panggil ⟦C0⟧ lalu:\n⟦C1⟧\nSelesai `unclosed";
+        assert_eq!(
+            protected.restore(translated).unwrap(),
+            "Panggil `iter()` lalu:\n```rust\nlet total: i32 = v.iter().sum();\n```\nSelesai `unclosed"
+        );
+        assert!(protected.restore("Panggil ⟦C0⟧ lalu").is_err());
+        assert!(protected.restore("⟦C0⟧ ⟦C0⟧ ⟦C1⟧").is_err());
+
+        assert!(LocalizedText::from_english("Ownership".to_string()).needs_translation("id"));
+        assert!(
+            LocalizedText::new("Ownership".to_string(), " ".to_string()).needs_translation("id")
+        );
+        assert!(
+            LocalizedText::new("Ownership".to_string(), "Ownership".to_string())
+                .needs_translation("id")
+        );
+        assert!(
+            !LocalizedText::new("Ownership".to_string(), "Kepemilikan".to_string())
+                .needs_translation("id")
+        );
+        assert!(!LocalizedText::from_english(String::new()).needs_translation("id"));
+
+        let mut practice = CodePractice::new(
+            LocalizedText::from_english("Sum".to_string()),
+            LocalizedText::new("Add numbers".to_string(), "Jumlahkan".to_string()),
+            String::new(),
+            String::new(),
+            Difficulty::Beginner,
+            "basics".to_string(),
+            LessonId::new(),
+            TopicId::new(),
+            Points::new(10),
+        );
+        practice.add_hint(LocalizedText::from_english("Use a loop".to_string()));
+        let mut content = TranslatableContent::CodePractice(practice);
+        let fields: Vec<String> = content
+            .localized_fields()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(fields, ["title", "description", "hints.0"]);
+        content
+            .set_translation("hints.0", "id", "Gunakan perulangan".to_string())
+            .unwrap();
+        assert_eq!(
+            content.localized_field("hints.0").unwrap().text("id"),
+            Some("Gunakan perulangan")
+        );
+        assert!(content
+            .set_translation("hints.1", "id", String::new())
+            .is_err());
+        assert!(content
+            .set_translation("summary", "id", String::new())
+            .is_err());
+
+        let choices = UntranslatedField {
+            kind: ContentKind::Question,
+            content_id: QuestionId::new().to_string(),
+            content_title: "Which type owns its data?".to_string(),
+            field: "choices".to_string(),
+            source_text: "&str\nString\n&String".to_string(),
+        };
+        assert!(choices.check_translation("&str\nString\n&String").is_ok());
+        assert!(choices.check_translation("&str, String, &String").is_err());
+    }
+
+    #[test]
+    fn test_localized_text_falls_back_through_parent_locales() {
+        use crate::shared::utils::{
+            accepted_locale_fallbacks, locale_fallbacks, normalize_locale, parse_accept_language,
+        };
+
+        assert_eq!(normalize_locale("pt_br").as_deref(), Some("pt-BR"));
+        assert_eq!(
+            normalize_locale("ZH-hant-tw").as_deref(),
+            Some("zh-Hant-TW")
+        );
+        assert_eq!(normalize_locale("es-419").as_deref(), Some("es-419"));
+        assert_eq!(normalize_locale("*"), None);
+        assert_eq!(normalize_locale("english"), None);
+        assert_eq!(locale_fallbacks("pt-BR"), ["pt-BR", "pt", "en"]);
+        assert_eq!(locale_fallbacks("en-GB"), ["en-GB", "en"]);
+        assert_eq!(
+            accepted_locale_fallbacks(&["pt-BR", "id"]),
+            ["pt-BR", "pt", "id", "en"]
+        );
+        assert_eq!(
+            accepted_locale_fallbacks(&["en-GB", "id"]),
+            ["en-GB", "en", "id"]
+        );
+        assert_eq!(
+            parse_accept_language("fr;q=0.5, pt-br, *;q=0.1, de;q=0, id;q=0.5"),
+            ["pt-BR", "fr", "id"]
+        );
+
+        let mut text = LocalizedText::new("Ownership".to_string(), "Kepemilikan".to_string());
+        text.set("pt", "Posse".to_string());
+        assert_eq!(text.get("pt-BR"), "Posse");
+        assert_eq!(text.resolve("pt-BR"), Some(("pt", "Posse")));
+        assert_eq!(text.get("id-ID"), "Kepemilikan");
+        assert_eq!(text.get("fr"), "Ownership");
+        // Every accepted locale is tried before English
+        assert_eq!(text.get(&["fr", "id"][..]), "Kepemilikan");
+        assert_eq!(
+            text.resolve(&["fr", "pt-BR", "id"][..]),
+            Some(("pt", "Posse"))
+        );
+        assert_eq!(text.text("fr"), None);
+        let only_indonesian: LocalizedText =
+            serde_json::from_str(r#"{"id": "Kepemilikan"}"#).unwrap();
+        assert_eq!(only_indonesian.get("fr"), "Kepemilikan");
+
+        // Rows written before locales were data had every language, empty or not
+        let stored: LocalizedText = serde_json::from_str(r#"{"en": "Use +", "id": ""}"#).unwrap();
+        assert_eq!(stored, LocalizedText::from_english("Use +".to_string()));
+        assert_eq!(
+            serde_json::to_value(&text).unwrap(),
+            serde_json::json!({"en": "Ownership", "id": "Kepemilikan", "pt": "Posse"})
+        );
+
+        // Form edits of English and Indonesian keep the other locales
+        text.apply_edit(
+            LocalizedText::new("Ownership rules".to_string(), String::new()),
+            AUTHORED_LOCALES,
+        );
+        assert_eq!(text.locales().collect::<Vec<_>>(), ["en", "pt"]);
+
+        let topic = Topic::new(
+            text,
+            LocalizedText::from_english("Who frees memory".to_string()),
+            1,
+            LocalizedText::default(),
+        );
+        let response = serde_json::to_value(TopicResponse::new(topic, &["pt-BR"])).unwrap();
+        assert_eq!(response["title"]["text"], "Posse");
+        assert_eq!(response["title"]["locale"], "pt");
+        assert_eq!(response["title"]["en"], "Ownership rules");
+        assert_eq!(response["description"]["locale"], "en");
+        assert_eq!(response["required_skills"]["text"], "");
+    }
+
+    #[test]
+    fn test_lesson_blocks_validate_render_and_translate() {
+        let image = |url: &str| LessonBlock::Image {
+            url: url.to_string(),
+            alt: LocalizedText::from_english("Diagram".to_string()),
+            caption: LocalizedText::default(),
+        };
+        assert!(image("/static/moves.png").validate().is_ok());
+        assert!(image("https://example.com/moves.png").validate().is_ok());
+        assert!(image("javascript:alert(1)").validate().is_err());
+        assert!(image("//evil.example/x.png").validate().is_err());
+
+        let mut lesson = Lesson::new(
+            LocalizedText::from_english("Moves".to_string()),
+            TopicId::new(),
+            LocalizedText::default(),
+            String::new(),
+            1,
+        );
+        let empty_code = LessonBlock::Code {
+            code: "  ".to_string(),
+            runnable: false,
+            caption: LocalizedText::default(),
+        };
+        let err = lesson
+            .set_blocks(vec![image("/a.png"), empty_code])
+            .unwrap_err();
+        assert_eq!(err, "Block 2 (code): Code is empty");
+        assert!(lesson.blocks.is_empty());
+
+        lesson
+            .set_blocks(vec![
+                LessonBlock::Callout {
+                    style: CalloutStyle::Warning,
+                    text: LocalizedText::new(
+                        "[Click](javascript:alert(1)) <img src=x onerror=alert(1)>".to_string(),
+                        "Awas".to_string(),
+                    ),
+                },
+                LessonBlock::Image {
+                    url: "/a.png?x=\"><script>".to_string(),
+                    alt: LocalizedText::from_english("A \"quoted\" alt".to_string()),
+                    caption: LocalizedText::new("Moving".to_string(), "Memindahkan".to_string()),
+                },
+                LessonBlock::Markdown {
+                    text: LocalizedText::from_english("```rust\nlet a = 1;\n```".to_string()),
+                },
+            ])
+            .unwrap();
+        let html = lesson.render_html(&["en"]);
+        assert!(html.contains("lesson-callout-warning"));
+        assert!(!html.contains("javascript:"));
+        assert!(!html.contains("onerror"));
+        assert!(html.contains("src=\"/a.png?x=&quot;&gt;&lt;script&gt;\""));
+        assert!(html.contains("alt=\"A &quot;quoted&quot; alt\""));
+        assert!(html.contains("<code class=\"language-rust\">"));
+        assert!(lesson
+            .render_html(&["id"])
+            .contains("<figcaption>Memindahkan</figcaption>"));
+
+        // Block text goes through the translation workflow like any field
+        let mut content = TranslatableContent::Lesson(lesson);
+        let fields: Vec<String> = content
+            .localized_fields()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                "title",
+                "summary",
+                "blocks.0.text",
+                "blocks.1.alt",
+                "blocks.1.caption",
+                "blocks.2.text"
+            ]
+        );
+        content
+            .set_translation("blocks.1.alt", "id", "Diagram".to_string())
+            .unwrap();
+        assert_eq!(
+            content.localized_field("blocks.1.alt").unwrap().text("id"),
+            Some("Diagram")
+        );
+        assert!(content
+            .set_translation("blocks.0.alt", "id", "x".to_string())
+            .is_err());
+        assert!(content
+            .set_translation("blocks.9.text", "id", "x".to_string())
+            .is_err());
+    }
+
+    #[test]
+    fn test_asset_types_are_sniffed_from_content_and_keyed_by_hash() {
+        assert_eq!(
+            AssetType::sniff(b"\x89PNG\r\n\x1a\nrest"),
+            Some(AssetType::Png)
+        );
+        assert_eq!(
+            AssetType::sniff(&[0xFF, 0xD8, 0xFF, 0xE0]),
+            Some(AssetType::Jpeg)
+        );
+        assert_eq!(AssetType::sniff(b"GIF89a..."), Some(AssetType::Gif));
+        assert_eq!(
+            AssetType::sniff(b"RIFF\x10\x00\x00\x00WEBPVP8 "),
+            Some(AssetType::Webp)
+        );
+        assert_eq!(AssetType::sniff(b"%PDF-1.7"), Some(AssetType::Pdf));
+        // Types outside the allow-list, whatever the file is called
+        assert_eq!(
+            AssetType::sniff(b"<svg xmlns=\"http://www.w3.org/2000/svg\">"),
+            None
+        );
+        assert_eq!(AssetType::sniff(b"#!/bin/sh\nrm -rf /"), None);
+        assert_eq!(AssetType::sniff(b"RIFF\x10\x00\x00\x00WAVE"), None);
+        assert_eq!(
+            AssetType::from_content_type("image/webp"),
+            Ok(AssetType::Webp)
+        );
+
+        let sha256 = "ab".repeat(32);
+        let mut asset = Asset::new(
+            sha256.clone(),
+            AssetType::Jpeg,
+            1234,
+            "C:\\Users\\me\\\"cat\".jpg",
+            None,
+        );
+        assert_eq!(asset.storage_key, format!("ab/{sha256}.jpg"));
+        assert_eq!(asset.original_filename, "cat.jpg");
+        assert_eq!(asset.thumbnail_url(), None);
+        asset.add_thumbnail(800, 600);
+        assert_eq!(
+            asset.thumbnail_key,
+            Some(format!("thumbnails/ab/{sha256}.png"))
+        );
+        assert_eq!(
+            asset.thumbnail_url(),
+            Some(format!("/assets/{}/thumbnail", asset.id.to_string()))
+        );
+
+        assert_eq!(sanitize_filename("../../etc/passwd"), "passwd");
+        assert_eq!(sanitize_filename("  \u{0}\n "), "upload");
+        assert_eq!(sanitize_filename(&"x".repeat(300)).len(), 200);
+
+        // Lesson images served from the asset routes are recognized as assets
+        let image = |url: String| LessonBlock::Image {
+            url,
+            alt: LocalizedText::from_english("Cat".to_string()),
+            caption: LocalizedText::default(),
+        };
+        assert_eq!(image(asset.url()).asset_id(), Some(asset.id.clone()));
+        assert_eq!(
+            image(asset.thumbnail_url().unwrap()).asset_id(),
+            Some(asset.id.clone())
+        );
+        assert_eq!(image("/static/cat.png".to_string()).asset_id(), None);
+        assert_eq!(
+            image("https://example.com/assets/cat.png".to_string()).asset_id(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_content_snapshots_diff_field_by_field() {
+        let mut question = Question::new(
+            LocalizedText::new(
+                "Which moves?".to_string(),
+                "Mana yang berpindah?".to_string(),
+            ),
+            TopicId::new(),
+            Difficulty::Beginner,
+            Points::new(10),
+            QuestionType::MultipleChoice {
+                choices: LocalizedText::from_english("String\ni32".to_string()),
+                correct_index: 0,
+            },
+        );
+        let before = TranslatableContent::Question(question.clone());
+
+        question
+            .prompt
+            .set("id", "Mana yang dipindahkan?".to_string());
+        question.question_type = QuestionType::MultipleChoice {
+            choices: LocalizedText::from_english("String\ni32".to_string()),
+            correct_index: 1,
+        };
+        let mut after = TranslatableContent::Question(question.clone());
+        after.touch();
+
+        let changes = diff_snapshots(&before.snapshot(), &after.snapshot());
+        let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec!["prompt.id", "question_type.MultipleChoice.correct_index"]
+        );
+        assert_eq!(
+            changes[0].before,
+            Some(serde_json::json!("Mana yang berpindah?"))
+        );
+        assert_eq!(changes[1].after, Some(serde_json::json!(1)));
+
+        // A new variant replaces the old one; an added locale has no before
+        question.question_type = QuestionType::TrueFalse { answer: true };
+        question.prompt.set("pt-BR", "Qual é movido?".to_string());
+        let changes = diff_snapshots(
+            &after.snapshot(),
+            &TranslatableContent::Question(question).snapshot(),
+        );
+        let summary: Vec<(&str, bool, bool)> = changes
+            .iter()
+            .map(|c| (c.field.as_str(), c.before.is_some(), c.after.is_some()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("prompt.pt-BR", false, true),
+                ("question_type.MultipleChoice", true, false),
+                ("question_type.TrueFalse", false, true),
+            ]
+        );
+
+        // Restoring a snapshot gives back the item as it was
+        let restored =
+            TranslatableContent::from_snapshot(ContentKind::Question, before.snapshot()).unwrap();
+        assert!(diff_snapshots(&before.snapshot(), &restored.snapshot()).is_empty());
+        assert!(TranslatableContent::from_snapshot(ContentKind::Topic, before.snapshot()).is_err());
+    }
+
+    #[test]
+    fn test_asset_type_is_sniffed_from_content() {
+        assert_eq!(
+            AssetType::sniff(b"\x89PNG\r\n\x1a\n...."),
+            Some(AssetType::Png)
+        );
+        assert_eq!(AssetType::sniff(b"%PDF-1.4\n"), Some(AssetType::Pdf));
+        assert_eq!(AssetType::sniff(b"RIFF\0\0\0\0WEBP"), Some(AssetType::Webp));
+        assert_eq!(AssetType::sniff(b"<script>alert(1)</script>"), None);
+        assert_eq!(AssetType::sniff(b""), None);
+    }
+
+    #[test]
+    fn test_asset_keys_come_from_content_hash() {
+        let sha256 = "ab".repeat(32);
+        let mut asset = Asset::new(sha256.clone(), AssetType::Png, 10, "diagram.png", None);
+        assert_eq!(asset.storage_key, format!("ab/{sha256}.png"));
+        assert_eq!(asset.thumbnail_url(), None);
+
+        asset.add_thumbnail(640, 480);
+        assert_eq!(
+            asset.thumbnail_key,
+            Some(format!("thumbnails/ab/{sha256}.png"))
+        );
+        assert_eq!(
+            asset.thumbnail_url(),
+            Some(format!("/assets/{}/thumbnail", asset.id.to_string()))
+        );
+    }
+
+    #[test]
+    fn test_uploaded_file_names_are_sanitized() {
+        assert_eq!(sanitize_filename("../../diagram.png"), "diagram.png");
+        assert_eq!(sanitize_filename("C:\\docs\\notes.pdf"), "notes.pdf");
+        assert_eq!(sanitize_filename("a\"b'c\n.png"), "abc.png");
+        assert_eq!(sanitize_filename("dir/"), "upload");
+        assert_eq!(sanitize_filename(&"x".repeat(300)).len(), 200);
+    }
 }
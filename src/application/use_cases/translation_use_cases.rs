@@ -0,0 +1,340 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::domain::entities::{
+    AiRateLimited, ContentAuthor, ContentKind, MachineTranslation, TranslatableContent,
+    UntranslatedField,
+};
+use crate::domain::repositories::{
+    CodePracticeRepository, LessonRepository, QuestionRepository, TopicRepository,
+    TranslationRepository,
+};
+use crate::domain::services::ContentVersionService;
+use crate::domain::value_objects::{CodePracticeId, LessonId, QuestionId, TopicId, TranslationId};
+use crate::infrastructure::external_services::{AiCaller, AiService};
+
+/// Language machine translations are written in; content is authored in English.
+pub const TRANSLATION_LANGUAGE: &str = "id";
+
+/// Fields translated per run when the caller does not say; the AI rate limits
+/// may stop a run earlier.
+pub const DEFAULT_TRANSLATION_BATCH: usize = 10;
+
+/// Recorded as the author of content changes made by approving translations.
+pub const TRANSLATION_REVIEW_AUTHOR: &str = "Translation review";
+
+const CONTENT_PAGE_SIZE: u32 = 100;
+
+#[derive(Debug, Serialize)]
+pub struct TranslationRunReport {
+    pub translated: usize,
+    /// Fields that could not be translated, with the reason.
+    pub failed: Vec<String>,
+    /// Untranslated fields still without a translation to review.
+    pub remaining: usize,
+    /// Whether the run stopped early at the AI rate limit.
+    pub rate_limited: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TranslationReviewReport {
+    pub reviewed: usize,
+    /// Translations left alone or discarded, with the reason.
+    pub skipped: Vec<String>,
+}
+
+/// Machine translation of content whose Indonesian text is missing or a copy of
+/// the English. Translations wait for an editor before they reach learners.
+#[derive(Clone)]
+pub struct TranslationUseCases {
+    topic_repository: Arc<dyn TopicRepository>,
+    lesson_repository: Arc<dyn LessonRepository>,
+    question_repository: Arc<dyn QuestionRepository>,
+    code_practice_repository: Arc<dyn CodePracticeRepository>,
+    translation_repository: Arc<dyn TranslationRepository>,
+    content_version_service: Arc<dyn ContentVersionService>,
+    ai_service: AiService,
+}
+
+impl TranslationUseCases {
+    pub fn new(
+        topic_repository: Arc<dyn TopicRepository>,
+        lesson_repository: Arc<dyn LessonRepository>,
+        question_repository: Arc<dyn QuestionRepository>,
+        code_practice_repository: Arc<dyn CodePracticeRepository>,
+        translation_repository: Arc<dyn TranslationRepository>,
+        content_version_service: Arc<dyn ContentVersionService>,
+        ai_service: AiService,
+    ) -> Self {
+        Self {
+            topic_repository,
+            lesson_repository,
+            question_repository,
+            code_practice_repository,
+            translation_repository,
+            content_version_service,
+            ai_service,
+        }
+    }
+
+    /// Fields that need a translation and have none waiting for review.
+    pub async fn untranslated_fields(&self) -> Result<Vec<UntranslatedField>> {
+        let pending = self.translation_repository.list_pending().await?;
+        let mut fields = Vec::new();
+        for content in self.all_content().await? {
+            for (field, text) in content.localized_fields() {
+                if !text.needs_translation(TRANSLATION_LANGUAGE) {
+                    continue;
+                }
+                let untranslated = UntranslatedField {
+                    kind: content.kind(),
+                    content_id: content.content_id(),
+                    content_title: content.title().to_string(),
+                    field,
+                    source_text: text.english().to_string(),
+                };
+                if !pending.iter().any(|t| untranslated.matches(t)) {
+                    fields.push(untranslated);
+                }
+            }
+        }
+        Ok(fields)
+    }
+
+    /// Machine-translates up to `limit` untranslated fields and queues them for
+    /// review. Stops at the AI rate limit; a later run picks up the rest.
+    pub async fn translate_missing(&self, limit: usize) -> Result<TranslationRunReport> {
+        let fields = self.untranslated_fields().await?;
+        let mut report = TranslationRunReport {
+            translated: 0,
+            failed: Vec::new(),
+            remaining: fields.len(),
+            rate_limited: false,
+        };
+
+        for field in fields.iter().take(limit) {
+            let translated = self
+                .ai_service
+                .translate(&AiCaller::Admin, &field.source_text, TRANSLATION_LANGUAGE)
+                .await;
+            let result = translated.and_then(|text| {
+                field
+                    .check_translation(&text)
+                    .map(|_| text)
+                    .map_err(|e| anyhow::anyhow!(e))
+            });
+            match result {
+                Ok(text) => {
+                    self.translation_repository
+                        .create(&MachineTranslation::new(
+                            field,
+                            TRANSLATION_LANGUAGE.to_string(),
+                            text,
+                            self.ai_service.provider_name(),
+                        ))
+                        .await?;
+                    report.translated += 1;
+                    report.remaining -= 1;
+                }
+                Err(e) if e.is::<AiRateLimited>() => {
+                    report.rate_limited = true;
+                    break;
+                }
+                Err(e) => report.failed.push(format!(
+                    "{} \"{}\" {}: {e}",
+                    field.kind.label(),
+                    field.content_title,
+                    field.field
+                )),
+            }
+        }
+        Ok(report)
+    }
+
+    /// Translations waiting for review, oldest first.
+    pub async fn pending_translations(&self) -> Result<Vec<MachineTranslation>> {
+        self.translation_repository.list_pending().await
+    }
+
+    /// Writes the translations into their content. Translations whose English was
+    /// edited since, or whose content is gone, are rejected instead.
+    pub async fn approve(&self, ids: &[String]) -> Result<TranslationReviewReport> {
+        let mut report = TranslationReviewReport {
+            reviewed: 0,
+            skipped: Vec::new(),
+        };
+        for id in ids {
+            let Some(mut translation) = self.find_pending(id, &mut report).await? else {
+                continue;
+            };
+            let content = self
+                .load_content(translation.kind, &translation.content_id)
+                .await?;
+            let up_to_date = content
+                .as_ref()
+                .and_then(|content| content.localized_field(&translation.field))
+                .is_some_and(|current| !translation.is_stale(current));
+            let mut content = match content {
+                Some(content) if up_to_date => content,
+                _ => {
+                    translation.reject().map_err(|e| anyhow::anyhow!(e))?;
+                    self.translation_repository.update(&translation).await?;
+                    report.skipped.push(format!(
+                        "{}: the English text changed or was removed, so it was rejected",
+                        translation.field
+                    ));
+                    continue;
+                }
+            };
+
+            content
+                .set_translation(
+                    &translation.field,
+                    &translation.language,
+                    translation.translated_text.clone(),
+                )
+                .map_err(|e| anyhow::anyhow!(e))?;
+            self.content_version_service
+                .update_content(
+                    &content,
+                    ContentAuthor::new(TRANSLATION_REVIEW_AUTHOR),
+                    &format!(
+                        "Approved {} translation of {} from {}",
+                        translation.language, translation.field, translation.provider
+                    ),
+                )
+                .await?;
+            translation.approve().map_err(|e| anyhow::anyhow!(e))?;
+            self.translation_repository.update(&translation).await?;
+            report.reviewed += 1;
+        }
+        Ok(report)
+    }
+
+    pub async fn reject(&self, ids: &[String]) -> Result<TranslationReviewReport> {
+        let mut report = TranslationReviewReport {
+            reviewed: 0,
+            skipped: Vec::new(),
+        };
+        for id in ids {
+            let Some(mut translation) = self.find_pending(id, &mut report).await? else {
+                continue;
+            };
+            translation.reject().map_err(|e| anyhow::anyhow!(e))?;
+            self.translation_repository.update(&translation).await?;
+            report.reviewed += 1;
+        }
+        Ok(report)
+    }
+
+    async fn find_pending(
+        &self,
+        id: &str,
+        report: &mut TranslationReviewReport,
+    ) -> Result<Option<MachineTranslation>> {
+        let translation_id = TranslationId::from_str(id).map_err(|e| anyhow::anyhow!(e))?;
+        match self
+            .translation_repository
+            .find_by_id(&translation_id)
+            .await?
+        {
+            Some(translation) if translation.needs_review() => Ok(Some(translation)),
+            Some(translation) => {
+                report
+                    .skipped
+                    .push(format!("{id}: already {}", translation.status.as_str()));
+                Ok(None)
+            }
+            None => {
+                report.skipped.push(format!("{id}: not found"));
+                Ok(None)
+            }
+        }
+    }
+
+    async fn all_content(&self) -> Result<Vec<TranslatableContent>> {
+        let mut content: Vec<TranslatableContent> = self
+            .topic_repository
+            .list_by_order()
+            .await?
+            .into_iter()
+            .map(TranslatableContent::Topic)
+            .collect();
+
+        let mut offset = 0;
+        loop {
+            let page = self
+                .lesson_repository
+                .list(CONTENT_PAGE_SIZE, offset)
+                .await?;
+            let done = page.len() < CONTENT_PAGE_SIZE as usize;
+            content.extend(page.into_iter().map(TranslatableContent::Lesson));
+            if done {
+                break;
+            }
+            offset += CONTENT_PAGE_SIZE;
+        }
+
+        let mut offset = 0;
+        loop {
+            let page = self
+                .question_repository
+                .list(CONTENT_PAGE_SIZE, offset)
+                .await?;
+            let done = page.len() < CONTENT_PAGE_SIZE as usize;
+            content.extend(page.into_iter().map(TranslatableContent::Question));
+            if done {
+                break;
+            }
+            offset += CONTENT_PAGE_SIZE;
+        }
+
+        let mut offset = 0;
+        loop {
+            let page = self
+                .code_practice_repository
+                .list(CONTENT_PAGE_SIZE, offset)
+                .await?;
+            let done = page.len() < CONTENT_PAGE_SIZE as usize;
+            content.extend(page.into_iter().map(TranslatableContent::CodePractice));
+            if done {
+                break;
+            }
+            offset += CONTENT_PAGE_SIZE;
+        }
+
+        Ok(content)
+    }
+
+    async fn load_content(
+        &self,
+        kind: ContentKind,
+        content_id: &str,
+    ) -> Result<Option<TranslatableContent>> {
+        let invalid = |e: String| anyhow::anyhow!(e);
+        Ok(match kind {
+            ContentKind::Topic => self
+                .topic_repository
+                .find_by_id(&TopicId::from_str(content_id).map_err(invalid)?)
+                .await?
+                .map(TranslatableContent::Topic),
+            ContentKind::Lesson => self
+                .lesson_repository
+                .find_by_id(&LessonId::from_str(content_id).map_err(invalid)?)
+                .await?
+                .map(TranslatableContent::Lesson),
+            ContentKind::Question => self
+                .question_repository
+                .find_by_id(&QuestionId::from_string(content_id).map_err(invalid)?)
+                .await?
+                .map(TranslatableContent::Question),
+            ContentKind::CodePractice => self
+                .code_practice_repository
+                .find_by_id(&CodePracticeId::from_string(content_id).map_err(invalid)?)
+                .await?
+                .map(TranslatableContent::CodePractice),
+        })
+    }
+}
@@ -0,0 +1,250 @@
+use anyhow::Result;
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::domain::entities::{MistakeContext, TutorError, TutorFeedback, TutorUsage, User};
+use crate::domain::repositories::{
+    CodePracticeRepository, CodeSubmissionRepository, QuestionRepository, QuizAttemptRepository,
+    TutorFeedbackRepository, UserRepository,
+};
+use crate::domain::value_objects::{CodeSubmissionId, QuestionId, QuizAttemptId, UserId};
+use crate::infrastructure::external_services::{AiCaller, AiService};
+use crate::shared::errors::DomainError;
+use crate::shared::utils::normalize_locale;
+
+#[derive(Debug, Deserialize)]
+pub struct TutorQuery {
+    /// Language of the hint; the learner's preferred language when omitted.
+    pub lang: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TutorHintResponse {
+    pub hint: String,
+    pub language: String,
+    /// Whether the hint was reused from an identical earlier answer, which is free.
+    pub cached: bool,
+    pub daily_quota: u32,
+    pub remaining_today: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TutorQuotaResponse {
+    pub daily_quota: u32,
+    pub remaining_today: u32,
+}
+
+#[derive(Clone)]
+pub struct TutorUseCases {
+    user_repository: Arc<dyn UserRepository>,
+    question_repository: Arc<dyn QuestionRepository>,
+    quiz_attempt_repository: Arc<dyn QuizAttemptRepository>,
+    code_practice_repository: Arc<dyn CodePracticeRepository>,
+    code_submission_repository: Arc<dyn CodeSubmissionRepository>,
+    tutor_feedback_repository: Arc<dyn TutorFeedbackRepository>,
+    ai_service: AiService,
+    daily_quota: u32,
+}
+
+impl TutorUseCases {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        question_repository: Arc<dyn QuestionRepository>,
+        quiz_attempt_repository: Arc<dyn QuizAttemptRepository>,
+        code_practice_repository: Arc<dyn CodePracticeRepository>,
+        code_submission_repository: Arc<dyn CodeSubmissionRepository>,
+        tutor_feedback_repository: Arc<dyn TutorFeedbackRepository>,
+        ai_service: AiService,
+        daily_quota: u32,
+    ) -> Self {
+        Self {
+            user_repository,
+            question_repository,
+            quiz_attempt_repository,
+            code_practice_repository,
+            code_submission_repository,
+            tutor_feedback_repository,
+            ai_service,
+            daily_quota,
+        }
+    }
+
+    /// Explains a wrong answer in a quiz attempt. Attempts give no feedback while
+    /// they are open, so this is only available once it is closed.
+    pub async fn explain_quiz_answer(
+        &self,
+        user_id: &UserId,
+        attempt_id: &str,
+        question_id: &str,
+        query: TutorQuery,
+    ) -> Result<TutorHintResponse> {
+        let attempt_id = QuizAttemptId::from_str(attempt_id).map_err(DomainError::NotFound)?;
+        let question_id = QuestionId::from_string(question_id).map_err(DomainError::NotFound)?;
+        let attempt = self
+            .quiz_attempt_repository
+            .find_by_id(&attempt_id)
+            .await?
+            .filter(|attempt| attempt.user_id == *user_id)
+            .ok_or_else(|| DomainError::NotFound("Quiz attempt not found".to_string()))?;
+        if attempt.is_open(Utc::now()) {
+            return Err(DomainError::Invalid(
+                "Hints are available once the quiz attempt is submitted".to_string(),
+            )
+            .into());
+        }
+        let item = attempt.item(&question_id).ok_or_else(|| {
+            DomainError::NotFound("Question is not part of this quiz attempt".to_string())
+        })?;
+        let answer = attempt
+            .answer_for(&question_id)
+            .ok_or_else(|| DomainError::Invalid("Question was not answered".to_string()))?;
+        if answer.correct {
+            return Err(DomainError::Invalid("This answer is correct".to_string()).into());
+        }
+        let question = self
+            .question_repository
+            .find_by_id(&question_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Question not found".to_string()))?
+            .with_choice_order(&item.choice_order);
+
+        let user = self.load_user(user_id).await?;
+        let language = Self::language(&user, query)?;
+        let context = MistakeContext::for_question(&question, &answer.user_answer, &language);
+        self.explain(&user, context, language).await
+    }
+
+    /// Explains why one of the learner's code submissions was not accepted.
+    pub async fn explain_code_submission(
+        &self,
+        user_id: &UserId,
+        submission_id: &str,
+        query: TutorQuery,
+    ) -> Result<TutorHintResponse> {
+        let submission_id =
+            CodeSubmissionId::from_str(submission_id).map_err(DomainError::NotFound)?;
+        let submission = self
+            .code_submission_repository
+            .find_by_id(&submission_id)
+            .await?
+            .filter(|submission| submission.user_id == *user_id)
+            .ok_or_else(|| DomainError::NotFound("Code submission not found".to_string()))?;
+        if submission.is_accepted() {
+            return Err(DomainError::Invalid("This submission was accepted".to_string()).into());
+        }
+        let code_practice = self
+            .code_practice_repository
+            .find_by_id(&submission.code_practice_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Code practice not found".to_string()))?;
+
+        let user = self.load_user(user_id).await?;
+        let language = Self::language(&user, query)?;
+        let context = MistakeContext::for_code_submission(&code_practice, &submission, &language);
+        self.explain(&user, context, language).await
+    }
+
+    pub async fn get_quota(&self, user_id: &UserId) -> Result<TutorQuotaResponse> {
+        let user = self.load_user(user_id).await?;
+        let usage = self.load_usage(&user, Self::local_today(&user)).await?;
+        Ok(TutorQuotaResponse {
+            daily_quota: self.daily_quota,
+            remaining_today: usage.remaining(self.daily_quota),
+        })
+    }
+
+    /// Serves the cached hint for identical answers; otherwise asks the provider,
+    /// charging the learner's daily quota only when a hint is produced.
+    async fn explain(
+        &self,
+        user: &User,
+        context: MistakeContext,
+        language: String,
+    ) -> Result<TutorHintResponse> {
+        let today = Self::local_today(user);
+        let usage = self.load_usage(user, today).await?;
+        let cache_key = context.cache_key(&language);
+        if let Some(cached) = self
+            .tutor_feedback_repository
+            .find_cached(&cache_key)
+            .await?
+        {
+            return Ok(TutorHintResponse {
+                hint: cached.hint,
+                language,
+                cached: true,
+                daily_quota: self.daily_quota,
+                remaining_today: usage.remaining(self.daily_quota),
+            });
+        }
+
+        // Reserved before the provider is called so concurrent requests cannot
+        // overrun the quota, and given back when no hint comes out of it
+        let usage = self
+            .tutor_feedback_repository
+            .reserve_request(&user.id, today, self.daily_quota)
+            .await?
+            .ok_or(TutorError::QuotaExceeded {
+                quota: self.daily_quota,
+            })?;
+        let hint = match self
+            .ai_service
+            .explain_mistake(&AiCaller::User(user.id.clone()), &context, &language)
+            .await
+        {
+            Ok(hint) => hint,
+            Err(error) => {
+                self.tutor_feedback_repository
+                    .release_request(&user.id, today)
+                    .await?;
+                return Err(error);
+            }
+        };
+        self.tutor_feedback_repository
+            .save_cached(&TutorFeedback::new(
+                cache_key,
+                language.clone(),
+                hint.clone(),
+                self.ai_service.provider_name(),
+            ))
+            .await?;
+
+        Ok(TutorHintResponse {
+            hint,
+            language,
+            cached: false,
+            daily_quota: self.daily_quota,
+            remaining_today: usage.remaining(self.daily_quota),
+        })
+    }
+
+    async fn load_user(&self, user_id: &UserId) -> Result<User> {
+        self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("User not found".to_string()).into())
+    }
+
+    async fn load_usage(&self, user: &User, today: NaiveDate) -> Result<TutorUsage> {
+        Ok(self
+            .tutor_feedback_repository
+            .find_usage(&user.id, today)
+            .await?
+            .unwrap_or_else(|| TutorUsage::new(user.id.clone(), today)))
+    }
+
+    fn local_today(user: &User) -> NaiveDate {
+        Utc::now().with_timezone(&user.tz()).date_naive()
+    }
+
+    fn language(user: &User, query: TutorQuery) -> Result<String> {
+        let language = query
+            .lang
+            .unwrap_or_else(|| user.preferred_language.clone());
+        normalize_locale(&language)
+            .ok_or_else(|| DomainError::Invalid(format!("Invalid language: {language}")).into())
+    }
+}
@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::UserId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountAuditAction {
+    DataExported,
+    DeletionRequested,
+    DeletionCancelled,
+    AccountDeleted,
+}
+
+impl AccountAuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccountAuditAction::DataExported => "data_exported",
+            AccountAuditAction::DeletionRequested => "deletion_requested",
+            AccountAuditAction::DeletionCancelled => "deletion_cancelled",
+            AccountAuditAction::AccountDeleted => "account_deleted",
+        }
+    }
+}
+
+impl std::str::FromStr for AccountAuditAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "data_exported" => Ok(AccountAuditAction::DataExported),
+            "deletion_requested" => Ok(AccountAuditAction::DeletionRequested),
+            "deletion_cancelled" => Ok(AccountAuditAction::DeletionCancelled),
+            "account_deleted" => Ok(AccountAuditAction::AccountDeleted),
+            _ => Err(format!("Invalid audit action: {s}")),
+        }
+    }
+}
+
+/// Privacy-relevant action on an account. Kept after the account itself is gone,
+/// so it only references the user by id.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountAuditEvent {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub action: AccountAuditAction,
+    pub details: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AccountAuditEvent {
+    pub fn new(user_id: UserId, action: AccountAuditAction, details: serde_json::Value) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            action,
+            details,
+            created_at: Utc::now(),
+        }
+    }
+}
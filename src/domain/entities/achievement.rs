@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::{LocalizedText, UserId};
+
+/// Condition under which an achievement unlocks. Stored as JSON in the catalogue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AchievementRule {
+    TotalXp { threshold: u32 },
+    StreakDays { threshold: u32 },
+    PerfectLessons { threshold: u32 },
+    CodePracticesCompleted { threshold: u32 },
+    TopicsMastered { threshold: u32 },
+}
+
+impl AchievementRule {
+    pub fn threshold(&self) -> u32 {
+        match self {
+            AchievementRule::TotalXp { threshold }
+            | AchievementRule::StreakDays { threshold }
+            | AchievementRule::PerfectLessons { threshold }
+            | AchievementRule::CodePracticesCompleted { threshold }
+            | AchievementRule::TopicsMastered { threshold } => *threshold,
+        }
+    }
+
+    /// The learner's current value for the measure this rule looks at.
+    pub fn current_value(&self, stats: &AchievementStats) -> u32 {
+        match self {
+            AchievementRule::TotalXp { .. } => stats.total_xp,
+            AchievementRule::StreakDays { .. } => stats.highest_streak_days,
+            AchievementRule::PerfectLessons { .. } => stats.perfect_lessons,
+            AchievementRule::CodePracticesCompleted { .. } => stats.code_practices_completed,
+            AchievementRule::TopicsMastered { .. } => stats.topics_mastered,
+        }
+    }
+
+    pub fn is_met(&self, stats: &AchievementStats) -> bool {
+        self.current_value(stats) >= self.threshold()
+    }
+}
+
+/// Snapshot of the progress measures achievement rules are evaluated against.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AchievementStats {
+    pub total_xp: u32,
+    pub highest_streak_days: u32,
+    pub perfect_lessons: u32,
+    pub code_practices_completed: u32,
+    pub topics_mastered: u32,
+}
+
+/// Catalogue entry describing an achievement and how it is earned.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Achievement {
+    /// Stable key, e.g. `streak_7`.
+    pub id: String,
+    pub title: LocalizedText,
+    pub description: LocalizedText,
+    pub icon: String,
+    pub rule: AchievementRule,
+    /// XP granted once when the achievement unlocks.
+    pub xp_bonus: u32,
+    pub sort_order: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserAchievement {
+    pub user_id: UserId,
+    pub achievement_id: String,
+    pub unlocked_at: DateTime<Utc>,
+}
+
+impl UserAchievement {
+    pub fn new(user_id: UserId, achievement_id: String) -> Self {
+        Self {
+            user_id,
+            achievement_id,
+            unlocked_at: Utc::now(),
+        }
+    }
+}
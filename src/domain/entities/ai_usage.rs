@@ -0,0 +1,245 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::domain::value_objects::{AiCallId, UserId};
+
+/// What an AI call was made for, for usage accounting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AiFeature {
+    QuestionGeneration,
+    CodeValidation,
+    CodeExplanation,
+    CodeReview,
+    TutorHint,
+    Translation,
+}
+
+impl AiFeature {
+    pub fn all() -> Vec<AiFeature> {
+        vec![
+            AiFeature::QuestionGeneration,
+            AiFeature::CodeValidation,
+            AiFeature::CodeExplanation,
+            AiFeature::CodeReview,
+            AiFeature::TutorHint,
+            AiFeature::Translation,
+        ]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AiFeature::QuestionGeneration => "question_generation",
+            AiFeature::CodeValidation => "code_validation",
+            AiFeature::CodeExplanation => "code_explanation",
+            AiFeature::CodeReview => "code_review",
+            AiFeature::TutorHint => "tutor_hint",
+            AiFeature::Translation => "translation",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AiFeature::QuestionGeneration => "Question generation",
+            AiFeature::CodeValidation => "Code validation",
+            AiFeature::CodeExplanation => "Code explanation",
+            AiFeature::CodeReview => "Code review",
+            AiFeature::TutorHint => "Tutor hints",
+            AiFeature::Translation => "Translation",
+        }
+    }
+}
+
+impl std::str::FromStr for AiFeature {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "question_generation" => Ok(AiFeature::QuestionGeneration),
+            "code_validation" => Ok(AiFeature::CodeValidation),
+            "code_explanation" => Ok(AiFeature::CodeExplanation),
+            "code_review" => Ok(AiFeature::CodeReview),
+            "tutor_hint" => Ok(AiFeature::TutorHint),
+            "translation" => Ok(AiFeature::Translation),
+            _ => Err(format!("Invalid AI feature: {s}")),
+        }
+    }
+}
+
+/// List price of a model in USD micros per million tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModelPricing {
+    pub input_per_million: u64,
+    pub output_per_million: u64,
+}
+
+/// Published prices of the hosted models the providers are usually pointed at,
+/// matched on the model name's prefix. Update alongside the providers' price lists.
+const MODEL_PRICES: &[(&str, ModelPricing)] = &[
+    (
+        "gemini-1.5-flash",
+        ModelPricing {
+            input_per_million: 75_000,
+            output_per_million: 300_000,
+        },
+    ),
+    (
+        "gemini-1.5-pro",
+        ModelPricing {
+            input_per_million: 1_250_000,
+            output_per_million: 5_000_000,
+        },
+    ),
+    (
+        "gemini-pro",
+        ModelPricing {
+            input_per_million: 500_000,
+            output_per_million: 1_500_000,
+        },
+    ),
+    (
+        "gpt-4o-mini",
+        ModelPricing {
+            input_per_million: 150_000,
+            output_per_million: 600_000,
+        },
+    ),
+    (
+        "gpt-4o",
+        ModelPricing {
+            input_per_million: 2_500_000,
+            output_per_million: 10_000_000,
+        },
+    ),
+];
+
+/// Pricing for a provider name such as `gemini/gemini-1.5-flash`. Local and mock
+/// models have none and cost nothing.
+pub fn model_pricing(provider: &str) -> Option<ModelPricing> {
+    let model = provider.rsplit('/').next().unwrap_or(provider);
+    MODEL_PRICES
+        .iter()
+        .find(|(prefix, _)| model.starts_with(prefix))
+        .map(|(_, pricing)| *pricing)
+}
+
+/// Cost of a call in USD micros, rounded to the nearest micro.
+pub fn call_cost_micros(provider: &str, input_tokens: u32, output_tokens: u32) -> u64 {
+    model_pricing(provider).map_or(0, |pricing| {
+        let total = u64::from(input_tokens) * pricing.input_per_million
+            + u64::from(output_tokens) * pricing.output_per_million;
+        (total + 500_000) / 1_000_000
+    })
+}
+
+/// One request to an AI feature, served by the provider or from the cache.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AiCallRecord {
+    pub id: AiCallId,
+    pub feature: AiFeature,
+    /// `None` for calls made from the admin pages.
+    pub user_id: Option<UserId>,
+    pub provider: String,
+    pub cached: bool,
+    pub succeeded: bool,
+    pub input_tokens: u32,
+    pub output_tokens: u32,
+    pub cost_micros: u64,
+    pub latency_ms: u32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AiCallRecord {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        feature: AiFeature,
+        user_id: Option<UserId>,
+        provider: String,
+        cached: bool,
+        succeeded: bool,
+        input_tokens: u32,
+        output_tokens: u32,
+        latency_ms: u32,
+    ) -> Self {
+        Self {
+            id: AiCallId::new(),
+            feature,
+            user_id,
+            cost_micros: call_cost_micros(&provider, input_tokens, output_tokens),
+            provider,
+            cached,
+            succeeded,
+            input_tokens,
+            output_tokens,
+            latency_ms,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// AI calls of one feature on one UTC day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AiUsageSummary {
+    pub day: NaiveDate,
+    pub feature: AiFeature,
+    pub calls: u32,
+    pub cached_calls: u32,
+    pub failed_calls: u32,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cost_micros: u64,
+}
+
+impl AiUsageSummary {
+    pub fn empty(day: NaiveDate, feature: AiFeature) -> Self {
+        Self {
+            day,
+            feature,
+            calls: 0,
+            cached_calls: 0,
+            failed_calls: 0,
+            input_tokens: 0,
+            output_tokens: 0,
+            cost_micros: 0,
+        }
+    }
+
+    pub fn add(&mut self, call: &AiCallRecord) {
+        self.calls += 1;
+        self.cached_calls += u32::from(call.cached);
+        self.failed_calls += u32::from(!call.succeeded);
+        self.input_tokens += u64::from(call.input_tokens);
+        self.output_tokens += u64::from(call.output_tokens);
+        self.cost_micros += call.cost_micros;
+    }
+
+    /// Adds another summary's counts, e.g. to total several days.
+    pub fn merge(&mut self, other: &AiUsageSummary) {
+        self.calls += other.calls;
+        self.cached_calls += other.cached_calls;
+        self.failed_calls += other.failed_calls;
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cost_micros += other.cost_micros;
+    }
+}
+
+/// An AI request was refused because the caller or the whole site is over its
+/// request rate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AiRateLimited {
+    pub retry_after_secs: u64,
+}
+
+impl fmt::Display for AiRateLimited {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Too many AI requests; try again in {} seconds",
+            self.retry_after_secs
+        )
+    }
+}
+
+impl std::error::Error for AiRateLimited {}
@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::{ApiKeyId, ApiScope, UserId};
+
+/// User-scoped credential for integrations. Only the prefix is stored in clear text;
+/// the secret part is kept as a hash and shown to the user once at creation time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKey {
+    pub id: ApiKeyId,
+    pub user_id: UserId,
+    pub name: String,
+    pub prefix: String,
+    pub secret_hash: String,
+    pub scopes: Vec<ApiScope>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ApiKey {
+    pub fn new(
+        user_id: UserId,
+        name: String,
+        prefix: String,
+        secret_hash: String,
+        scopes: Vec<ApiScope>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: ApiKeyId::new(),
+            user_id,
+            name,
+            prefix,
+            secret_hash,
+            scopes,
+            last_used_at: None,
+            revoked_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.revoked_at.is_none()
+    }
+
+    pub fn has_scope(&self, scope: ApiScope) -> bool {
+        self.scopes.contains(&scope)
+    }
+
+    pub fn revoke(&mut self) {
+        if self.revoked_at.is_none() {
+            let now = Utc::now();
+            self.revoked_at = Some(now);
+            self.updated_at = now;
+        }
+    }
+
+    pub fn mark_used(&mut self) {
+        let now = Utc::now();
+        self.last_used_at = Some(now);
+        self.updated_at = now;
+    }
+}
@@ -0,0 +1,199 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::domain::value_objects::{AssetId, UserId};
+
+/// Kinds of files that can be uploaded. The type is recognized from the file's
+/// content, never from its name or the type the browser claims, so a script
+/// renamed to `.png` is refused rather than served as an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AssetType {
+    Png,
+    Jpeg,
+    Gif,
+    Webp,
+    Pdf,
+}
+
+impl AssetType {
+    pub fn all() -> Vec<AssetType> {
+        vec![
+            AssetType::Png,
+            AssetType::Jpeg,
+            AssetType::Gif,
+            AssetType::Webp,
+            AssetType::Pdf,
+        ]
+    }
+
+    /// The type of `data` by its leading magic bytes, if it is an allowed one.
+    pub fn sniff(data: &[u8]) -> Option<AssetType> {
+        if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Some(AssetType::Png)
+        } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Some(AssetType::Jpeg)
+        } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+            Some(AssetType::Gif)
+        } else if data.len() >= 12 && data.starts_with(b"RIFF") && &data[8..12] == b"WEBP" {
+            Some(AssetType::Webp)
+        } else if data.starts_with(b"%PDF-") {
+            Some(AssetType::Pdf)
+        } else {
+            None
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            AssetType::Png => "image/png",
+            AssetType::Jpeg => "image/jpeg",
+            AssetType::Gif => "image/gif",
+            AssetType::Webp => "image/webp",
+            AssetType::Pdf => "application/pdf",
+        }
+    }
+
+    pub fn from_content_type(s: &str) -> Result<Self, String> {
+        Self::all()
+            .into_iter()
+            .find(|asset_type| asset_type.content_type() == s)
+            .ok_or_else(|| format!("Invalid asset content type: {s}"))
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            AssetType::Png => "png",
+            AssetType::Jpeg => "jpg",
+            AssetType::Gif => "gif",
+            AssetType::Webp => "webp",
+            AssetType::Pdf => "pdf",
+        }
+    }
+
+    pub fn is_image(&self) -> bool {
+        !matches!(self, AssetType::Pdf)
+    }
+}
+
+/// An uploaded file. Content is stored under a key derived from its SHA-256, so
+/// uploading the same file twice stores it once.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Asset {
+    pub id: AssetId,
+    /// Hex SHA-256 of the content.
+    pub sha256: String,
+    pub asset_type: AssetType,
+    pub size_bytes: u64,
+    /// Name the file was uploaded with, for display and downloads only.
+    pub original_filename: String,
+    pub storage_key: String,
+    /// Images only; set once the thumbnail is stored.
+    pub thumbnail_key: Option<String>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    /// `None` for uploads from the admin pages.
+    pub uploaded_by: Option<UserId>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Asset {
+    pub fn new(
+        sha256: String,
+        asset_type: AssetType,
+        size_bytes: u64,
+        original_filename: &str,
+        uploaded_by: Option<UserId>,
+    ) -> Self {
+        Self {
+            id: AssetId::new(),
+            storage_key: format!("{}/{}.{}", &sha256[..2], sha256, asset_type.extension()),
+            sha256,
+            asset_type,
+            size_bytes,
+            original_filename: sanitize_filename(original_filename),
+            thumbnail_key: None,
+            width: None,
+            height: None,
+            uploaded_by,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Records the image's dimensions and that its thumbnail is stored.
+    pub fn add_thumbnail(&mut self, width: u32, height: u32) {
+        self.width = Some(width);
+        self.height = Some(height);
+        self.thumbnail_key = Some(format!(
+            "thumbnails/{}/{}.png",
+            &self.sha256[..2],
+            self.sha256
+        ));
+    }
+
+    /// Public path the asset is served from, for use in lessons and questions.
+    pub fn url(&self) -> String {
+        asset_url(&self.id)
+    }
+
+    pub fn thumbnail_url(&self) -> Option<String> {
+        self.thumbnail_key
+            .as_ref()
+            .map(|_| format!("{}/thumbnail", self.url()))
+    }
+}
+
+/// Public path of the asset with this ID.
+pub fn asset_url(id: &AssetId) -> String {
+    format!("/assets/{id}")
+}
+
+/// The last path component of an uploaded file name, without control characters
+/// or quotes, at most 200 characters.
+pub fn sanitize_filename(name: &str) -> String {
+    let base = name.rsplit(['/', '\\']).next().unwrap_or_default();
+    let cleaned: String = base
+        .chars()
+        .filter(|c| !c.is_control() && !matches!(c, '"' | '\''))
+        .take(200)
+        .collect();
+    let cleaned = cleaned.trim();
+    if cleaned.is_empty() {
+        "upload".to_string()
+    } else {
+        cleaned.to_string()
+    }
+}
+
+/// Why an upload was refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssetError {
+    TooLarge {
+        max_bytes: u64,
+    },
+    Empty,
+    UnsupportedType,
+    /// The file looks like an image but cannot be decoded as one.
+    InvalidImage(String),
+}
+
+impl fmt::Display for AssetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssetError::TooLarge { max_bytes } => {
+                write!(f, "File is larger than the {max_bytes} byte limit")
+            }
+            AssetError::Empty => write!(f, "File is empty"),
+            AssetError::UnsupportedType => {
+                write!(
+                    f,
+                    "Unsupported file type; upload PNG, JPEG, GIF, WebP or PDF"
+                )
+            }
+            AssetError::InvalidImage(reason) => write!(f, "Image could not be read: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for AssetError {}
@@ -1,6 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::domain::entities::hint_usage::HINT_XP_COST_PERCENT;
 use crate::domain::value_objects::{
     CodePracticeId, Difficulty, LessonId, LocalizedText, Points, TopicId,
 };
@@ -73,4 +74,10 @@ impl CodePractice {
         self.points = points;
         self.updated_at = Utc::now();
     }
+
+    /// Completion XP left after revealing `hints_used` hints.
+    pub fn xp_with_hints(&self, hints_used: u32) -> Points {
+        let cost_percent = (hints_used * HINT_XP_COST_PERCENT).min(100);
+        Points::new(self.points.value() * (100 - cost_percent) / 100)
+    }
 }
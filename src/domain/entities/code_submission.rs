@@ -0,0 +1,89 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::{CodePracticeId, CodeSubmissionId, UserId};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubmissionVerdict {
+    Accepted,
+    WrongAnswer,
+    CompileError,
+    RuntimeError,
+    TimeLimitExceeded,
+}
+
+impl SubmissionVerdict {
+    pub fn all() -> Vec<SubmissionVerdict> {
+        vec![
+            SubmissionVerdict::Accepted,
+            SubmissionVerdict::WrongAnswer,
+            SubmissionVerdict::CompileError,
+            SubmissionVerdict::RuntimeError,
+            SubmissionVerdict::TimeLimitExceeded,
+        ]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubmissionVerdict::Accepted => "accepted",
+            SubmissionVerdict::WrongAnswer => "wrong_answer",
+            SubmissionVerdict::CompileError => "compile_error",
+            SubmissionVerdict::RuntimeError => "runtime_error",
+            SubmissionVerdict::TimeLimitExceeded => "time_limit_exceeded",
+        }
+    }
+}
+
+impl std::str::FromStr for SubmissionVerdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "accepted" => Ok(SubmissionVerdict::Accepted),
+            "wrong_answer" => Ok(SubmissionVerdict::WrongAnswer),
+            "compile_error" => Ok(SubmissionVerdict::CompileError),
+            "runtime_error" => Ok(SubmissionVerdict::RuntimeError),
+            "time_limit_exceeded" => Ok(SubmissionVerdict::TimeLimitExceeded),
+            _ => Err(format!("Invalid submission verdict: {s}")),
+        }
+    }
+}
+
+/// One attempt at a code practice, kept so learners and mentors can look back
+/// at how a solution evolved.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeSubmission {
+    pub id: CodeSubmissionId,
+    pub user_id: UserId,
+    pub code_practice_id: CodePracticeId,
+    pub code: String,
+    pub verdict: SubmissionVerdict,
+    /// Compiler and program output, when the code was run.
+    pub runner_output: Option<String>,
+    pub submitted_at: DateTime<Utc>,
+}
+
+impl CodeSubmission {
+    pub fn new(
+        user_id: UserId,
+        code_practice_id: CodePracticeId,
+        code: String,
+        verdict: SubmissionVerdict,
+        runner_output: Option<String>,
+    ) -> Self {
+        Self {
+            id: CodeSubmissionId::new(),
+            user_id,
+            code_practice_id,
+            code,
+            verdict,
+            runner_output,
+            submitted_at: Utc::now(),
+        }
+    }
+
+    pub fn is_accepted(&self) -> bool {
+        self.verdict == SubmissionVerdict::Accepted
+    }
+}
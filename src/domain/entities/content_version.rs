@@ -0,0 +1,132 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+use crate::domain::entities::{ContentKind, TranslatableContent};
+use crate::domain::value_objects::{ContentVersionId, UserId};
+
+/// Snapshot fields left out of diffs: they change on every save.
+const UNVERSIONED_FIELDS: &[&str] = &["updated_at"];
+
+/// Who changed a content item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentAuthor {
+    /// `None` for changes from the admin pages and automated workflows.
+    pub user_id: Option<UserId>,
+    pub name: String,
+}
+
+impl ContentAuthor {
+    pub fn new(name: &str) -> Self {
+        Self {
+            user_id: None,
+            name: name.to_string(),
+        }
+    }
+
+    /// Changes made through the admin pages.
+    pub fn admin() -> Self {
+        Self::new("Admin")
+    }
+}
+
+/// A content item as it was before one change to it, with who made the change,
+/// when and why. Versions count from 1 for each item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContentVersion {
+    pub id: ContentVersionId,
+    pub kind: ContentKind,
+    pub content_id: String,
+    pub version: u32,
+    /// The item before the change, serialized as stored.
+    pub snapshot: Value,
+    pub author: ContentAuthor,
+    pub change_note: String,
+    /// When the change was made.
+    pub created_at: DateTime<Utc>,
+}
+
+impl ContentVersion {
+    /// Unnumbered until recorded; the repository assigns the next number.
+    pub fn new(previous: &TranslatableContent, author: ContentAuthor, change_note: &str) -> Self {
+        Self {
+            id: ContentVersionId::new(),
+            kind: previous.kind(),
+            content_id: previous.content_id(),
+            version: 0,
+            snapshot: previous.snapshot(),
+            author,
+            change_note: change_note.trim().to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    /// The item as it was in this version.
+    pub fn content(&self) -> Result<TranslatableContent, String> {
+        TranslatableContent::from_snapshot(self.kind, self.snapshot.clone())
+    }
+}
+
+/// A value that differs between two snapshots. Nested values are named by path:
+/// `title.id`, `hints.2.en` or `question_type.MultipleChoice.correct_index`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    /// `None` when the value was added.
+    pub before: Option<Value>,
+    /// `None` when the value was removed.
+    pub after: Option<Value>,
+}
+
+/// Every value that differs between two snapshots of an item, in field order.
+/// Localized text is compared locale by locale and lists item by item.
+pub fn diff_snapshots(before: &Value, after: &Value) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    diff_values("", before, after, &mut changes);
+    changes.retain(|change| !UNVERSIONED_FIELDS.contains(&change.field.as_str()));
+    changes
+}
+
+fn diff_values(path: &str, before: &Value, after: &Value, changes: &mut Vec<FieldChange>) {
+    let child_path = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{path}.{key}")
+        }
+    };
+    let mut diff_children =
+        |key: String, before: Option<&Value>, after: Option<&Value>| match (before, after) {
+            (Some(before), Some(after)) => diff_values(&key, before, after, changes),
+            (before, after) => changes.push(FieldChange {
+                field: key,
+                before: before.cloned(),
+                after: after.cloned(),
+            }),
+        };
+
+    match (before, after) {
+        (Value::Object(before), Value::Object(after)) => {
+            let keys: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+            for key in keys {
+                diff_children(child_path(key), before.get(key), after.get(key));
+            }
+        }
+        (Value::Array(before), Value::Array(after)) => {
+            for index in 0..before.len().max(after.len()) {
+                diff_children(
+                    child_path(&index.to_string()),
+                    before.get(index),
+                    after.get(index),
+                );
+            }
+        }
+        _ if before != after => changes.push(FieldChange {
+            field: path.to_string(),
+            before: Some(before.clone()),
+            after: Some(after.clone()),
+        }),
+        _ => {}
+    }
+}
@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::{CodePracticeId, UserId};
+
+/// Share of a code practice's points each revealed hint takes off its completion XP.
+pub const HINT_XP_COST_PERCENT: u32 = 20;
+
+/// How many of a code practice's hints a learner has revealed. Hints are revealed
+/// in order, so the count is enough to know which ones they have seen.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HintUsage {
+    pub user_id: UserId,
+    pub code_practice_id: CodePracticeId,
+    pub hints_revealed: u32,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HintUsage {
+    pub fn new(user_id: UserId, code_practice_id: CodePracticeId) -> Self {
+        Self {
+            user_id,
+            code_practice_id,
+            hints_revealed: 0,
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// Reveals the next hint out of `total_hints`. Returns its index.
+    pub fn reveal_next(&mut self, total_hints: u32) -> Result<u32, String> {
+        if self.hints_revealed >= total_hints {
+            return Err("All hints have already been revealed".to_string());
+        }
+        self.hints_revealed += 1;
+        self.updated_at = Utc::now();
+        Ok(self.hints_revealed - 1)
+    }
+}
@@ -1,7 +1,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::domain::value_objects::{LessonId, LocalizedText, QuestionId, TopicId};
+use crate::domain::entities::LessonBlock;
+use crate::domain::value_objects::{AssetId, LessonId, LocalizedText, QuestionId, TopicId};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Lesson {
@@ -10,6 +11,8 @@ pub struct Lesson {
     pub topic_id: TopicId,
     pub summary: LocalizedText,
     pub questions: Vec<QuestionId>,
+    /// Teaching material, in reading order.
+    pub blocks: Vec<LessonBlock>,
     pub attribution_url: String,
     pub order: u32,
     pub created_at: DateTime<Utc>,
@@ -31,6 +34,7 @@ impl Lesson {
             topic_id,
             summary,
             questions: Vec::new(),
+            blocks: Vec::new(),
             attribution_url,
             order,
             created_at: now,
@@ -54,4 +58,44 @@ impl Lesson {
         self.order = order;
         self.updated_at = Utc::now();
     }
+
+    /// Replaces the lesson's content, rejecting it whole if any block is invalid.
+    pub fn set_blocks(&mut self, blocks: Vec<LessonBlock>) -> Result<(), String> {
+        for (index, block) in blocks.iter().enumerate() {
+            block
+                .validate()
+                .map_err(|e| format!("Block {} ({}): {}", index + 1, block.code(), e))?;
+        }
+        self.blocks = blocks;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Questions embedded in the content, in order of appearance.
+    pub fn embedded_question_ids(&self) -> Vec<&QuestionId> {
+        self.blocks
+            .iter()
+            .filter_map(|block| match block {
+                LessonBlock::Question { question_id } => Some(question_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Uploaded images shown in the content, in order of appearance.
+    pub fn embedded_asset_ids(&self) -> Vec<AssetId> {
+        self.blocks
+            .iter()
+            .filter_map(LessonBlock::asset_id)
+            .collect()
+    }
+
+    /// The whole content as sanitized HTML in the first of `locales` with text.
+    pub fn render_html(&self, locales: &[&str]) -> String {
+        self.blocks
+            .iter()
+            .map(|block| block.render_html(locales))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
 }
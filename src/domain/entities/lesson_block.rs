@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use crate::domain::value_objects::{AssetId, LocalizedText, QuestionId};
+use crate::shared::utils::{escape_html, render_markdown};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CalloutStyle {
+    #[default]
+    Note,
+    Tip,
+    Warning,
+}
+
+impl CalloutStyle {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CalloutStyle::Note => "note",
+            CalloutStyle::Tip => "tip",
+            CalloutStyle::Warning => "warning",
+        }
+    }
+}
+
+/// One piece of a lesson's teaching material. Lessons show their blocks in
+/// order; text is localized per block and Markdown where noted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LessonBlock {
+    /// Markdown prose.
+    Markdown { text: LocalizedText },
+    /// A Rust snippet. Runnable ones are offered to learners to run as is.
+    Code {
+        code: String,
+        #[serde(default)]
+        runnable: bool,
+        #[serde(default, skip_serializing_if = "LocalizedText::is_empty")]
+        caption: LocalizedText,
+    },
+    /// Markdown set apart from the prose, such as a tip or a common pitfall.
+    Callout {
+        #[serde(default)]
+        style: CalloutStyle,
+        text: LocalizedText,
+    },
+    /// An image by URL: absolute `http(s)` or a path on this site.
+    Image {
+        url: String,
+        alt: LocalizedText,
+        #[serde(default, skip_serializing_if = "LocalizedText::is_empty")]
+        caption: LocalizedText,
+    },
+    /// A question learners answer in place.
+    Question { question_id: QuestionId },
+}
+
+impl LessonBlock {
+    /// Short, stable identifier of the block type, as exposed to clients.
+    pub fn code(&self) -> &'static str {
+        match self {
+            LessonBlock::Markdown { .. } => "markdown",
+            LessonBlock::Code { .. } => "code",
+            LessonBlock::Callout { .. } => "callout",
+            LessonBlock::Image { .. } => "image",
+            LessonBlock::Question { .. } => "question",
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            LessonBlock::Markdown { text } | LessonBlock::Callout { text, .. } => {
+                require_english(text, "text")
+            }
+            LessonBlock::Code { code, .. } => {
+                if code.trim().is_empty() {
+                    return Err("Code is empty".to_string());
+                }
+                Ok(())
+            }
+            LessonBlock::Image { url, alt, .. } => {
+                let on_site = url.starts_with('/') && !url.starts_with("//");
+                if !(on_site || url.starts_with("https://") || url.starts_with("http://")) {
+                    return Err(format!("Image URL must be http(s) or a site path: {url}"));
+                }
+                require_english(alt, "alt text")
+            }
+            LessonBlock::Question { .. } => Ok(()),
+        }
+    }
+
+    /// The uploaded asset an image block shows, when its URL is one of ours.
+    pub fn asset_id(&self) -> Option<AssetId> {
+        let LessonBlock::Image { url, .. } = self else {
+            return None;
+        };
+        let path = url.strip_prefix("/assets/")?;
+        let id = path.strip_suffix("/thumbnail").unwrap_or(path);
+        AssetId::from_str(id).ok()
+    }
+
+    /// Localized fields by name, for translating them one at a time.
+    pub fn localized_fields(&self) -> Vec<(&'static str, &LocalizedText)> {
+        match self {
+            LessonBlock::Markdown { text } | LessonBlock::Callout { text, .. } => {
+                vec![("text", text)]
+            }
+            LessonBlock::Code { caption, .. } => vec![("caption", caption)],
+            LessonBlock::Image { alt, caption, .. } => vec![("alt", alt), ("caption", caption)],
+            LessonBlock::Question { .. } => Vec::new(),
+        }
+    }
+
+    pub fn localized_field_mut(&mut self, field: &str) -> Option<&mut LocalizedText> {
+        match (self, field) {
+            (LessonBlock::Markdown { text }, "text")
+            | (LessonBlock::Callout { text, .. }, "text") => Some(text),
+            (LessonBlock::Code { caption, .. }, "caption")
+            | (LessonBlock::Image { caption, .. }, "caption") => Some(caption),
+            (LessonBlock::Image { alt, .. }, "alt") => Some(alt),
+            _ => None,
+        }
+    }
+
+    /// The block as HTML in the first of `locales` with text. Markdown is
+    /// rendered and sanitized, every other value escaped. Questions become an
+    /// empty placeholder carrying the question ID, for the client to fill in.
+    pub fn render_html(&self, locales: &[&str]) -> String {
+        match self {
+            LessonBlock::Markdown { text } => format!(
+                "<div class=\"lesson-markdown\">{}</div>",
+                render_markdown(text.get(locales))
+            ),
+            LessonBlock::Code {
+                code,
+                runnable,
+                caption,
+            } => format!(
+                "<figure class=\"lesson-code\"><pre><code class=\"language-rust\" data-runnable=\"{}\">{}</code></pre>{}</figure>",
+                runnable,
+                escape_html(code),
+                figcaption(caption, locales)
+            ),
+            LessonBlock::Callout { style, text } => format!(
+                "<aside class=\"lesson-callout lesson-callout-{}\">{}</aside>",
+                style.as_str(),
+                render_markdown(text.get(locales))
+            ),
+            LessonBlock::Image { url, alt, caption } => format!(
+                "<figure class=\"lesson-image\"><img src=\"{}\" alt=\"{}\">{}</figure>",
+                escape_html(url),
+                escape_html(alt.get(locales)),
+                figcaption(caption, locales)
+            ),
+            LessonBlock::Question { question_id } => format!(
+                "<div class=\"lesson-question\" data-question-id=\"{}\"></div>",
+                question_id.to_string()
+            ),
+        }
+    }
+}
+
+fn require_english(text: &LocalizedText, field: &str) -> Result<(), String> {
+    if text.english().trim().is_empty() {
+        return Err(format!("English {field} is required"));
+    }
+    Ok(())
+}
+
+fn figcaption(caption: &LocalizedText, locales: &[&str]) -> String {
+    if caption.is_empty() {
+        return String::new();
+    }
+    format!(
+        "<figcaption>{}</figcaption>",
+        escape_html(caption.get(locales))
+    )
+}
@@ -0,0 +1,193 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::user_progress::{LessonResult, QuestionResult};
+use crate::domain::value_objects::{LessonId, LessonSessionId, Points, QuestionId, UserId};
+
+/// XP added to a lesson's reward when it is finished with three stars.
+pub const PERFECT_LESSON_XP_BONUS: u32 = 20;
+pub const MAX_LESSON_STARS: u32 = 3;
+/// Fraction of a correct answer deducted from the score for every retry.
+const RETRY_PENALTY: f64 = 0.5;
+const TWO_STAR_SCORE: f64 = 0.8;
+const ONE_STAR_SCORE: f64 = 0.5;
+
+/// Latest answer to one question of a session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionAnswer {
+    pub question_id: QuestionId,
+    pub user_answer: String,
+    pub correct: bool,
+    pub attempts: u32,
+    /// Time spent over all attempts.
+    pub time_spent_ms: u64,
+    /// Points the question is worth once answered correctly.
+    pub points: Points,
+    pub answered_at: DateTime<Utc>,
+}
+
+/// A learner's run through a lesson. Questions can be retried until answered
+/// correctly; retries lower the stars awarded when the session is finished.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LessonSession {
+    pub id: LessonSessionId,
+    pub user_id: UserId,
+    pub lesson_id: LessonId,
+    /// Snapshot of the lesson's questions when the session started.
+    pub question_ids: Vec<QuestionId>,
+    pub answers: Vec<SessionAnswer>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Number of times the session has been saved, so a save based on a stale
+    /// copy can be detected.
+    #[serde(skip)]
+    pub revision: u32,
+}
+
+impl LessonSession {
+    pub fn new(user_id: UserId, lesson_id: LessonId, question_ids: Vec<QuestionId>) -> Self {
+        Self {
+            id: LessonSessionId::new(),
+            user_id,
+            lesson_id,
+            question_ids,
+            answers: Vec::new(),
+            started_at: Utc::now(),
+            finished_at: None,
+            revision: 0,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished_at.is_some()
+    }
+
+    pub fn answer_for(&self, question_id: &QuestionId) -> Option<&SessionAnswer> {
+        self.answers.iter().find(|a| a.question_id == *question_id)
+    }
+
+    /// Records an attempt at one of the session's questions.
+    pub fn record_answer(
+        &mut self,
+        question_id: QuestionId,
+        user_answer: String,
+        correct: bool,
+        time_spent_ms: u64,
+        points: Points,
+    ) -> Result<&SessionAnswer, String> {
+        if self.is_finished() {
+            return Err("Lesson session is already finished".to_string());
+        }
+        if !self.question_ids.contains(&question_id) {
+            return Err("Question is not part of this lesson session".to_string());
+        }
+
+        let now = Utc::now();
+        let index = match self
+            .answers
+            .iter()
+            .position(|a| a.question_id == question_id)
+        {
+            Some(index) => {
+                let answer = &mut self.answers[index];
+                if answer.correct {
+                    return Err("Question has already been answered correctly".to_string());
+                }
+                answer.user_answer = user_answer;
+                answer.correct = correct;
+                answer.attempts += 1;
+                answer.time_spent_ms += time_spent_ms;
+                answer.answered_at = now;
+                index
+            }
+            None => {
+                self.answers.push(SessionAnswer {
+                    question_id,
+                    user_answer,
+                    correct,
+                    attempts: 1,
+                    time_spent_ms,
+                    points,
+                    answered_at: now,
+                });
+                self.answers.len() - 1
+            }
+        };
+
+        Ok(&self.answers[index])
+    }
+
+    pub fn correct_answers(&self) -> u32 {
+        self.answers.iter().filter(|a| a.correct).count() as u32
+    }
+
+    pub fn retries(&self) -> u32 {
+        self.answers.iter().map(|a| a.attempts - 1).sum()
+    }
+
+    /// Accuracy between 0 and 1, with each retry costing half a correct answer.
+    /// Unanswered questions count as wrong.
+    pub fn score(&self) -> f64 {
+        if self.question_ids.is_empty() {
+            return 0.0;
+        }
+        let earned = self.correct_answers() as f64 - RETRY_PENALTY * self.retries() as f64;
+        (earned / self.question_ids.len() as f64).clamp(0.0, 1.0)
+    }
+
+    /// Three stars need every question right on the first try.
+    pub fn stars(&self) -> u32 {
+        let score = self.score();
+        if score >= 1.0 {
+            MAX_LESSON_STARS
+        } else if score >= TWO_STAR_SCORE {
+            2
+        } else if score >= ONE_STAR_SCORE {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Closes the session and builds its result. `xp_earned` is the points of the
+    /// correctly answered questions plus the perfect-score bonus, before any cap.
+    pub fn finish(&mut self) -> Result<LessonResult, String> {
+        if self.is_finished() {
+            return Err("Lesson session is already finished".to_string());
+        }
+        let now = Utc::now();
+        self.finished_at = Some(now);
+
+        let perfect_score = self.stars() == MAX_LESSON_STARS;
+        let mut xp_earned = self
+            .answers
+            .iter()
+            .filter(|a| a.correct)
+            .map(|a| a.points.value())
+            .sum::<u32>();
+        if perfect_score {
+            xp_earned += PERFECT_LESSON_XP_BONUS;
+        }
+
+        let question_results = self
+            .answers
+            .iter()
+            .map(|a| QuestionResult {
+                question_id: a.question_id.clone(),
+                correct: a.correct,
+                user_answer: a.user_answer.clone(),
+                time_spent_ms: a.time_spent_ms,
+                points: if a.correct { a.points } else { Points::new(0) },
+                completed_at: a.answered_at,
+            })
+            .collect();
+
+        Ok(LessonResult {
+            lesson_id: self.lesson_id.clone(),
+            question_results,
+            completed_at: now,
+            xp_earned: Points::new(xp_earned),
+            perfect_score,
+        })
+    }
+}
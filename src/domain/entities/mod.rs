@@ -1,17 +1,55 @@
+pub mod account_audit_event;
+pub mod achievement;
+pub mod ai_usage;
+pub mod api_key;
+pub mod asset;
 pub mod code_practice;
+pub mod code_submission;
+pub mod content_version;
+pub mod hint_usage;
 pub mod leaderboard_entry;
 pub mod lesson;
+pub mod lesson_block;
+pub mod lesson_session;
 pub mod notification;
+pub mod prerequisite;
 pub mod question;
+pub mod question_draft;
+pub mod quiz;
+pub mod quiz_attempt;
+pub mod review_card;
 pub mod topic;
+pub mod topic_ability;
+pub mod translation;
+pub mod tutor_feedback;
 pub mod user;
 pub mod user_progress;
 
+pub use account_audit_event::{AccountAuditAction, AccountAuditEvent};
+pub use achievement::{Achievement, AchievementRule, AchievementStats, UserAchievement};
+pub use ai_usage::{AiCallRecord, AiFeature, AiRateLimited, AiUsageSummary};
+pub use api_key::ApiKey;
+pub use asset::{asset_url, sanitize_filename, Asset, AssetError, AssetType};
 pub use code_practice::CodePractice;
+pub use code_submission::{CodeSubmission, SubmissionVerdict};
+pub use content_version::{diff_snapshots, ContentAuthor, ContentVersion, FieldChange};
+pub use hint_usage::HintUsage;
 pub use leaderboard_entry::LeaderboardEntry;
 pub use lesson::Lesson;
+pub use lesson_block::{CalloutStyle, LessonBlock};
+pub use lesson_session::{LessonSession, SessionAnswer};
 pub use notification::Notification;
+pub use prerequisite::{LessonPrerequisite, TopicPrerequisite, UnlockState};
 pub use question::{Question, QuestionType};
+pub use question_draft::{DraftStatus, GeneratedQuestion, QuestionDraft};
+pub use quiz::Quiz;
+pub use quiz_attempt::{QuizAnswer, QuizAttempt, QuizAttemptItem};
+pub use review_card::ReviewCard;
 pub use topic::Topic;
+pub use topic_ability::TopicAbility;
+pub use translation::{
+    ContentKind, MachineTranslation, TranslatableContent, TranslationStatus, UntranslatedField,
+};
+pub use tutor_feedback::{MistakeContext, TutorError, TutorFeedback, TutorUsage};
 pub use user::User;
 pub use user_progress::UserProgress;
@@ -0,0 +1,115 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::domain::value_objects::{LessonId, TopicId};
+
+/// Stars every lesson of a prerequisite topic needs unless the edge says otherwise.
+pub const DEFAULT_TOPIC_PREREQUISITE_STARS: u32 = 2;
+/// Stars a prerequisite lesson needs unless the edge says otherwise.
+pub const DEFAULT_LESSON_PREREQUISITE_STARS: u32 = 1;
+
+/// `topic_id` unlocks once every lesson of `prerequisite_topic_id` has `min_stars`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TopicPrerequisite {
+    pub topic_id: TopicId,
+    pub prerequisite_topic_id: TopicId,
+    pub min_stars: u32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TopicPrerequisite {
+    pub fn new(topic_id: TopicId, prerequisite_topic_id: TopicId, min_stars: u32) -> Self {
+        Self {
+            topic_id,
+            prerequisite_topic_id,
+            min_stars,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// `lesson_id` unlocks once `prerequisite_lesson_id` has `min_stars`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LessonPrerequisite {
+    pub lesson_id: LessonId,
+    pub prerequisite_lesson_id: LessonId,
+    pub min_stars: u32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LessonPrerequisite {
+    pub fn new(lesson_id: LessonId, prerequisite_lesson_id: LessonId, min_stars: u32) -> Self {
+        Self {
+            lesson_id,
+            prerequisite_lesson_id,
+            min_stars,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Where a topic or lesson stands on a learner's path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnlockState {
+    Locked,
+    Unlocked,
+    Completed,
+}
+
+impl UnlockState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnlockState::Locked => "locked",
+            UnlockState::Unlocked => "unlocked",
+            UnlockState::Completed => "completed",
+        }
+    }
+}
+
+/// Dependency graph where an edge points from a node to the nodes it requires.
+#[derive(Debug, Clone)]
+pub struct DependencyGraph<T: Eq + Hash + Clone> {
+    edges: HashMap<T, Vec<T>>,
+}
+
+impl<T: Eq + Hash + Clone> Default for DependencyGraph<T> {
+    fn default() -> Self {
+        Self {
+            edges: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Eq + Hash + Clone> DependencyGraph<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_edge(&mut self, node: T, requires: T) {
+        self.edges.entry(node).or_default().push(requires);
+    }
+
+    /// Whether any of `targets` can be reached from any of `starts`. Adding edges
+    /// from the targets to the starts would then close a cycle.
+    pub fn reaches(&self, starts: &[T], targets: &[T]) -> bool {
+        let targets: HashSet<&T> = targets.iter().collect();
+        let mut visited: HashSet<&T> = HashSet::new();
+        let mut stack: Vec<&T> = starts.iter().collect();
+
+        while let Some(node) = stack.pop() {
+            if targets.contains(node) {
+                return true;
+            }
+            if !visited.insert(node) {
+                continue;
+            }
+            if let Some(requires) = self.edges.get(node) {
+                stack.extend(requires.iter());
+            }
+        }
+        false
+    }
+}
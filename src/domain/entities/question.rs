@@ -1,7 +1,10 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::domain::value_objects::{Difficulty, LocalizedText, Points, QuestionId, TopicId};
+use crate::domain::entities::asset_url;
+use crate::domain::value_objects::{
+    AssetId, Difficulty, LocalizedText, Points, QuestionId, TopicId,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum QuestionType {
@@ -30,6 +33,20 @@ pub enum QuestionType {
     },
 }
 
+impl QuestionType {
+    /// Short, stable identifier of the question type, as exposed to clients.
+    pub fn code(&self) -> &'static str {
+        match self {
+            QuestionType::MultipleChoice { .. } => "mcq",
+            QuestionType::TrueFalse { .. } => "tf",
+            QuestionType::FillInBlank { .. } => "fib",
+            QuestionType::CodeOutputPrediction { .. } => "predict_output",
+            QuestionType::CodeFix { .. } => "code_fix",
+            QuestionType::CodeWriting { .. } => "code_write",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CodeValidators {
     pub must_include: Option<Vec<String>>,
@@ -37,6 +54,17 @@ pub struct CodeValidators {
     pub test_cases: Option<Vec<TestCase>>,
 }
 
+impl CodeValidators {
+    /// Whether any snippet is required or forbidden, the only checks that run
+    /// without executing the code.
+    pub fn has_static_checks(&self) -> bool {
+        [&self.must_include, &self.must_not_include]
+            .into_iter()
+            .flatten()
+            .any(|snippets| !snippets.is_empty())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TestCase {
     pub input: String,
@@ -52,6 +80,8 @@ pub struct Question {
     pub difficulty: Difficulty,
     pub points: Points,
     pub question_type: QuestionType,
+    /// Uploaded image shown with the prompt.
+    pub image: Option<AssetId>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -73,6 +103,7 @@ impl Question {
             difficulty,
             points,
             question_type,
+            image: None,
             created_at: now,
             updated_at: now,
         }
@@ -92,4 +123,158 @@ impl Question {
         self.points = points;
         self.updated_at = Utc::now();
     }
+
+    /// Answer options for choice-based questions, in display order. Multiple-choice
+    /// options are stored one per line in each locale; the English lines decide
+    /// how many options there are.
+    pub fn choice_options(&self) -> Vec<LocalizedText> {
+        match &self.question_type {
+            QuestionType::MultipleChoice { choices, .. } => {
+                let mut options: Vec<LocalizedText> = non_empty_lines(choices.english())
+                    .map(|line| LocalizedText::from_english(line.to_string()))
+                    .collect();
+                for (locale, lines) in choices.translations() {
+                    for (option, line) in options.iter_mut().zip(non_empty_lines(lines)) {
+                        option.set(locale, line.to_string());
+                    }
+                }
+                options
+            }
+            QuestionType::CodeFix { choices, .. } => choices
+                .iter()
+                .map(|choice| LocalizedText::from_english(choice.clone()))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn image_url(&self) -> Option<String> {
+        self.image.as_ref().map(asset_url)
+    }
+
+    /// Snippet the learner reads, fixes or starts from, for code questions.
+    pub fn display_code(&self) -> Option<&str> {
+        match &self.question_type {
+            QuestionType::CodeOutputPrediction { code, .. }
+            | QuestionType::CodeFix { code, .. } => Some(code),
+            QuestionType::CodeWriting { scaffold, .. } => Some(scaffold),
+            _ => None,
+        }
+    }
+
+    /// A copy with the answer options reordered so that option `i` is the stored
+    /// option `order[i]`, and the correct index remapped to match. Questions without
+    /// options, or an order that is not a permutation of them, are returned unchanged.
+    pub fn with_choice_order(&self, order: &[u32]) -> Question {
+        let options = self.choice_options();
+        let mut sorted = order.to_vec();
+        sorted.sort_unstable();
+        if options.is_empty() || !sorted.iter().copied().eq(0..options.len() as u32) {
+            return self.clone();
+        }
+
+        let remap = |correct_index: u32| {
+            order
+                .iter()
+                .position(|&i| i == correct_index)
+                .map_or(correct_index, |p| p as u32)
+        };
+        let mut question = self.clone();
+        question.question_type = match &self.question_type {
+            QuestionType::MultipleChoice {
+                choices: stored,
+                correct_index,
+            } => {
+                let mut choices = LocalizedText::default();
+                for locale in stored.locales() {
+                    let lines: Vec<&str> = order
+                        .iter()
+                        .map(|&i| options[i as usize].get(locale))
+                        .collect();
+                    choices.set(locale, lines.join("\n"));
+                }
+                QuestionType::MultipleChoice {
+                    choices,
+                    correct_index: remap(*correct_index),
+                }
+            }
+            QuestionType::CodeFix {
+                code,
+                choices,
+                correct_index,
+            } => QuestionType::CodeFix {
+                code: code.clone(),
+                choices: order.iter().map(|&i| choices[i as usize].clone()).collect(),
+                correct_index: remap(*correct_index),
+            },
+            other => other.clone(),
+        };
+        question
+    }
+
+    /// The answer `check_answer` accepts, in the same format, for showing after
+    /// grading. Code-writing questions have no single expected answer.
+    pub fn expected_answer(&self) -> Option<String> {
+        match &self.question_type {
+            QuestionType::MultipleChoice { correct_index, .. }
+            | QuestionType::CodeFix { correct_index, .. } => Some(correct_index.to_string()),
+            QuestionType::TrueFalse { answer } => Some(answer.to_string()),
+            QuestionType::FillInBlank { acceptable_answers } => acceptable_answers.first().cloned(),
+            QuestionType::CodeOutputPrediction {
+                expected_stdout, ..
+            } => Some(expected_stdout.clone()),
+            QuestionType::CodeWriting { .. } => None,
+        }
+    }
+
+    /// Grades a learner's answer. Choice-based questions take the option index,
+    /// true/false takes `true` or `false`, the rest take free text.
+    pub fn check_answer(&self, answer: &str) -> bool {
+        let answer = answer.trim();
+        match &self.question_type {
+            QuestionType::MultipleChoice { correct_index, .. }
+            | QuestionType::CodeFix { correct_index, .. } => {
+                answer.parse::<u32>().ok() == Some(*correct_index)
+            }
+            QuestionType::TrueFalse { answer: expected } => {
+                answer.to_lowercase().parse::<bool>().ok() == Some(*expected)
+            }
+            QuestionType::FillInBlank { acceptable_answers } => acceptable_answers
+                .iter()
+                .any(|acceptable| acceptable.trim().eq_ignore_ascii_case(answer)),
+            QuestionType::CodeOutputPrediction {
+                expected_stdout, ..
+            } => normalize_output(answer) == normalize_output(expected_stdout),
+            // Test cases need a sandbox to run; only the static checks apply here,
+            // so an answer nothing can check is never counted as correct
+            QuestionType::CodeWriting { validators, .. } => {
+                validators.has_static_checks()
+                    && !answer.is_empty()
+                    && validators
+                        .must_include
+                        .iter()
+                        .flatten()
+                        .all(|snippet| answer.contains(snippet.as_str()))
+                    && !validators
+                        .must_not_include
+                        .iter()
+                        .flatten()
+                        .any(|snippet| answer.contains(snippet.as_str()))
+            }
+        }
+    }
+}
+
+fn non_empty_lines(text: &str) -> impl Iterator<Item = &str> {
+    text.lines().map(str::trim).filter(|line| !line.is_empty())
+}
+
+/// Ignores trailing whitespace and line-ending differences in program output.
+fn normalize_output(output: &str) -> String {
+    output
+        .trim_end()
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
 }
@@ -0,0 +1,401 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::question::CodeValidators;
+use crate::domain::entities::{Question, QuestionType};
+use crate::domain::value_objects::{Difficulty, LocalizedText, Points, QuestionDraftId, TopicId};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DraftStatus {
+    Pending,
+    Accepted,
+    Rejected,
+}
+
+impl DraftStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DraftStatus::Pending => "pending",
+            DraftStatus::Accepted => "accepted",
+            DraftStatus::Rejected => "rejected",
+        }
+    }
+}
+
+impl std::str::FromStr for DraftStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(DraftStatus::Pending),
+            "accepted" => Ok(DraftStatus::Accepted),
+            "rejected" => Ok(DraftStatus::Rejected),
+            _ => Err(format!("Invalid draft status: {s}")),
+        }
+    }
+}
+
+/// A generated question waiting for an editor to accept, edit or reject it.
+/// Accepted drafts are saved as questions under the draft question's ID.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestionDraft {
+    pub id: QuestionDraftId,
+    pub question: Question,
+    pub status: DraftStatus,
+    /// Where the draft came from, e.g. the model that generated it.
+    pub source: String,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+impl QuestionDraft {
+    pub fn new(question: Question, source: String) -> Self {
+        Self {
+            id: QuestionDraftId::new(),
+            question,
+            status: DraftStatus::Pending,
+            source,
+            created_at: Utc::now(),
+            reviewed_at: None,
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.status == DraftStatus::Pending
+    }
+
+    pub fn update_question(&mut self, question: Question) -> Result<(), String> {
+        self.ensure_pending()?;
+        self.question = question;
+        Ok(())
+    }
+
+    pub fn accept(&mut self) -> Result<(), String> {
+        self.review(DraftStatus::Accepted)
+    }
+
+    pub fn reject(&mut self) -> Result<(), String> {
+        self.review(DraftStatus::Rejected)
+    }
+
+    fn review(&mut self, status: DraftStatus) -> Result<(), String> {
+        self.ensure_pending()?;
+        self.status = status;
+        self.reviewed_at = Some(Utc::now());
+        Ok(())
+    }
+
+    fn ensure_pending(&self) -> Result<(), String> {
+        if self.is_pending() {
+            Ok(())
+        } else {
+            Err(format!("Draft was already {}", self.status.as_str()))
+        }
+    }
+}
+
+/// One question as a model is asked to return it, before validation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedQuestion {
+    pub prompt_en: String,
+    pub prompt_id: String,
+    pub explanation_en: String,
+    pub explanation_id: String,
+    #[serde(flatten)]
+    pub answer: GeneratedAnswer,
+}
+
+/// The answer definition, tagged with the `QuestionType::code` it maps to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum GeneratedAnswer {
+    #[serde(rename = "mcq")]
+    MultipleChoice {
+        choices_en: Vec<String>,
+        choices_id: Vec<String>,
+        correct_index: u32,
+    },
+    #[serde(rename = "tf")]
+    TrueFalse { answer: bool },
+    #[serde(rename = "fib")]
+    FillInBlank { acceptable_answers: Vec<String> },
+    #[serde(rename = "predict_output")]
+    CodeOutputPrediction {
+        code: String,
+        expected_stdout: String,
+    },
+    #[serde(rename = "code_fix")]
+    CodeFix {
+        code: String,
+        choices: Vec<String>,
+        correct_index: u32,
+    },
+    #[serde(rename = "code_write")]
+    CodeWriting {
+        scaffold: String,
+        #[serde(default)]
+        must_include: Vec<String>,
+        #[serde(default)]
+        must_not_include: Vec<String>,
+    },
+}
+
+/// Question type codes that can be generated, as used by `QuestionType::code`.
+pub const GENERATED_QUESTION_TYPES: &[&str] = &[
+    "mcq",
+    "tf",
+    "fib",
+    "predict_output",
+    "code_fix",
+    "code_write",
+];
+
+/// JSON shape a model must return for one question of the given type, to be
+/// quoted in the prompt. `None` for unknown type codes.
+pub fn generated_question_schema(type_code: &str) -> Option<&'static str> {
+    let schema = match type_code {
+        "mcq" => {
+            r#"{"type": "mcq", "prompt_en": string, "prompt_id": string, "explanation_en": string, "explanation_id": string, "choices_en": [2-6 single-line strings], "choices_id": [same choices in Indonesian], "correct_index": 0-based integer}"#
+        }
+        "tf" => {
+            r#"{"type": "tf", "prompt_en": string, "prompt_id": string, "explanation_en": string, "explanation_id": string, "answer": boolean}"#
+        }
+        "fib" => {
+            r#"{"type": "fib", "prompt_en": string with ___ for the blank, "prompt_id": string, "explanation_en": string, "explanation_id": string, "acceptable_answers": [strings]}"#
+        }
+        "predict_output" => {
+            r#"{"type": "predict_output", "prompt_en": string, "prompt_id": string, "explanation_en": string, "explanation_id": string, "code": complete Rust program, "expected_stdout": exact output}"#
+        }
+        "code_fix" => {
+            r#"{"type": "code_fix", "prompt_en": string, "prompt_id": string, "explanation_en": string, "explanation_id": string, "code": Rust code with a bug, "choices": [2-6 candidate fixes], "correct_index": 0-based integer}"#
+        }
+        "code_write" => {
+            r#"{"type": "code_write", "prompt_en": string, "prompt_id": string, "explanation_en": string, "explanation_id": string, "scaffold": Rust starter code, "must_include": [strings the solution must contain], "must_not_include": [strings it must not contain]}"#
+        }
+        _ => return None,
+    };
+    Some(schema)
+}
+
+/// Parses a model reply holding a JSON array of questions, or an object with a
+/// `questions` array, optionally inside a Markdown code fence.
+pub fn parse_generated_questions(reply: &str) -> Result<Vec<GeneratedQuestion>, String> {
+    let trimmed = reply.trim();
+    let json = trimmed
+        .strip_prefix("```json")
+        .or_else(|| trimmed.strip_prefix("```"))
+        .and_then(|rest| rest.trim_end().strip_suffix("```"))
+        .unwrap_or(trimmed);
+
+    let mut value: serde_json::Value =
+        serde_json::from_str(json.trim()).map_err(|e| format!("Reply is not valid JSON: {e}"))?;
+    if let Some(questions) = value.get_mut("questions") {
+        value = questions.take();
+    }
+    let questions: Vec<GeneratedQuestion> = serde_json::from_value(value)
+        .map_err(|e| format!("Reply does not match the question schema: {e}"))?;
+    if questions.is_empty() {
+        return Err("Reply contains no questions".to_string());
+    }
+    Ok(questions)
+}
+
+impl GeneratedQuestion {
+    pub fn type_code(&self) -> &'static str {
+        match self.answer {
+            GeneratedAnswer::MultipleChoice { .. } => "mcq",
+            GeneratedAnswer::TrueFalse { .. } => "tf",
+            GeneratedAnswer::FillInBlank { .. } => "fib",
+            GeneratedAnswer::CodeOutputPrediction { .. } => "predict_output",
+            GeneratedAnswer::CodeFix { .. } => "code_fix",
+            GeneratedAnswer::CodeWriting { .. } => "code_write",
+        }
+    }
+
+    /// Checks the question can be answered as stored: both languages present,
+    /// options in range and code where the type needs it.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.prompt_en.trim().is_empty() || self.prompt_id.trim().is_empty() {
+            return Err("Prompt must be given in English and Indonesian".to_string());
+        }
+        match &self.answer {
+            GeneratedAnswer::MultipleChoice {
+                choices_en,
+                choices_id,
+                correct_index,
+            } => {
+                validate_choices(choices_en, *correct_index)?;
+                if choices_id.len() != choices_en.len() {
+                    return Err("Choices must be given in both languages".to_string());
+                }
+                validate_choices(choices_id, *correct_index)?;
+                // Stored one per line, so a choice cannot span lines
+                if choices_en
+                    .iter()
+                    .chain(choices_id)
+                    .any(|c| c.contains('\n'))
+                {
+                    return Err("Choices must be single lines".to_string());
+                }
+            }
+            GeneratedAnswer::TrueFalse { .. } => {}
+            GeneratedAnswer::FillInBlank { acceptable_answers } => {
+                if acceptable_answers.iter().all(|a| a.trim().is_empty()) {
+                    return Err("Fill-in-the-blank needs an acceptable answer".to_string());
+                }
+            }
+            GeneratedAnswer::CodeOutputPrediction { code, .. } => require_code(code)?,
+            GeneratedAnswer::CodeFix {
+                code,
+                choices,
+                correct_index,
+            } => {
+                require_code(code)?;
+                validate_choices(choices, *correct_index)?;
+            }
+            GeneratedAnswer::CodeWriting { scaffold, .. } => require_code(scaffold)?,
+        }
+        Ok(())
+    }
+
+    /// A new question built from this one, after validating it.
+    pub fn into_question(
+        self,
+        topic_id: TopicId,
+        difficulty: Difficulty,
+        points: Points,
+    ) -> Result<Question, String> {
+        self.validate()?;
+
+        let question_type = match self.answer {
+            GeneratedAnswer::MultipleChoice {
+                choices_en,
+                choices_id,
+                correct_index,
+            } => QuestionType::MultipleChoice {
+                choices: LocalizedText::new(choices_en.join("\n"), choices_id.join("\n")),
+                correct_index,
+            },
+            GeneratedAnswer::TrueFalse { answer } => QuestionType::TrueFalse { answer },
+            GeneratedAnswer::FillInBlank { acceptable_answers } => {
+                QuestionType::FillInBlank { acceptable_answers }
+            }
+            GeneratedAnswer::CodeOutputPrediction {
+                code,
+                expected_stdout,
+            } => QuestionType::CodeOutputPrediction {
+                code,
+                expected_stdout,
+            },
+            GeneratedAnswer::CodeFix {
+                code,
+                choices,
+                correct_index,
+            } => QuestionType::CodeFix {
+                code,
+                choices,
+                correct_index,
+            },
+            GeneratedAnswer::CodeWriting {
+                scaffold,
+                must_include,
+                must_not_include,
+            } => QuestionType::CodeWriting {
+                scaffold,
+                validators: CodeValidators {
+                    must_include: (!must_include.is_empty()).then_some(must_include),
+                    must_not_include: (!must_not_include.is_empty()).then_some(must_not_include),
+                    test_cases: None,
+                },
+            },
+        };
+
+        let mut question = Question::new(
+            LocalizedText::new(self.prompt_en, self.prompt_id),
+            topic_id,
+            difficulty,
+            points,
+            question_type,
+        );
+        if !self.explanation_en.trim().is_empty() {
+            question.set_explanation(LocalizedText::new(self.explanation_en, self.explanation_id));
+        }
+        Ok(question)
+    }
+}
+
+/// The editable form of a question, used to revise drafts with the same
+/// validation as generated output.
+impl From<&Question> for GeneratedQuestion {
+    fn from(question: &Question) -> Self {
+        let answer = match &question.question_type {
+            QuestionType::MultipleChoice { correct_index, .. } => {
+                let options = question.choice_options();
+                GeneratedAnswer::MultipleChoice {
+                    choices_en: options.iter().map(|o| o.english().to_string()).collect(),
+                    choices_id: options.iter().map(|o| o.get("id").to_string()).collect(),
+                    correct_index: *correct_index,
+                }
+            }
+            QuestionType::TrueFalse { answer } => GeneratedAnswer::TrueFalse { answer: *answer },
+            QuestionType::FillInBlank { acceptable_answers } => GeneratedAnswer::FillInBlank {
+                acceptable_answers: acceptable_answers.clone(),
+            },
+            QuestionType::CodeOutputPrediction {
+                code,
+                expected_stdout,
+            } => GeneratedAnswer::CodeOutputPrediction {
+                code: code.clone(),
+                expected_stdout: expected_stdout.clone(),
+            },
+            QuestionType::CodeFix {
+                code,
+                choices,
+                correct_index,
+            } => GeneratedAnswer::CodeFix {
+                code: code.clone(),
+                choices: choices.clone(),
+                correct_index: *correct_index,
+            },
+            QuestionType::CodeWriting {
+                scaffold,
+                validators,
+            } => GeneratedAnswer::CodeWriting {
+                scaffold: scaffold.clone(),
+                must_include: validators.must_include.clone().unwrap_or_default(),
+                must_not_include: validators.must_not_include.clone().unwrap_or_default(),
+            },
+        };
+        let explanation = question.explanation.clone().unwrap_or_default();
+
+        Self {
+            prompt_en: question.prompt.english().to_string(),
+            prompt_id: question.prompt.get("id").to_string(),
+            explanation_en: explanation.english().to_string(),
+            explanation_id: explanation.get("id").to_string(),
+            answer,
+        }
+    }
+}
+
+fn validate_choices(choices: &[String], correct_index: u32) -> Result<(), String> {
+    if !(2..=6).contains(&choices.len()) {
+        return Err("Questions need between 2 and 6 choices".to_string());
+    }
+    if correct_index as usize >= choices.len() {
+        return Err(format!("Correct index {correct_index} is out of range"));
+    }
+    // Blank lines are dropped when choices are read back, shifting the indices
+    if choices.iter().any(|choice| choice.trim().is_empty()) {
+        return Err("Choices must not be blank".to_string());
+    }
+    Ok(())
+}
+
+fn require_code(code: &str) -> Result<(), String> {
+    if code.trim().is_empty() {
+        return Err("Code questions need a code snippet".to_string());
+    }
+    Ok(())
+}
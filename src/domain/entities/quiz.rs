@@ -0,0 +1,100 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::Question;
+use crate::domain::value_objects::{Difficulty, LocalizedText, QuizId, TopicId};
+
+/// Percentage of the available points needed to pass, unless configured otherwise.
+pub const DEFAULT_QUIZ_PASSING_SCORE: u32 = 70;
+
+/// A fixed-length, timed assessment. Every attempt draws `question_count` questions
+/// at random from the pool of questions in `topic_ids` with an allowed difficulty.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Quiz {
+    pub id: QuizId,
+    pub title: LocalizedText,
+    pub description: LocalizedText,
+    pub topic_ids: Vec<TopicId>,
+    /// Difficulties drawn from; empty means any.
+    pub difficulties: Vec<Difficulty>,
+    pub question_count: u32,
+    pub time_limit_secs: u32,
+    /// Percentage of the available points needed to pass.
+    pub passing_score: u32,
+    /// `None` allows unlimited attempts.
+    pub max_attempts: Option<u32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Quiz {
+    pub fn new(
+        title: LocalizedText,
+        description: LocalizedText,
+        topic_ids: Vec<TopicId>,
+        question_count: u32,
+        time_limit_secs: u32,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: QuizId::new(),
+            title,
+            description,
+            topic_ids,
+            difficulties: Vec::new(),
+            question_count,
+            time_limit_secs,
+            passing_score: DEFAULT_QUIZ_PASSING_SCORE,
+            max_attempts: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn update_difficulties(&mut self, difficulties: Vec<Difficulty>) {
+        self.difficulties = difficulties;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn update_passing_score(&mut self, passing_score: u32) {
+        self.passing_score = passing_score;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn update_max_attempts(&mut self, max_attempts: Option<u32>) {
+        self.max_attempts = max_attempts;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn time_limit(&self) -> Duration {
+        Duration::seconds(i64::from(self.time_limit_secs))
+    }
+
+    /// Whether a question belongs to this quiz's pool.
+    pub fn includes(&self, question: &Question) -> bool {
+        self.topic_ids.contains(&question.topic_id)
+            && (self.difficulties.is_empty() || self.difficulties.contains(&question.difficulty))
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.title.english().trim().is_empty() {
+            return Err("Quiz title is required".to_string());
+        }
+        if self.topic_ids.is_empty() {
+            return Err("A quiz needs at least one topic".to_string());
+        }
+        if self.question_count == 0 {
+            return Err("A quiz needs at least one question".to_string());
+        }
+        if self.time_limit_secs == 0 {
+            return Err("Time limit must be positive".to_string());
+        }
+        if self.passing_score > 100 {
+            return Err("Passing score must be between 0 and 100".to_string());
+        }
+        if self.max_attempts == Some(0) {
+            return Err("Allowed attempts must be positive".to_string());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::Quiz;
+use crate::domain::value_objects::{Points, QuestionId, QuizAttemptId, QuizId, UserId};
+
+/// A question drawn for an attempt.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuizAttemptItem {
+    pub question_id: QuestionId,
+    /// `choice_order[i]` is the stored index of the option shown at position `i`.
+    /// Empty for questions without options.
+    pub choice_order: Vec<u32>,
+    pub points: Points,
+}
+
+/// Latest answer to one question of an attempt, as given against the shuffled options.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuizAnswer {
+    pub question_id: QuestionId,
+    pub user_answer: String,
+    pub correct: bool,
+    pub answered_at: DateTime<Utc>,
+}
+
+/// A learner's try at a quiz. Answers can be changed until the attempt is
+/// submitted or its deadline passes; no feedback is given before then.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuizAttempt {
+    pub id: QuizAttemptId,
+    pub quiz_id: QuizId,
+    pub user_id: UserId,
+    pub items: Vec<QuizAttemptItem>,
+    pub answers: Vec<QuizAnswer>,
+    pub started_at: DateTime<Utc>,
+    pub deadline: DateTime<Utc>,
+    pub submitted_at: Option<DateTime<Utc>>,
+}
+
+impl QuizAttempt {
+    pub fn new(
+        quiz: &Quiz,
+        user_id: UserId,
+        items: Vec<QuizAttemptItem>,
+        started_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: QuizAttemptId::new(),
+            quiz_id: quiz.id.clone(),
+            user_id,
+            items,
+            answers: Vec::new(),
+            started_at,
+            deadline: started_at + quiz.time_limit(),
+            submitted_at: None,
+        }
+    }
+
+    pub fn is_submitted(&self) -> bool {
+        self.submitted_at.is_some()
+    }
+
+    /// Whether answers are still accepted at `now`.
+    pub fn is_open(&self, now: DateTime<Utc>) -> bool {
+        !self.is_submitted() && now < self.deadline
+    }
+
+    pub fn remaining_secs(&self, now: DateTime<Utc>) -> i64 {
+        if self.is_open(now) {
+            (self.deadline - now).num_seconds()
+        } else {
+            0
+        }
+    }
+
+    pub fn item(&self, question_id: &QuestionId) -> Option<&QuizAttemptItem> {
+        self.items.iter().find(|i| i.question_id == *question_id)
+    }
+
+    pub fn answer_for(&self, question_id: &QuestionId) -> Option<&QuizAnswer> {
+        self.answers.iter().find(|a| a.question_id == *question_id)
+    }
+
+    /// Records or replaces the answer to one of the attempt's questions.
+    pub fn record_answer(
+        &mut self,
+        question_id: QuestionId,
+        user_answer: String,
+        correct: bool,
+        now: DateTime<Utc>,
+    ) -> Result<(), String> {
+        if self.is_submitted() {
+            return Err("Quiz attempt is already submitted".to_string());
+        }
+        if now >= self.deadline {
+            return Err("Time is up for this quiz attempt".to_string());
+        }
+        if self.item(&question_id).is_none() {
+            return Err("Question is not part of this quiz attempt".to_string());
+        }
+
+        let answer = QuizAnswer {
+            question_id,
+            user_answer,
+            correct,
+            answered_at: now,
+        };
+        match self
+            .answers
+            .iter_mut()
+            .find(|a| a.question_id == answer.question_id)
+        {
+            Some(existing) => *existing = answer,
+            None => self.answers.push(answer),
+        }
+        Ok(())
+    }
+
+    /// Closes the attempt. An attempt submitted late is closed at its deadline;
+    /// answers could not be recorded after it anyway.
+    pub fn submit(&mut self, now: DateTime<Utc>) -> Result<(), String> {
+        if self.is_submitted() {
+            return Err("Quiz attempt is already submitted".to_string());
+        }
+        self.submitted_at = Some(now.min(self.deadline));
+        Ok(())
+    }
+
+    pub fn correct_answers(&self) -> u32 {
+        self.answers.iter().filter(|a| a.correct).count() as u32
+    }
+
+    pub fn total_points(&self) -> u32 {
+        self.items.iter().map(|i| i.points.value()).sum()
+    }
+
+    pub fn earned_points(&self) -> u32 {
+        self.items
+            .iter()
+            .filter(|i| self.answer_for(&i.question_id).is_some_and(|a| a.correct))
+            .map(|i| i.points.value())
+            .sum()
+    }
+
+    /// Percentage of the available points earned, rounded down.
+    pub fn score(&self) -> u32 {
+        match self.total_points() {
+            0 => 0,
+            total => self.earned_points() * 100 / total,
+        }
+    }
+
+    pub fn passed(&self, passing_score: u32) -> bool {
+        self.is_submitted() && self.score() >= passing_score
+    }
+}
@@ -0,0 +1,132 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::domain::value_objects::{QuestionId, UserId};
+
+pub const DEFAULT_EASE_FACTOR: f64 = 2.5;
+/// SM-2 never lets a card get harder than this.
+pub const MIN_EASE_FACTOR: f64 = 1.3;
+/// Most questions served in one review set.
+pub const DAILY_REVIEW_LIMIT: usize = 20;
+/// XP for each correctly recalled review question.
+pub const REVIEW_XP: u32 = 5;
+/// Correct answers at least this fast count as effortless recall.
+pub const QUICK_RECALL_MS: u64 = 8_000;
+/// Correct answers slower than this count as recalled with difficulty.
+pub const SLOW_RECALL_MS: u64 = 30_000;
+
+/// A learner's memory of one question, scheduled with SM-2.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewCard {
+    pub user_id: UserId,
+    pub question_id: QuestionId,
+    pub ease_factor: f64,
+    pub interval_days: u32,
+    /// Successful reviews in a row.
+    pub repetitions: u32,
+    /// Times the question was forgotten after having been learned.
+    pub lapses: u32,
+    /// In the learner's local calendar.
+    pub due_date: NaiveDate,
+    pub last_reviewed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ReviewCard {
+    pub fn new(user_id: UserId, question_id: QuestionId, today: NaiveDate) -> Self {
+        let now = Utc::now();
+        Self {
+            user_id,
+            question_id,
+            ease_factor: DEFAULT_EASE_FACTOR,
+            interval_days: 0,
+            repetitions: 0,
+            lapses: 0,
+            due_date: today,
+            last_reviewed_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// SM-2 response quality (0-5) from correctness and answer time. An unknown
+    /// time (`0`) is treated as a normal recall.
+    pub fn recall_quality(correct: bool, time_spent_ms: u64) -> u8 {
+        match (correct, time_spent_ms) {
+            (false, _) => 1,
+            (true, 0) => 4,
+            (true, ms) if ms <= QUICK_RECALL_MS => 5,
+            (true, ms) if ms > SLOW_RECALL_MS => 3,
+            (true, _) => 4,
+        }
+    }
+
+    pub fn is_due(&self, today: NaiveDate) -> bool {
+        self.due_date <= today
+    }
+
+    /// Applies one review and schedules the next. A failed recall (quality below 3)
+    /// starts the card over and brings it back tomorrow.
+    pub fn review(&mut self, quality: u8, today: NaiveDate) {
+        let quality = quality.min(5);
+        if quality < 3 {
+            if self.repetitions > 0 {
+                self.lapses += 1;
+            }
+            self.repetitions = 0;
+            self.interval_days = 1;
+        } else {
+            self.interval_days = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => (self.interval_days as f64 * self.ease_factor).round() as u32,
+            };
+            self.repetitions += 1;
+        }
+
+        let miss = f64::from(5 - quality);
+        self.ease_factor =
+            (self.ease_factor + 0.1 - miss * (0.08 + miss * 0.02)).max(MIN_EASE_FACTOR);
+        self.due_date = today + Duration::days(i64::from(self.interval_days));
+
+        let now = Utc::now();
+        self.last_reviewed_at = Some(now);
+        self.updated_at = now;
+    }
+}
+
+/// Reorders items round-robin across their keys, keeping each key's own order,
+/// so consecutive items come from different groups where possible.
+pub fn interleave_by_key<T, K, F>(items: Vec<T>, key: F) -> Vec<T>
+where
+    K: Eq + Hash + Clone,
+    F: Fn(&T) -> K,
+{
+    let mut order: Vec<K> = Vec::new();
+    let mut groups: HashMap<K, VecDeque<T>> = HashMap::new();
+    for item in items {
+        let k = key(&item);
+        if !groups.contains_key(&k) {
+            order.push(k.clone());
+        }
+        groups.entry(k).or_default().push_back(item);
+    }
+
+    let mut mixed = Vec::new();
+    while !groups.is_empty() {
+        for k in &order {
+            if let Some(group) = groups.get_mut(k) {
+                if let Some(item) = group.pop_front() {
+                    mixed.push(item);
+                }
+                if group.is_empty() {
+                    groups.remove(k);
+                }
+            }
+        }
+    }
+    mixed
+}
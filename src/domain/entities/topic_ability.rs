@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::{Difficulty, TopicId};
+
+/// Rating of a learner with no history in a topic, level with `Intermediate`.
+pub const INITIAL_ABILITY: f64 = 1000.0;
+/// How far one answer can move the rating.
+pub const ABILITY_K_FACTOR: f64 = 32.0;
+/// Success chance the selector aims for: hard enough to learn from, easy
+/// enough to keep going.
+pub const TARGET_SUCCESS_RATE: f64 = 0.7;
+
+/// A learner's Elo-style ability in one topic, estimated from their answers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TopicAbility {
+    pub topic_id: TopicId,
+    pub rating: f64,
+    pub answered: u32,
+    pub correct: u32,
+}
+
+impl TopicAbility {
+    pub fn new(topic_id: TopicId) -> Self {
+        Self {
+            topic_id,
+            rating: INITIAL_ABILITY,
+            answered: 0,
+            correct: 0,
+        }
+    }
+
+    /// Probability of answering a question of `difficulty` correctly.
+    pub fn expected_success(&self, difficulty: Difficulty) -> f64 {
+        1.0 / (1.0 + 10f64.powf((difficulty.rating() - self.rating) / 400.0))
+    }
+
+    pub fn record(&mut self, difficulty: Difficulty, correct: bool) {
+        let score = if correct { 1.0 } else { 0.0 };
+        self.rating += ABILITY_K_FACTOR * (score - self.expected_success(difficulty));
+        self.answered += 1;
+        if correct {
+            self.correct += 1;
+        }
+    }
+
+    /// How far `difficulty` is from the target success rate; lower is a better fit.
+    pub fn mismatch(&self, difficulty: Difficulty) -> f64 {
+        (self.expected_success(difficulty) - TARGET_SUCCESS_RATE).abs()
+    }
+
+    /// The difficulty that currently fits the learner best.
+    pub fn recommended_difficulty(&self) -> Difficulty {
+        Difficulty::all()
+            .into_iter()
+            .min_by(|a, b| self.mismatch(*a).total_cmp(&self.mismatch(*b)))
+            .unwrap_or(Difficulty::Beginner)
+    }
+
+    pub fn accuracy(&self) -> Option<f64> {
+        (self.answered > 0).then(|| f64::from(self.correct) / f64::from(self.answered))
+    }
+}
@@ -0,0 +1,359 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::{CodePractice, Lesson, Question, QuestionType, Topic};
+use crate::domain::value_objects::{LocalizedText, TranslationId};
+
+/// Kinds of content with localized text that editors can update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentKind {
+    Topic,
+    Lesson,
+    Question,
+    CodePractice,
+}
+
+impl ContentKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentKind::Topic => "topic",
+            ContentKind::Lesson => "lesson",
+            ContentKind::Question => "question",
+            ContentKind::CodePractice => "code_practice",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ContentKind::Topic => "Topic",
+            ContentKind::Lesson => "Lesson",
+            ContentKind::Question => "Question",
+            ContentKind::CodePractice => "Code practice",
+        }
+    }
+}
+
+impl std::str::FromStr for ContentKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "topic" => Ok(ContentKind::Topic),
+            "lesson" => Ok(ContentKind::Lesson),
+            "question" => Ok(ContentKind::Question),
+            "code_practice" => Ok(ContentKind::CodePractice),
+            _ => Err(format!("Invalid content kind: {s}")),
+        }
+    }
+}
+
+/// A content item, for working on its localized fields without caring which
+/// kind it is.
+#[derive(Debug, Clone)]
+pub enum TranslatableContent {
+    Topic(Topic),
+    Lesson(Lesson),
+    Question(Question),
+    CodePractice(CodePractice),
+}
+
+impl TranslatableContent {
+    pub fn kind(&self) -> ContentKind {
+        match self {
+            TranslatableContent::Topic(_) => ContentKind::Topic,
+            TranslatableContent::Lesson(_) => ContentKind::Lesson,
+            TranslatableContent::Question(_) => ContentKind::Question,
+            TranslatableContent::CodePractice(_) => ContentKind::CodePractice,
+        }
+    }
+
+    pub fn content_id(&self) -> String {
+        match self {
+            TranslatableContent::Topic(topic) => topic.id.to_string(),
+            TranslatableContent::Lesson(lesson) => lesson.id.to_string(),
+            TranslatableContent::Question(question) => question.id.to_string(),
+            TranslatableContent::CodePractice(practice) => practice.id.to_string(),
+        }
+    }
+
+    /// English title, for listing the item to editors.
+    pub fn title(&self) -> &str {
+        match self {
+            TranslatableContent::Topic(topic) => topic.title.english(),
+            TranslatableContent::Lesson(lesson) => lesson.title.english(),
+            TranslatableContent::Question(question) => question.prompt.english(),
+            TranslatableContent::CodePractice(practice) => practice.title.english(),
+        }
+    }
+
+    /// Every localized field by name. List items are named `field.index`, and
+    /// lesson blocks `blocks.index.field`; the options of a multiple-choice
+    /// question are one field, one option per line. Empty optional fields such as
+    /// block captions are left out.
+    pub fn localized_fields(&self) -> Vec<(String, &LocalizedText)> {
+        let mut fields: Vec<(String, &LocalizedText)> = Vec::new();
+        match self {
+            TranslatableContent::Topic(topic) => {
+                fields.push(("title".to_string(), &topic.title));
+                fields.push(("description".to_string(), &topic.description));
+                fields.push(("required_skills".to_string(), &topic.required_skills));
+            }
+            TranslatableContent::Lesson(lesson) => {
+                fields.push(("title".to_string(), &lesson.title));
+                fields.push(("summary".to_string(), &lesson.summary));
+                for (index, block) in lesson.blocks.iter().enumerate() {
+                    for (field, text) in block.localized_fields() {
+                        if !text.is_empty() {
+                            fields.push((format!("blocks.{index}.{field}"), text));
+                        }
+                    }
+                }
+            }
+            TranslatableContent::Question(question) => {
+                fields.push(("prompt".to_string(), &question.prompt));
+                if let Some(explanation) = &question.explanation {
+                    fields.push(("explanation".to_string(), explanation));
+                }
+                if let QuestionType::MultipleChoice { choices, .. } = &question.question_type {
+                    fields.push(("choices".to_string(), choices));
+                }
+            }
+            TranslatableContent::CodePractice(practice) => {
+                fields.push(("title".to_string(), &practice.title));
+                fields.push(("description".to_string(), &practice.description));
+                for (index, hint) in practice.hints.iter().enumerate() {
+                    fields.push((format!("hints.{index}"), hint));
+                }
+            }
+        }
+        fields
+    }
+
+    pub fn localized_field(&self, field: &str) -> Option<&LocalizedText> {
+        self.localized_fields()
+            .into_iter()
+            .find(|(name, _)| name == field)
+            .map(|(_, text)| text)
+    }
+
+    /// Sets the text of `field` in `language` and marks the item as updated.
+    pub fn set_translation(
+        &mut self,
+        field: &str,
+        language: &str,
+        text: String,
+    ) -> Result<(), String> {
+        let target = match (&mut *self, field) {
+            (TranslatableContent::Topic(topic), "title") => &mut topic.title,
+            (TranslatableContent::Topic(topic), "description") => &mut topic.description,
+            (TranslatableContent::Topic(topic), "required_skills") => &mut topic.required_skills,
+            (TranslatableContent::Lesson(lesson), "title") => &mut lesson.title,
+            (TranslatableContent::Lesson(lesson), "summary") => &mut lesson.summary,
+            (TranslatableContent::Question(question), "prompt") => &mut question.prompt,
+            (TranslatableContent::Question(question), "explanation") => question
+                .explanation
+                .as_mut()
+                .ok_or_else(|| "Question has no explanation".to_string())?,
+            (TranslatableContent::Question(question), "choices") => {
+                match &mut question.question_type {
+                    QuestionType::MultipleChoice { choices, .. } => choices,
+                    _ => return Err("Question has no translatable choices".to_string()),
+                }
+            }
+            (TranslatableContent::Lesson(lesson), field) => field
+                .strip_prefix("blocks.")
+                .and_then(|rest| rest.split_once('.'))
+                .and_then(|(index, name)| {
+                    let block = lesson.blocks.get_mut(index.parse::<usize>().ok()?)?;
+                    block.localized_field_mut(name)
+                })
+                .ok_or_else(|| format!("Unknown field: {field}"))?,
+            (TranslatableContent::CodePractice(practice), "title") => &mut practice.title,
+            (TranslatableContent::CodePractice(practice), "description") => {
+                &mut practice.description
+            }
+            (TranslatableContent::CodePractice(practice), field) => field
+                .strip_prefix("hints.")
+                .and_then(|index| index.parse::<usize>().ok())
+                .and_then(|index| practice.hints.get_mut(index))
+                .ok_or_else(|| format!("Unknown field: {field}"))?,
+            (_, field) => return Err(format!("Unknown field: {field}")),
+        };
+        target.set(language, text);
+        self.touch();
+        Ok(())
+    }
+
+    /// Marks the item as updated now.
+    pub fn touch(&mut self) {
+        let now = Utc::now();
+        match self {
+            TranslatableContent::Topic(topic) => topic.updated_at = now,
+            TranslatableContent::Lesson(lesson) => lesson.updated_at = now,
+            TranslatableContent::Question(question) => question.updated_at = now,
+            TranslatableContent::CodePractice(practice) => practice.updated_at = now,
+        }
+    }
+
+    /// The whole item as JSON, for keeping earlier versions of it.
+    pub fn snapshot(&self) -> serde_json::Value {
+        let snapshot = match self {
+            TranslatableContent::Topic(topic) => serde_json::to_value(topic),
+            TranslatableContent::Lesson(lesson) => serde_json::to_value(lesson),
+            TranslatableContent::Question(question) => serde_json::to_value(question),
+            TranslatableContent::CodePractice(practice) => serde_json::to_value(practice),
+        };
+        // Content holds only strings, numbers and string-keyed maps
+        snapshot.expect("content serializes to JSON")
+    }
+
+    pub fn from_snapshot(kind: ContentKind, snapshot: serde_json::Value) -> Result<Self, String> {
+        let invalid = |e: serde_json::Error| format!("Invalid {} snapshot: {e}", kind.as_str());
+        Ok(match kind {
+            ContentKind::Topic => {
+                TranslatableContent::Topic(serde_json::from_value(snapshot).map_err(invalid)?)
+            }
+            ContentKind::Lesson => {
+                TranslatableContent::Lesson(serde_json::from_value(snapshot).map_err(invalid)?)
+            }
+            ContentKind::Question => {
+                TranslatableContent::Question(serde_json::from_value(snapshot).map_err(invalid)?)
+            }
+            ContentKind::CodePractice => TranslatableContent::CodePractice(
+                serde_json::from_value(snapshot).map_err(invalid)?,
+            ),
+        })
+    }
+}
+
+/// A field whose Indonesian text is missing or copied from the English.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UntranslatedField {
+    pub kind: ContentKind,
+    pub content_id: String,
+    pub content_title: String,
+    pub field: String,
+    pub source_text: String,
+}
+
+impl UntranslatedField {
+    /// Rejects translations that cannot be stored as they are: multiple-choice
+    /// options must stay one per line, in the same number.
+    pub fn check_translation(&self, translated: &str) -> Result<(), String> {
+        let lines = |text: &str| text.lines().filter(|line| !line.trim().is_empty()).count();
+        if translated.trim().is_empty() {
+            return Err("Translation is empty".to_string());
+        }
+        if self.field == "choices" && lines(&self.source_text) != lines(translated) {
+            return Err("Translation does not keep one option per line".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn matches(&self, translation: &MachineTranslation) -> bool {
+        self.kind == translation.kind
+            && self.content_id == translation.content_id
+            && self.field == translation.field
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TranslationStatus {
+    /// Machine-translated, waiting for an editor.
+    NeedsReview,
+    Approved,
+    Rejected,
+}
+
+impl TranslationStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TranslationStatus::NeedsReview => "needs_review",
+            TranslationStatus::Approved => "approved",
+            TranslationStatus::Rejected => "rejected",
+        }
+    }
+}
+
+impl std::str::FromStr for TranslationStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "needs_review" => Ok(TranslationStatus::NeedsReview),
+            "approved" => Ok(TranslationStatus::Approved),
+            "rejected" => Ok(TranslationStatus::Rejected),
+            _ => Err(format!("Invalid translation status: {s}")),
+        }
+    }
+}
+
+/// A machine translation of one field. It is written into the content only
+/// once an editor approves it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MachineTranslation {
+    pub id: TranslationId,
+    pub kind: ContentKind,
+    pub content_id: String,
+    pub field: String,
+    pub language: String,
+    /// The English text that was translated.
+    pub source_text: String,
+    pub translated_text: String,
+    pub status: TranslationStatus,
+    /// LLM provider that translated it.
+    pub provider: String,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+impl MachineTranslation {
+    pub fn new(
+        field: &UntranslatedField,
+        language: String,
+        translated_text: String,
+        provider: String,
+    ) -> Self {
+        Self {
+            id: TranslationId::new(),
+            kind: field.kind,
+            content_id: field.content_id.clone(),
+            field: field.field.clone(),
+            language,
+            source_text: field.source_text.clone(),
+            translated_text,
+            status: TranslationStatus::NeedsReview,
+            provider,
+            created_at: Utc::now(),
+            reviewed_at: None,
+        }
+    }
+
+    pub fn needs_review(&self) -> bool {
+        self.status == TranslationStatus::NeedsReview
+    }
+
+    /// Whether the English was edited after it was translated.
+    pub fn is_stale(&self, current: &LocalizedText) -> bool {
+        current.english() != self.source_text
+    }
+
+    pub fn approve(&mut self) -> Result<(), String> {
+        self.review(TranslationStatus::Approved)
+    }
+
+    pub fn reject(&mut self) -> Result<(), String> {
+        self.review(TranslationStatus::Rejected)
+    }
+
+    fn review(&mut self, status: TranslationStatus) -> Result<(), String> {
+        if !self.needs_review() {
+            return Err(format!("Translation was already {}", self.status.as_str()));
+        }
+        self.status = status;
+        self.reviewed_at = Some(Utc::now());
+        Ok(())
+    }
+}
@@ -0,0 +1,213 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::domain::entities::{CodePractice, CodeSubmission, Question};
+use crate::domain::value_objects::UserId;
+use crate::shared::utils::{code_similarity, stable_hash};
+
+/// Answers shorter than this are not looked for in hints verbatim; values such
+/// as `3` or `true` turn up in ordinary prose.
+const MIN_LEAK_CHECK_LEN: usize = 8;
+
+/// A code block in a hint at least this similar to the solution gives it away.
+const CODE_LEAK_SIMILARITY: u32 = 60;
+
+/// Everything the tutor is told about a wrong answer, in the learner's language.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MistakeContext {
+    /// The question prompt or exercise description.
+    pub task: String,
+    /// Code shown with the task, if any.
+    pub code: Option<String>,
+    pub correct_answer: Option<String>,
+    pub explanation: Option<String>,
+    pub learner_answer: String,
+    /// Compiler diagnostics or test failures, for code.
+    pub diagnostics: Option<String>,
+}
+
+impl MistakeContext {
+    /// A wrong answer to `question`, given against its options in the order the
+    /// learner saw them.
+    pub fn for_question(question: &Question, learner_answer: &str, language: &str) -> Self {
+        let options = question.choice_options();
+        // Choice answers are indices; the tutor needs the option text
+        let describe = |answer: &str| match answer.trim().parse::<usize>() {
+            Ok(index) if index < options.len() => {
+                format!("{index}: {}", options[index].get(language))
+            }
+            _ => answer.to_string(),
+        };
+        Self {
+            task: question.prompt.get(language).to_string(),
+            code: question.display_code().map(str::to_string),
+            correct_answer: question.expected_answer().map(|answer| describe(&answer)),
+            explanation: question
+                .explanation
+                .as_ref()
+                .map(|explanation| explanation.get(language).to_string())
+                .filter(|explanation| !explanation.is_empty()),
+            learner_answer: describe(learner_answer),
+            diagnostics: None,
+        }
+    }
+
+    /// A code submission to `code_practice` that was not accepted.
+    pub fn for_code_submission(
+        code_practice: &CodePractice,
+        submission: &CodeSubmission,
+        language: &str,
+    ) -> Self {
+        let mut diagnostics = format!("Verdict: {}", submission.verdict.as_str());
+        if let Some(output) = &submission.runner_output {
+            diagnostics.push('\n');
+            diagnostics.push_str(output);
+        }
+        Self {
+            task: format!(
+                "{}\n{}",
+                code_practice.title.get(language),
+                code_practice.description.get(language)
+            ),
+            code: None,
+            correct_answer: Some(code_practice.solution.clone()),
+            explanation: None,
+            learner_answer: submission.code.clone(),
+            diagnostics: Some(diagnostics),
+        }
+    }
+
+    /// Same for identical (question, answer) pairs, so their hint is generated once.
+    /// Editing the question changes the key.
+    pub fn cache_key(&self, language: &str) -> String {
+        stable_hash(&[
+            language,
+            &self.task,
+            self.code.as_deref().unwrap_or_default(),
+            self.correct_answer.as_deref().unwrap_or_default(),
+            self.explanation.as_deref().unwrap_or_default(),
+            self.learner_answer.trim(),
+            self.diagnostics.as_deref().unwrap_or_default(),
+        ])
+    }
+
+    /// Whether `hint` gives the correct answer away, verbatim or as code close
+    /// to it.
+    pub fn reveals_answer(&self, hint: &str) -> bool {
+        let Some(answer) = self.correct_answer.as_deref() else {
+            return false;
+        };
+        let answer_text = collapse_whitespace(answer);
+        if answer_text.len() >= MIN_LEAK_CHECK_LEN
+            && collapse_whitespace(hint).contains(&answer_text)
+        {
+            return true;
+        }
+        code_blocks(hint).any(|block| code_similarity(block, answer) >= CODE_LEAK_SIMILARITY)
+    }
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Contents of the fenced code blocks in a Markdown reply.
+fn code_blocks(text: &str) -> impl Iterator<Item = &str> {
+    text.split("```").skip(1).step_by(2).map(|block| {
+        // Drop the language tag on the opening fence
+        block.split_once('\n').map_or(block, |(_, code)| code)
+    })
+}
+
+/// A hint generated for one (question, answer) pair, reused for identical pairs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TutorFeedback {
+    pub cache_key: String,
+    pub language: String,
+    pub hint: String,
+    /// LLM provider that wrote the hint.
+    pub provider: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TutorFeedback {
+    pub fn new(cache_key: String, language: String, hint: String, provider: String) -> Self {
+        Self {
+            cache_key,
+            language,
+            hint,
+            provider,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Tutor hints generated for a learner on one local day. Cached hints are free.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TutorUsage {
+    pub user_id: UserId,
+    pub usage_date: NaiveDate,
+    pub requests: u32,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TutorUsage {
+    pub fn new(user_id: UserId, usage_date: NaiveDate) -> Self {
+        Self {
+            user_id,
+            usage_date,
+            requests: 0,
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn remaining(&self, quota: u32) -> u32 {
+        quota.saturating_sub(self.requests)
+    }
+
+    pub fn record_request(&mut self, quota: u32) -> Result<(), TutorError> {
+        if self.remaining(quota) == 0 {
+            return Err(TutorError::QuotaExceeded { quota });
+        }
+        self.requests += 1;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+}
+
+/// Why the tutor gave no hint, for callers that report these differently from
+/// other failures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TutorError {
+    QuotaExceeded {
+        quota: u32,
+    },
+    /// Every hint the provider wrote gave the answer away.
+    NoSafeHint,
+    /// The LLM provider failed.
+    Unavailable(String),
+}
+
+impl fmt::Display for TutorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TutorError::QuotaExceeded { quota } => write!(
+                f,
+                "Daily tutor limit of {quota} hints reached; try again tomorrow"
+            ),
+            TutorError::NoSafeHint => {
+                write!(
+                    f,
+                    "The tutor could not write a hint without giving the answer away"
+                )
+            }
+            TutorError::Unavailable(reason) => write!(f, "The tutor is unavailable: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for TutorError {}
@@ -1,8 +1,14 @@
 use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 
 use crate::domain::value_objects::{Email, Password, Points, UserId};
 
+pub const DEFAULT_LANGUAGE: &str = "en";
+pub const DEFAULT_TIME_ZONE: &str = "UTC";
+/// Shown instead of the real name once an account is scheduled for deletion.
+pub const ANONYMIZED_DISPLAY_NAME: &str = "Anonymous learner";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
     pub id: UserId,
@@ -13,6 +19,19 @@ pub struct User {
     pub current_streak_days: u32,
     pub highest_streak_days: u32,
     pub last_active_date: chrono::NaiveDate,
+    pub preferred_language: String,
+    /// IANA time zone name, e.g. `Asia/Jakarta`.
+    pub time_zone: String,
+    pub avatar_url: Option<String>,
+    /// New address waiting for verification; `email` stays unchanged until then.
+    pub pending_email: Option<Email>,
+    pub email_verification_token_hash: Option<String>,
+    pub email_verification_expires_at: Option<DateTime<Utc>>,
+    pub deletion_requested_at: Option<DateTime<Utc>>,
+    /// The account is purged once this moment has passed.
+    pub deletion_scheduled_for: Option<DateTime<Utc>>,
+    /// May read full content, answers included, through the editor API.
+    pub is_content_editor: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -29,6 +48,15 @@ impl User {
             current_streak_days: 0,
             highest_streak_days: 0,
             last_active_date: now.date_naive(),
+            preferred_language: DEFAULT_LANGUAGE.to_string(),
+            time_zone: DEFAULT_TIME_ZONE.to_string(),
+            avatar_url: None,
+            pending_email: None,
+            email_verification_token_hash: None,
+            email_verification_expires_at: None,
+            deletion_requested_at: None,
+            deletion_scheduled_for: None,
+            is_content_editor: false,
             created_at: now,
             updated_at: now,
         }
@@ -55,4 +83,63 @@ impl User {
     pub fn verify_password(&self, plain_password: &str) -> Result<bool, String> {
         self.password.verify(plain_password)
     }
+
+    /// Parsed time zone, falling back to UTC for unknown values.
+    pub fn tz(&self) -> Tz {
+        self.time_zone.parse().unwrap_or(Tz::UTC)
+    }
+
+    pub fn change_password(&mut self, password: Password) {
+        self.password = password;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn request_email_change(
+        &mut self,
+        new_email: Email,
+        token_hash: String,
+        expires_at: DateTime<Utc>,
+    ) {
+        self.pending_email = Some(new_email);
+        self.email_verification_token_hash = Some(token_hash);
+        self.email_verification_expires_at = Some(expires_at);
+        self.updated_at = Utc::now();
+    }
+
+    /// Swaps in the pending address. Returns `false` when nothing is pending.
+    pub fn confirm_email_change(&mut self) -> bool {
+        let Some(new_email) = self.pending_email.take() else {
+            return false;
+        };
+        self.email = new_email;
+        self.email_verification_token_hash = None;
+        self.email_verification_expires_at = None;
+        self.updated_at = Utc::now();
+        true
+    }
+
+    pub fn is_pending_deletion(&self) -> bool {
+        self.deletion_scheduled_for.is_some()
+    }
+
+    pub fn public_display_name(&self) -> &str {
+        if self.is_pending_deletion() {
+            ANONYMIZED_DISPLAY_NAME
+        } else {
+            &self.display_name
+        }
+    }
+
+    pub fn schedule_deletion(&mut self, grace_period: chrono::Duration) {
+        let now = Utc::now();
+        self.deletion_requested_at = Some(now);
+        self.deletion_scheduled_for = Some(now + grace_period);
+        self.updated_at = now;
+    }
+
+    pub fn cancel_deletion(&mut self) {
+        self.deletion_requested_at = None;
+        self.deletion_scheduled_for = None;
+        self.updated_at = Utc::now();
+    }
 }
@@ -1,4 +1,4 @@
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::domain::value_objects::{CodePracticeId, LessonId, Points, QuestionId, UserId};
@@ -31,6 +31,42 @@ pub struct CompletedCodePractice {
     pub xp_earned: Points,
 }
 
+/// Used when neither the user nor the configuration sets a daily XP cap.
+pub const DEFAULT_DAILY_XP_CAP: u32 = 1000;
+
+/// Outcome of awarding XP under the daily cap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct XpAward {
+    pub granted: Points,
+    pub withheld: Points,
+}
+
+/// Most streak freezes a learner can hold at once.
+pub const MAX_STREAK_FREEZES: u32 = 2;
+/// XP price of one streak freeze.
+pub const STREAK_FREEZE_PRICE: u32 = 200;
+/// A freeze is earned for every this many consecutive days.
+pub const STREAK_FREEZE_EARN_INTERVAL: u32 = 7;
+/// XP price of restoring a broken streak.
+pub const STREAK_REPAIR_PRICE: u32 = 500;
+/// How long a broken streak can still be repaired.
+pub const STREAK_REPAIR_WINDOW_HOURS: i64 = 48;
+
+/// Outcome of checking a streak against the learner's current local day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreakCheck {
+    Intact,
+    /// Missed days were covered by freezes from the inventory.
+    Frozen {
+        freezes_used: u32,
+    },
+    /// The streak was lost; it can be repaired until `repair_expires_at`.
+    Broken {
+        lost_streak_days: u32,
+        repair_expires_at: DateTime<Utc>,
+    },
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserProgress {
     pub user_id: UserId,
@@ -41,8 +77,15 @@ pub struct UserProgress {
     pub current_streak_days: u32,
     pub highest_streak_days: u32,
     pub last_active_date: NaiveDate,
-    pub daily_xp_cap: u32,
+    /// Per-user override of the global daily XP cap.
+    pub daily_xp_cap: Option<u32>,
+    /// XP granted since `last_xp_reset_date` (the user's local date).
+    pub xp_earned_today: u32,
     pub last_xp_reset_date: NaiveDate,
+    pub streak_freezes: u32,
+    /// Streak lost at the last break, restorable while the repair offer lasts.
+    pub repairable_streak_days: u32,
+    pub streak_repair_expires_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -60,25 +103,91 @@ impl UserProgress {
             current_streak_days: 0,
             highest_streak_days: 0,
             last_active_date: today,
-            daily_xp_cap: 1000, // Default daily XP cap
+            daily_xp_cap: None,
+            xp_earned_today: 0,
             last_xp_reset_date: today,
+            streak_freezes: 0,
+            repairable_streak_days: 0,
+            streak_repair_expires_at: None,
             created_at: now,
             updated_at: now,
         }
     }
 
-    pub fn add_question_result(&mut self, result: QuestionResult) {
-        let points = result.points;
-        self.completed_questions.push(result);
+    pub fn effective_daily_xp_cap(&self, default_cap: u32) -> u32 {
+        self.daily_xp_cap.unwrap_or(default_cap)
+    }
+
+    /// Starts a new XP day once the user's local date has moved past the last reset.
+    pub fn reset_daily_xp_if_needed(&mut self, today: NaiveDate) {
+        if today > self.last_xp_reset_date {
+            self.xp_earned_today = 0;
+            self.last_xp_reset_date = today;
+        }
+    }
+
+    pub fn remaining_daily_xp(&self, default_cap: u32, today: NaiveDate) -> u32 {
+        let cap = self.effective_daily_xp_cap(default_cap);
+        if today > self.last_xp_reset_date {
+            cap
+        } else {
+            cap.saturating_sub(self.xp_earned_today)
+        }
+    }
+
+    /// Adds as much of `points` as today's cap allows.
+    pub fn award_xp(&mut self, points: Points, default_cap: u32, today: NaiveDate) -> XpAward {
+        self.reset_daily_xp_if_needed(today);
+
+        let granted = points
+            .value()
+            .min(self.remaining_daily_xp(default_cap, today));
+        self.xp_earned_today += granted;
+        self.total_xp = self.total_xp.add(Points::new(granted));
+        self.updated_at = Utc::now();
+
+        XpAward {
+            granted: Points::new(granted),
+            withheld: Points::new(points.value() - granted),
+        }
+    }
+
+    /// Grants reward XP, such as achievement bonuses, outside the daily cap.
+    pub fn add_bonus_xp(&mut self, points: Points) {
         self.total_xp = self.total_xp.add(points);
         self.updated_at = Utc::now();
     }
 
-    pub fn add_code_practice_completion(&mut self, completion: CompletedCodePractice) {
-        let xp_earned = completion.xp_earned;
+    /// Records the result with the points actually granted after the daily cap.
+    pub fn add_question_result(
+        &mut self,
+        mut result: QuestionResult,
+        default_cap: u32,
+        today: NaiveDate,
+    ) -> XpAward {
+        let award = self.award_xp(result.points, default_cap, today);
+        result.points = award.granted;
+        self.completed_questions.push(result);
+        award
+    }
+
+    /// Records the completion with the XP actually granted after the daily cap.
+    pub fn add_code_practice_completion(
+        &mut self,
+        mut completion: CompletedCodePractice,
+        default_cap: u32,
+        today: NaiveDate,
+    ) -> XpAward {
+        let award = self.award_xp(completion.xp_earned, default_cap, today);
+        completion.xp_earned = award.granted;
         self.completed_code_practices.push(completion);
-        self.total_xp = self.total_xp.add(xp_earned);
-        self.updated_at = Utc::now();
+        award
+    }
+
+    pub fn has_completed_code_practice(&self, code_practice_id: &CodePracticeId) -> bool {
+        self.completed_code_practices
+            .iter()
+            .any(|c| c.id == *code_practice_id && c.is_correct)
     }
 
     pub fn update_lesson_stars(&mut self, lesson_id: LessonId, stars: u32) {
@@ -94,6 +203,133 @@ impl UserProgress {
         self.updated_at = Utc::now();
     }
 
+    /// Awards a finished lesson's XP under the daily cap and keeps the best stars
+    /// earned for it. `result.xp_earned` is lowered to the XP actually granted.
+    pub fn complete_lesson(
+        &mut self,
+        result: &mut LessonResult,
+        stars: u32,
+        default_cap: u32,
+        today: NaiveDate,
+    ) -> XpAward {
+        let award = self.award_xp(result.xp_earned, default_cap, today);
+        result.xp_earned = award.granted;
+        self.completed_questions
+            .extend(result.question_results.iter().cloned());
+
+        let best = self
+            .lesson_stars
+            .iter()
+            .find(|(id, _)| *id == result.lesson_id)
+            .map_or(0, |(_, best)| *best);
+        self.update_lesson_stars(result.lesson_id.clone(), stars.max(best));
+        award
+    }
+
+    /// Counts activity on the learner's local `today` towards the streak: the first
+    /// activity of a new day extends it, a gap of one or more missed days restarts it.
+    /// Call `check_streak` first so missed days can be covered by freezes.
+    pub fn record_activity(&mut self, today: NaiveDate) {
+        let days_since_active = (today - self.last_active_date).num_days();
+        let streak = match days_since_active {
+            // Same day (or the user moved to an earlier time zone)
+            _ if days_since_active <= 0 && self.current_streak_days > 0 => self.current_streak_days,
+            1 => self.current_streak_days + 1,
+            _ => 1,
+        };
+
+        if days_since_active == 1
+            && streak % STREAK_FREEZE_EARN_INTERVAL == 0
+            && self.streak_freezes < MAX_STREAK_FREEZES
+        {
+            self.streak_freezes += 1;
+        }
+        if today > self.last_active_date {
+            self.last_active_date = today;
+        }
+        self.update_streak(streak);
+    }
+
+    /// Applies the days missed before the learner's local `today`: freezes are consumed
+    /// one per missed day, and if there are not enough the streak is broken.
+    pub fn check_streak(&mut self, today: NaiveDate, now: DateTime<Utc>) -> StreakCheck {
+        let missed_days = (today - self.last_active_date).num_days() - 1;
+        if self.current_streak_days == 0 || missed_days <= 0 {
+            return StreakCheck::Intact;
+        }
+
+        let missed_days = missed_days as u32;
+        if missed_days <= self.streak_freezes {
+            self.streak_freezes -= missed_days;
+            self.last_active_date = today - Duration::days(1);
+            self.updated_at = now;
+            return StreakCheck::Frozen {
+                freezes_used: missed_days,
+            };
+        }
+
+        let lost_streak_days = self.current_streak_days;
+        let repair_expires_at = now + Duration::hours(STREAK_REPAIR_WINDOW_HOURS);
+        self.current_streak_days = 0;
+        self.repairable_streak_days = lost_streak_days;
+        self.streak_repair_expires_at = Some(repair_expires_at);
+        self.updated_at = now;
+        StreakCheck::Broken {
+            lost_streak_days,
+            repair_expires_at,
+        }
+    }
+
+    pub fn can_repair_streak(&self, now: DateTime<Utc>) -> bool {
+        self.repairable_streak_days > 0
+            && self
+                .streak_repair_expires_at
+                .is_some_and(|expires_at| expires_at > now)
+    }
+
+    /// Buys a freeze with XP. Fails when the inventory is full or XP is short.
+    pub fn buy_streak_freeze(&mut self, price: Points) -> Result<(), String> {
+        if self.streak_freezes >= MAX_STREAK_FREEZES {
+            return Err(format!(
+                "You already hold the maximum of {MAX_STREAK_FREEZES} streak freezes"
+            ));
+        }
+        self.total_xp = self
+            .total_xp
+            .subtract(price)
+            .ok_or_else(|| "Not enough XP to buy a streak freeze".to_string())?;
+        self.streak_freezes += 1;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Restores the lost streak for XP while the repair offer is open. Days earned
+    /// since the break are added on top. Returns the restored streak length.
+    pub fn repair_streak(
+        &mut self,
+        price: Points,
+        today: NaiveDate,
+        now: DateTime<Utc>,
+    ) -> Result<u32, String> {
+        if !self.can_repair_streak(now) {
+            return Err("There is no broken streak to repair".to_string());
+        }
+        self.total_xp = self
+            .total_xp
+            .subtract(price)
+            .ok_or_else(|| "Not enough XP to repair the streak".to_string())?;
+
+        let restored = self.repairable_streak_days + self.current_streak_days;
+        if self.current_streak_days == 0 {
+            // Treat yesterday as covered so activity today continues the streak
+            self.last_active_date = today - Duration::days(1);
+        }
+        self.repairable_streak_days = 0;
+        self.streak_repair_expires_at = None;
+        self.update_streak(restored);
+        Ok(restored)
+    }
+
     pub fn update_streak(&mut self, days: u32) {
         self.current_streak_days = days;
         if days > self.highest_streak_days {
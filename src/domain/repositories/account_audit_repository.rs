@@ -0,0 +1,11 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::AccountAuditEvent;
+use crate::domain::value_objects::UserId;
+
+#[async_trait]
+pub trait AccountAuditRepository: Send + Sync + 'static {
+    async fn create(&self, event: &AccountAuditEvent) -> Result<()>;
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<AccountAuditEvent>>;
+}
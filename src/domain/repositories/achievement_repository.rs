@@ -0,0 +1,15 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::{Achievement, UserAchievement};
+use crate::domain::value_objects::UserId;
+
+#[async_trait]
+pub trait AchievementRepository: Send + Sync + 'static {
+    /// The full catalogue, ordered for display.
+    async fn list_definitions(&self) -> Result<Vec<Achievement>>;
+    async fn upsert_definition(&self, achievement: &Achievement) -> Result<()>;
+    async fn find_unlocked_by_user(&self, user_id: &UserId) -> Result<Vec<UserAchievement>>;
+    /// Records an unlock. Returns `false` if the user already had it.
+    async fn award(&self, user_achievement: &UserAchievement) -> Result<bool>;
+}
@@ -0,0 +1,15 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::{AiCallRecord, AiUsageSummary};
+use crate::domain::value_objects::UserId;
+
+#[async_trait]
+pub trait AiUsageRepository: Send + Sync + 'static {
+    async fn record(&self, call: &AiCallRecord) -> Result<()>;
+    /// Calls made since `since`, grouped by UTC day and feature, newest day first.
+    async fn daily_summary(&self, since: DateTime<Utc>) -> Result<Vec<AiUsageSummary>>;
+    /// AI calls made for a user, most recent first.
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<AiCallRecord>>;
+}
@@ -0,0 +1,15 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::ApiKey;
+use crate::domain::value_objects::{ApiKeyId, UserId};
+
+#[async_trait]
+pub trait ApiKeyRepository: Send + Sync + 'static {
+    async fn create(&self, api_key: &ApiKey) -> Result<()>;
+    async fn find_by_id(&self, id: &ApiKeyId) -> Result<Option<ApiKey>>;
+    async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>>;
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<ApiKey>>;
+    async fn update(&self, api_key: &ApiKey) -> Result<()>;
+    async fn delete(&self, id: &ApiKeyId) -> Result<()>;
+}
@@ -0,0 +1,15 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::Asset;
+use crate::domain::value_objects::AssetId;
+
+#[async_trait]
+pub trait AssetRepository: Send + Sync + 'static {
+    async fn create(&self, asset: &Asset) -> Result<()>;
+    async fn find_by_id(&self, id: &AssetId) -> Result<Option<Asset>>;
+    /// The asset already stored with this content, if any.
+    async fn find_by_sha256(&self, sha256: &str) -> Result<Option<Asset>>;
+    /// Newest first.
+    async fn list(&self, limit: u32, offset: u32) -> Result<Vec<Asset>>;
+}
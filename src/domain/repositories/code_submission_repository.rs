@@ -0,0 +1,24 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::CodeSubmission;
+use crate::domain::value_objects::{CodePracticeId, CodeSubmissionId, UserId};
+
+#[async_trait]
+pub trait CodeSubmissionRepository: Send + Sync + 'static {
+    async fn create(&self, submission: &CodeSubmission) -> Result<()>;
+    async fn find_by_id(&self, id: &CodeSubmissionId) -> Result<Option<CodeSubmission>>;
+    /// Most recent first.
+    async fn list_by_user_and_practice(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<Vec<CodeSubmission>>;
+    /// Every learner's submissions to a practice, most recent first.
+    async fn list_by_practice(
+        &self,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<Vec<CodeSubmission>>;
+    /// Submissions of a user to every practice, most recent first.
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<CodeSubmission>>;
+}
@@ -0,0 +1,26 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::{ContentKind, ContentVersion};
+use crate::domain::value_objects::ContentVersionId;
+
+#[async_trait]
+pub trait ContentVersionRepository: Send + Sync + 'static {
+    /// Records `version` under its item's next version number, chosen while no
+    /// other version of the item is being recorded, and returns that number.
+    async fn create(&self, version: &ContentVersion) -> Result<u32>;
+    /// Removes a version whose change could not be saved.
+    async fn delete(&self, id: &ContentVersionId) -> Result<()>;
+    /// Versions of an item, newest first.
+    async fn list_for_content(
+        &self,
+        kind: ContentKind,
+        content_id: &str,
+    ) -> Result<Vec<ContentVersion>>;
+    async fn find(
+        &self,
+        kind: ContentKind,
+        content_id: &str,
+        version: u32,
+    ) -> Result<Option<ContentVersion>>;
+}
@@ -0,0 +1,17 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::HintUsage;
+use crate::domain::value_objects::{CodePracticeId, UserId};
+
+#[async_trait]
+pub trait HintUsageRepository: Send + Sync + 'static {
+    async fn find(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<Option<HintUsage>>;
+    async fn save(&self, usage: &HintUsage) -> Result<()>;
+    /// Hints a user revealed, most recently used practice first.
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<HintUsage>>;
+}
@@ -0,0 +1,16 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::LessonSession;
+use crate::domain::value_objects::{LessonSessionId, UserId};
+
+#[async_trait]
+pub trait LessonSessionRepository: Send + Sync + 'static {
+    async fn create(&self, session: &LessonSession) -> Result<()>;
+    async fn find_by_id(&self, id: &LessonSessionId) -> Result<Option<LessonSession>>;
+    /// Saves the session unless it was saved since `session` was loaded, and
+    /// reports whether it was saved.
+    async fn update(&self, session: &LessonSession) -> Result<bool>;
+    /// Sessions of a user, most recent first.
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<LessonSession>>;
+}
@@ -1,17 +1,49 @@
+pub mod account_audit_repository;
+pub mod achievement_repository;
+pub mod ai_usage_repository;
+pub mod api_key_repository;
+pub mod asset_repository;
 pub mod code_practice_repository;
+pub mod code_submission_repository;
+pub mod content_version_repository;
+pub mod hint_usage_repository;
 pub mod leaderboard_repository;
 pub mod lesson_repository;
+pub mod lesson_session_repository;
 pub mod notification_repository;
+pub mod prerequisite_repository;
+pub mod question_draft_repository;
 pub mod question_repository;
+pub mod quiz_attempt_repository;
+pub mod quiz_repository;
+pub mod review_card_repository;
 pub mod topic_repository;
+pub mod translation_repository;
+pub mod tutor_feedback_repository;
 pub mod user_progress_repository;
 pub mod user_repository;
 
+pub use account_audit_repository::AccountAuditRepository;
+pub use achievement_repository::AchievementRepository;
+pub use ai_usage_repository::AiUsageRepository;
+pub use api_key_repository::ApiKeyRepository;
+pub use asset_repository::AssetRepository;
 pub use code_practice_repository::CodePracticeRepository;
+pub use code_submission_repository::CodeSubmissionRepository;
+pub use content_version_repository::ContentVersionRepository;
+pub use hint_usage_repository::HintUsageRepository;
 pub use leaderboard_repository::LeaderboardRepository;
 pub use lesson_repository::LessonRepository;
+pub use lesson_session_repository::LessonSessionRepository;
 pub use notification_repository::NotificationRepository;
+pub use prerequisite_repository::PrerequisiteRepository;
+pub use question_draft_repository::QuestionDraftRepository;
 pub use question_repository::QuestionRepository;
+pub use quiz_attempt_repository::QuizAttemptRepository;
+pub use quiz_repository::QuizRepository;
+pub use review_card_repository::ReviewCardRepository;
 pub use topic_repository::TopicRepository;
+pub use translation_repository::TranslationRepository;
+pub use tutor_feedback_repository::TutorFeedbackRepository;
 pub use user_progress_repository::UserProgressRepository;
 pub use user_repository::UserRepository;
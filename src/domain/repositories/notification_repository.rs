@@ -26,6 +26,10 @@ pub trait NotificationRepository: Send + Sync {
     async fn mark_as_read(&self, id: &NotificationId) -> Result<(), Box<dyn Error + Send + Sync>>;
     async fn mark_all_as_read(&self, user_id: &UserId) -> Result<(), Box<dyn Error + Send + Sync>>;
     async fn delete(&self, id: &NotificationId) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn delete_by_user_id(
+        &self,
+        user_id: &UserId,
+    ) -> Result<u32, Box<dyn Error + Send + Sync>>;
     async fn delete_old_notifications(
         &self,
         days_old: u32,
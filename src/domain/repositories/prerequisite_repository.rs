@@ -0,0 +1,25 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::{LessonPrerequisite, TopicPrerequisite};
+use crate::domain::value_objects::{LessonId, TopicId};
+
+#[async_trait]
+pub trait PrerequisiteRepository: Send + Sync + 'static {
+    async fn list_topic_prerequisites(&self) -> Result<Vec<TopicPrerequisite>>;
+    /// Inserts the edge, or updates `min_stars` if it already exists.
+    async fn save_topic_prerequisite(&self, prerequisite: &TopicPrerequisite) -> Result<()>;
+    async fn delete_topic_prerequisite(
+        &self,
+        topic_id: &TopicId,
+        prerequisite_topic_id: &TopicId,
+    ) -> Result<()>;
+    async fn list_lesson_prerequisites(&self) -> Result<Vec<LessonPrerequisite>>;
+    /// Inserts the edge, or updates `min_stars` if it already exists.
+    async fn save_lesson_prerequisite(&self, prerequisite: &LessonPrerequisite) -> Result<()>;
+    async fn delete_lesson_prerequisite(
+        &self,
+        lesson_id: &LessonId,
+        prerequisite_lesson_id: &LessonId,
+    ) -> Result<()>;
+}
@@ -0,0 +1,14 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::QuestionDraft;
+use crate::domain::value_objects::QuestionDraftId;
+
+#[async_trait]
+pub trait QuestionDraftRepository: Send + Sync + 'static {
+    async fn create(&self, draft: &QuestionDraft) -> Result<()>;
+    async fn find_by_id(&self, id: &QuestionDraftId) -> Result<Option<QuestionDraft>>;
+    /// Drafts awaiting review, oldest first.
+    async fn list_pending(&self) -> Result<Vec<QuestionDraft>>;
+    async fn update(&self, draft: &QuestionDraft) -> Result<()>;
+}
@@ -8,6 +8,8 @@ use crate::domain::value_objects::{Difficulty, QuestionId, TopicId};
 pub trait QuestionRepository: Send + Sync + 'static {
     async fn create(&self, question: &Question) -> Result<()>;
     async fn find_by_id(&self, id: &QuestionId) -> Result<Option<Question>>;
+    /// The questions that exist among `ids`, in no particular order.
+    async fn find_by_ids(&self, ids: &[QuestionId]) -> Result<Vec<Question>>;
     async fn find_by_topic_id(&self, topic_id: &TopicId) -> Result<Vec<Question>>;
     async fn find_by_difficulty(&self, difficulty: &Difficulty) -> Result<Vec<Question>>;
     async fn update(&self, question: &Question) -> Result<()>;
@@ -0,0 +1,28 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::QuizAttempt;
+use crate::domain::value_objects::{QuizAttemptId, QuizId, UserId};
+
+#[async_trait]
+pub trait QuizAttemptRepository: Send + Sync + 'static {
+    async fn create(&self, attempt: &QuizAttempt) -> Result<()>;
+    /// Creates the attempt unless the user already has an open attempt at the quiz
+    /// or has used `max_attempts`. Starts by the same user at the same quiz are
+    /// checked one at a time. Reports whether the attempt was created.
+    async fn create_if_allowed(
+        &self,
+        attempt: &QuizAttempt,
+        max_attempts: Option<u32>,
+    ) -> Result<bool>;
+    async fn find_by_id(&self, id: &QuizAttemptId) -> Result<Option<QuizAttempt>>;
+    async fn update(&self, attempt: &QuizAttempt) -> Result<()>;
+    /// Attempts of a user at a quiz, most recent first.
+    async fn list_by_user_and_quiz(
+        &self,
+        user_id: &UserId,
+        quiz_id: &QuizId,
+    ) -> Result<Vec<QuizAttempt>>;
+    /// Attempts of a user at every quiz, most recent first.
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<QuizAttempt>>;
+}
@@ -0,0 +1,13 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::Quiz;
+use crate::domain::value_objects::QuizId;
+
+#[async_trait]
+pub trait QuizRepository: Send + Sync + 'static {
+    async fn create(&self, quiz: &Quiz) -> Result<()>;
+    async fn find_by_id(&self, id: &QuizId) -> Result<Option<Quiz>>;
+    async fn list(&self) -> Result<Vec<Quiz>>;
+    async fn delete(&self, id: &QuizId) -> Result<()>;
+}
@@ -0,0 +1,23 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+use crate::domain::entities::ReviewCard;
+use crate::domain::value_objects::{QuestionId, UserId};
+
+#[async_trait]
+pub trait ReviewCardRepository: Send + Sync + 'static {
+    async fn find(&self, user_id: &UserId, question_id: &QuestionId) -> Result<Option<ReviewCard>>;
+    /// Inserts the card, or replaces the scheduling state of an existing one.
+    async fn save(&self, card: &ReviewCard) -> Result<()>;
+    /// Cards due on or before `today`, most overdue first.
+    async fn find_due(
+        &self,
+        user_id: &UserId,
+        today: NaiveDate,
+        limit: u32,
+    ) -> Result<Vec<ReviewCard>>;
+    async fn count_due(&self, user_id: &UserId, today: NaiveDate) -> Result<u64>;
+    /// Every card of a user, soonest due first.
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<ReviewCard>>;
+}
@@ -0,0 +1,14 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::MachineTranslation;
+use crate::domain::value_objects::TranslationId;
+
+#[async_trait]
+pub trait TranslationRepository: Send + Sync + 'static {
+    async fn create(&self, translation: &MachineTranslation) -> Result<()>;
+    async fn find_by_id(&self, id: &TranslationId) -> Result<Option<MachineTranslation>>;
+    /// Translations awaiting review, oldest first.
+    async fn list_pending(&self) -> Result<Vec<MachineTranslation>>;
+    async fn update(&self, translation: &MachineTranslation) -> Result<()>;
+}
@@ -0,0 +1,29 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+use crate::domain::entities::{TutorFeedback, TutorUsage};
+use crate::domain::value_objects::UserId;
+
+#[async_trait]
+pub trait TutorFeedbackRepository: Send + Sync + 'static {
+    async fn find_cached(&self, cache_key: &str) -> Result<Option<TutorFeedback>>;
+    async fn save_cached(&self, feedback: &TutorFeedback) -> Result<()>;
+    async fn find_usage(
+        &self,
+        user_id: &UserId,
+        usage_date: NaiveDate,
+    ) -> Result<Option<TutorUsage>>;
+    /// Counts one request against the day's `quota` in a single atomic step, so
+    /// concurrent requests cannot overrun it. `None` when the quota is used up.
+    async fn reserve_request(
+        &self,
+        user_id: &UserId,
+        usage_date: NaiveDate,
+        quota: u32,
+    ) -> Result<Option<TutorUsage>>;
+    /// Gives back a reserved request that produced no hint.
+    async fn release_request(&self, user_id: &UserId, usage_date: NaiveDate) -> Result<()>;
+    /// Daily tutor usage of a user, most recent day first.
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<TutorUsage>>;
+}
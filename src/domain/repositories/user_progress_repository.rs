@@ -1,6 +1,8 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
+use crate::domain::entities::user_progress::LessonResult;
 use crate::domain::entities::UserProgress;
 use crate::domain::value_objects::UserId;
 
@@ -9,6 +11,31 @@ pub trait UserProgressRepository: Send + Sync + 'static {
     async fn create(&self, progress: &UserProgress) -> Result<()>;
     async fn find_by_user_id(&self, user_id: &UserId) -> Result<Option<UserProgress>>;
     async fn update(&self, progress: &UserProgress) -> Result<()>;
+    /// Saves `progress` unless the stored row was saved after it was loaded, i.e.
+    /// its `updated_at` is no longer `loaded_at`, and reports whether it was saved.
+    async fn update_if_unchanged(
+        &self,
+        progress: &UserProgress,
+        loaded_at: DateTime<Utc>,
+    ) -> Result<bool>;
+    /// Writes only the streak columns of `progress`, under the same condition as
+    /// `update_if_unchanged`.
+    async fn update_streak_if_unchanged(
+        &self,
+        progress: &UserProgress,
+        loaded_at: DateTime<Utc>,
+    ) -> Result<bool>;
     async fn delete(&self, user_id: &UserId) -> Result<()>;
     async fn list(&self, limit: u32, offset: u32) -> Result<Vec<UserProgress>>;
+    /// Progress with a running streak, ordered by user and starting after `after`.
+    /// The completed questions, code practices and lesson stars are left empty.
+    async fn list_running_streaks(
+        &self,
+        after: Option<&UserId>,
+        limit: u32,
+    ) -> Result<Vec<UserProgress>>;
+    /// Adds a finished lesson to the user's lesson results. Repeats of the same lesson
+    /// accumulate XP and keep a perfect score once reached.
+    async fn record_lesson_result(&self, user_id: &UserId, result: &LessonResult) -> Result<()>;
+    async fn find_lesson_results(&self, user_id: &UserId) -> Result<Vec<LessonResult>>;
 }
@@ -1,5 +1,6 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 use crate::domain::entities::User;
 use crate::domain::value_objects::{Email, UserId};
@@ -12,4 +13,6 @@ pub trait UserRepository: Send + Sync + 'static {
     async fn update(&self, user: &User) -> Result<()>;
     async fn delete(&self, id: &UserId) -> Result<()>;
     async fn list(&self, limit: u32, offset: u32) -> Result<Vec<User>>;
+    /// Accounts whose deletion grace period ended at or before `now`.
+    async fn find_due_for_deletion(&self, now: DateTime<Utc>) -> Result<Vec<User>>;
 }
@@ -0,0 +1,245 @@
+use anyhow::Result;
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use argon2::password_hash::SaltString;
+use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
+use async_trait::async_trait;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{Duration, Utc};
+use chrono_tz::Tz;
+use std::sync::Arc;
+
+use crate::domain::entities::User;
+use crate::domain::repositories::UserRepository;
+use crate::domain::value_objects::{Email, Password, UserId};
+use crate::shared::utils::normalize_locale;
+
+const EMAIL_TOKEN_BYTES: usize = 32;
+const EMAIL_TOKEN_TTL_HOURS: i64 = 24;
+const MAX_DISPLAY_NAME_LENGTH: usize = 100;
+const MAX_AVATAR_URL_LENGTH: usize = 2048;
+
+/// Partial profile update; `None` leaves the field untouched.
+/// `avatar_url: Some(None)` removes the avatar.
+#[derive(Debug, Default)]
+pub struct ProfileUpdate {
+    pub display_name: Option<String>,
+    pub preferred_language: Option<String>,
+    pub time_zone: Option<String>,
+    pub avatar_url: Option<Option<String>>,
+}
+
+/// Pending email change together with the plain verification token to mail out.
+#[derive(Debug)]
+pub struct EmailChangeTicket {
+    pub user: User,
+    pub new_email: Email,
+    pub token: String,
+}
+
+#[async_trait]
+pub trait AccountService: Send + Sync + 'static {
+    async fn get_profile(&self, user_id: &UserId) -> Result<User>;
+    async fn update_profile(&self, user_id: &UserId, update: ProfileUpdate) -> Result<User>;
+    async fn change_password(
+        &self,
+        user_id: &UserId,
+        current_password: &str,
+        new_password: &str,
+    ) -> Result<()>;
+    async fn request_email_change(
+        &self,
+        user_id: &UserId,
+        new_email: &str,
+        current_password: &str,
+    ) -> Result<EmailChangeTicket>;
+    async fn confirm_email_change(&self, user_id: &UserId, token: &str) -> Result<User>;
+}
+
+pub struct AccountServiceImpl {
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl AccountServiceImpl {
+    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
+        Self { user_repository }
+    }
+
+    async fn load_user(&self, user_id: &UserId) -> Result<User> {
+        self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("User not found"))
+    }
+
+    fn check_password(user: &User, password: &str) -> Result<()> {
+        let valid = user
+            .verify_password(password)
+            .map_err(|e| anyhow::anyhow!("Password verification failed: {e}"))?;
+        if !valid {
+            return Err(anyhow::anyhow!("Current password is incorrect"));
+        }
+        Ok(())
+    }
+
+    fn validate_avatar_url(url: &str) -> Result<()> {
+        if url.len() > MAX_AVATAR_URL_LENGTH {
+            return Err(anyhow::anyhow!("Avatar URL is too long"));
+        }
+        if !url.starts_with("https://") && !url.starts_with("http://") {
+            return Err(anyhow::anyhow!("Avatar URL must be an http(s) URL"));
+        }
+        Ok(())
+    }
+
+    fn generate_token() -> String {
+        let mut bytes = [0u8; EMAIL_TOKEN_BYTES];
+        OsRng.fill_bytes(&mut bytes);
+        URL_SAFE_NO_PAD.encode(bytes)
+    }
+
+    fn hash_token(token: &str) -> Result<String> {
+        let salt = SaltString::generate(&mut OsRng);
+        Argon2::default()
+            .hash_password(token.as_bytes(), &salt)
+            .map(|hash| hash.to_string())
+            .map_err(|e| anyhow::anyhow!("Failed to hash verification token: {e}"))
+    }
+
+    fn verify_token(token: &str, token_hash: &str) -> bool {
+        match PasswordHash::new(token_hash) {
+            Ok(parsed_hash) => Argon2::default()
+                .verify_password(token.as_bytes(), &parsed_hash)
+                .is_ok(),
+            Err(_) => false,
+        }
+    }
+}
+
+#[async_trait]
+impl AccountService for AccountServiceImpl {
+    async fn get_profile(&self, user_id: &UserId) -> Result<User> {
+        self.load_user(user_id).await
+    }
+
+    async fn update_profile(&self, user_id: &UserId, update: ProfileUpdate) -> Result<User> {
+        let mut user = self.load_user(user_id).await?;
+
+        if let Some(display_name) = update.display_name {
+            let display_name = display_name.trim().to_string();
+            if display_name.is_empty() {
+                return Err(anyhow::anyhow!("Display name cannot be empty"));
+            }
+            if display_name.chars().count() > MAX_DISPLAY_NAME_LENGTH {
+                return Err(anyhow::anyhow!("Display name is too long"));
+            }
+            user.display_name = display_name;
+        }
+
+        if let Some(language) = update.preferred_language {
+            // Any locale is accepted; content missing in it falls back per field
+            user.preferred_language = normalize_locale(&language)
+                .ok_or_else(|| anyhow::anyhow!("Invalid language: {language}"))?;
+        }
+
+        if let Some(time_zone) = update.time_zone {
+            time_zone
+                .parse::<Tz>()
+                .map_err(|_| anyhow::anyhow!("Unknown time zone: {time_zone}"))?;
+            user.time_zone = time_zone;
+        }
+
+        if let Some(avatar_url) = update.avatar_url {
+            if let Some(url) = &avatar_url {
+                Self::validate_avatar_url(url)?;
+            }
+            user.avatar_url = avatar_url;
+        }
+
+        user.updated_at = Utc::now();
+        self.user_repository.update(&user).await?;
+
+        Ok(user)
+    }
+
+    async fn change_password(
+        &self,
+        user_id: &UserId,
+        current_password: &str,
+        new_password: &str,
+    ) -> Result<()> {
+        let mut user = self.load_user(user_id).await?;
+        Self::check_password(&user, current_password)?;
+
+        let password = Password::new(new_password).map_err(|e| anyhow::anyhow!(e))?;
+        user.change_password(password);
+        self.user_repository.update(&user).await
+    }
+
+    async fn request_email_change(
+        &self,
+        user_id: &UserId,
+        new_email: &str,
+        current_password: &str,
+    ) -> Result<EmailChangeTicket> {
+        let mut user = self.load_user(user_id).await?;
+        Self::check_password(&user, current_password)?;
+
+        let new_email = Email::new(new_email.to_string()).map_err(|e| anyhow::anyhow!(e))?;
+        if new_email == user.email {
+            return Err(anyhow::anyhow!("New email is the same as the current one"));
+        }
+        if self
+            .user_repository
+            .find_by_email(&new_email)
+            .await?
+            .is_some()
+        {
+            return Err(anyhow::anyhow!("Email is already in use"));
+        }
+
+        let token = Self::generate_token();
+        let expires_at = Utc::now() + Duration::hours(EMAIL_TOKEN_TTL_HOURS);
+        user.request_email_change(new_email.clone(), Self::hash_token(&token)?, expires_at);
+        self.user_repository.update(&user).await?;
+
+        Ok(EmailChangeTicket {
+            user,
+            new_email,
+            token,
+        })
+    }
+
+    async fn confirm_email_change(&self, user_id: &UserId, token: &str) -> Result<User> {
+        let mut user = self.load_user(user_id).await?;
+
+        let (Some(new_email), Some(token_hash), Some(expires_at)) = (
+            user.pending_email.clone(),
+            user.email_verification_token_hash.clone(),
+            user.email_verification_expires_at,
+        ) else {
+            return Err(anyhow::anyhow!("No email change is pending"));
+        };
+
+        if expires_at < Utc::now() {
+            return Err(anyhow::anyhow!("Verification token has expired"));
+        }
+        if !Self::verify_token(token, &token_hash) {
+            return Err(anyhow::anyhow!("Invalid verification token"));
+        }
+        // The address may have been taken since the change was requested
+        if self
+            .user_repository
+            .find_by_email(&new_email)
+            .await?
+            .is_some()
+        {
+            return Err(anyhow::anyhow!("Email is already in use"));
+        }
+
+        user.confirm_email_change();
+        self.user_repository.update(&user).await?;
+
+        Ok(user)
+    }
+}
@@ -0,0 +1,223 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::domain::entities::user::DEFAULT_LANGUAGE;
+use crate::domain::entities::{Achievement, AchievementStats, UserAchievement, UserProgress};
+use crate::domain::repositories::{
+    AchievementRepository, LessonRepository, UserProgressRepository, UserRepository,
+};
+use crate::domain::services::NotificationService;
+use crate::domain::value_objects::{LessonId, Points, UserId};
+
+/// Stars a lesson needs to count as perfect.
+const PERFECT_LESSON_STARS: u32 = 3;
+
+/// A catalogue entry as seen by one learner.
+#[derive(Debug, Clone)]
+pub struct AchievementProgress {
+    pub achievement: Achievement,
+    pub current: u32,
+    pub target: u32,
+    pub unlocked_at: Option<DateTime<Utc>>,
+}
+
+#[async_trait]
+pub trait AchievementService: Send + Sync + 'static {
+    /// Awards every achievement whose rule is now met. Safe to call repeatedly;
+    /// returns only the achievements unlocked by this call.
+    async fn evaluate(&self, user_id: &UserId) -> Result<Vec<Achievement>>;
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<AchievementProgress>>;
+}
+
+pub struct AchievementServiceImpl {
+    achievement_repository: Arc<dyn AchievementRepository>,
+    user_progress_repository: Arc<dyn UserProgressRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    lesson_repository: Arc<dyn LessonRepository>,
+    notification_service: Arc<dyn NotificationService>,
+}
+
+impl AchievementServiceImpl {
+    pub fn new(
+        achievement_repository: Arc<dyn AchievementRepository>,
+        user_progress_repository: Arc<dyn UserProgressRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        lesson_repository: Arc<dyn LessonRepository>,
+        notification_service: Arc<dyn NotificationService>,
+    ) -> Self {
+        Self {
+            achievement_repository,
+            user_progress_repository,
+            user_repository,
+            lesson_repository,
+            notification_service,
+        }
+    }
+
+    async fn collect_stats(&self, progress: &UserProgress) -> Result<AchievementStats> {
+        let perfect_lessons: HashSet<_> = progress
+            .lesson_stars
+            .iter()
+            .filter(|(_, stars)| *stars >= PERFECT_LESSON_STARS)
+            .map(|(lesson_id, _)| lesson_id.clone())
+            .collect();
+
+        let code_practices_completed = progress
+            .completed_code_practices
+            .iter()
+            .filter(|completion| completion.is_correct)
+            .map(|completion| completion.id.clone())
+            .collect::<HashSet<_>>()
+            .len() as u32;
+
+        Ok(AchievementStats {
+            total_xp: progress.total_xp.value(),
+            highest_streak_days: progress.highest_streak_days,
+            perfect_lessons: perfect_lessons.len() as u32,
+            code_practices_completed,
+            topics_mastered: self.count_topics_mastered(&perfect_lessons).await?,
+        })
+    }
+
+    /// Topics in which every lesson is perfect. Only topics the learner has at least
+    /// one perfect lesson in can qualify, so only those are loaded.
+    async fn count_topics_mastered(&self, perfect_lessons: &HashSet<LessonId>) -> Result<u32> {
+        let mut candidate_topics = HashSet::new();
+        for lesson_id in perfect_lessons {
+            if let Some(lesson) = self.lesson_repository.find_by_id(lesson_id).await? {
+                candidate_topics.insert(lesson.topic_id);
+            }
+        }
+
+        let mut mastered = 0;
+        for topic_id in &candidate_topics {
+            let lessons = self.lesson_repository.find_by_topic_id(topic_id).await?;
+            if !lessons.is_empty() && lessons.iter().all(|l| perfect_lessons.contains(&l.id)) {
+                mastered += 1;
+            }
+        }
+        Ok(mastered)
+    }
+
+    async fn notify_unlock(&self, user_id: &UserId, achievement: &Achievement, language: &str) {
+        let notified = self
+            .notification_service
+            .create_achievement_notification(
+                user_id,
+                achievement.title.get(language).to_string(),
+                achievement.description.get(language).to_string(),
+                achievement.xp_bonus,
+            )
+            .await;
+        if let Err(e) = notified {
+            tracing::warn!("Failed to send achievement notification: {}", e);
+        }
+    }
+}
+
+#[async_trait]
+impl AchievementService for AchievementServiceImpl {
+    async fn evaluate(&self, user_id: &UserId) -> Result<Vec<Achievement>> {
+        let Some(mut progress) = self
+            .user_progress_repository
+            .find_by_user_id(user_id)
+            .await?
+        else {
+            return Ok(Vec::new());
+        };
+
+        let definitions = self.achievement_repository.list_definitions().await?;
+        let mut unlocked: HashSet<String> = self
+            .achievement_repository
+            .find_unlocked_by_user(user_id)
+            .await?
+            .into_iter()
+            .map(|a| a.achievement_id)
+            .collect();
+        if definitions.iter().all(|a| unlocked.contains(&a.id)) {
+            return Ok(Vec::new());
+        }
+
+        let language = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .map(|user| user.preferred_language)
+            .unwrap_or_else(|| DEFAULT_LANGUAGE.to_string());
+
+        let mut stats = self.collect_stats(&progress).await?;
+        let mut newly_unlocked = Vec::new();
+        // Bonus XP can itself cross an XP milestone, so repeat until nothing changes
+        loop {
+            let due: Vec<&Achievement> = definitions
+                .iter()
+                .filter(|a| !unlocked.contains(&a.id) && a.rule.is_met(&stats))
+                .collect();
+            if due.is_empty() {
+                break;
+            }
+
+            for achievement in due {
+                unlocked.insert(achievement.id.clone());
+                let user_achievement =
+                    UserAchievement::new(user_id.clone(), achievement.id.clone());
+                // Another evaluation may have awarded it concurrently
+                if !self.achievement_repository.award(&user_achievement).await? {
+                    continue;
+                }
+
+                progress.add_bonus_xp(Points::new(achievement.xp_bonus));
+                self.notify_unlock(user_id, achievement, &language).await;
+                newly_unlocked.push(achievement.clone());
+            }
+            stats.total_xp = progress.total_xp.value();
+        }
+
+        if newly_unlocked.iter().any(|a| a.xp_bonus > 0) {
+            self.user_progress_repository.update(&progress).await?;
+        }
+
+        Ok(newly_unlocked)
+    }
+
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<AchievementProgress>> {
+        let progress = self
+            .user_progress_repository
+            .find_by_user_id(user_id)
+            .await?
+            .unwrap_or_else(|| UserProgress::new(user_id.clone()));
+        let stats = self.collect_stats(&progress).await?;
+
+        let unlocked = self
+            .achievement_repository
+            .find_unlocked_by_user(user_id)
+            .await?;
+
+        let definitions = self.achievement_repository.list_definitions().await?;
+        Ok(definitions
+            .into_iter()
+            .map(|achievement| {
+                let target = achievement.rule.threshold();
+                let unlocked_at = unlocked
+                    .iter()
+                    .find(|a| a.achievement_id == achievement.id)
+                    .map(|a| a.unlocked_at);
+                let current = if unlocked_at.is_some() {
+                    target
+                } else {
+                    achievement.rule.current_value(&stats).min(target)
+                };
+
+                AchievementProgress {
+                    achievement,
+                    current,
+                    target,
+                    unlocked_at,
+                }
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,184 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::domain::entities::topic_ability::TopicAbility;
+use crate::domain::entities::Question;
+use crate::domain::repositories::{QuestionRepository, TopicRepository, UserProgressRepository};
+use crate::domain::value_objects::{QuestionId, TopicId, UserId};
+use crate::shared::errors::DomainError;
+
+#[derive(Debug, Clone)]
+pub struct NextQuestion {
+    pub question: Question,
+    pub ability: TopicAbility,
+    /// Estimated chance the learner answers this question correctly.
+    pub expected_success: f64,
+}
+
+#[async_trait]
+pub trait AdaptiveLearningService: Send + Sync + 'static {
+    /// Ability per topic the learner has answered questions in, in curriculum order.
+    async fn get_abilities(&self, user_id: &UserId) -> Result<Vec<TopicAbility>>;
+    async fn get_topic_ability(&self, user_id: &UserId, topic_id: &TopicId)
+        -> Result<TopicAbility>;
+    /// Picks the question in the topic whose difficulty best matches the learner.
+    async fn next_question(&self, user_id: &UserId, topic_id: &TopicId) -> Result<NextQuestion>;
+}
+
+/// A learner's latest answer to one question.
+#[derive(Debug, Clone, Copy)]
+struct LastAnswer {
+    correct: bool,
+    at: DateTime<Utc>,
+}
+
+pub struct AdaptiveLearningServiceImpl {
+    question_repository: Arc<dyn QuestionRepository>,
+    topic_repository: Arc<dyn TopicRepository>,
+    user_progress_repository: Arc<dyn UserProgressRepository>,
+}
+
+impl AdaptiveLearningServiceImpl {
+    pub fn new(
+        question_repository: Arc<dyn QuestionRepository>,
+        topic_repository: Arc<dyn TopicRepository>,
+        user_progress_repository: Arc<dyn UserProgressRepository>,
+    ) -> Self {
+        Self {
+            question_repository,
+            topic_repository,
+            user_progress_repository,
+        }
+    }
+
+    /// Replays the learner's answer log in order. Answers to deleted questions are skipped.
+    async fn replay_history(
+        &self,
+        user_id: &UserId,
+    ) -> Result<(
+        HashMap<TopicId, TopicAbility>,
+        HashMap<QuestionId, LastAnswer>,
+    )> {
+        let mut results = self
+            .user_progress_repository
+            .find_by_user_id(user_id)
+            .await?
+            .map(|progress| progress.completed_questions)
+            .unwrap_or_default();
+        results.sort_by_key(|r| r.completed_at);
+
+        // One query for every answered question rather than one per answer
+        let answered: Vec<QuestionId> = results
+            .iter()
+            .map(|result| result.question_id.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let questions: HashMap<QuestionId, Question> = self
+            .question_repository
+            .find_by_ids(&answered)
+            .await?
+            .into_iter()
+            .map(|question| (question.id.clone(), question))
+            .collect();
+
+        let mut abilities: HashMap<TopicId, TopicAbility> = HashMap::new();
+        let mut last_answers: HashMap<QuestionId, LastAnswer> = HashMap::new();
+        for result in results {
+            let Some(question) = questions.get(&result.question_id) else {
+                continue;
+            };
+
+            abilities
+                .entry(question.topic_id.clone())
+                .or_insert_with(|| TopicAbility::new(question.topic_id.clone()))
+                .record(question.difficulty, result.correct);
+            last_answers.insert(
+                result.question_id.clone(),
+                LastAnswer {
+                    correct: result.correct,
+                    at: result.completed_at,
+                },
+            );
+        }
+
+        Ok((abilities, last_answers))
+    }
+
+    /// Orders candidates by: not yet answered correctly, closest to the target success
+    /// rate, then least recently seen.
+    fn compare_candidates(
+        ability: &TopicAbility,
+        last_answers: &HashMap<QuestionId, LastAnswer>,
+        a: &Question,
+        b: &Question,
+    ) -> Ordering {
+        let mastered = |q: &Question| last_answers.get(&q.id).is_some_and(|l| l.correct);
+        let seen_at = |q: &Question| last_answers.get(&q.id).map(|l| l.at);
+
+        mastered(a)
+            .cmp(&mastered(b))
+            .then(
+                ability
+                    .mismatch(a.difficulty)
+                    .total_cmp(&ability.mismatch(b.difficulty)),
+            )
+            .then(seen_at(a).cmp(&seen_at(b)))
+    }
+}
+
+#[async_trait]
+impl AdaptiveLearningService for AdaptiveLearningServiceImpl {
+    async fn get_abilities(&self, user_id: &UserId) -> Result<Vec<TopicAbility>> {
+        let (mut abilities, _) = self.replay_history(user_id).await?;
+        let topics = self.topic_repository.list_by_order().await?;
+
+        Ok(topics
+            .into_iter()
+            .filter_map(|topic| abilities.remove(&topic.id))
+            .collect())
+    }
+
+    async fn get_topic_ability(
+        &self,
+        user_id: &UserId,
+        topic_id: &TopicId,
+    ) -> Result<TopicAbility> {
+        let (mut abilities, _) = self.replay_history(user_id).await?;
+        Ok(abilities
+            .remove(topic_id)
+            .unwrap_or_else(|| TopicAbility::new(topic_id.clone())))
+    }
+
+    async fn next_question(&self, user_id: &UserId, topic_id: &TopicId) -> Result<NextQuestion> {
+        self.topic_repository
+            .find_by_id(topic_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Topic not found".to_string()))?;
+
+        let (mut abilities, last_answers) = self.replay_history(user_id).await?;
+        let ability = abilities
+            .remove(topic_id)
+            .unwrap_or_else(|| TopicAbility::new(topic_id.clone()));
+
+        let question = self
+            .question_repository
+            .find_by_topic_id(topic_id)
+            .await?
+            .into_iter()
+            .min_by(|a, b| Self::compare_candidates(&ability, &last_answers, a, b))
+            .ok_or_else(|| {
+                DomainError::NotFound("No questions available for this topic".to_string())
+            })?;
+
+        Ok(NextQuestion {
+            expected_success: ability.expected_success(question.difficulty),
+            question,
+            ability,
+        })
+    }
+}
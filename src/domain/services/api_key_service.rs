@@ -0,0 +1,186 @@
+use anyhow::Result;
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use argon2::password_hash::SaltString;
+use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
+use async_trait::async_trait;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use std::sync::Arc;
+
+use crate::domain::entities::ApiKey;
+use crate::domain::repositories::ApiKeyRepository;
+use crate::domain::value_objects::{ApiKeyId, ApiScope, UserId};
+
+/// Every API key starts with this marker so it can be told apart from a JWT
+/// in the `Authorization: Bearer` header.
+pub const API_KEY_MARKER: &str = "rlk";
+
+const PREFIX_LENGTH: usize = 12;
+const SECRET_BYTES: usize = 32;
+const MAX_KEYS_PER_USER: usize = 20;
+
+/// Freshly created key together with the plain text value, which is never stored.
+#[derive(Debug)]
+pub struct IssuedApiKey {
+    pub api_key: ApiKey,
+    pub plain_key: String,
+}
+
+pub fn is_api_key(token: &str) -> bool {
+    token.starts_with(&format!("{API_KEY_MARKER}_"))
+}
+
+#[async_trait]
+pub trait ApiKeyService: Send + Sync + 'static {
+    async fn create_api_key(
+        &self,
+        user_id: &UserId,
+        name: String,
+        scopes: Vec<ApiScope>,
+    ) -> Result<IssuedApiKey>;
+    async fn list_api_keys(&self, user_id: &UserId) -> Result<Vec<ApiKey>>;
+    async fn revoke_api_key(&self, user_id: &UserId, key_id: &ApiKeyId) -> Result<ApiKey>;
+    async fn authenticate(&self, plain_key: &str) -> Result<ApiKey>;
+}
+
+pub struct ApiKeyServiceImpl {
+    api_key_repository: Arc<dyn ApiKeyRepository>,
+}
+
+impl ApiKeyServiceImpl {
+    pub fn new(api_key_repository: Arc<dyn ApiKeyRepository>) -> Self {
+        Self { api_key_repository }
+    }
+
+    fn generate_prefix() -> String {
+        uuid::Uuid::new_v4().simple().to_string()[..PREFIX_LENGTH].to_string()
+    }
+
+    fn generate_secret() -> String {
+        let mut bytes = [0u8; SECRET_BYTES];
+        OsRng.fill_bytes(&mut bytes);
+        URL_SAFE_NO_PAD.encode(bytes)
+    }
+
+    fn hash_secret(secret: &str) -> Result<String> {
+        let salt = SaltString::generate(&mut OsRng);
+        Argon2::default()
+            .hash_password(secret.as_bytes(), &salt)
+            .map(|hash| hash.to_string())
+            .map_err(|e| anyhow::anyhow!("Failed to hash API key: {e}"))
+    }
+
+    fn verify_secret(secret: &str, secret_hash: &str) -> bool {
+        match PasswordHash::new(secret_hash) {
+            Ok(parsed_hash) => Argon2::default()
+                .verify_password(secret.as_bytes(), &parsed_hash)
+                .is_ok(),
+            Err(_) => false,
+        }
+    }
+
+    /// Splits `rlk_<prefix>_<secret>` into its prefix and secret parts.
+    fn split_key(plain_key: &str) -> Option<(&str, &str)> {
+        let rest = plain_key.strip_prefix(&format!("{API_KEY_MARKER}_"))?;
+        let (prefix, secret) = rest.split_once('_')?;
+        if prefix.len() != PREFIX_LENGTH || secret.is_empty() {
+            return None;
+        }
+        Some((prefix, secret))
+    }
+}
+
+#[async_trait]
+impl ApiKeyService for ApiKeyServiceImpl {
+    async fn create_api_key(
+        &self,
+        user_id: &UserId,
+        name: String,
+        scopes: Vec<ApiScope>,
+    ) -> Result<IssuedApiKey> {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Err(anyhow::anyhow!("API key name cannot be empty"));
+        }
+        if name.len() > 100 {
+            return Err(anyhow::anyhow!("API key name is too long"));
+        }
+        if scopes.is_empty() {
+            return Err(anyhow::anyhow!("API key must have at least one scope"));
+        }
+
+        let active_keys = self
+            .api_key_repository
+            .find_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .filter(|key| key.is_active())
+            .count();
+        if active_keys >= MAX_KEYS_PER_USER {
+            return Err(anyhow::anyhow!(
+                "A user can have at most {MAX_KEYS_PER_USER} active API keys"
+            ));
+        }
+
+        let mut unique_scopes = Vec::new();
+        for scope in scopes {
+            if !unique_scopes.contains(&scope) {
+                unique_scopes.push(scope);
+            }
+        }
+
+        let prefix = Self::generate_prefix();
+        let secret = Self::generate_secret();
+        let secret_hash = Self::hash_secret(&secret)?;
+
+        let api_key = ApiKey::new(user_id.clone(), name, prefix, secret_hash, unique_scopes);
+        self.api_key_repository.create(&api_key).await?;
+
+        let plain_key = format!("{API_KEY_MARKER}_{}_{secret}", api_key.prefix);
+
+        Ok(IssuedApiKey { api_key, plain_key })
+    }
+
+    async fn list_api_keys(&self, user_id: &UserId) -> Result<Vec<ApiKey>> {
+        self.api_key_repository.find_by_user_id(user_id).await
+    }
+
+    async fn revoke_api_key(&self, user_id: &UserId, key_id: &ApiKeyId) -> Result<ApiKey> {
+        let mut api_key = self
+            .api_key_repository
+            .find_by_id(key_id)
+            .await?
+            .filter(|key| key.user_id == *user_id)
+            .ok_or_else(|| anyhow::anyhow!("API key not found"))?;
+
+        api_key.revoke();
+        self.api_key_repository.update(&api_key).await?;
+
+        Ok(api_key)
+    }
+
+    async fn authenticate(&self, plain_key: &str) -> Result<ApiKey> {
+        let (prefix, secret) =
+            Self::split_key(plain_key).ok_or_else(|| anyhow::anyhow!("Malformed API key"))?;
+
+        let mut api_key = self
+            .api_key_repository
+            .find_by_prefix(prefix)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Invalid API key"))?;
+
+        if !api_key.is_active() {
+            return Err(anyhow::anyhow!("API key has been revoked"));
+        }
+
+        if !Self::verify_secret(secret, &api_key.secret_hash) {
+            return Err(anyhow::anyhow!("Invalid API key"));
+        }
+
+        // Track usage so users can spot stale or leaked keys
+        api_key.mark_used();
+        self.api_key_repository.update(&api_key).await?;
+
+        Ok(api_key)
+    }
+}
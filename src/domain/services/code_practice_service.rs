@@ -0,0 +1,335 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+use crate::domain::entities::user_progress::{CompletedCodePractice, XpAward};
+use crate::domain::entities::{CodePractice, CodeSubmission, HintUsage, SubmissionVerdict};
+use crate::domain::repositories::{
+    CodePracticeRepository, CodeSubmissionRepository, HintUsageRepository, UserProgressRepository,
+};
+use crate::domain::services::ProgressService;
+use crate::domain::value_objects::{
+    CodePracticeId, CodeSubmissionId, LocalizedText, Points, UserId,
+};
+use crate::shared::errors::DomainError;
+use crate::shared::utils::unified_diff;
+
+/// A learner's view of a code practice's hints and solution.
+#[derive(Debug, Clone)]
+pub struct HintStatus {
+    pub code_practice_id: CodePracticeId,
+    /// Hints revealed so far, in order.
+    pub revealed_hints: Vec<LocalizedText>,
+    pub total_hints: u32,
+    /// XP a completion would earn now, before the daily cap.
+    pub xp_available: Points,
+    pub completed: bool,
+    /// Only set once the practice is completed or every hint is revealed.
+    pub solution: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CodePracticeCompletion {
+    pub code_practice: CodePractice,
+    pub hints_used: u32,
+    /// XP after the hint cost, before the daily cap.
+    pub xp_earned: Points,
+    pub award: XpAward,
+}
+
+#[derive(Debug, Clone)]
+pub struct CodeSubmissionOutcome {
+    pub submission: CodeSubmission,
+    /// Set when the submission was accepted and the completion recorded.
+    pub completion: Option<CodePracticeCompletion>,
+}
+
+/// Unified diff between two versions of a learner's code.
+#[derive(Debug, Clone)]
+pub struct SubmissionDiff {
+    /// `None` when diffing against the practice's initial code.
+    pub from: Option<CodeSubmission>,
+    pub to: CodeSubmission,
+    pub diff: String,
+}
+
+#[async_trait]
+pub trait CodePracticeService: Send + Sync + 'static {
+    async fn get_hint_status(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<HintStatus>;
+    /// Reveals the next hint, lowering the XP a later completion earns.
+    async fn reveal_next_hint(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<HintStatus>;
+    /// Records a correct solution, with XP reduced by the hints the learner revealed.
+    async fn complete(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+        user_code: String,
+    ) -> Result<CodePracticeCompletion>;
+    /// Stores an attempt with the verdict the caller supplies; accepted attempts
+    /// also complete the practice. The code itself is never run here.
+    async fn submit_code(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+        code: String,
+        verdict: SubmissionVerdict,
+        runner_output: Option<String>,
+    ) -> Result<CodeSubmissionOutcome>;
+    /// The learner's attempts, most recent first.
+    async fn list_submissions(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<Vec<CodeSubmission>>;
+    /// Diffs `to` against `from`, or against the initial code when `from` is `None`.
+    async fn diff_submissions(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+        from: Option<&CodeSubmissionId>,
+        to: &CodeSubmissionId,
+    ) -> Result<SubmissionDiff>;
+}
+
+pub struct CodePracticeServiceImpl {
+    code_practice_repository: Arc<dyn CodePracticeRepository>,
+    hint_usage_repository: Arc<dyn HintUsageRepository>,
+    code_submission_repository: Arc<dyn CodeSubmissionRepository>,
+    user_progress_repository: Arc<dyn UserProgressRepository>,
+    progress_service: Arc<dyn ProgressService>,
+}
+
+impl CodePracticeServiceImpl {
+    pub fn new(
+        code_practice_repository: Arc<dyn CodePracticeRepository>,
+        hint_usage_repository: Arc<dyn HintUsageRepository>,
+        code_submission_repository: Arc<dyn CodeSubmissionRepository>,
+        user_progress_repository: Arc<dyn UserProgressRepository>,
+        progress_service: Arc<dyn ProgressService>,
+    ) -> Self {
+        Self {
+            code_practice_repository,
+            hint_usage_repository,
+            code_submission_repository,
+            user_progress_repository,
+            progress_service,
+        }
+    }
+
+    async fn load_code_practice(&self, code_practice_id: &CodePracticeId) -> Result<CodePractice> {
+        self.code_practice_repository
+            .find_by_id(code_practice_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Code practice not found".to_string()).into())
+    }
+
+    async fn load_usage(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<HintUsage> {
+        Ok(self
+            .hint_usage_repository
+            .find(user_id, code_practice_id)
+            .await?
+            .unwrap_or_else(|| HintUsage::new(user_id.clone(), code_practice_id.clone())))
+    }
+
+    /// Submissions of other users or practices are reported as missing.
+    async fn load_submission(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+        submission_id: &CodeSubmissionId,
+    ) -> Result<CodeSubmission> {
+        self.code_submission_repository
+            .find_by_id(submission_id)
+            .await?
+            .filter(|submission| {
+                submission.user_id == *user_id && submission.code_practice_id == *code_practice_id
+            })
+            .ok_or_else(|| DomainError::NotFound("Code submission not found".to_string()).into())
+    }
+
+    async fn is_completed(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<bool> {
+        Ok(self
+            .user_progress_repository
+            .find_by_user_id(user_id)
+            .await?
+            .is_some_and(|progress| progress.has_completed_code_practice(code_practice_id)))
+    }
+
+    fn status(code_practice: CodePractice, usage: &HintUsage, completed: bool) -> HintStatus {
+        let total_hints = code_practice.hints.len() as u32;
+        let revealed = usage.hints_revealed.min(total_hints);
+        // A practice without hints keeps its solution until it is completed
+        let all_hints_used = total_hints > 0 && revealed == total_hints;
+
+        HintStatus {
+            xp_available: code_practice.xp_with_hints(revealed),
+            solution: (completed || all_hints_used).then(|| code_practice.solution.clone()),
+            revealed_hints: code_practice
+                .hints
+                .into_iter()
+                .take(revealed as usize)
+                .collect(),
+            code_practice_id: code_practice.id,
+            total_hints,
+            completed,
+        }
+    }
+}
+
+#[async_trait]
+impl CodePracticeService for CodePracticeServiceImpl {
+    async fn get_hint_status(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<HintStatus> {
+        let code_practice = self.load_code_practice(code_practice_id).await?;
+        let usage = self.load_usage(user_id, code_practice_id).await?;
+        let completed = self.is_completed(user_id, code_practice_id).await?;
+
+        Ok(Self::status(code_practice, &usage, completed))
+    }
+
+    async fn reveal_next_hint(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<HintStatus> {
+        let code_practice = self.load_code_practice(code_practice_id).await?;
+        let mut usage = self.load_usage(user_id, code_practice_id).await?;
+        usage
+            .reveal_next(code_practice.hints.len() as u32)
+            .map_err(DomainError::Invalid)?;
+        self.hint_usage_repository.save(&usage).await?;
+        let completed = self.is_completed(user_id, code_practice_id).await?;
+
+        Ok(Self::status(code_practice, &usage, completed))
+    }
+
+    async fn complete(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+        user_code: String,
+    ) -> Result<CodePracticeCompletion> {
+        let code_practice = self.load_code_practice(code_practice_id).await?;
+        let usage = self.load_usage(user_id, code_practice_id).await?;
+        let hints_used = usage.hints_revealed.min(code_practice.hints.len() as u32);
+        let xp_earned = code_practice.xp_with_hints(hints_used);
+
+        let award = self
+            .progress_service
+            .add_code_practice_completion(
+                user_id,
+                CompletedCodePractice {
+                    id: code_practice_id.clone(),
+                    completed_at: Utc::now(),
+                    user_code,
+                    is_correct: true,
+                    xp_earned,
+                },
+            )
+            .await?;
+
+        Ok(CodePracticeCompletion {
+            code_practice,
+            hints_used,
+            xp_earned,
+            award,
+        })
+    }
+
+    async fn submit_code(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+        code: String,
+        verdict: SubmissionVerdict,
+        runner_output: Option<String>,
+    ) -> Result<CodeSubmissionOutcome> {
+        self.load_code_practice(code_practice_id).await?;
+        let submission = CodeSubmission::new(
+            user_id.clone(),
+            code_practice_id.clone(),
+            code,
+            verdict,
+            runner_output,
+        );
+        self.code_submission_repository.create(&submission).await?;
+
+        let completion = if submission.is_accepted() {
+            Some(
+                self.complete(user_id, code_practice_id, submission.code.clone())
+                    .await?,
+            )
+        } else {
+            None
+        };
+
+        Ok(CodeSubmissionOutcome {
+            submission,
+            completion,
+        })
+    }
+
+    async fn list_submissions(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<Vec<CodeSubmission>> {
+        self.load_code_practice(code_practice_id).await?;
+        self.code_submission_repository
+            .list_by_user_and_practice(user_id, code_practice_id)
+            .await
+    }
+
+    async fn diff_submissions(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+        from: Option<&CodeSubmissionId>,
+        to: &CodeSubmissionId,
+    ) -> Result<SubmissionDiff> {
+        let to = self.load_submission(user_id, code_practice_id, to).await?;
+        let (from, old_code, old_label) = match from {
+            Some(from_id) => {
+                let from = self
+                    .load_submission(user_id, code_practice_id, from_id)
+                    .await?;
+                let label = format!("submission/{}", from.id);
+                let code = from.code.clone();
+                (Some(from), code, label)
+            }
+            None => {
+                let code_practice = self.load_code_practice(code_practice_id).await?;
+                (None, code_practice.initial_code, "initial_code".to_string())
+            }
+        };
+        let diff = unified_diff(
+            &old_code,
+            &to.code,
+            &old_label,
+            &format!("submission/{}", to.id),
+        )
+        .ok_or_else(|| DomainError::Invalid("Submissions are too large to diff".to_string()))?;
+
+        Ok(SubmissionDiff { from, to, diff })
+    }
+}
@@ -0,0 +1,209 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::entities::{
+    diff_snapshots, ContentAuthor, ContentKind, ContentVersion, TranslatableContent,
+};
+use crate::domain::repositories::{
+    CodePracticeRepository, ContentVersionRepository, LessonRepository, QuestionRepository,
+    TopicRepository,
+};
+use crate::domain::value_objects::{CodePracticeId, LessonId, QuestionId, TopicId};
+
+#[async_trait]
+pub trait ContentVersionService: Send + Sync + 'static {
+    /// Saves an edited item, first recording its stored state as a new version.
+    /// Saves that change nothing record no version. Returns the version recorded.
+    async fn update_content(
+        &self,
+        content: &TranslatableContent,
+        author: ContentAuthor,
+        change_note: &str,
+    ) -> Result<Option<ContentVersion>>;
+    /// The item with this ID, whichever kind it is.
+    async fn find_content(&self, content_id: &str) -> Result<Option<TranslatableContent>>;
+    /// Versions of an item, newest first.
+    async fn history(&self, kind: ContentKind, content_id: &str) -> Result<Vec<ContentVersion>>;
+    async fn get_version(
+        &self,
+        kind: ContentKind,
+        content_id: &str,
+        version: u32,
+    ) -> Result<ContentVersion>;
+    /// Puts an item back as it was in `version`. The state it replaces is
+    /// recorded as a new version, so a restore can itself be undone.
+    async fn restore(
+        &self,
+        kind: ContentKind,
+        content_id: &str,
+        version: u32,
+        author: ContentAuthor,
+        change_note: &str,
+    ) -> Result<TranslatableContent>;
+}
+
+pub struct ContentVersionServiceImpl {
+    content_version_repository: Arc<dyn ContentVersionRepository>,
+    topic_repository: Arc<dyn TopicRepository>,
+    lesson_repository: Arc<dyn LessonRepository>,
+    question_repository: Arc<dyn QuestionRepository>,
+    code_practice_repository: Arc<dyn CodePracticeRepository>,
+}
+
+impl ContentVersionServiceImpl {
+    pub fn new(
+        content_version_repository: Arc<dyn ContentVersionRepository>,
+        topic_repository: Arc<dyn TopicRepository>,
+        lesson_repository: Arc<dyn LessonRepository>,
+        question_repository: Arc<dyn QuestionRepository>,
+        code_practice_repository: Arc<dyn CodePracticeRepository>,
+    ) -> Self {
+        Self {
+            content_version_repository,
+            topic_repository,
+            lesson_repository,
+            question_repository,
+            code_practice_repository,
+        }
+    }
+
+    async fn load(
+        &self,
+        kind: ContentKind,
+        content_id: &str,
+    ) -> Result<Option<TranslatableContent>> {
+        // IDs of the wrong shape name no content
+        Ok(match kind {
+            ContentKind::Topic => match TopicId::from_str(content_id) {
+                Ok(id) => self
+                    .topic_repository
+                    .find_by_id(&id)
+                    .await?
+                    .map(TranslatableContent::Topic),
+                Err(_) => None,
+            },
+            ContentKind::Lesson => match LessonId::from_str(content_id) {
+                Ok(id) => self
+                    .lesson_repository
+                    .find_by_id(&id)
+                    .await?
+                    .map(TranslatableContent::Lesson),
+                Err(_) => None,
+            },
+            ContentKind::Question => match QuestionId::from_string(content_id) {
+                Ok(id) => self
+                    .question_repository
+                    .find_by_id(&id)
+                    .await?
+                    .map(TranslatableContent::Question),
+                Err(_) => None,
+            },
+            ContentKind::CodePractice => match CodePracticeId::from_string(content_id) {
+                Ok(id) => self
+                    .code_practice_repository
+                    .find_by_id(&id)
+                    .await?
+                    .map(TranslatableContent::CodePractice),
+                Err(_) => None,
+            },
+        })
+    }
+
+    async fn save(&self, content: &TranslatableContent) -> Result<()> {
+        match content {
+            TranslatableContent::Topic(topic) => self.topic_repository.update(topic).await,
+            TranslatableContent::Lesson(lesson) => self.lesson_repository.update(lesson).await,
+            TranslatableContent::Question(question) => {
+                self.question_repository.update(question).await
+            }
+            TranslatableContent::CodePractice(practice) => {
+                self.code_practice_repository.update(practice).await
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ContentVersionService for ContentVersionServiceImpl {
+    async fn update_content(
+        &self,
+        content: &TranslatableContent,
+        author: ContentAuthor,
+        change_note: &str,
+    ) -> Result<Option<ContentVersion>> {
+        let stored = self
+            .load(content.kind(), &content.content_id())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("{} not found", content.kind().label()))?;
+
+        if diff_snapshots(&stored.snapshot(), &content.snapshot()).is_empty() {
+            self.save(content).await?;
+            return Ok(None);
+        }
+
+        let mut version = ContentVersion::new(&stored, author, change_note);
+        version.version = self.content_version_repository.create(&version).await?;
+        if let Err(error) = self.save(content).await {
+            // The stored state was not replaced, so it is not a previous version
+            self.content_version_repository.delete(&version.id).await?;
+            return Err(error);
+        }
+        Ok(Some(version))
+    }
+
+    async fn find_content(&self, content_id: &str) -> Result<Option<TranslatableContent>> {
+        for kind in [
+            ContentKind::Topic,
+            ContentKind::Lesson,
+            ContentKind::Question,
+            ContentKind::CodePractice,
+        ] {
+            if let Some(content) = self.load(kind, content_id).await? {
+                return Ok(Some(content));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn history(&self, kind: ContentKind, content_id: &str) -> Result<Vec<ContentVersion>> {
+        self.content_version_repository
+            .list_for_content(kind, content_id)
+            .await
+    }
+
+    async fn get_version(
+        &self,
+        kind: ContentKind,
+        content_id: &str,
+        version: u32,
+    ) -> Result<ContentVersion> {
+        self.content_version_repository
+            .find(kind, content_id, version)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Version {version} not found"))
+    }
+
+    async fn restore(
+        &self,
+        kind: ContentKind,
+        content_id: &str,
+        version: u32,
+        author: ContentAuthor,
+        change_note: &str,
+    ) -> Result<TranslatableContent> {
+        let mut content = self
+            .get_version(kind, content_id, version)
+            .await?
+            .content()
+            .map_err(|e| anyhow::anyhow!(e))?;
+        content.touch();
+
+        let change_note = match change_note.trim() {
+            "" => format!("Restored version {version}"),
+            note => format!("Restored version {version}: {note}"),
+        };
+        self.update_content(&content, author, &change_note).await?;
+        Ok(content)
+    }
+}
@@ -0,0 +1,273 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::domain::entities::user_progress::LessonResult;
+use crate::domain::entities::{
+    AccountAuditAction, AccountAuditEvent, AiCallRecord, ApiKey, CodeSubmission, HintUsage,
+    LessonSession, Notification, QuizAttempt, ReviewCard, TutorUsage, User, UserAchievement,
+    UserProgress,
+};
+use crate::domain::repositories::{
+    AccountAuditRepository, AchievementRepository, AiUsageRepository, ApiKeyRepository,
+    CodeSubmissionRepository, HintUsageRepository, LessonSessionRepository, NotificationRepository,
+    QuizAttemptRepository, ReviewCardRepository, TutorFeedbackRepository, UserProgressRepository,
+    UserRepository,
+};
+use crate::domain::value_objects::UserId;
+
+pub const DEFAULT_DELETION_GRACE_DAYS: i64 = 30;
+
+/// Upper bound for notifications included in an export.
+const EXPORT_NOTIFICATION_LIMIT: u32 = 10_000;
+
+/// Everything stored about a learner, as returned by a data export.
+#[derive(Debug)]
+pub struct UserDataExport {
+    pub exported_at: DateTime<Utc>,
+    pub user: User,
+    pub progress: Option<UserProgress>,
+    pub lesson_results: Vec<LessonResult>,
+    pub lesson_sessions: Vec<LessonSession>,
+    pub quiz_attempts: Vec<QuizAttempt>,
+    pub code_submissions: Vec<CodeSubmission>,
+    pub hint_usage: Vec<HintUsage>,
+    pub review_cards: Vec<ReviewCard>,
+    pub achievements: Vec<UserAchievement>,
+    pub tutor_usage: Vec<TutorUsage>,
+    pub ai_calls: Vec<AiCallRecord>,
+    pub notifications: Vec<Notification>,
+    pub api_keys: Vec<ApiKey>,
+    pub account_events: Vec<AccountAuditEvent>,
+}
+
+/// Where a learner's activity is stored, read for data exports.
+pub struct LearnerActivityRepositories {
+    pub lesson_session_repository: Arc<dyn LessonSessionRepository>,
+    pub quiz_attempt_repository: Arc<dyn QuizAttemptRepository>,
+    pub code_submission_repository: Arc<dyn CodeSubmissionRepository>,
+    pub hint_usage_repository: Arc<dyn HintUsageRepository>,
+    pub review_card_repository: Arc<dyn ReviewCardRepository>,
+    pub achievement_repository: Arc<dyn AchievementRepository>,
+    pub tutor_feedback_repository: Arc<dyn TutorFeedbackRepository>,
+    pub ai_usage_repository: Arc<dyn AiUsageRepository>,
+}
+
+/// Outcome of one purge run. Accounts that failed stay scheduled and are
+/// retried on the next run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PurgeReport {
+    pub purged: u32,
+    pub failed: u32,
+}
+
+#[async_trait]
+pub trait DataPrivacyService: Send + Sync + 'static {
+    async fn export_user_data(&self, user_id: &UserId) -> Result<UserDataExport>;
+    async fn request_deletion(&self, user_id: &UserId, current_password: &str) -> Result<User>;
+    async fn cancel_deletion(&self, user_id: &UserId) -> Result<User>;
+    /// Permanently removes accounts whose grace period has ended. One failing
+    /// account does not stop the others from being purged.
+    async fn purge_due_accounts(&self) -> Result<PurgeReport>;
+}
+
+pub struct DataPrivacyServiceImpl {
+    user_repository: Arc<dyn UserRepository>,
+    user_progress_repository: Arc<dyn UserProgressRepository>,
+    notification_repository: Arc<dyn NotificationRepository>,
+    api_key_repository: Arc<dyn ApiKeyRepository>,
+    account_audit_repository: Arc<dyn AccountAuditRepository>,
+    activity: LearnerActivityRepositories,
+    grace_period: Duration,
+}
+
+impl DataPrivacyServiceImpl {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        user_progress_repository: Arc<dyn UserProgressRepository>,
+        notification_repository: Arc<dyn NotificationRepository>,
+        api_key_repository: Arc<dyn ApiKeyRepository>,
+        account_audit_repository: Arc<dyn AccountAuditRepository>,
+        activity: LearnerActivityRepositories,
+        grace_period_days: i64,
+    ) -> Self {
+        Self {
+            user_repository,
+            user_progress_repository,
+            notification_repository,
+            api_key_repository,
+            account_audit_repository,
+            activity,
+            grace_period: Duration::days(grace_period_days),
+        }
+    }
+
+    async fn load_user(&self, user_id: &UserId) -> Result<User> {
+        self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("User not found"))
+    }
+
+    async fn record(
+        &self,
+        user_id: &UserId,
+        action: AccountAuditAction,
+        details: serde_json::Value,
+    ) -> Result<()> {
+        let event = AccountAuditEvent::new(user_id.clone(), action, details);
+        self.account_audit_repository.create(&event).await
+    }
+
+    async fn purge_account(&self, user: &User) -> Result<()> {
+        // Notifications are not stored in the database, so they need explicit cleanup
+        self.notification_repository
+            .delete_by_user_id(&user.id)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to delete notifications: {e}"))?;
+
+        // Progress, results, submissions and API keys go with the user row via ON DELETE CASCADE
+        self.user_repository.delete(&user.id).await?;
+
+        self.record(
+            &user.id,
+            AccountAuditAction::AccountDeleted,
+            json!({ "requested_at": user.deletion_requested_at }),
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl DataPrivacyService for DataPrivacyServiceImpl {
+    async fn export_user_data(&self, user_id: &UserId) -> Result<UserDataExport> {
+        let user = self.load_user(user_id).await?;
+        let progress = self
+            .user_progress_repository
+            .find_by_user_id(user_id)
+            .await?;
+        let lesson_results = self
+            .user_progress_repository
+            .find_lesson_results(user_id)
+            .await?;
+        let activity = &self.activity;
+        let lesson_sessions = activity
+            .lesson_session_repository
+            .list_by_user(user_id)
+            .await?;
+        let quiz_attempts = activity
+            .quiz_attempt_repository
+            .list_by_user(user_id)
+            .await?;
+        let code_submissions = activity
+            .code_submission_repository
+            .list_by_user(user_id)
+            .await?;
+        let hint_usage = activity.hint_usage_repository.list_by_user(user_id).await?;
+        let review_cards = activity
+            .review_card_repository
+            .list_by_user(user_id)
+            .await?;
+        let achievements = activity
+            .achievement_repository
+            .find_unlocked_by_user(user_id)
+            .await?;
+        let tutor_usage = activity
+            .tutor_feedback_repository
+            .list_by_user(user_id)
+            .await?;
+        let ai_calls = activity.ai_usage_repository.list_by_user(user_id).await?;
+        let notifications = self
+            .notification_repository
+            .get_by_user_id(user_id, EXPORT_NOTIFICATION_LIMIT, 0)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to load notifications: {e}"))?;
+        let api_keys = self.api_key_repository.find_by_user_id(user_id).await?;
+
+        self.record(user_id, AccountAuditAction::DataExported, json!({}))
+            .await?;
+        let account_events = self
+            .account_audit_repository
+            .find_by_user_id(user_id)
+            .await?;
+
+        Ok(UserDataExport {
+            exported_at: Utc::now(),
+            user,
+            progress,
+            lesson_results,
+            lesson_sessions,
+            quiz_attempts,
+            code_submissions,
+            hint_usage,
+            review_cards,
+            achievements,
+            tutor_usage,
+            ai_calls,
+            notifications,
+            api_keys,
+            account_events,
+        })
+    }
+
+    async fn request_deletion(&self, user_id: &UserId, current_password: &str) -> Result<User> {
+        let mut user = self.load_user(user_id).await?;
+
+        let valid = user
+            .verify_password(current_password)
+            .map_err(|e| anyhow::anyhow!("Password verification failed: {e}"))?;
+        if !valid {
+            return Err(anyhow::anyhow!("Current password is incorrect"));
+        }
+        if user.is_pending_deletion() {
+            return Err(anyhow::anyhow!("Account deletion is already scheduled"));
+        }
+
+        user.schedule_deletion(self.grace_period);
+        self.user_repository.update(&user).await?;
+        self.record(
+            user_id,
+            AccountAuditAction::DeletionRequested,
+            json!({ "scheduled_for": user.deletion_scheduled_for }),
+        )
+        .await?;
+
+        Ok(user)
+    }
+
+    async fn cancel_deletion(&self, user_id: &UserId) -> Result<User> {
+        let mut user = self.load_user(user_id).await?;
+        if !user.is_pending_deletion() {
+            return Err(anyhow::anyhow!("No account deletion is scheduled"));
+        }
+
+        user.cancel_deletion();
+        self.user_repository.update(&user).await?;
+        self.record(user_id, AccountAuditAction::DeletionCancelled, json!({}))
+            .await?;
+
+        Ok(user)
+    }
+
+    async fn purge_due_accounts(&self) -> Result<PurgeReport> {
+        let due_users = self
+            .user_repository
+            .find_due_for_deletion(Utc::now())
+            .await?;
+
+        let mut report = PurgeReport::default();
+        for user in &due_users {
+            match self.purge_account(user).await {
+                Ok(()) => report.purged += 1,
+                Err(e) => {
+                    tracing::error!("Failed to purge account {}: {}", user.id.to_string(), e);
+                    report.failed += 1;
+                }
+            }
+        }
+
+        Ok(report)
+    }
+}
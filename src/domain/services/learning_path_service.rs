@@ -0,0 +1,365 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::domain::entities::lesson_session::MAX_LESSON_STARS;
+use crate::domain::entities::prerequisite::DependencyGraph;
+use crate::domain::entities::{Lesson, LessonPrerequisite, Topic, TopicPrerequisite, UnlockState};
+use crate::domain::repositories::{
+    LessonRepository, PrerequisiteRepository, TopicRepository, UserProgressRepository,
+};
+use crate::domain::value_objects::{LessonId, TopicId, UserId};
+
+#[derive(Debug, Clone)]
+pub struct LessonNode {
+    pub lesson: Lesson,
+    pub state: UnlockState,
+    /// Best stars so far, `None` if the lesson was never finished.
+    pub stars: Option<u32>,
+    pub prerequisites: Vec<LessonPrerequisite>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TopicNode {
+    pub topic: Topic,
+    pub state: UnlockState,
+    pub prerequisites: Vec<TopicPrerequisite>,
+    pub lessons: Vec<LessonNode>,
+}
+
+/// The whole curriculum in order, as seen by one learner.
+#[derive(Debug, Clone)]
+pub struct LearningPath {
+    pub topics: Vec<TopicNode>,
+}
+
+impl LearningPath {
+    pub fn lesson(&self, lesson_id: &LessonId) -> Option<&LessonNode> {
+        self.topics
+            .iter()
+            .flat_map(|topic| topic.lessons.iter())
+            .find(|node| node.lesson.id == *lesson_id)
+    }
+}
+
+#[async_trait]
+pub trait LearningPathService: Send + Sync + 'static {
+    async fn get_path(&self, user_id: &UserId) -> Result<LearningPath>;
+    async fn is_lesson_unlocked(&self, user_id: &UserId, lesson_id: &LessonId) -> Result<bool>;
+    /// Adds or updates an edge. Rejected if it would make the graph cyclic.
+    async fn add_topic_prerequisite(
+        &self,
+        topic_id: &TopicId,
+        prerequisite_topic_id: &TopicId,
+        min_stars: u32,
+    ) -> Result<TopicPrerequisite>;
+    async fn remove_topic_prerequisite(
+        &self,
+        topic_id: &TopicId,
+        prerequisite_topic_id: &TopicId,
+    ) -> Result<()>;
+    /// Adds or updates an edge. Rejected if it would make the graph cyclic.
+    async fn add_lesson_prerequisite(
+        &self,
+        lesson_id: &LessonId,
+        prerequisite_lesson_id: &LessonId,
+        min_stars: u32,
+    ) -> Result<LessonPrerequisite>;
+    async fn remove_lesson_prerequisite(
+        &self,
+        lesson_id: &LessonId,
+        prerequisite_lesson_id: &LessonId,
+    ) -> Result<()>;
+}
+
+/// Curriculum content together with the prerequisite edges between it.
+struct ContentGraph {
+    topics: Vec<Topic>,
+    lessons_by_topic: HashMap<TopicId, Vec<Lesson>>,
+    topic_prerequisites: Vec<TopicPrerequisite>,
+    lesson_prerequisites: Vec<LessonPrerequisite>,
+}
+
+impl ContentGraph {
+    fn lesson_ids(&self, topic_id: &TopicId) -> Vec<LessonId> {
+        self.lessons_by_topic
+            .get(topic_id)
+            .map(|lessons| lessons.iter().map(|l| l.id.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Lesson-level dependencies, where a topic prerequisite makes every lesson of the
+    /// topic depend on every lesson of the prerequisite topic. A cycle here means some
+    /// lesson can never be unlocked, even if the topic and lesson graphs are each acyclic.
+    fn lesson_dependencies(&self) -> DependencyGraph<LessonId> {
+        let mut graph = DependencyGraph::new();
+        for edge in &self.lesson_prerequisites {
+            graph.add_edge(edge.lesson_id.clone(), edge.prerequisite_lesson_id.clone());
+        }
+        for edge in &self.topic_prerequisites {
+            for lesson_id in self.lesson_ids(&edge.topic_id) {
+                for prerequisite_id in self.lesson_ids(&edge.prerequisite_topic_id) {
+                    graph.add_edge(lesson_id.clone(), prerequisite_id);
+                }
+            }
+        }
+        graph
+    }
+
+    fn topic_dependencies(&self) -> DependencyGraph<TopicId> {
+        let mut graph = DependencyGraph::new();
+        for edge in &self.topic_prerequisites {
+            graph.add_edge(edge.topic_id.clone(), edge.prerequisite_topic_id.clone());
+        }
+        graph
+    }
+
+    fn evaluate(self, lesson_stars: &HashMap<LessonId, u32>) -> LearningPath {
+        let has_stars = |lesson_id: &LessonId, min_stars: u32| {
+            lesson_stars
+                .get(lesson_id)
+                .is_some_and(|stars| *stars >= min_stars)
+        };
+
+        let topics = self
+            .topics
+            .iter()
+            .map(|topic| {
+                let prerequisites: Vec<TopicPrerequisite> = self
+                    .topic_prerequisites
+                    .iter()
+                    .filter(|edge| edge.topic_id == topic.id)
+                    .cloned()
+                    .collect();
+                let topic_unlocked = prerequisites.iter().all(|edge| {
+                    self.lesson_ids(&edge.prerequisite_topic_id)
+                        .iter()
+                        .all(|lesson_id| has_stars(lesson_id, edge.min_stars))
+                });
+
+                let lessons: Vec<LessonNode> = self
+                    .lessons_by_topic
+                    .get(&topic.id)
+                    .cloned()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|lesson| {
+                        let prerequisites: Vec<LessonPrerequisite> = self
+                            .lesson_prerequisites
+                            .iter()
+                            .filter(|edge| edge.lesson_id == lesson.id)
+                            .cloned()
+                            .collect();
+                        let stars = lesson_stars.get(&lesson.id).copied();
+                        let state = if has_stars(&lesson.id, 1) {
+                            UnlockState::Completed
+                        } else if topic_unlocked
+                            && prerequisites
+                                .iter()
+                                .all(|edge| has_stars(&edge.prerequisite_lesson_id, edge.min_stars))
+                        {
+                            UnlockState::Unlocked
+                        } else {
+                            UnlockState::Locked
+                        };
+
+                        LessonNode {
+                            lesson,
+                            state,
+                            stars,
+                            prerequisites,
+                        }
+                    })
+                    .collect();
+
+                let state = if !topic_unlocked {
+                    UnlockState::Locked
+                } else if !lessons.is_empty()
+                    && lessons.iter().all(|l| l.state == UnlockState::Completed)
+                {
+                    UnlockState::Completed
+                } else {
+                    UnlockState::Unlocked
+                };
+
+                TopicNode {
+                    topic: topic.clone(),
+                    state,
+                    prerequisites,
+                    lessons,
+                }
+            })
+            .collect();
+
+        LearningPath { topics }
+    }
+}
+
+pub struct LearningPathServiceImpl {
+    topic_repository: Arc<dyn TopicRepository>,
+    lesson_repository: Arc<dyn LessonRepository>,
+    prerequisite_repository: Arc<dyn PrerequisiteRepository>,
+    user_progress_repository: Arc<dyn UserProgressRepository>,
+}
+
+impl LearningPathServiceImpl {
+    pub fn new(
+        topic_repository: Arc<dyn TopicRepository>,
+        lesson_repository: Arc<dyn LessonRepository>,
+        prerequisite_repository: Arc<dyn PrerequisiteRepository>,
+        user_progress_repository: Arc<dyn UserProgressRepository>,
+    ) -> Self {
+        Self {
+            topic_repository,
+            lesson_repository,
+            prerequisite_repository,
+            user_progress_repository,
+        }
+    }
+
+    async fn load_graph(&self) -> Result<ContentGraph> {
+        let topics = self.topic_repository.list_by_order().await?;
+        let mut lessons_by_topic = HashMap::new();
+        for topic in &topics {
+            let mut lessons = self.lesson_repository.find_by_topic_id(&topic.id).await?;
+            lessons.sort_by_key(|l| l.order);
+            lessons_by_topic.insert(topic.id.clone(), lessons);
+        }
+
+        Ok(ContentGraph {
+            topics,
+            lessons_by_topic,
+            topic_prerequisites: self
+                .prerequisite_repository
+                .list_topic_prerequisites()
+                .await?,
+            lesson_prerequisites: self
+                .prerequisite_repository
+                .list_lesson_prerequisites()
+                .await?,
+        })
+    }
+
+    fn validate_min_stars(min_stars: u32) -> Result<()> {
+        if min_stars > MAX_LESSON_STARS {
+            return Err(anyhow::anyhow!(
+                "Required stars must be between 0 and {MAX_LESSON_STARS}"
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl LearningPathService for LearningPathServiceImpl {
+    async fn get_path(&self, user_id: &UserId) -> Result<LearningPath> {
+        let lesson_stars: HashMap<LessonId, u32> = self
+            .user_progress_repository
+            .find_by_user_id(user_id)
+            .await?
+            .map(|progress| progress.lesson_stars.into_iter().collect())
+            .unwrap_or_default();
+
+        Ok(self.load_graph().await?.evaluate(&lesson_stars))
+    }
+
+    async fn is_lesson_unlocked(&self, user_id: &UserId, lesson_id: &LessonId) -> Result<bool> {
+        let path = self.get_path(user_id).await?;
+        Ok(path
+            .lesson(lesson_id)
+            .is_some_and(|node| node.state != UnlockState::Locked))
+    }
+
+    async fn add_topic_prerequisite(
+        &self,
+        topic_id: &TopicId,
+        prerequisite_topic_id: &TopicId,
+        min_stars: u32,
+    ) -> Result<TopicPrerequisite> {
+        Self::validate_min_stars(min_stars)?;
+        if topic_id == prerequisite_topic_id {
+            return Err(anyhow::anyhow!("A topic cannot be its own prerequisite"));
+        }
+
+        let graph = self.load_graph().await?;
+        for id in [topic_id, prerequisite_topic_id] {
+            if !graph.topics.iter().any(|t| t.id == *id) {
+                return Err(anyhow::anyhow!("Topic not found: {}", id.to_string()));
+            }
+        }
+        if graph.topic_dependencies().reaches(
+            std::slice::from_ref(prerequisite_topic_id),
+            std::slice::from_ref(topic_id),
+        ) || graph.lesson_dependencies().reaches(
+            &graph.lesson_ids(prerequisite_topic_id),
+            &graph.lesson_ids(topic_id),
+        ) {
+            return Err(anyhow::anyhow!("Prerequisite would create a cycle"));
+        }
+
+        let prerequisite =
+            TopicPrerequisite::new(topic_id.clone(), prerequisite_topic_id.clone(), min_stars);
+        self.prerequisite_repository
+            .save_topic_prerequisite(&prerequisite)
+            .await?;
+        Ok(prerequisite)
+    }
+
+    async fn remove_topic_prerequisite(
+        &self,
+        topic_id: &TopicId,
+        prerequisite_topic_id: &TopicId,
+    ) -> Result<()> {
+        self.prerequisite_repository
+            .delete_topic_prerequisite(topic_id, prerequisite_topic_id)
+            .await
+    }
+
+    async fn add_lesson_prerequisite(
+        &self,
+        lesson_id: &LessonId,
+        prerequisite_lesson_id: &LessonId,
+        min_stars: u32,
+    ) -> Result<LessonPrerequisite> {
+        Self::validate_min_stars(min_stars)?;
+        if lesson_id == prerequisite_lesson_id {
+            return Err(anyhow::anyhow!("A lesson cannot be its own prerequisite"));
+        }
+
+        let graph = self.load_graph().await?;
+        for id in [lesson_id, prerequisite_lesson_id] {
+            if !graph
+                .lessons_by_topic
+                .values()
+                .flatten()
+                .any(|l| l.id == *id)
+            {
+                return Err(anyhow::anyhow!("Lesson not found: {}", id.to_string()));
+            }
+        }
+        if graph.lesson_dependencies().reaches(
+            std::slice::from_ref(prerequisite_lesson_id),
+            std::slice::from_ref(lesson_id),
+        ) {
+            return Err(anyhow::anyhow!("Prerequisite would create a cycle"));
+        }
+
+        let prerequisite =
+            LessonPrerequisite::new(lesson_id.clone(), prerequisite_lesson_id.clone(), min_stars);
+        self.prerequisite_repository
+            .save_lesson_prerequisite(&prerequisite)
+            .await?;
+        Ok(prerequisite)
+    }
+
+    async fn remove_lesson_prerequisite(
+        &self,
+        lesson_id: &LessonId,
+        prerequisite_lesson_id: &LessonId,
+    ) -> Result<()> {
+        self.prerequisite_repository
+            .delete_lesson_prerequisite(lesson_id, prerequisite_lesson_id)
+            .await
+    }
+}
@@ -0,0 +1,255 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::entities::user::DEFAULT_LANGUAGE;
+use crate::domain::entities::user_progress::{LessonResult, XpAward};
+use crate::domain::entities::{Lesson, LessonSession, Question};
+use crate::domain::repositories::{
+    LessonRepository, LessonSessionRepository, QuestionRepository, UserRepository,
+};
+use crate::domain::services::{LearningPathService, NotificationService, ProgressService};
+use crate::domain::value_objects::{LessonId, LessonSessionId, LocalizedText, QuestionId, UserId};
+
+/// Times a session change is retried when other requests keep saving first.
+const MAX_SAVE_ATTEMPTS: usize = 3;
+
+/// A freshly started session with the questions to present, in lesson order.
+#[derive(Debug, Clone)]
+pub struct StartedLessonSession {
+    pub session: LessonSession,
+    pub lesson: Lesson,
+    pub questions: Vec<Question>,
+}
+
+/// Feedback on a single attempt.
+#[derive(Debug, Clone)]
+pub struct AnswerOutcome {
+    pub correct: bool,
+    pub attempts: u32,
+    /// Only revealed once the question is answered correctly, so retries stay meaningful.
+    pub explanation: Option<LocalizedText>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LessonCompletion {
+    pub session: LessonSession,
+    pub result: LessonResult,
+    pub stars: u32,
+    pub award: XpAward,
+}
+
+#[async_trait]
+pub trait LessonSessionService: Send + Sync + 'static {
+    async fn start(&self, user_id: &UserId, lesson_id: &LessonId) -> Result<StartedLessonSession>;
+    async fn submit_answer(
+        &self,
+        user_id: &UserId,
+        session_id: &LessonSessionId,
+        question_id: &QuestionId,
+        answer: &str,
+        time_spent_ms: u64,
+    ) -> Result<AnswerOutcome>;
+    async fn finish(
+        &self,
+        user_id: &UserId,
+        session_id: &LessonSessionId,
+    ) -> Result<LessonCompletion>;
+}
+
+pub struct LessonSessionServiceImpl {
+    lesson_session_repository: Arc<dyn LessonSessionRepository>,
+    lesson_repository: Arc<dyn LessonRepository>,
+    question_repository: Arc<dyn QuestionRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    progress_service: Arc<dyn ProgressService>,
+    notification_service: Arc<dyn NotificationService>,
+    learning_path_service: Arc<dyn LearningPathService>,
+}
+
+impl LessonSessionServiceImpl {
+    pub fn new(
+        lesson_session_repository: Arc<dyn LessonSessionRepository>,
+        lesson_repository: Arc<dyn LessonRepository>,
+        question_repository: Arc<dyn QuestionRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        progress_service: Arc<dyn ProgressService>,
+        notification_service: Arc<dyn NotificationService>,
+        learning_path_service: Arc<dyn LearningPathService>,
+    ) -> Self {
+        Self {
+            lesson_session_repository,
+            lesson_repository,
+            question_repository,
+            user_repository,
+            progress_service,
+            notification_service,
+            learning_path_service,
+        }
+    }
+
+    /// Sessions of other users are reported as missing rather than forbidden.
+    async fn load_session(
+        &self,
+        user_id: &UserId,
+        session_id: &LessonSessionId,
+    ) -> Result<LessonSession> {
+        self.lesson_session_repository
+            .find_by_id(session_id)
+            .await?
+            .filter(|session| session.user_id == *user_id)
+            .ok_or_else(|| anyhow::anyhow!("Lesson session not found"))
+    }
+
+    /// Applies `change` to the session and saves it. When another request saved
+    /// the session first, the change is applied again to the newly stored one.
+    async fn change_session<T: Send>(
+        &self,
+        user_id: &UserId,
+        session_id: &LessonSessionId,
+        change: impl Fn(&mut LessonSession) -> Result<T> + Send,
+    ) -> Result<(LessonSession, T)> {
+        for _ in 0..MAX_SAVE_ATTEMPTS {
+            let mut session = self.load_session(user_id, session_id).await?;
+            let changed = change(&mut session)?;
+            if self.lesson_session_repository.update(&session).await? {
+                return Ok((session, changed));
+            }
+        }
+        Err(anyhow::anyhow!(
+            "Lesson session is being changed by another request; try again"
+        ))
+    }
+
+    async fn notify_completion(&self, user_id: &UserId, lesson_id: &LessonId, xp_gained: u32) {
+        let language = match self.user_repository.find_by_id(user_id).await {
+            Ok(Some(user)) => user.preferred_language,
+            _ => DEFAULT_LANGUAGE.to_string(),
+        };
+        let lesson_title = match self.lesson_repository.find_by_id(lesson_id).await {
+            Ok(Some(lesson)) => lesson.title.get(&language).to_string(),
+            _ => String::new(),
+        };
+
+        let notified = self
+            .notification_service
+            .create_lesson_complete_notification(user_id, lesson_title, xp_gained)
+            .await;
+        if let Err(e) = notified {
+            tracing::warn!("Failed to send lesson completion notification: {}", e);
+        }
+    }
+}
+
+#[async_trait]
+impl LessonSessionService for LessonSessionServiceImpl {
+    async fn start(&self, user_id: &UserId, lesson_id: &LessonId) -> Result<StartedLessonSession> {
+        let lesson = self
+            .lesson_repository
+            .find_by_id(lesson_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Lesson not found"))?;
+        if !self
+            .learning_path_service
+            .is_lesson_unlocked(user_id, lesson_id)
+            .await?
+        {
+            return Err(anyhow::anyhow!("Lesson is locked"));
+        }
+
+        let mut questions = Vec::with_capacity(lesson.questions.len());
+        for question_id in &lesson.questions {
+            if let Some(question) = self.question_repository.find_by_id(question_id).await? {
+                questions.push(question);
+            }
+        }
+        if questions.is_empty() {
+            return Err(anyhow::anyhow!("Lesson has no questions"));
+        }
+
+        let session = LessonSession::new(
+            user_id.clone(),
+            lesson.id.clone(),
+            questions.iter().map(|q| q.id.clone()).collect(),
+        );
+        self.lesson_session_repository.create(&session).await?;
+
+        Ok(StartedLessonSession {
+            session,
+            lesson,
+            questions,
+        })
+    }
+
+    async fn submit_answer(
+        &self,
+        user_id: &UserId,
+        session_id: &LessonSessionId,
+        question_id: &QuestionId,
+        answer: &str,
+        time_spent_ms: u64,
+    ) -> Result<AnswerOutcome> {
+        let session = self.load_session(user_id, session_id).await?;
+        if !session.question_ids.contains(question_id) {
+            return Err(anyhow::anyhow!(
+                "Question is not part of this lesson session"
+            ));
+        }
+        let question = self
+            .question_repository
+            .find_by_id(question_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Question not found"))?;
+
+        let correct = question.check_answer(answer);
+        let (_, attempts) = self
+            .change_session(user_id, session_id, |session| {
+                session
+                    .record_answer(
+                        question_id.clone(),
+                        answer.to_string(),
+                        correct,
+                        time_spent_ms,
+                        question.points,
+                    )
+                    .map(|answer| answer.attempts)
+                    .map_err(|e| anyhow::anyhow!(e))
+            })
+            .await?;
+
+        Ok(AnswerOutcome {
+            correct,
+            attempts,
+            explanation: if correct { question.explanation } else { None },
+        })
+    }
+
+    async fn finish(
+        &self,
+        user_id: &UserId,
+        session_id: &LessonSessionId,
+    ) -> Result<LessonCompletion> {
+        // Closed before XP is awarded so a repeated finish cannot pay out twice
+        let (session, (stars, result)) = self
+            .change_session(user_id, session_id, |session| {
+                let stars = session.stars();
+                let result = session.finish().map_err(|e| anyhow::anyhow!(e))?;
+                Ok((stars, result))
+            })
+            .await?;
+
+        let (result, award) = self
+            .progress_service
+            .complete_lesson(user_id, result, stars)
+            .await?;
+        self.notify_completion(user_id, &session.lesson_id, award.granted.value())
+            .await;
+
+        Ok(LessonCompletion {
+            session,
+            result,
+            stars,
+            award,
+        })
+    }
+}
@@ -1,9 +1,35 @@
+pub mod account_service;
+pub mod achievement_service;
+pub mod adaptive_learning_service;
+pub mod api_key_service;
 pub mod auth_service;
+pub mod code_practice_service;
+pub mod content_version_service;
+pub mod data_privacy_service;
 pub mod leaderboard_service;
+pub mod learning_path_service;
+pub mod lesson_session_service;
 pub mod notification_service;
+pub mod plagiarism_service;
 pub mod progress_service;
+pub mod question_draft_service;
+pub mod quiz_service;
+pub mod review_service;
 
+pub use account_service::AccountService;
+pub use achievement_service::AchievementService;
+pub use adaptive_learning_service::AdaptiveLearningService;
+pub use api_key_service::ApiKeyService;
 pub use auth_service::AuthService;
+pub use code_practice_service::CodePracticeService;
+pub use content_version_service::ContentVersionService;
+pub use data_privacy_service::{DataPrivacyService, PurgeReport};
 pub use leaderboard_service::LeaderboardService;
+pub use learning_path_service::LearningPathService;
+pub use lesson_session_service::LessonSessionService;
 pub use notification_service::NotificationService;
+pub use plagiarism_service::PlagiarismService;
 pub use progress_service::ProgressService;
+pub use question_draft_service::QuestionDraftService;
+pub use quiz_service::QuizService;
+pub use review_service::ReviewService;
@@ -2,7 +2,9 @@ use crate::domain::entities::Notification;
 use crate::domain::repositories::NotificationRepository;
 use crate::domain::value_objects::{NotificationId, UserId};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use std::error::Error;
+use std::sync::Arc;
 
 #[async_trait]
 pub trait NotificationService: Send + Sync {
@@ -81,6 +83,22 @@ pub trait NotificationService: Send + Sync {
         user_name: String,
     ) -> Result<Notification, Box<dyn Error + Send + Sync>>;
 
+    async fn create_streak_freeze_used_notification(
+        &self,
+        user_id: &UserId,
+        freezes_used: u32,
+        streak_days: u32,
+        freezes_left: u32,
+    ) -> Result<Notification, Box<dyn Error + Send + Sync>>;
+
+    async fn create_streak_broken_notification(
+        &self,
+        user_id: &UserId,
+        lost_streak_days: u32,
+        repair_price: u32,
+        repair_expires_at: DateTime<Utc>,
+    ) -> Result<Notification, Box<dyn Error + Send + Sync>>;
+
     async fn create_progress_notification(
         &self,
         user_id: &UserId,
@@ -97,14 +115,14 @@ pub trait NotificationService: Send + Sync {
 }
 
 pub struct NotificationServiceImpl {
-    notification_repository: Box<dyn NotificationRepository>,
-    user_repository: Box<dyn crate::domain::repositories::UserRepository>,
+    notification_repository: Arc<dyn NotificationRepository>,
+    user_repository: Arc<dyn crate::domain::repositories::UserRepository>,
 }
 
 impl NotificationServiceImpl {
     pub fn new(
-        notification_repository: Box<dyn NotificationRepository>,
-        user_repository: Box<dyn crate::domain::repositories::UserRepository>,
+        notification_repository: Arc<dyn NotificationRepository>,
+        user_repository: Arc<dyn crate::domain::repositories::UserRepository>,
     ) -> Self {
         Self {
             notification_repository,
@@ -292,6 +310,54 @@ impl NotificationService for NotificationServiceImpl {
         .await
     }
 
+    async fn create_streak_freeze_used_notification(
+        &self,
+        user_id: &UserId,
+        freezes_used: u32,
+        streak_days: u32,
+        freezes_left: u32,
+    ) -> Result<Notification, Box<dyn Error + Send + Sync>> {
+        let metadata = serde_json::json!({
+            "freezes_used": freezes_used,
+            "streak_days": streak_days,
+            "freezes_left": freezes_left
+        });
+
+        self.create_notification(
+            user_id,
+            "🧊 Streak Freeze Used".to_string(),
+            format!("You missed a day, so {freezes_used} streak freeze(s) kept your {streak_days}-day streak alive. {freezes_left} left."),
+            crate::domain::entities::notification::NotificationType::Reminder,
+            crate::domain::entities::notification::NotificationPriority::Medium,
+            Some(metadata),
+        )
+        .await
+    }
+
+    async fn create_streak_broken_notification(
+        &self,
+        user_id: &UserId,
+        lost_streak_days: u32,
+        repair_price: u32,
+        repair_expires_at: DateTime<Utc>,
+    ) -> Result<Notification, Box<dyn Error + Send + Sync>> {
+        let metadata = serde_json::json!({
+            "lost_streak_days": lost_streak_days,
+            "repair_price": repair_price,
+            "repair_expires_at": repair_expires_at
+        });
+
+        self.create_notification(
+            user_id,
+            "💔 Streak Lost".to_string(),
+            format!("Your {lost_streak_days}-day streak ended. Repair it for {repair_price} XP before {} UTC.", repair_expires_at.format("%Y-%m-%d %H:%M")),
+            crate::domain::entities::notification::NotificationType::Reminder,
+            crate::domain::entities::notification::NotificationPriority::High,
+            Some(metadata),
+        )
+        .await
+    }
+
     async fn create_progress_notification(
         &self,
         user_id: &UserId,
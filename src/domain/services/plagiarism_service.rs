@@ -0,0 +1,202 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::domain::entities::{CodePractice, CodeSubmission};
+use crate::domain::repositories::{CodePracticeRepository, CodeSubmissionRepository};
+use crate::domain::value_objects::{CodePracticeId, CodeSubmissionId, UserId};
+use crate::shared::utils::{fingerprint_similarity, fingerprints};
+
+/// Similarity percentage at or above which a pair is flagged by default.
+pub const DEFAULT_SIMILARITY_THRESHOLD: u32 = 80;
+
+#[derive(Debug, Clone)]
+pub struct SimilarPair {
+    pub first: CodeSubmission,
+    pub second: CodeSubmission,
+    pub similarity: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct SolutionMatch {
+    pub submission: CodeSubmission,
+    pub similarity: u32,
+}
+
+/// Flagged submissions of one practice, most similar first.
+#[derive(Debug, Clone)]
+pub struct SimilarityReport {
+    pub code_practice: CodePractice,
+    pub threshold: u32,
+    /// Learners whose latest submission was compared.
+    pub submissions_compared: usize,
+    pub pairs: Vec<SimilarPair>,
+    pub solution_matches: Vec<SolutionMatch>,
+}
+
+/// Two versions of a practice's code side by side.
+#[derive(Debug, Clone)]
+pub struct SubmissionComparison {
+    pub code_practice: CodePractice,
+    pub left: CodeSubmission,
+    /// `None` when comparing against the practice's solution.
+    pub right: Option<CodeSubmission>,
+    pub right_code: String,
+    pub similarity: u32,
+}
+
+#[async_trait]
+pub trait PlagiarismService: Send + Sync + 'static {
+    /// Compares each learner's latest submission with every other learner's
+    /// and with the stored solution, keeping those at or above `threshold`.
+    async fn similarity_report(
+        &self,
+        code_practice_id: &CodePracticeId,
+        threshold: u32,
+    ) -> Result<SimilarityReport>;
+    /// Compares `left` with `right`, or with the solution when `right` is `None`.
+    async fn compare(
+        &self,
+        code_practice_id: &CodePracticeId,
+        left: &CodeSubmissionId,
+        right: Option<&CodeSubmissionId>,
+    ) -> Result<SubmissionComparison>;
+}
+
+pub struct PlagiarismServiceImpl {
+    code_practice_repository: Arc<dyn CodePracticeRepository>,
+    code_submission_repository: Arc<dyn CodeSubmissionRepository>,
+}
+
+impl PlagiarismServiceImpl {
+    pub fn new(
+        code_practice_repository: Arc<dyn CodePracticeRepository>,
+        code_submission_repository: Arc<dyn CodeSubmissionRepository>,
+    ) -> Self {
+        Self {
+            code_practice_repository,
+            code_submission_repository,
+        }
+    }
+
+    async fn load_code_practice(&self, code_practice_id: &CodePracticeId) -> Result<CodePractice> {
+        self.code_practice_repository
+            .find_by_id(code_practice_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Code practice not found"))
+    }
+
+    async fn load_submission(
+        &self,
+        code_practice_id: &CodePracticeId,
+        submission_id: &CodeSubmissionId,
+    ) -> Result<CodeSubmission> {
+        self.code_submission_repository
+            .find_by_id(submission_id)
+            .await?
+            .filter(|submission| submission.code_practice_id == *code_practice_id)
+            .ok_or_else(|| anyhow::anyhow!("Code submission not found"))
+    }
+
+    /// Fingerprints of `code` without those of the practice's starting code,
+    /// which every learner shares and which says nothing about copying.
+    fn own_fingerprints(code: &str, scaffold: &HashSet<u64>) -> HashSet<u64> {
+        fingerprints(code).difference(scaffold).copied().collect()
+    }
+}
+
+#[async_trait]
+impl PlagiarismService for PlagiarismServiceImpl {
+    async fn similarity_report(
+        &self,
+        code_practice_id: &CodePracticeId,
+        threshold: u32,
+    ) -> Result<SimilarityReport> {
+        let code_practice = self.load_code_practice(code_practice_id).await?;
+
+        // Submissions come newest first, so the first one seen per learner is their latest
+        let mut seen: HashSet<UserId> = HashSet::new();
+        let latest: Vec<CodeSubmission> = self
+            .code_submission_repository
+            .list_by_practice(code_practice_id)
+            .await?
+            .into_iter()
+            .filter(|submission| seen.insert(submission.user_id.clone()))
+            .collect();
+        let scaffold = fingerprints(&code_practice.initial_code);
+        let prints: HashMap<CodeSubmissionId, HashSet<u64>> = latest
+            .iter()
+            .map(|submission| {
+                (
+                    submission.id.clone(),
+                    Self::own_fingerprints(&submission.code, &scaffold),
+                )
+            })
+            .collect();
+
+        let mut pairs = Vec::new();
+        for (index, first) in latest.iter().enumerate() {
+            for second in &latest[index + 1..] {
+                let similarity = fingerprint_similarity(&prints[&first.id], &prints[&second.id]);
+                if similarity >= threshold {
+                    pairs.push(SimilarPair {
+                        first: first.clone(),
+                        second: second.clone(),
+                        similarity,
+                    });
+                }
+            }
+        }
+        pairs.sort_by_key(|pair| std::cmp::Reverse(pair.similarity));
+
+        let solution_prints = Self::own_fingerprints(&code_practice.solution, &scaffold);
+        let mut solution_matches: Vec<SolutionMatch> = latest
+            .iter()
+            .map(|submission| SolutionMatch {
+                submission: submission.clone(),
+                similarity: fingerprint_similarity(&prints[&submission.id], &solution_prints),
+            })
+            .filter(|matched| matched.similarity >= threshold)
+            .collect();
+        solution_matches.sort_by_key(|matched| std::cmp::Reverse(matched.similarity));
+
+        Ok(SimilarityReport {
+            code_practice,
+            threshold,
+            submissions_compared: latest.len(),
+            pairs,
+            solution_matches,
+        })
+    }
+
+    async fn compare(
+        &self,
+        code_practice_id: &CodePracticeId,
+        left: &CodeSubmissionId,
+        right: Option<&CodeSubmissionId>,
+    ) -> Result<SubmissionComparison> {
+        let code_practice = self.load_code_practice(code_practice_id).await?;
+        let left = self.load_submission(code_practice_id, left).await?;
+        let right = match right {
+            Some(right_id) => Some(self.load_submission(code_practice_id, right_id).await?),
+            None => None,
+        };
+        let right_code = right
+            .as_ref()
+            .map_or_else(|| code_practice.solution.clone(), |r| r.code.clone());
+        let scaffold = fingerprints(&code_practice.initial_code);
+        let similarity = fingerprint_similarity(
+            &Self::own_fingerprints(&left.code, &scaffold),
+            &Self::own_fingerprints(&right_code, &scaffold),
+        );
+
+        Ok(SubmissionComparison {
+            code_practice,
+            left,
+            right,
+            right_code,
+            similarity,
+        })
+    }
+}
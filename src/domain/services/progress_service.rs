@@ -1,99 +1,398 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
 use std::sync::Arc;
 
-use crate::domain::entities::user_progress::{CompletedCodePractice, QuestionResult};
-use crate::domain::entities::UserProgress;
+use crate::domain::entities::user_progress::{
+    CompletedCodePractice, LessonResult, QuestionResult, StreakCheck, XpAward, MAX_STREAK_FREEZES,
+    STREAK_FREEZE_PRICE, STREAK_REPAIR_PRICE,
+};
+use crate::domain::entities::{ReviewCard, UserProgress};
+use crate::domain::repositories::{ReviewCardRepository, UserRepository};
+use crate::domain::services::{AchievementService, NotificationService};
 use crate::domain::value_objects::{Points, UserId};
 
+/// Times a progress change is retried when other requests keep saving first.
+const MAX_SAVE_ATTEMPTS: usize = 3;
+
+/// Daily XP budget as seen from the user's local day.
+#[derive(Debug, Clone)]
+pub struct DailyXpStatus {
+    pub cap: u32,
+    pub earned_today: u32,
+    pub remaining: u32,
+    /// Next local midnight, expressed in UTC.
+    pub resets_at: DateTime<Utc>,
+}
+
+/// Streak state together with the freeze inventory and any open repair offer.
+#[derive(Debug, Clone)]
+pub struct StreakStatus {
+    pub current_streak_days: u32,
+    pub highest_streak_days: u32,
+    pub last_active_date: NaiveDate,
+    pub freezes: u32,
+    pub max_freezes: u32,
+    pub freeze_price: u32,
+    pub repair: Option<StreakRepairOffer>,
+}
+
+#[derive(Debug, Clone)]
+pub struct StreakRepairOffer {
+    pub streak_days: u32,
+    pub price: u32,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl StreakStatus {
+    fn from_progress(progress: &UserProgress, now: DateTime<Utc>) -> Self {
+        let repair = match progress.streak_repair_expires_at {
+            Some(expires_at) if progress.can_repair_streak(now) => Some(StreakRepairOffer {
+                streak_days: progress.repairable_streak_days,
+                price: STREAK_REPAIR_PRICE,
+                expires_at,
+            }),
+            _ => None,
+        };
+
+        Self {
+            current_streak_days: progress.current_streak_days,
+            highest_streak_days: progress.highest_streak_days,
+            last_active_date: progress.last_active_date,
+            freezes: progress.streak_freezes,
+            max_freezes: MAX_STREAK_FREEZES,
+            freeze_price: STREAK_FREEZE_PRICE,
+            repair,
+        }
+    }
+}
+
 #[async_trait]
 pub trait ProgressService: Send + Sync + 'static {
     async fn get_user_progress(&self, user_id: &UserId) -> Result<Option<UserProgress>>;
     async fn update_user_progress(&self, progress: &UserProgress) -> Result<()>;
-    async fn add_question_result(&self, user_id: &UserId, result: QuestionResult) -> Result<()>;
+    async fn add_question_result(
+        &self,
+        user_id: &UserId,
+        result: QuestionResult,
+    ) -> Result<XpAward>;
     async fn add_code_practice_completion(
         &self,
         user_id: &UserId,
         completion: CompletedCodePractice,
-    ) -> Result<()>;
+    ) -> Result<XpAward>;
+    /// Records a finished lesson with its stars. Returns the result with the XP
+    /// actually granted after the daily cap.
+    async fn complete_lesson(
+        &self,
+        user_id: &UserId,
+        result: LessonResult,
+        stars: u32,
+    ) -> Result<(LessonResult, XpAward)>;
     async fn get_user_xp(&self, user_id: &UserId) -> Result<Points>;
     async fn update_streak(&self, user_id: &UserId, days: u32) -> Result<()>;
+    async fn get_daily_xp_status(&self, user_id: &UserId) -> Result<DailyXpStatus>;
+    /// Sets or clears (`None`) the user's own daily XP cap.
+    async fn set_daily_xp_cap(&self, user_id: &UserId, cap: Option<u32>) -> Result<()>;
+    /// Applies freezes or resets streaks of learners who missed a full local day.
+    /// Returns how many streaks were broken.
+    async fn expire_broken_streaks(&self) -> Result<u32>;
+    async fn get_streak_status(&self, user_id: &UserId) -> Result<StreakStatus>;
+    async fn buy_streak_freeze(&self, user_id: &UserId) -> Result<StreakStatus>;
+    async fn repair_streak(&self, user_id: &UserId) -> Result<StreakStatus>;
 }
 
 pub struct ProgressServiceImpl {
     user_progress_repository: Arc<dyn crate::domain::repositories::UserProgressRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    notification_service: Arc<dyn NotificationService>,
+    achievement_service: Arc<dyn AchievementService>,
+    review_card_repository: Arc<dyn ReviewCardRepository>,
+    default_daily_xp_cap: u32,
 }
 
 impl ProgressServiceImpl {
     pub fn new(
         user_progress_repository: Arc<dyn crate::domain::repositories::UserProgressRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        notification_service: Arc<dyn NotificationService>,
+        achievement_service: Arc<dyn AchievementService>,
+        review_card_repository: Arc<dyn ReviewCardRepository>,
+        default_daily_xp_cap: u32,
     ) -> Self {
         Self {
             user_progress_repository,
+            user_repository,
+            notification_service,
+            achievement_service,
+            review_card_repository,
+            default_daily_xp_cap,
         }
     }
-}
 
-#[async_trait]
-impl ProgressService for ProgressServiceImpl {
-    async fn get_user_progress(&self, user_id: &UserId) -> Result<Option<UserProgress>> {
-        self.user_progress_repository.find_by_user_id(user_id).await
+    /// Time zone the user's day boundaries are computed in.
+    async fn user_time_zone(&self, user_id: &UserId) -> Result<Tz> {
+        Ok(self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .map(|user| user.tz())
+            .unwrap_or(Tz::UTC))
     }
 
-    async fn update_user_progress(&self, progress: &UserProgress) -> Result<()> {
-        self.user_progress_repository.update(progress).await
+    async fn local_today(&self, user_id: &UserId) -> Result<NaiveDate> {
+        Ok(Utc::now()
+            .with_timezone(&self.user_time_zone(user_id).await?)
+            .date_naive())
+    }
+
+    /// Tells the learner that missed days used up freezes or broke the streak.
+    /// Runs once the settled streak is saved.
+    async fn notify_streak_check(&self, progress: &UserProgress, check: StreakCheck) {
+        let notified = match check {
+            StreakCheck::Intact => return,
+            StreakCheck::Frozen { freezes_used } => {
+                self.notification_service
+                    .create_streak_freeze_used_notification(
+                        &progress.user_id,
+                        freezes_used,
+                        progress.current_streak_days,
+                        progress.streak_freezes,
+                    )
+                    .await
+            }
+            StreakCheck::Broken {
+                lost_streak_days,
+                repair_expires_at,
+            } => {
+                self.notification_service
+                    .create_streak_broken_notification(
+                        &progress.user_id,
+                        lost_streak_days,
+                        STREAK_REPAIR_PRICE,
+                        repair_expires_at,
+                    )
+                    .await
+            }
+        };
+
+        // A failed notification must not undo the streak bookkeeping
+        if let Err(e) = notified {
+            tracing::warn!("Failed to send streak notification: {}", e);
+        }
+    }
+
+    /// Runs after each progress event. Progress is already saved, so a failure here
+    /// is logged and retried on the next event instead of failing the request.
+    async fn evaluate_achievements(&self, user_id: &UserId) {
+        if let Err(e) = self.achievement_service.evaluate(user_id).await {
+            tracing::warn!("Achievement evaluation failed: {}", e);
+        }
     }
 
-    async fn add_question_result(&self, user_id: &UserId, result: QuestionResult) -> Result<()> {
-        // Get or create user progress
-        let mut progress = match self
+    /// Feeds each answer into the question's spaced-repetition card. Like achievements,
+    /// this runs after progress is saved, so a failure is logged rather than returned.
+    async fn schedule_reviews(
+        &self,
+        user_id: &UserId,
+        results: &[QuestionResult],
+        today: NaiveDate,
+    ) {
+        for result in results {
+            let scheduled = async {
+                let mut card = self
+                    .review_card_repository
+                    .find(user_id, &result.question_id)
+                    .await?
+                    .unwrap_or_else(|| {
+                        ReviewCard::new(user_id.clone(), result.question_id.clone(), today)
+                    });
+                card.review(
+                    ReviewCard::recall_quality(result.correct, result.time_spent_ms),
+                    today,
+                );
+                self.review_card_repository.save(&card).await
+            };
+            if let Err(e) = scheduled.await {
+                tracing::warn!("Failed to schedule review: {}", e);
+            }
+        }
+    }
+
+    async fn find_or_create(&self, user_id: &UserId) -> Result<UserProgress> {
+        match self
             .user_progress_repository
             .find_by_user_id(user_id)
             .await?
         {
-            Some(progress) => progress,
+            Some(progress) => Ok(progress),
             None => {
-                let new_progress = UserProgress::new(user_id.clone());
-                self.user_progress_repository.create(&new_progress).await?;
-                new_progress
+                let created = self
+                    .user_progress_repository
+                    .create(&UserProgress::new(user_id.clone()))
+                    .await;
+                // Read back so `updated_at` is exactly what is stored. A concurrent
+                // request may have created the progress instead, which is fine too.
+                match self
+                    .user_progress_repository
+                    .find_by_user_id(user_id)
+                    .await?
+                {
+                    Some(progress) => Ok(progress),
+                    None => {
+                        created?;
+                        Err(anyhow::anyhow!("User progress could not be created"))
+                    }
+                }
             }
-        };
+        }
+    }
 
-        // Add question result
-        progress.add_question_result(result);
+    /// Applies `change` to the user's progress and saves it. When another request
+    /// saved the progress first, the change is applied again to the newly stored
+    /// progress, so concurrent requests never overwrite each other's XP.
+    async fn change_progress<T: Send>(
+        &self,
+        user_id: &UserId,
+        change: impl Fn(&mut UserProgress) -> T + Send,
+    ) -> Result<(UserProgress, T)> {
+        for _ in 0..MAX_SAVE_ATTEMPTS {
+            let mut progress = self.find_or_create(user_id).await?;
+            let loaded_at = progress.updated_at;
+            let changed = change(&mut progress);
+            // Every save moves `updated_at`, which is what later saves are checked against
+            progress.updated_at = Utc::now();
+            if self
+                .user_progress_repository
+                .update_if_unchanged(&progress, loaded_at)
+                .await?
+            {
+                return Ok((progress, changed));
+            }
+        }
+        Err(anyhow::anyhow!(
+            "User progress is being changed by another request; try again"
+        ))
+    }
 
-        // Update in database
-        self.user_progress_repository.update(&progress).await?;
+    /// Settles missed days for one listed user. If their progress was saved since
+    /// it was listed, it is left alone: that request already settled the streak.
+    async fn expire_streak(&self, mut progress: UserProgress) -> Result<StreakCheck> {
+        let today = self.local_today(&progress.user_id).await?;
+        let loaded_at = progress.updated_at;
+        let check = progress.check_streak(today, Utc::now());
+        if check == StreakCheck::Intact
+            || !self
+                .user_progress_repository
+                .update_streak_if_unchanged(&progress, loaded_at)
+                .await?
+        {
+            return Ok(StreakCheck::Intact);
+        }
 
-        Ok(())
+        self.notify_streak_check(&progress, check).await;
+        Ok(check)
+    }
+
+    fn next_local_midnight(tz: Tz, now: DateTime<Utc>) -> DateTime<Utc> {
+        let tomorrow = now.with_timezone(&tz).date_naive() + Duration::days(1);
+        tomorrow
+            .and_hms_opt(0, 0, 0)
+            .and_then(|midnight| tz.from_local_datetime(&midnight).earliest())
+            .map(|midnight| midnight.with_timezone(&Utc))
+            // Midnight can be skipped by a DST transition
+            .unwrap_or(now + Duration::days(1))
+    }
+}
+
+#[async_trait]
+impl ProgressService for ProgressServiceImpl {
+    async fn get_user_progress(&self, user_id: &UserId) -> Result<Option<UserProgress>> {
+        self.user_progress_repository.find_by_user_id(user_id).await
+    }
+
+    async fn update_user_progress(&self, progress: &UserProgress) -> Result<()> {
+        self.user_progress_repository.update(progress).await
+    }
+
+    async fn add_question_result(
+        &self,
+        user_id: &UserId,
+        result: QuestionResult,
+    ) -> Result<XpAward> {
+        let today = self.local_today(user_id).await?;
+
+        let (progress, (award, check)) = self
+            .change_progress(user_id, |progress| {
+                // Add question result, capped by today's remaining XP
+                let award =
+                    progress.add_question_result(result.clone(), self.default_daily_xp_cap, today);
+                let check = progress.check_streak(today, Utc::now());
+                progress.record_activity(today);
+                (award, check)
+            })
+            .await?;
+        self.notify_streak_check(&progress, check).await;
+        self.schedule_reviews(user_id, &[result], today).await;
+        self.evaluate_achievements(user_id).await;
+
+        Ok(award)
     }
 
     async fn add_code_practice_completion(
         &self,
         user_id: &UserId,
         completion: CompletedCodePractice,
-    ) -> Result<()> {
-        // Get or create user progress
-        let mut progress = match self
-            .user_progress_repository
-            .find_by_user_id(user_id)
-            .await?
-        {
-            Some(progress) => progress,
-            None => {
-                let new_progress = UserProgress::new(user_id.clone());
-                self.user_progress_repository.create(&new_progress).await?;
-                new_progress
-            }
-        };
+    ) -> Result<XpAward> {
+        let today = self.local_today(user_id).await?;
 
-        // Add code practice completion
-        progress.add_code_practice_completion(completion);
+        let (progress, (award, check)) = self
+            .change_progress(user_id, |progress| {
+                // Add code practice completion, capped by today's remaining XP
+                let award = progress.add_code_practice_completion(
+                    completion.clone(),
+                    self.default_daily_xp_cap,
+                    today,
+                );
+                let check = progress.check_streak(today, Utc::now());
+                progress.record_activity(today);
+                (award, check)
+            })
+            .await?;
+        self.notify_streak_check(&progress, check).await;
+        self.evaluate_achievements(user_id).await;
 
-        // Update in database
-        self.user_progress_repository.update(&progress).await?;
+        Ok(award)
+    }
 
-        Ok(())
+    async fn complete_lesson(
+        &self,
+        user_id: &UserId,
+        result: LessonResult,
+        stars: u32,
+    ) -> Result<(LessonResult, XpAward)> {
+        let today = self.local_today(user_id).await?;
+
+        let (progress, (result, award, check)) = self
+            .change_progress(user_id, |progress| {
+                let mut result = result.clone();
+                let award =
+                    progress.complete_lesson(&mut result, stars, self.default_daily_xp_cap, today);
+                let check = progress.check_streak(today, Utc::now());
+                progress.record_activity(today);
+                (result, award, check)
+            })
+            .await?;
+        self.notify_streak_check(&progress, check).await;
+        self.user_progress_repository
+            .record_lesson_result(user_id, &result)
+            .await?;
+        self.schedule_reviews(user_id, &result.question_results, today)
+            .await;
+        self.evaluate_achievements(user_id).await;
+
+        Ok((result, award))
     }
 
     async fn get_user_xp(&self, user_id: &UserId) -> Result<Points> {
@@ -108,27 +407,131 @@ impl ProgressService for ProgressServiceImpl {
     }
 
     async fn update_streak(&self, user_id: &UserId, days: u32) -> Result<()> {
-        // Get or create user progress
-        let mut progress = match self
+        self.change_progress(user_id, |progress| {
+            progress.update_streak(days);
+            progress.update_last_active();
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_daily_xp_status(&self, user_id: &UserId) -> Result<DailyXpStatus> {
+        let tz = self.user_time_zone(user_id).await?;
+        let now = Utc::now();
+        let today = now.with_timezone(&tz).date_naive();
+        let progress = self
             .user_progress_repository
             .find_by_user_id(user_id)
             .await?
-        {
-            Some(progress) => progress,
-            None => {
-                let new_progress = UserProgress::new(user_id.clone());
-                self.user_progress_repository.create(&new_progress).await?;
-                new_progress
-            }
-        };
+            .unwrap_or_else(|| UserProgress::new(user_id.clone()));
 
-        // Update streak
-        progress.update_streak(days);
-        progress.update_last_active();
+        let cap = progress.effective_daily_xp_cap(self.default_daily_xp_cap);
+        let remaining = progress.remaining_daily_xp(self.default_daily_xp_cap, today);
 
-        // Update in database
-        self.user_progress_repository.update(&progress).await?;
+        let earned_today = if today > progress.last_xp_reset_date {
+            0
+        } else {
+            progress.xp_earned_today
+        };
+
+        Ok(DailyXpStatus {
+            cap,
+            earned_today,
+            remaining,
+            resets_at: Self::next_local_midnight(tz, now),
+        })
+    }
 
+    async fn set_daily_xp_cap(&self, user_id: &UserId, cap: Option<u32>) -> Result<()> {
+        self.change_progress(user_id, |progress| progress.daily_xp_cap = cap)
+            .await?;
         Ok(())
     }
+
+    async fn expire_broken_streaks(&self) -> Result<u32> {
+        const PAGE_SIZE: u32 = 500;
+
+        // Paged by user id, which streak updates do not change
+        let mut broken = 0;
+        let mut after: Option<UserId> = None;
+        loop {
+            let page = self
+                .user_progress_repository
+                .list_running_streaks(after.as_ref(), PAGE_SIZE)
+                .await?;
+            let page_len = page.len() as u32;
+            after = page.last().map(|progress| progress.user_id.clone());
+
+            for progress in page {
+                let user_id = progress.user_id.clone();
+                match self.expire_streak(progress).await {
+                    Ok(StreakCheck::Broken { .. }) => broken += 1,
+                    Ok(_) => {}
+                    Err(e) => {
+                        tracing::error!(
+                            "Failed to expire streak of user {}: {e}",
+                            user_id.to_string()
+                        );
+                    }
+                }
+            }
+
+            if page_len < PAGE_SIZE {
+                break;
+            }
+        }
+
+        Ok(broken)
+    }
+
+    async fn get_streak_status(&self, user_id: &UserId) -> Result<StreakStatus> {
+        let progress = self
+            .user_progress_repository
+            .find_by_user_id(user_id)
+            .await?
+            .unwrap_or_else(|| UserProgress::new(user_id.clone()));
+        Ok(StreakStatus::from_progress(&progress, Utc::now()))
+    }
+
+    async fn buy_streak_freeze(&self, user_id: &UserId) -> Result<StreakStatus> {
+        let today = self.local_today(user_id).await?;
+
+        // Saved even when the purchase fails, to keep the settled streak
+        let (progress, (check, purchase)) = self
+            .change_progress(user_id, |progress| {
+                // Settle missed days first so a new freeze cannot cover them retroactively
+                let check = progress.check_streak(today, Utc::now());
+                (
+                    check,
+                    progress.buy_streak_freeze(Points::new(STREAK_FREEZE_PRICE)),
+                )
+            })
+            .await?;
+        self.notify_streak_check(&progress, check).await;
+        purchase.map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(StreakStatus::from_progress(&progress, Utc::now()))
+    }
+
+    async fn repair_streak(&self, user_id: &UserId) -> Result<StreakStatus> {
+        let today = self.local_today(user_id).await?;
+        let now = Utc::now();
+
+        let (progress, (check, repair)) = self
+            .change_progress(user_id, |progress| {
+                // The expiry job may not have run since the break yet
+                let check = progress.check_streak(today, now);
+                (
+                    check,
+                    progress.repair_streak(Points::new(STREAK_REPAIR_PRICE), today, now),
+                )
+            })
+            .await?;
+        self.notify_streak_check(&progress, check).await;
+        repair.map_err(|e| anyhow::anyhow!(e))?;
+        self.evaluate_achievements(user_id).await;
+
+        Ok(StreakStatus::from_progress(&progress, now))
+    }
 }
@@ -0,0 +1,127 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::entities::{GeneratedQuestion, Question, QuestionDraft};
+use crate::domain::repositories::{QuestionDraftRepository, QuestionRepository};
+use crate::domain::value_objects::{Difficulty, Points, QuestionDraftId, TopicId};
+
+#[async_trait]
+pub trait QuestionDraftService: Send + Sync + 'static {
+    /// Queues generated questions for review; fails without saving any if one is invalid.
+    async fn create_drafts(
+        &self,
+        generated: Vec<GeneratedQuestion>,
+        topic_id: &TopicId,
+        difficulty: Difficulty,
+        points: Points,
+        source: &str,
+    ) -> Result<Vec<QuestionDraft>>;
+    /// Drafts awaiting review, oldest first.
+    async fn list_pending(&self) -> Result<Vec<QuestionDraft>>;
+    async fn get_draft(&self, id: &QuestionDraftId) -> Result<QuestionDraft>;
+    /// Replaces a pending draft's content, keeping its question ID and topic.
+    async fn update_draft(
+        &self,
+        id: &QuestionDraftId,
+        edited: GeneratedQuestion,
+        difficulty: Difficulty,
+        points: Points,
+    ) -> Result<QuestionDraft>;
+    /// Saves the draft as a question.
+    async fn accept_draft(&self, id: &QuestionDraftId) -> Result<Question>;
+    async fn reject_draft(&self, id: &QuestionDraftId) -> Result<QuestionDraft>;
+}
+
+pub struct QuestionDraftServiceImpl {
+    question_draft_repository: Arc<dyn QuestionDraftRepository>,
+    question_repository: Arc<dyn QuestionRepository>,
+}
+
+impl QuestionDraftServiceImpl {
+    pub fn new(
+        question_draft_repository: Arc<dyn QuestionDraftRepository>,
+        question_repository: Arc<dyn QuestionRepository>,
+    ) -> Self {
+        Self {
+            question_draft_repository,
+            question_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl QuestionDraftService for QuestionDraftServiceImpl {
+    async fn create_drafts(
+        &self,
+        generated: Vec<GeneratedQuestion>,
+        topic_id: &TopicId,
+        difficulty: Difficulty,
+        points: Points,
+        source: &str,
+    ) -> Result<Vec<QuestionDraft>> {
+        let drafts = generated
+            .into_iter()
+            .map(|question| {
+                question
+                    .into_question(topic_id.clone(), difficulty, points)
+                    .map(|question| QuestionDraft::new(question, source.to_string()))
+            })
+            .collect::<Result<Vec<_>, String>>()
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        for draft in &drafts {
+            self.question_draft_repository.create(draft).await?;
+        }
+        Ok(drafts)
+    }
+
+    async fn list_pending(&self) -> Result<Vec<QuestionDraft>> {
+        self.question_draft_repository.list_pending().await
+    }
+
+    async fn get_draft(&self, id: &QuestionDraftId) -> Result<QuestionDraft> {
+        self.question_draft_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Question draft not found"))
+    }
+
+    async fn update_draft(
+        &self,
+        id: &QuestionDraftId,
+        edited: GeneratedQuestion,
+        difficulty: Difficulty,
+        points: Points,
+    ) -> Result<QuestionDraft> {
+        let mut draft = self.get_draft(id).await?;
+        let mut question = edited
+            .into_question(draft.question.topic_id.clone(), difficulty, points)
+            .map_err(|e| anyhow::anyhow!(e))?;
+        question.id = draft.question.id.clone();
+        question.created_at = draft.question.created_at;
+
+        draft
+            .update_question(question)
+            .map_err(|e| anyhow::anyhow!(e))?;
+        self.question_draft_repository.update(&draft).await?;
+        Ok(draft)
+    }
+
+    async fn accept_draft(&self, id: &QuestionDraftId) -> Result<Question> {
+        let mut draft = self.get_draft(id).await?;
+        draft.accept().map_err(|e| anyhow::anyhow!(e))?;
+
+        self.question_repository.create(&draft.question).await?;
+        self.question_draft_repository.update(&draft).await?;
+        Ok(draft.question)
+    }
+
+    async fn reject_draft(&self, id: &QuestionDraftId) -> Result<QuestionDraft> {
+        let mut draft = self.get_draft(id).await?;
+        draft.reject().map_err(|e| anyhow::anyhow!(e))?;
+
+        self.question_draft_repository.update(&draft).await?;
+        Ok(draft)
+    }
+}
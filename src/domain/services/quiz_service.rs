@@ -0,0 +1,289 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rand::seq::SliceRandom;
+use std::sync::Arc;
+
+use crate::domain::entities::{Question, Quiz, QuizAttempt, QuizAttemptItem};
+use crate::domain::repositories::{QuestionRepository, QuizAttemptRepository, QuizRepository};
+use crate::domain::value_objects::{QuestionId, QuizAttemptId, QuizId, UserId};
+use crate::shared::errors::DomainError;
+
+/// An attempt with its quiz and drawn questions, in attempt order. Choice
+/// questions have their options shuffled as the learner sees them.
+#[derive(Debug, Clone)]
+pub struct QuizAttemptDetails {
+    pub attempt: QuizAttempt,
+    pub quiz: Quiz,
+    pub questions: Vec<Question>,
+}
+
+#[async_trait]
+pub trait QuizService: Send + Sync + 'static {
+    async fn list_quizzes(&self) -> Result<Vec<Quiz>>;
+    async fn save_quiz(&self, quiz: &Quiz) -> Result<()>;
+    /// Starts a new attempt, or resumes the learner's attempt still in progress.
+    async fn start_attempt(&self, user_id: &UserId, quiz_id: &QuizId)
+        -> Result<QuizAttemptDetails>;
+    async fn submit_answer(
+        &self,
+        user_id: &UserId,
+        attempt_id: &QuizAttemptId,
+        question_id: &QuestionId,
+        answer: &str,
+    ) -> Result<QuizAttempt>;
+    async fn submit_attempt(
+        &self,
+        user_id: &UserId,
+        attempt_id: &QuizAttemptId,
+    ) -> Result<QuizAttemptDetails>;
+    /// Attempts past their deadline are submitted as they stand.
+    async fn get_attempt(
+        &self,
+        user_id: &UserId,
+        attempt_id: &QuizAttemptId,
+    ) -> Result<QuizAttemptDetails>;
+    async fn list_attempts(&self, user_id: &UserId, quiz_id: &QuizId) -> Result<Vec<QuizAttempt>>;
+}
+
+pub struct QuizServiceImpl {
+    quiz_repository: Arc<dyn QuizRepository>,
+    quiz_attempt_repository: Arc<dyn QuizAttemptRepository>,
+    question_repository: Arc<dyn QuestionRepository>,
+}
+
+impl QuizServiceImpl {
+    pub fn new(
+        quiz_repository: Arc<dyn QuizRepository>,
+        quiz_attempt_repository: Arc<dyn QuizAttemptRepository>,
+        question_repository: Arc<dyn QuestionRepository>,
+    ) -> Self {
+        Self {
+            quiz_repository,
+            quiz_attempt_repository,
+            question_repository,
+        }
+    }
+
+    async fn load_quiz(&self, quiz_id: &QuizId) -> Result<Quiz> {
+        self.quiz_repository
+            .find_by_id(quiz_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Quiz not found".to_string()).into())
+    }
+
+    /// Attempts of other users are reported as missing rather than forbidden.
+    async fn load_attempt(
+        &self,
+        user_id: &UserId,
+        attempt_id: &QuizAttemptId,
+    ) -> Result<QuizAttempt> {
+        self.quiz_attempt_repository
+            .find_by_id(attempt_id)
+            .await?
+            .filter(|attempt| attempt.user_id == *user_id)
+            .ok_or_else(|| DomainError::NotFound("Quiz attempt not found".to_string()).into())
+    }
+
+    /// Submits an attempt whose deadline has passed. Returns whether it was closed.
+    async fn close_if_expired(
+        &self,
+        attempt: &mut QuizAttempt,
+        now: DateTime<Utc>,
+    ) -> Result<bool> {
+        if attempt.is_submitted() || now < attempt.deadline {
+            return Ok(false);
+        }
+        attempt.submit(now).map_err(|e| anyhow::anyhow!(e))?;
+        self.quiz_attempt_repository.update(attempt).await?;
+        Ok(true)
+    }
+
+    async fn details(&self, attempt: QuizAttempt, quiz: Quiz) -> Result<QuizAttemptDetails> {
+        let mut questions = Vec::with_capacity(attempt.items.len());
+        for item in &attempt.items {
+            if let Some(question) = self
+                .question_repository
+                .find_by_id(&item.question_id)
+                .await?
+            {
+                questions.push(question.with_choice_order(&item.choice_order));
+            }
+        }
+        Ok(QuizAttemptDetails {
+            attempt,
+            quiz,
+            questions,
+        })
+    }
+
+    async fn question_pool(&self, quiz: &Quiz) -> Result<Vec<Question>> {
+        let mut pool = Vec::new();
+        for topic_id in &quiz.topic_ids {
+            let questions = self.question_repository.find_by_topic_id(topic_id).await?;
+            pool.extend(questions.into_iter().filter(|q| quiz.includes(q)));
+        }
+        Ok(pool)
+    }
+
+    /// Picks `count` questions in random order and shuffles each one's options.
+    fn draw_items(pool: &[Question], count: usize) -> Vec<QuizAttemptItem> {
+        let mut rng = rand::thread_rng();
+        pool.choose_multiple(&mut rng, count)
+            .map(|question| {
+                let mut choice_order: Vec<u32> =
+                    (0..question.choice_options().len() as u32).collect();
+                choice_order.shuffle(&mut rng);
+                QuizAttemptItem {
+                    question_id: question.id.clone(),
+                    choice_order,
+                    points: question.points,
+                }
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl QuizService for QuizServiceImpl {
+    async fn list_quizzes(&self) -> Result<Vec<Quiz>> {
+        self.quiz_repository.list().await
+    }
+
+    async fn save_quiz(&self, quiz: &Quiz) -> Result<()> {
+        quiz.validate().map_err(DomainError::Invalid)?;
+        self.quiz_repository.create(quiz).await
+    }
+
+    async fn start_attempt(
+        &self,
+        user_id: &UserId,
+        quiz_id: &QuizId,
+    ) -> Result<QuizAttemptDetails> {
+        let quiz = self.load_quiz(quiz_id).await?;
+        let now = Utc::now();
+
+        let mut attempts = self
+            .quiz_attempt_repository
+            .list_by_user_and_quiz(user_id, quiz_id)
+            .await?;
+        for attempt in &mut attempts {
+            self.close_if_expired(attempt, now).await?;
+        }
+        if let Some(open) = attempts.iter().find(|a| a.is_open(now)) {
+            return self.details(open.clone(), quiz).await;
+        }
+        let no_attempts_left =
+            || DomainError::Invalid("No attempts left for this quiz".to_string()).into();
+        if quiz
+            .max_attempts
+            .is_some_and(|max| attempts.len() as u32 >= max)
+        {
+            return Err(no_attempts_left());
+        }
+
+        let pool = self.question_pool(&quiz).await?;
+        let count = quiz.question_count as usize;
+        if pool.len() < count {
+            return Err(anyhow::anyhow!(
+                "Quiz needs {} questions but its pool only has {}",
+                count,
+                pool.len()
+            ));
+        }
+
+        let items = Self::draw_items(&pool, count);
+        let attempt = QuizAttempt::new(&quiz, user_id.clone(), items, now);
+        if !self
+            .quiz_attempt_repository
+            .create_if_allowed(&attempt, quiz.max_attempts)
+            .await?
+        {
+            // Another request started an attempt since the checks above
+            let open = self
+                .quiz_attempt_repository
+                .list_by_user_and_quiz(user_id, quiz_id)
+                .await?
+                .into_iter()
+                .find(|a| a.is_open(now));
+            return match open {
+                Some(open) => self.details(open, quiz).await,
+                None => Err(no_attempts_left()),
+            };
+        }
+
+        self.details(attempt, quiz).await
+    }
+
+    async fn submit_answer(
+        &self,
+        user_id: &UserId,
+        attempt_id: &QuizAttemptId,
+        question_id: &QuestionId,
+        answer: &str,
+    ) -> Result<QuizAttempt> {
+        let mut attempt = self.load_attempt(user_id, attempt_id).await?;
+        let now = Utc::now();
+        if self.close_if_expired(&mut attempt, now).await? {
+            return Err(
+                DomainError::Invalid("Time is up for this quiz attempt".to_string()).into(),
+            );
+        }
+        let item = attempt.item(question_id).cloned().ok_or_else(|| {
+            DomainError::Invalid("Question is not part of this quiz attempt".to_string())
+        })?;
+        let question = self
+            .question_repository
+            .find_by_id(question_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Question not found".to_string()))?;
+
+        // The answer refers to the options in the order this attempt showed them
+        let correct = question
+            .with_choice_order(&item.choice_order)
+            .check_answer(answer);
+        attempt
+            .record_answer(question_id.clone(), answer.to_string(), correct, now)
+            .map_err(DomainError::Invalid)?;
+        self.quiz_attempt_repository.update(&attempt).await?;
+
+        Ok(attempt)
+    }
+
+    async fn submit_attempt(
+        &self,
+        user_id: &UserId,
+        attempt_id: &QuizAttemptId,
+    ) -> Result<QuizAttemptDetails> {
+        let mut attempt = self.load_attempt(user_id, attempt_id).await?;
+        attempt.submit(Utc::now()).map_err(DomainError::Invalid)?;
+        self.quiz_attempt_repository.update(&attempt).await?;
+
+        let quiz = self.load_quiz(&attempt.quiz_id).await?;
+        self.details(attempt, quiz).await
+    }
+
+    async fn get_attempt(
+        &self,
+        user_id: &UserId,
+        attempt_id: &QuizAttemptId,
+    ) -> Result<QuizAttemptDetails> {
+        let mut attempt = self.load_attempt(user_id, attempt_id).await?;
+        self.close_if_expired(&mut attempt, Utc::now()).await?;
+
+        let quiz = self.load_quiz(&attempt.quiz_id).await?;
+        self.details(attempt, quiz).await
+    }
+
+    async fn list_attempts(&self, user_id: &UserId, quiz_id: &QuizId) -> Result<Vec<QuizAttempt>> {
+        let now = Utc::now();
+        let mut attempts = self
+            .quiz_attempt_repository
+            .list_by_user_and_quiz(user_id, quiz_id)
+            .await?;
+        for attempt in &mut attempts {
+            self.close_if_expired(attempt, now).await?;
+        }
+        Ok(attempts)
+    }
+}
@@ -0,0 +1,168 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use chrono_tz::Tz;
+use std::sync::Arc;
+
+use crate::domain::entities::review_card::{interleave_by_key, DAILY_REVIEW_LIMIT, REVIEW_XP};
+use crate::domain::entities::user_progress::{QuestionResult, XpAward};
+use crate::domain::entities::{Question, ReviewCard};
+use crate::domain::repositories::{QuestionRepository, ReviewCardRepository, UserRepository};
+use crate::domain::services::ProgressService;
+use crate::domain::value_objects::{LocalizedText, Points, QuestionId, UserId};
+
+/// How many of the most overdue cards are considered when mixing a review set.
+const REVIEW_CANDIDATE_LIMIT: u32 = (DAILY_REVIEW_LIMIT * 5) as u32;
+
+#[derive(Debug, Clone)]
+pub struct ReviewItem {
+    pub card: ReviewCard,
+    pub question: Question,
+}
+
+/// Today's due questions, interleaved across topics.
+#[derive(Debug, Clone)]
+pub struct ReviewSet {
+    pub date: NaiveDate,
+    /// All cards due, of which at most `DAILY_REVIEW_LIMIT` are served.
+    pub due_total: u64,
+    pub items: Vec<ReviewItem>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReviewOutcome {
+    pub correct: bool,
+    pub award: XpAward,
+    /// The card as rescheduled by this review.
+    pub card: ReviewCard,
+    pub explanation: Option<LocalizedText>,
+}
+
+#[async_trait]
+pub trait ReviewService: Send + Sync + 'static {
+    async fn get_review_set(&self, user_id: &UserId) -> Result<ReviewSet>;
+    /// Answers a due review question. Each review is a single attempt.
+    async fn submit_review(
+        &self,
+        user_id: &UserId,
+        question_id: &QuestionId,
+        answer: &str,
+        time_spent_ms: u64,
+    ) -> Result<ReviewOutcome>;
+}
+
+pub struct ReviewServiceImpl {
+    review_card_repository: Arc<dyn ReviewCardRepository>,
+    question_repository: Arc<dyn QuestionRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    progress_service: Arc<dyn ProgressService>,
+}
+
+impl ReviewServiceImpl {
+    pub fn new(
+        review_card_repository: Arc<dyn ReviewCardRepository>,
+        question_repository: Arc<dyn QuestionRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        progress_service: Arc<dyn ProgressService>,
+    ) -> Self {
+        Self {
+            review_card_repository,
+            question_repository,
+            user_repository,
+            progress_service,
+        }
+    }
+
+    /// Due dates are kept in the learner's local calendar.
+    async fn local_today(&self, user_id: &UserId) -> Result<NaiveDate> {
+        let tz = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .map(|user| user.tz())
+            .unwrap_or(Tz::UTC);
+        Ok(Utc::now().with_timezone(&tz).date_naive())
+    }
+}
+
+#[async_trait]
+impl ReviewService for ReviewServiceImpl {
+    async fn get_review_set(&self, user_id: &UserId) -> Result<ReviewSet> {
+        let today = self.local_today(user_id).await?;
+        let due_total = self
+            .review_card_repository
+            .count_due(user_id, today)
+            .await?;
+        let cards = self
+            .review_card_repository
+            .find_due(user_id, today, REVIEW_CANDIDATE_LIMIT)
+            .await?;
+
+        let mut items = Vec::with_capacity(cards.len());
+        for card in cards {
+            // Questions deleted since they were answered simply drop out
+            if let Some(question) = self
+                .question_repository
+                .find_by_id(&card.question_id)
+                .await?
+            {
+                items.push(ReviewItem { card, question });
+            }
+        }
+        let mut items = interleave_by_key(items, |item| item.question.topic_id.clone());
+        items.truncate(DAILY_REVIEW_LIMIT);
+
+        Ok(ReviewSet {
+            date: today,
+            due_total,
+            items,
+        })
+    }
+
+    async fn submit_review(
+        &self,
+        user_id: &UserId,
+        question_id: &QuestionId,
+        answer: &str,
+        time_spent_ms: u64,
+    ) -> Result<ReviewOutcome> {
+        let today = self.local_today(user_id).await?;
+        self.review_card_repository
+            .find(user_id, question_id)
+            .await?
+            .filter(|card| card.is_due(today))
+            .ok_or_else(|| anyhow::anyhow!("Question is not due for review"))?;
+        let question = self
+            .question_repository
+            .find_by_id(question_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Question not found"))?;
+
+        let correct = question.check_answer(answer);
+        let result = QuestionResult {
+            question_id: question_id.clone(),
+            correct,
+            user_answer: answer.to_string(),
+            time_spent_ms,
+            points: Points::new(if correct { REVIEW_XP } else { 0 }),
+            completed_at: Utc::now(),
+        };
+        // Recording the result also reschedules the card
+        let award = self
+            .progress_service
+            .add_question_result(user_id, result)
+            .await?;
+        let card = self
+            .review_card_repository
+            .find(user_id, question_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Review card not found"))?;
+
+        Ok(ReviewOutcome {
+            correct,
+            award,
+            card,
+            explanation: question.explanation,
+        })
+    }
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AiCallId(pub Uuid);
+
+impl AiCallId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    pub fn from_string(s: String) -> Result<Self, String> {
+        s.parse()
+    }
+}
+
+impl FromStr for AiCallId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::parse_str(s)
+            .map(Self)
+            .map_err(|_| "Invalid UUID format".to_string())
+    }
+}
+
+impl fmt::Display for AiCallId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Default for AiCallId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Uuid> for AiCallId {
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+impl From<AiCallId> for Uuid {
+    fn from(ai_call_id: AiCallId) -> Self {
+        ai_call_id.0
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// Permission granted to an API key. Bearer JWT sessions implicitly hold every scope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ApiScope {
+    #[serde(rename = "content:read")]
+    ContentRead,
+    #[serde(rename = "progress:read")]
+    ProgressRead,
+}
+
+impl ApiScope {
+    pub fn all() -> Vec<ApiScope> {
+        vec![ApiScope::ContentRead, ApiScope::ProgressRead]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiScope::ContentRead => "content:read",
+            ApiScope::ProgressRead => "progress:read",
+        }
+    }
+}
+
+impl std::str::FromStr for ApiScope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "content:read" => Ok(ApiScope::ContentRead),
+            "progress:read" => Ok(ApiScope::ProgressRead),
+            _ => Err(format!("Invalid API scope: {s}")),
+        }
+    }
+}
+
+impl std::fmt::Display for ApiScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
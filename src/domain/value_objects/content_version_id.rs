@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ContentVersionId(pub Uuid);
+
+impl ContentVersionId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    pub fn from_string(s: String) -> Result<Self, String> {
+        s.parse()
+    }
+}
+
+impl FromStr for ContentVersionId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::parse_str(s)
+            .map(Self)
+            .map_err(|_| "Invalid UUID format".to_string())
+    }
+}
+
+impl fmt::Display for ContentVersionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Default for ContentVersionId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Uuid> for ContentVersionId {
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+impl From<ContentVersionId> for Uuid {
+    fn from(content_version_id: ContentVersionId) -> Self {
+        content_version_id.0
+    }
+}
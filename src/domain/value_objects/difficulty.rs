@@ -36,6 +36,23 @@ impl Difficulty {
             Difficulty::Advanced => 2.0,
         }
     }
+
+    /// Elo-style rating a learner is measured against when answering at this level.
+    pub fn rating(&self) -> f64 {
+        match self {
+            Difficulty::Beginner => 800.0,
+            Difficulty::Intermediate => 1000.0,
+            Difficulty::Advanced => 1200.0,
+        }
+    }
+
+    pub fn all() -> [Difficulty; 3] {
+        [
+            Difficulty::Beginner,
+            Difficulty::Intermediate,
+            Difficulty::Advanced,
+        ]
+    }
 }
 
 impl std::fmt::Display for Difficulty {
@@ -1,32 +1,125 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+use crate::shared::utils::{accepted_locale_fallbacks, DEFAULT_LOCALE};
+
+/// Locales content is written in by editors; other locales come from
+/// translators and the editor API.
+pub const AUTHORED_LOCALES: &[&str] = &["en", "id"];
+
+/// Locales to read text in: one tag, or the ordered list a request accepts.
+pub trait LocalePreference {
+    /// Every locale to try, most preferred first, ending with the default.
+    fn lookup_order(&self) -> Vec<String>;
+}
+
+impl LocalePreference for str {
+    fn lookup_order(&self) -> Vec<String> {
+        accepted_locale_fallbacks(&[self])
+    }
+}
+
+impl LocalePreference for String {
+    fn lookup_order(&self) -> Vec<String> {
+        self.as_str().lookup_order()
+    }
+}
+
+impl LocalePreference for [&str] {
+    fn lookup_order(&self) -> Vec<String> {
+        accepted_locale_fallbacks(self)
+    }
+}
+
+/// Text in any number of locales, keyed by BCP 47 tag such as `en`, `id` or
+/// `pt-BR`. Serialized as a plain object of those tags; empty translations are
+/// not kept.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "BTreeMap<String, String>", into = "BTreeMap<String, String>")]
 pub struct LocalizedText {
-    pub en: String,
-    pub id: String,
+    translations: BTreeMap<String, String>,
 }
 
 impl LocalizedText {
+    /// English and Indonesian text, the two locales content is authored in.
     pub fn new(en: String, id: String) -> Self {
-        Self { en, id }
+        let mut text = Self::from_english(en);
+        text.set("id", id);
+        text
     }
 
     pub fn from_english(text: String) -> Self {
-        Self {
-            en: text.clone(),
-            id: text,
+        let mut localized = Self::default();
+        localized.set(DEFAULT_LOCALE, text);
+        localized
+    }
+
+    /// The text for `locale`, falling back to its parent locales and then to
+    /// English: `pt-BR` is served `pt` text when there is no `pt-BR` one. Given
+    /// several accepted locales, each is tried with its parents, in order, before
+    /// English. Text with no English at all falls back to whichever locale comes
+    /// first.
+    pub fn get<L: LocalePreference + ?Sized>(&self, locale: &L) -> &str {
+        self.resolve(locale).map_or("", |(_, text)| text)
+    }
+
+    /// Like `get`, together with the locale the text was found in.
+    pub fn resolve<L: LocalePreference + ?Sized>(&self, locale: &L) -> Option<(&str, &str)> {
+        locale
+            .lookup_order()
+            .iter()
+            .find_map(|candidate| self.translations.get_key_value(candidate.as_str()))
+            .or_else(|| self.translations.iter().next())
+            .map(|(locale, text)| (locale.as_str(), text.as_str()))
+    }
+
+    /// The text written for exactly `locale`, without falling back.
+    pub fn text(&self, locale: &str) -> Option<&str> {
+        self.translations.get(locale).map(String::as_str)
+    }
+
+    pub fn english(&self) -> &str {
+        self.text(DEFAULT_LOCALE).unwrap_or_default()
+    }
+
+    /// Sets the text for `locale`; empty text removes the translation.
+    pub fn set(&mut self, locale: &str, text: String) {
+        if text.is_empty() {
+            self.translations.remove(locale);
+        } else {
+            self.translations.insert(locale.to_string(), text);
         }
     }
 
-    pub fn get(&self, language: &str) -> &str {
-        match language {
-            "id" => &self.id,
-            _ => &self.en,
+    /// Takes `edited`'s text for `locales`, keeping every other locale, for forms
+    /// that only edit some of them. Locales left empty in `edited` are removed.
+    pub fn apply_edit(&mut self, edited: LocalizedText, locales: &[&str]) {
+        for locale in locales {
+            let text = edited.text(locale).unwrap_or_default().to_string();
+            self.set(locale, text);
         }
     }
 
+    /// Locales with text, in tag order.
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        self.translations.keys().map(String::as_str)
+    }
+
+    pub fn translations(&self) -> &BTreeMap<String, String> {
+        &self.translations
+    }
+
     pub fn is_empty(&self) -> bool {
-        self.en.is_empty() && self.id.is_empty()
+        self.translations.is_empty()
+    }
+
+    /// Whether the text for `locale` is missing or still a copy of the English.
+    pub fn needs_translation(&self, locale: &str) -> bool {
+        let english = self.english();
+        !english.trim().is_empty()
+            && self
+                .text(locale)
+                .is_none_or(|text| text.trim().is_empty() || text == english)
     }
 }
 
@@ -35,3 +128,20 @@ impl From<String> for LocalizedText {
         Self::from_english(text)
     }
 }
+
+impl From<BTreeMap<String, String>> for LocalizedText {
+    fn from(translations: BTreeMap<String, String>) -> Self {
+        Self {
+            translations: translations
+                .into_iter()
+                .filter(|(_, text)| !text.is_empty())
+                .collect(),
+        }
+    }
+}
+
+impl From<LocalizedText> for BTreeMap<String, String> {
+    fn from(text: LocalizedText) -> Self {
+        text.translations
+    }
+}
@@ -1,23 +1,45 @@
+pub mod ai_call_id;
+pub mod api_key_id;
+pub mod api_scope;
+pub mod asset_id;
 pub mod code_practice_id;
+pub mod code_submission_id;
+pub mod content_version_id;
 pub mod difficulty;
 pub mod email;
 pub mod lesson_id;
+pub mod lesson_session_id;
 pub mod localized_text;
 pub mod notification_id;
 pub mod password;
 pub mod points;
+pub mod question_draft_id;
 pub mod question_id;
+pub mod quiz_attempt_id;
+pub mod quiz_id;
 pub mod topic_id;
+pub mod translation_id;
 pub mod user_id;
 
+pub use ai_call_id::AiCallId;
+pub use api_key_id::ApiKeyId;
+pub use api_scope::ApiScope;
+pub use asset_id::AssetId;
 pub use code_practice_id::CodePracticeId;
+pub use code_submission_id::CodeSubmissionId;
+pub use content_version_id::ContentVersionId;
 pub use difficulty::Difficulty;
 pub use email::Email;
 pub use lesson_id::LessonId;
-pub use localized_text::LocalizedText;
+pub use lesson_session_id::LessonSessionId;
+pub use localized_text::{LocalePreference, LocalizedText, AUTHORED_LOCALES};
 pub use notification_id::NotificationId;
 pub use password::Password;
 pub use points::Points;
+pub use question_draft_id::QuestionDraftId;
 pub use question_id::QuestionId;
+pub use quiz_attempt_id::QuizAttemptId;
+pub use quiz_id::QuizId;
 pub use topic_id::TopicId;
+pub use translation_id::TranslationId;
 pub use user_id::UserId;
@@ -1,7 +1,9 @@
+use crate::domain::entities::user_progress::LessonResult;
 use crate::domain::entities::*;
 use crate::domain::value_objects::*;
 use crate::infrastructure::database::models::*;
 use anyhow::Result;
+use chrono::NaiveDate;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -11,9 +13,30 @@ pub struct MockDatabaseConnection {
     pub users: Arc<Mutex<HashMap<UserId, UserModel>>>,
     pub topics: Arc<Mutex<HashMap<TopicId, TopicModel>>>,
     pub lessons: Arc<Mutex<HashMap<LessonId, LessonModel>>>,
+    pub lesson_questions: Arc<Mutex<HashMap<LessonId, Vec<QuestionId>>>>,
     pub questions: Arc<Mutex<HashMap<QuestionId, QuestionModel>>>,
     pub code_practices: Arc<Mutex<HashMap<CodePracticeId, CodePracticeModel>>>,
     pub user_progress: Arc<Mutex<HashMap<UserId, UserProgressModel>>>,
+    pub lesson_results: Arc<Mutex<Vec<LessonResultModel>>>,
+    pub lesson_sessions: Arc<Mutex<HashMap<LessonSessionId, LessonSessionModel>>>,
+    pub topic_prerequisites: Arc<Mutex<Vec<TopicPrerequisiteModel>>>,
+    pub lesson_prerequisites: Arc<Mutex<Vec<LessonPrerequisiteModel>>>,
+    pub review_cards: Arc<Mutex<HashMap<(UserId, QuestionId), ReviewCardModel>>>,
+    pub quizzes: Arc<Mutex<HashMap<QuizId, QuizModel>>>,
+    pub quiz_attempts: Arc<Mutex<HashMap<QuizAttemptId, QuizAttemptModel>>>,
+    pub hint_usage: Arc<Mutex<HashMap<(UserId, CodePracticeId), HintUsageModel>>>,
+    pub code_submissions: Arc<Mutex<HashMap<CodeSubmissionId, CodeSubmissionModel>>>,
+    pub question_drafts: Arc<Mutex<HashMap<QuestionDraftId, QuestionDraftModel>>>,
+    pub tutor_feedback_cache: Arc<Mutex<HashMap<String, TutorFeedbackModel>>>,
+    pub tutor_usage: Arc<Mutex<HashMap<(UserId, NaiveDate), TutorUsageModel>>>,
+    pub ai_calls: Arc<Mutex<Vec<AiCallModel>>>,
+    pub machine_translations: Arc<Mutex<HashMap<TranslationId, MachineTranslationModel>>>,
+    pub api_keys: Arc<Mutex<HashMap<ApiKeyId, ApiKeyModel>>>,
+    pub account_audit_events: Arc<Mutex<Vec<AccountAuditEventModel>>>,
+    pub achievements: Arc<Mutex<HashMap<String, AchievementModel>>>,
+    pub user_achievements: Arc<Mutex<Vec<UserAchievementModel>>>,
+    pub assets: Arc<Mutex<HashMap<AssetId, AssetModel>>>,
+    pub content_versions: Arc<Mutex<Vec<ContentVersionModel>>>,
 }
 
 impl MockDatabaseConnection {
@@ -22,9 +45,30 @@ impl MockDatabaseConnection {
             users: Arc::new(Mutex::new(HashMap::new())),
             topics: Arc::new(Mutex::new(HashMap::new())),
             lessons: Arc::new(Mutex::new(HashMap::new())),
+            lesson_questions: Arc::new(Mutex::new(HashMap::new())),
             questions: Arc::new(Mutex::new(HashMap::new())),
             code_practices: Arc::new(Mutex::new(HashMap::new())),
             user_progress: Arc::new(Mutex::new(HashMap::new())),
+            lesson_results: Arc::new(Mutex::new(Vec::new())),
+            lesson_sessions: Arc::new(Mutex::new(HashMap::new())),
+            topic_prerequisites: Arc::new(Mutex::new(Vec::new())),
+            lesson_prerequisites: Arc::new(Mutex::new(Vec::new())),
+            review_cards: Arc::new(Mutex::new(HashMap::new())),
+            quizzes: Arc::new(Mutex::new(HashMap::new())),
+            quiz_attempts: Arc::new(Mutex::new(HashMap::new())),
+            hint_usage: Arc::new(Mutex::new(HashMap::new())),
+            code_submissions: Arc::new(Mutex::new(HashMap::new())),
+            question_drafts: Arc::new(Mutex::new(HashMap::new())),
+            tutor_feedback_cache: Arc::new(Mutex::new(HashMap::new())),
+            tutor_usage: Arc::new(Mutex::new(HashMap::new())),
+            ai_calls: Arc::new(Mutex::new(Vec::new())),
+            machine_translations: Arc::new(Mutex::new(HashMap::new())),
+            api_keys: Arc::new(Mutex::new(HashMap::new())),
+            account_audit_events: Arc::new(Mutex::new(Vec::new())),
+            achievements: Arc::new(Mutex::new(HashMap::new())),
+            user_achievements: Arc::new(Mutex::new(Vec::new())),
+            assets: Arc::new(Mutex::new(HashMap::new())),
+            content_versions: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
@@ -32,9 +76,30 @@ impl MockDatabaseConnection {
         self.users.lock().await.clear();
         self.topics.lock().await.clear();
         self.lessons.lock().await.clear();
+        self.lesson_questions.lock().await.clear();
         self.questions.lock().await.clear();
         self.code_practices.lock().await.clear();
         self.user_progress.lock().await.clear();
+        self.lesson_results.lock().await.clear();
+        self.lesson_sessions.lock().await.clear();
+        self.topic_prerequisites.lock().await.clear();
+        self.lesson_prerequisites.lock().await.clear();
+        self.review_cards.lock().await.clear();
+        self.quizzes.lock().await.clear();
+        self.quiz_attempts.lock().await.clear();
+        self.hint_usage.lock().await.clear();
+        self.code_submissions.lock().await.clear();
+        self.question_drafts.lock().await.clear();
+        self.tutor_feedback_cache.lock().await.clear();
+        self.tutor_usage.lock().await.clear();
+        self.ai_calls.lock().await.clear();
+        self.machine_translations.lock().await.clear();
+        self.api_keys.lock().await.clear();
+        self.account_audit_events.lock().await.clear();
+        self.achievements.lock().await.clear();
+        self.user_achievements.lock().await.clear();
+        self.assets.lock().await.clear();
+        self.content_versions.lock().await.clear();
         Ok(())
     }
 }
@@ -62,8 +127,16 @@ impl MockDatabaseConnection {
     }
 
     pub async fn update_user(&self, user: &User) -> Result<()> {
-        let user_model = UserModel::from(user.clone());
-        self.users.lock().await.insert(user.id.clone(), user_model);
+        let mut user_model = UserModel::from(user.clone());
+        let mut users = self.users.lock().await;
+        // Like the database, keep the XP and streak mirrored from progress
+        if let Some(stored) = users.get(&user.id) {
+            user_model.total_xp = stored.total_xp;
+            user_model.current_streak_days = stored.current_streak_days;
+            user_model.highest_streak_days = stored.highest_streak_days;
+            user_model.last_active_date = stored.last_active_date;
+        }
+        users.insert(user.id.clone(), user_model);
         Ok(())
     }
 
@@ -72,6 +145,18 @@ impl MockDatabaseConnection {
         Ok(())
     }
 
+    pub async fn find_users_due_for_deletion(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<User>> {
+        let users = self.users.lock().await;
+        Ok(users
+            .values()
+            .filter(|u| u.deletion_scheduled_for.is_some_and(|at| at <= now))
+            .map(|model| User::from(model.clone()))
+            .collect())
+    }
+
     pub async fn list_users(&self, limit: u32, offset: u32) -> Result<Vec<User>> {
         let users = self.users.lock().await;
         let users_vec: Vec<User> = users
@@ -134,52 +219,79 @@ impl MockDatabaseConnection {
     }
 
     // Lesson operations
+    async fn lesson_with_questions(&self, model: &LessonModel) -> Lesson {
+        let mut lesson = Lesson::from(model.clone());
+        lesson.questions = self
+            .lesson_questions
+            .lock()
+            .await
+            .get(&lesson.id)
+            .cloned()
+            .unwrap_or_default();
+        lesson
+    }
+
     pub async fn create_lesson(&self, lesson: &Lesson) -> Result<()> {
         let lesson_model = LessonModel::from(lesson.clone());
         self.lessons
             .lock()
             .await
             .insert(lesson.id.clone(), lesson_model);
+        self.lesson_questions
+            .lock()
+            .await
+            .insert(lesson.id.clone(), lesson.questions.clone());
         Ok(())
     }
 
     pub async fn find_lesson_by_id(&self, id: &LessonId) -> Result<Option<Lesson>> {
-        let lessons = self.lessons.lock().await;
-        Ok(lessons.get(id).map(|model| Lesson::from(model.clone())))
+        let model = self.lessons.lock().await.get(id).cloned();
+        match model {
+            Some(model) => Ok(Some(self.lesson_with_questions(&model).await)),
+            None => Ok(None),
+        }
     }
 
     pub async fn find_lessons_by_topic_id(&self, topic_id: &TopicId) -> Result<Vec<Lesson>> {
-        let lessons = self.lessons.lock().await;
-        let lessons_vec: Vec<Lesson> = lessons
+        let models: Vec<LessonModel> = self
+            .lessons
+            .lock()
+            .await
             .values()
             .filter(|l| l.topic_id == topic_id.0)
-            .map(|model| Lesson::from(model.clone()))
+            .cloned()
             .collect();
+        let mut lessons_vec = Vec::new();
+        for model in &models {
+            lessons_vec.push(self.lesson_with_questions(model).await);
+        }
         Ok(lessons_vec)
     }
 
     pub async fn update_lesson(&self, lesson: &Lesson) -> Result<()> {
-        let lesson_model = LessonModel::from(lesson.clone());
-        self.lessons
-            .lock()
-            .await
-            .insert(lesson.id.clone(), lesson_model);
-        Ok(())
+        self.create_lesson(lesson).await
     }
 
     pub async fn delete_lesson(&self, id: &LessonId) -> Result<()> {
         self.lessons.lock().await.remove(id);
+        self.lesson_questions.lock().await.remove(id);
         Ok(())
     }
 
     pub async fn list_lessons(&self, limit: u32, offset: u32) -> Result<Vec<Lesson>> {
-        let lessons = self.lessons.lock().await;
-        let lessons_vec: Vec<Lesson> = lessons
+        let models: Vec<LessonModel> = self
+            .lessons
+            .lock()
+            .await
             .values()
             .skip(offset as usize)
             .take(limit as usize)
-            .map(|model| Lesson::from(model.clone()))
+            .cloned()
             .collect();
+        let mut lessons_vec = Vec::new();
+        for model in &models {
+            lessons_vec.push(self.lesson_with_questions(model).await);
+        }
         Ok(lessons_vec)
     }
 
@@ -198,6 +310,17 @@ impl MockDatabaseConnection {
         Ok(questions.get(id).map(|model| Question::from(model.clone())))
     }
 
+    pub async fn find_questions_by_ids(&self, ids: &[QuestionId]) -> Result<Vec<Question>> {
+        let questions = self.questions.lock().await;
+        Ok(ids
+            .iter()
+            .collect::<std::collections::HashSet<_>>()
+            .into_iter()
+            .filter_map(|id| questions.get(id))
+            .map(|model| Question::from(model.clone()))
+            .collect())
+    }
+
     pub async fn find_questions_by_topic_id(&self, topic_id: &TopicId) -> Result<Vec<Question>> {
         let questions = self.questions.lock().await;
         let questions_vec: Vec<Question> = questions
@@ -337,9 +460,21 @@ impl MockDatabaseConnection {
             .lock()
             .await
             .insert(progress.user_id.clone(), progress_model);
+        self.mirror_progress_to_user(progress).await;
         Ok(())
     }
 
+    /// Copies the XP and streak onto the user, as the database does on every
+    /// progress write.
+    async fn mirror_progress_to_user(&self, progress: &UserProgress) {
+        if let Some(user) = self.users.lock().await.get_mut(&progress.user_id) {
+            user.total_xp = progress.total_xp.value() as i32;
+            user.current_streak_days = progress.current_streak_days as i32;
+            user.highest_streak_days = progress.highest_streak_days as i32;
+            user.last_active_date = progress.last_active_date;
+        }
+    }
+
     pub async fn find_user_progress_by_user_id(
         &self,
         user_id: &UserId,
@@ -356,9 +491,69 @@ impl MockDatabaseConnection {
             .lock()
             .await
             .insert(progress.user_id.clone(), progress_model);
+        self.mirror_progress_to_user(progress).await;
         Ok(())
     }
 
+    pub async fn update_user_progress_if_unchanged(
+        &self,
+        progress: &UserProgress,
+        loaded_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<bool> {
+        {
+            let mut user_progress = self.user_progress.lock().await;
+            match user_progress.get_mut(&progress.user_id) {
+                Some(stored) if stored.updated_at == loaded_at => {
+                    *stored = UserProgressModel::from(progress.clone());
+                }
+                _ => return Ok(false),
+            }
+        }
+        self.mirror_progress_to_user(progress).await;
+        Ok(true)
+    }
+
+    pub async fn update_user_streak_if_unchanged(
+        &self,
+        progress: &UserProgress,
+        loaded_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<bool> {
+        let saved = {
+            let mut user_progress = self.user_progress.lock().await;
+            match user_progress.get_mut(&progress.user_id) {
+                Some(stored) if stored.updated_at == loaded_at => {
+                    stored.current_streak_days = progress.current_streak_days as i32;
+                    stored.last_active_date = progress.last_active_date;
+                    stored.streak_freezes = progress.streak_freezes as i32;
+                    stored.repairable_streak_days = progress.repairable_streak_days as i32;
+                    stored.streak_repair_expires_at = progress.streak_repair_expires_at;
+                    stored.updated_at = progress.updated_at;
+                    UserProgress::from(stored.clone())
+                }
+                _ => return Ok(false),
+            }
+        };
+        self.mirror_progress_to_user(&saved).await;
+        Ok(true)
+    }
+
+    pub async fn list_running_streaks(
+        &self,
+        after: Option<&UserId>,
+        limit: u32,
+    ) -> Result<Vec<UserProgress>> {
+        let user_progress = self.user_progress.lock().await;
+        let mut running: Vec<UserProgress> = user_progress
+            .values()
+            .filter(|model| model.current_streak_days > 0)
+            .filter(|model| after.is_none_or(|after| model.user_id > after.0))
+            .map(|model| UserProgress::from(model.clone()))
+            .collect();
+        running.sort_by_key(|progress| progress.user_id.0);
+        running.truncate(limit as usize);
+        Ok(running)
+    }
+
     pub async fn delete_user_progress(&self, user_id: &UserId) -> Result<()> {
         self.user_progress.lock().await.remove(user_id);
         Ok(())
@@ -377,7 +572,9 @@ impl MockDatabaseConnection {
                     .map(|user_model| {
                         let leaderboard_model = LeaderboardEntryModel {
                             user_id: progress_model.user_id,
-                            display_name: user_model.display_name.clone(),
+                            display_name: User::from(user_model.clone())
+                                .public_display_name()
+                                .to_string(),
                             total_xp: progress_model.total_xp,
                             xp_this_week: progress_model.total_xp, // Simplified - in real implementation this would be calculated
                             rank: 0,                               // Will be set after sorting
@@ -406,7 +603,9 @@ impl MockDatabaseConnection {
                     .map(|user_model| {
                         let leaderboard_model = LeaderboardEntryModel {
                             user_id: progress_model.user_id,
-                            display_name: user_model.display_name.clone(),
+                            display_name: User::from(user_model.clone())
+                                .public_display_name()
+                                .to_string(),
                             total_xp: progress_model.total_xp,
                             xp_this_week: progress_model.total_xp, // Simplified - in real implementation this would be calculated
                             rank: 0,                               // Will be set after sorting
@@ -439,4 +638,760 @@ impl MockDatabaseConnection {
 
         Ok(Some(rank))
     }
+
+    // API key operations
+    pub async fn create_api_key(&self, api_key: &ApiKey) -> Result<()> {
+        let api_key_model = ApiKeyModel::from(api_key.clone());
+        self.api_keys
+            .lock()
+            .await
+            .insert(api_key.id.clone(), api_key_model);
+        Ok(())
+    }
+
+    pub async fn find_api_key_by_id(&self, id: &ApiKeyId) -> Result<Option<ApiKey>> {
+        let api_keys = self.api_keys.lock().await;
+        Ok(api_keys.get(id).map(|model| ApiKey::from(model.clone())))
+    }
+
+    pub async fn find_api_key_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>> {
+        let api_keys = self.api_keys.lock().await;
+        Ok(api_keys
+            .values()
+            .find(|k| k.prefix == prefix)
+            .map(|model| ApiKey::from(model.clone())))
+    }
+
+    pub async fn find_api_keys_by_user_id(&self, user_id: &UserId) -> Result<Vec<ApiKey>> {
+        let api_keys = self.api_keys.lock().await;
+        let mut api_keys_vec: Vec<ApiKey> = api_keys
+            .values()
+            .filter(|k| k.user_id == user_id.0)
+            .map(|model| ApiKey::from(model.clone()))
+            .collect();
+        api_keys_vec.sort_by_key(|b| std::cmp::Reverse(b.created_at));
+        Ok(api_keys_vec)
+    }
+
+    pub async fn update_api_key(&self, api_key: &ApiKey) -> Result<()> {
+        let api_key_model = ApiKeyModel::from(api_key.clone());
+        self.api_keys
+            .lock()
+            .await
+            .insert(api_key.id.clone(), api_key_model);
+        Ok(())
+    }
+
+    pub async fn delete_api_key(&self, id: &ApiKeyId) -> Result<()> {
+        self.api_keys.lock().await.remove(id);
+        Ok(())
+    }
+
+    // Account audit operations
+    pub async fn create_account_audit_event(&self, event: &AccountAuditEvent) -> Result<()> {
+        let event_model = AccountAuditEventModel::from(event.clone());
+        self.account_audit_events.lock().await.push(event_model);
+        Ok(())
+    }
+
+    pub async fn find_account_audit_events_by_user_id(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<AccountAuditEvent>> {
+        let events = self.account_audit_events.lock().await;
+        Ok(events
+            .iter()
+            .filter(|e| e.user_id == user_id.0)
+            .map(|model| AccountAuditEvent::from(model.clone()))
+            .collect())
+    }
+
+    // Achievement operations
+    pub async fn list_achievements(&self) -> Result<Vec<Achievement>> {
+        let achievements = self.achievements.lock().await;
+        let mut models: Vec<_> = achievements.values().cloned().collect();
+        models.sort_by(|a, b| a.sort_order.cmp(&b.sort_order).then(a.id.cmp(&b.id)));
+        Ok(models.into_iter().map(Achievement::from).collect())
+    }
+
+    pub async fn upsert_achievement(&self, achievement: &Achievement) -> Result<()> {
+        let achievement_model = AchievementModel::from(achievement.clone());
+        self.achievements
+            .lock()
+            .await
+            .insert(achievement.id.clone(), achievement_model);
+        Ok(())
+    }
+
+    pub async fn find_user_achievements(&self, user_id: &UserId) -> Result<Vec<UserAchievement>> {
+        let user_achievements = self.user_achievements.lock().await;
+        Ok(user_achievements
+            .iter()
+            .filter(|a| a.user_id == user_id.0)
+            .map(|model| UserAchievement::from(model.clone()))
+            .collect())
+    }
+
+    pub async fn award_achievement(&self, user_achievement: &UserAchievement) -> Result<bool> {
+        let mut user_achievements = self.user_achievements.lock().await;
+        if user_achievements.iter().any(|a| {
+            a.user_id == user_achievement.user_id.0
+                && a.achievement_id == user_achievement.achievement_id
+        }) {
+            return Ok(false);
+        }
+        user_achievements.push(UserAchievementModel::from(user_achievement.clone()));
+        Ok(true)
+    }
+    // Lesson result operations
+    pub async fn record_lesson_result(
+        &self,
+        user_id: &UserId,
+        result: &LessonResult,
+    ) -> Result<()> {
+        let mut lesson_results = self.lesson_results.lock().await;
+        match lesson_results
+            .iter_mut()
+            .find(|r| r.user_id == user_id.0 && r.lesson_id == result.lesson_id.0)
+        {
+            Some(existing) => {
+                existing.xp_earned += result.xp_earned.value() as i32;
+                existing.perfect_score |= result.perfect_score;
+                existing.completed_at = result.completed_at;
+            }
+            None => lesson_results.push(LessonResultModel {
+                id: uuid::Uuid::new_v4(),
+                user_id: user_id.0,
+                lesson_id: result.lesson_id.0,
+                xp_earned: result.xp_earned.value() as i32,
+                perfect_score: result.perfect_score,
+                completed_at: result.completed_at,
+            }),
+        }
+        Ok(())
+    }
+
+    pub async fn find_lesson_results(&self, user_id: &UserId) -> Result<Vec<LessonResult>> {
+        let lesson_results = self.lesson_results.lock().await;
+        Ok(lesson_results
+            .iter()
+            .filter(|r| r.user_id == user_id.0)
+            .map(|model| LessonResult::from(model.clone()))
+            .collect())
+    }
+
+    // Lesson session operations
+    pub async fn create_lesson_session(&self, session: &LessonSession) -> Result<()> {
+        let session_model = LessonSessionModel::from(session.clone());
+        self.lesson_sessions
+            .lock()
+            .await
+            .insert(session.id.clone(), session_model);
+        Ok(())
+    }
+
+    pub async fn find_lesson_session_by_id(
+        &self,
+        id: &LessonSessionId,
+    ) -> Result<Option<LessonSession>> {
+        let lesson_sessions = self.lesson_sessions.lock().await;
+        Ok(lesson_sessions
+            .get(id)
+            .map(|model| LessonSession::from(model.clone())))
+    }
+
+    pub async fn find_lesson_sessions_by_user(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<LessonSession>> {
+        let lesson_sessions = self.lesson_sessions.lock().await;
+        let mut found: Vec<LessonSession> = lesson_sessions
+            .values()
+            .filter(|model| model.user_id == user_id.0)
+            .map(|model| LessonSession::from(model.clone()))
+            .collect();
+        found.sort_by_key(|session| std::cmp::Reverse(session.started_at));
+        Ok(found)
+    }
+
+    pub async fn update_lesson_session(&self, session: &LessonSession) -> Result<bool> {
+        let mut lesson_sessions = self.lesson_sessions.lock().await;
+        match lesson_sessions.get_mut(&session.id) {
+            Some(stored) if stored.revision == session.revision as i32 => {
+                *stored = LessonSessionModel::from(session.clone());
+                stored.revision += 1;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+    // Prerequisite operations
+    pub async fn list_topic_prerequisites(&self) -> Result<Vec<TopicPrerequisite>> {
+        let prerequisites = self.topic_prerequisites.lock().await;
+        Ok(prerequisites
+            .iter()
+            .map(|model| TopicPrerequisite::from(model.clone()))
+            .collect())
+    }
+
+    pub async fn save_topic_prerequisite(&self, prerequisite: &TopicPrerequisite) -> Result<()> {
+        let mut prerequisites = self.topic_prerequisites.lock().await;
+        prerequisites.retain(|p| {
+            !(p.topic_id == prerequisite.topic_id.0
+                && p.prerequisite_topic_id == prerequisite.prerequisite_topic_id.0)
+        });
+        prerequisites.push(TopicPrerequisiteModel::from(prerequisite.clone()));
+        Ok(())
+    }
+
+    pub async fn delete_topic_prerequisite(
+        &self,
+        topic_id: &TopicId,
+        prerequisite_topic_id: &TopicId,
+    ) -> Result<()> {
+        self.topic_prerequisites.lock().await.retain(|p| {
+            !(p.topic_id == topic_id.0 && p.prerequisite_topic_id == prerequisite_topic_id.0)
+        });
+        Ok(())
+    }
+
+    pub async fn list_lesson_prerequisites(&self) -> Result<Vec<LessonPrerequisite>> {
+        let prerequisites = self.lesson_prerequisites.lock().await;
+        Ok(prerequisites
+            .iter()
+            .map(|model| LessonPrerequisite::from(model.clone()))
+            .collect())
+    }
+
+    pub async fn save_lesson_prerequisite(&self, prerequisite: &LessonPrerequisite) -> Result<()> {
+        let mut prerequisites = self.lesson_prerequisites.lock().await;
+        prerequisites.retain(|p| {
+            !(p.lesson_id == prerequisite.lesson_id.0
+                && p.prerequisite_lesson_id == prerequisite.prerequisite_lesson_id.0)
+        });
+        prerequisites.push(LessonPrerequisiteModel::from(prerequisite.clone()));
+        Ok(())
+    }
+
+    pub async fn delete_lesson_prerequisite(
+        &self,
+        lesson_id: &LessonId,
+        prerequisite_lesson_id: &LessonId,
+    ) -> Result<()> {
+        self.lesson_prerequisites.lock().await.retain(|p| {
+            !(p.lesson_id == lesson_id.0 && p.prerequisite_lesson_id == prerequisite_lesson_id.0)
+        });
+        Ok(())
+    }
+
+    // Review card operations
+    pub async fn find_review_card(
+        &self,
+        user_id: &UserId,
+        question_id: &QuestionId,
+    ) -> Result<Option<ReviewCard>> {
+        let cards = self.review_cards.lock().await;
+        Ok(cards
+            .get(&(user_id.clone(), question_id.clone()))
+            .map(|model| ReviewCard::from(model.clone())))
+    }
+
+    pub async fn find_review_cards_by_user(&self, user_id: &UserId) -> Result<Vec<ReviewCard>> {
+        let review_cards = self.review_cards.lock().await;
+        let mut found: Vec<ReviewCard> = review_cards
+            .values()
+            .filter(|model| model.user_id == user_id.0)
+            .map(|model| ReviewCard::from(model.clone()))
+            .collect();
+        found.sort_by_key(|card| card.due_date);
+        Ok(found)
+    }
+
+    pub async fn save_review_card(&self, card: &ReviewCard) -> Result<()> {
+        let mut cards = self.review_cards.lock().await;
+        cards.insert(
+            (card.user_id.clone(), card.question_id.clone()),
+            ReviewCardModel::from(card.clone()),
+        );
+        Ok(())
+    }
+
+    pub async fn find_due_review_cards(
+        &self,
+        user_id: &UserId,
+        today: NaiveDate,
+    ) -> Result<Vec<ReviewCard>> {
+        let cards = self.review_cards.lock().await;
+        let mut due: Vec<ReviewCard> = cards
+            .values()
+            .filter(|model| model.user_id == user_id.0 && model.due_date <= today)
+            .map(|model| ReviewCard::from(model.clone()))
+            .collect();
+        due.sort_by(|a, b| {
+            a.due_date
+                .cmp(&b.due_date)
+                .then(a.ease_factor.total_cmp(&b.ease_factor))
+        });
+        Ok(due)
+    }
+
+    // Quiz operations
+    pub async fn create_quiz(&self, quiz: &Quiz) -> Result<()> {
+        let quiz_model = QuizModel::from(quiz.clone());
+        self.quizzes
+            .lock()
+            .await
+            .insert(quiz.id.clone(), quiz_model);
+        Ok(())
+    }
+
+    pub async fn find_quiz_by_id(&self, id: &QuizId) -> Result<Option<Quiz>> {
+        let quizzes = self.quizzes.lock().await;
+        Ok(quizzes.get(id).map(|model| Quiz::from(model.clone())))
+    }
+
+    pub async fn list_quizzes(&self) -> Result<Vec<Quiz>> {
+        let quizzes = self.quizzes.lock().await;
+        let mut list: Vec<Quiz> = quizzes
+            .values()
+            .map(|model| Quiz::from(model.clone()))
+            .collect();
+        list.sort_by_key(|quiz| std::cmp::Reverse(quiz.created_at));
+        Ok(list)
+    }
+
+    pub async fn delete_quiz(&self, id: &QuizId) -> Result<()> {
+        self.quizzes.lock().await.remove(id);
+        self.quiz_attempts
+            .lock()
+            .await
+            .retain(|_, attempt| attempt.quiz_id != id.0);
+        Ok(())
+    }
+
+    // Quiz attempt operations
+    pub async fn save_quiz_attempt(&self, attempt: &QuizAttempt) -> Result<()> {
+        let attempt_model = QuizAttemptModel::from(attempt.clone());
+        self.quiz_attempts
+            .lock()
+            .await
+            .insert(attempt.id.clone(), attempt_model);
+        Ok(())
+    }
+
+    pub async fn create_quiz_attempt_if_allowed(
+        &self,
+        attempt: &QuizAttempt,
+        max_attempts: Option<u32>,
+    ) -> Result<bool> {
+        let mut quiz_attempts = self.quiz_attempts.lock().await;
+        let existing: Vec<&QuizAttemptModel> = quiz_attempts
+            .values()
+            .filter(|model| {
+                model.user_id == attempt.user_id.0 && model.quiz_id == attempt.quiz_id.0
+            })
+            .collect();
+        let open = existing
+            .iter()
+            .any(|model| model.submitted_at.is_none() && model.deadline > attempt.started_at);
+        if open || max_attempts.is_some_and(|max| existing.len() as u32 >= max) {
+            return Ok(false);
+        }
+        quiz_attempts.insert(attempt.id.clone(), QuizAttemptModel::from(attempt.clone()));
+        Ok(true)
+    }
+
+    pub async fn find_quiz_attempt_by_id(&self, id: &QuizAttemptId) -> Result<Option<QuizAttempt>> {
+        let quiz_attempts = self.quiz_attempts.lock().await;
+        Ok(quiz_attempts
+            .get(id)
+            .map(|model| QuizAttempt::from(model.clone())))
+    }
+
+    pub async fn find_quiz_attempts(
+        &self,
+        user_id: &UserId,
+        quiz_id: &QuizId,
+    ) -> Result<Vec<QuizAttempt>> {
+        let quiz_attempts = self.quiz_attempts.lock().await;
+        let mut attempts: Vec<QuizAttempt> = quiz_attempts
+            .values()
+            .filter(|model| model.user_id == user_id.0 && model.quiz_id == quiz_id.0)
+            .map(|model| QuizAttempt::from(model.clone()))
+            .collect();
+        attempts.sort_by_key(|attempt| std::cmp::Reverse(attempt.started_at));
+        Ok(attempts)
+    }
+
+    pub async fn find_quiz_attempts_by_user(&self, user_id: &UserId) -> Result<Vec<QuizAttempt>> {
+        let quiz_attempts = self.quiz_attempts.lock().await;
+        let mut found: Vec<QuizAttempt> = quiz_attempts
+            .values()
+            .filter(|model| model.user_id == user_id.0)
+            .map(|model| QuizAttempt::from(model.clone()))
+            .collect();
+        found.sort_by_key(|attempt| std::cmp::Reverse(attempt.started_at));
+        Ok(found)
+    }
+
+    // Hint usage operations
+    pub async fn find_hint_usage(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<Option<HintUsage>> {
+        let hint_usage = self.hint_usage.lock().await;
+        Ok(hint_usage
+            .get(&(user_id.clone(), code_practice_id.clone()))
+            .map(|model| HintUsage::from(model.clone())))
+    }
+
+    pub async fn find_hint_usage_by_user(&self, user_id: &UserId) -> Result<Vec<HintUsage>> {
+        let hint_usage = self.hint_usage.lock().await;
+        let mut found: Vec<HintUsage> = hint_usage
+            .values()
+            .filter(|model| model.user_id == user_id.0)
+            .map(|model| HintUsage::from(model.clone()))
+            .collect();
+        found.sort_by_key(|usage| std::cmp::Reverse(usage.updated_at));
+        Ok(found)
+    }
+
+    pub async fn save_hint_usage(&self, usage: &HintUsage) -> Result<()> {
+        self.hint_usage.lock().await.insert(
+            (usage.user_id.clone(), usage.code_practice_id.clone()),
+            HintUsageModel::from(usage.clone()),
+        );
+        Ok(())
+    }
+
+    // Code submission operations
+    pub async fn save_code_submission(&self, submission: &CodeSubmission) -> Result<()> {
+        self.code_submissions.lock().await.insert(
+            submission.id.clone(),
+            CodeSubmissionModel::from(submission.clone()),
+        );
+        Ok(())
+    }
+
+    pub async fn find_code_submission_by_id(
+        &self,
+        id: &CodeSubmissionId,
+    ) -> Result<Option<CodeSubmission>> {
+        let code_submissions = self.code_submissions.lock().await;
+        Ok(code_submissions
+            .get(id)
+            .map(|model| CodeSubmission::from(model.clone())))
+    }
+
+    pub async fn find_code_submissions(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<Vec<CodeSubmission>> {
+        let code_submissions = self.code_submissions.lock().await;
+        let mut submissions: Vec<CodeSubmission> = code_submissions
+            .values()
+            .filter(|model| {
+                model.user_id == user_id.0 && model.code_practice_id == code_practice_id.0
+            })
+            .map(|model| CodeSubmission::from(model.clone()))
+            .collect();
+        submissions.sort_by_key(|submission| std::cmp::Reverse(submission.submitted_at));
+        Ok(submissions)
+    }
+
+    pub async fn find_code_submissions_by_user(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<CodeSubmission>> {
+        let code_submissions = self.code_submissions.lock().await;
+        let mut found: Vec<CodeSubmission> = code_submissions
+            .values()
+            .filter(|model| model.user_id == user_id.0)
+            .map(|model| CodeSubmission::from(model.clone()))
+            .collect();
+        found.sort_by_key(|submission| std::cmp::Reverse(submission.submitted_at));
+        Ok(found)
+    }
+
+    pub async fn find_code_submissions_by_practice(
+        &self,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<Vec<CodeSubmission>> {
+        let code_submissions = self.code_submissions.lock().await;
+        let mut submissions: Vec<CodeSubmission> = code_submissions
+            .values()
+            .filter(|model| model.code_practice_id == code_practice_id.0)
+            .map(|model| CodeSubmission::from(model.clone()))
+            .collect();
+        submissions.sort_by_key(|submission| std::cmp::Reverse(submission.submitted_at));
+        Ok(submissions)
+    }
+
+    // Question draft operations
+    pub async fn save_question_draft(&self, draft: &QuestionDraft) -> Result<()> {
+        self.question_drafts
+            .lock()
+            .await
+            .insert(draft.id.clone(), QuestionDraftModel::from(draft.clone()));
+        Ok(())
+    }
+
+    pub async fn find_question_draft_by_id(
+        &self,
+        id: &QuestionDraftId,
+    ) -> Result<Option<QuestionDraft>> {
+        let question_drafts = self.question_drafts.lock().await;
+        question_drafts
+            .get(id)
+            .map(|model| QuestionDraft::try_from(model.clone()))
+            .transpose()
+    }
+
+    pub async fn find_pending_question_drafts(&self) -> Result<Vec<QuestionDraft>> {
+        let question_drafts = self.question_drafts.lock().await;
+        let mut drafts = question_drafts
+            .values()
+            .filter(|model| model.status == "pending")
+            .map(|model| QuestionDraft::try_from(model.clone()))
+            .collect::<Result<Vec<_>>>()?;
+        drafts.sort_by_key(|draft| draft.created_at);
+        Ok(drafts)
+    }
+
+    // Tutor feedback operations
+    pub async fn find_cached_tutor_feedback(
+        &self,
+        cache_key: &str,
+    ) -> Result<Option<TutorFeedback>> {
+        let tutor_feedback_cache = self.tutor_feedback_cache.lock().await;
+        Ok(tutor_feedback_cache
+            .get(cache_key)
+            .map(|model| TutorFeedback::from(model.clone())))
+    }
+
+    pub async fn save_cached_tutor_feedback(&self, feedback: &TutorFeedback) -> Result<()> {
+        self.tutor_feedback_cache.lock().await.insert(
+            feedback.cache_key.clone(),
+            TutorFeedbackModel::from(feedback.clone()),
+        );
+        Ok(())
+    }
+
+    pub async fn find_tutor_usage(
+        &self,
+        user_id: &UserId,
+        usage_date: NaiveDate,
+    ) -> Result<Option<TutorUsage>> {
+        let tutor_usage = self.tutor_usage.lock().await;
+        Ok(tutor_usage
+            .get(&(user_id.clone(), usage_date))
+            .map(|model| TutorUsage::from(model.clone())))
+    }
+
+    pub async fn find_tutor_usage_by_user(&self, user_id: &UserId) -> Result<Vec<TutorUsage>> {
+        let tutor_usage = self.tutor_usage.lock().await;
+        let mut found: Vec<TutorUsage> = tutor_usage
+            .values()
+            .filter(|model| model.user_id == user_id.0)
+            .map(|model| TutorUsage::from(model.clone()))
+            .collect();
+        found.sort_by_key(|usage| std::cmp::Reverse(usage.usage_date));
+        Ok(found)
+    }
+
+    pub async fn reserve_tutor_request(
+        &self,
+        user_id: &UserId,
+        usage_date: NaiveDate,
+        quota: u32,
+    ) -> Result<Option<TutorUsage>> {
+        let mut tutor_usage = self.tutor_usage.lock().await;
+        let key = (user_id.clone(), usage_date);
+        let mut usage = tutor_usage
+            .get(&key)
+            .map(|model| TutorUsage::from(model.clone()))
+            .unwrap_or_else(|| TutorUsage::new(user_id.clone(), usage_date));
+        if usage.record_request(quota).is_err() {
+            return Ok(None);
+        }
+        tutor_usage.insert(key, TutorUsageModel::from(usage.clone()));
+        Ok(Some(usage))
+    }
+
+    pub async fn release_tutor_request(
+        &self,
+        user_id: &UserId,
+        usage_date: NaiveDate,
+    ) -> Result<()> {
+        if let Some(model) = self
+            .tutor_usage
+            .lock()
+            .await
+            .get_mut(&(user_id.clone(), usage_date))
+        {
+            model.requests = (model.requests - 1).max(0);
+            model.updated_at = chrono::Utc::now();
+        }
+        Ok(())
+    }
+
+    // AI call operations
+    pub async fn record_ai_call(&self, call: &AiCallRecord) -> Result<()> {
+        self.ai_calls
+            .lock()
+            .await
+            .push(AiCallModel::from(call.clone()));
+        Ok(())
+    }
+
+    pub async fn find_ai_calls_by_user(&self, user_id: &UserId) -> Result<Vec<AiCallRecord>> {
+        let ai_calls = self.ai_calls.lock().await;
+        let mut found = ai_calls
+            .iter()
+            .filter(|model| model.user_id == Some(user_id.0))
+            .map(|model| AiCallRecord::try_from(model.clone()))
+            .collect::<Result<Vec<_>>>()?;
+        found.sort_by_key(|call| std::cmp::Reverse(call.created_at));
+        Ok(found)
+    }
+
+    pub async fn summarize_ai_calls(
+        &self,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<AiUsageSummary>> {
+        let ai_calls = self.ai_calls.lock().await;
+        let mut summaries: HashMap<(NaiveDate, AiFeature), AiUsageSummary> = HashMap::new();
+        for model in ai_calls.iter().filter(|model| model.created_at >= since) {
+            let call = AiCallRecord::try_from(model.clone())?;
+            let day = call.created_at.date_naive();
+            summaries
+                .entry((day, call.feature))
+                .or_insert_with(|| AiUsageSummary::empty(day, call.feature))
+                .add(&call);
+        }
+        let mut summaries: Vec<AiUsageSummary> = summaries.into_values().collect();
+        summaries.sort_by(|a, b| {
+            b.day
+                .cmp(&a.day)
+                .then_with(|| a.feature.as_str().cmp(b.feature.as_str()))
+        });
+        Ok(summaries)
+    }
+
+    // Machine translation operations
+    pub async fn save_machine_translation(&self, translation: &MachineTranslation) -> Result<()> {
+        self.machine_translations.lock().await.insert(
+            translation.id.clone(),
+            MachineTranslationModel::from(translation.clone()),
+        );
+        Ok(())
+    }
+
+    pub async fn find_machine_translation_by_id(
+        &self,
+        id: &TranslationId,
+    ) -> Result<Option<MachineTranslation>> {
+        let machine_translations = self.machine_translations.lock().await;
+        machine_translations
+            .get(id)
+            .map(|model| MachineTranslation::try_from(model.clone()))
+            .transpose()
+    }
+
+    pub async fn find_pending_machine_translations(&self) -> Result<Vec<MachineTranslation>> {
+        let machine_translations = self.machine_translations.lock().await;
+        let mut translations = machine_translations
+            .values()
+            .filter(|model| model.status == "needs_review")
+            .map(|model| MachineTranslation::try_from(model.clone()))
+            .collect::<Result<Vec<_>>>()?;
+        translations.sort_by_key(|translation| translation.created_at);
+        Ok(translations)
+    }
+
+    // Asset operations
+    pub async fn create_asset(&self, asset: &Asset) -> Result<()> {
+        let mut assets = self.assets.lock().await;
+        if assets.values().any(|model| model.sha256 == asset.sha256) {
+            return Err(anyhow::anyhow!("Asset with this content already exists"));
+        }
+        assets.insert(asset.id.clone(), AssetModel::from(asset.clone()));
+        Ok(())
+    }
+
+    pub async fn find_asset_by_id(&self, id: &AssetId) -> Result<Option<Asset>> {
+        let assets = self.assets.lock().await;
+        assets
+            .get(id)
+            .map(|model| Asset::try_from(model.clone()))
+            .transpose()
+    }
+
+    pub async fn find_asset_by_sha256(&self, sha256: &str) -> Result<Option<Asset>> {
+        let assets = self.assets.lock().await;
+        assets
+            .values()
+            .find(|model| model.sha256 == sha256)
+            .map(|model| Asset::try_from(model.clone()))
+            .transpose()
+    }
+
+    pub async fn list_assets(&self, limit: u32, offset: u32) -> Result<Vec<Asset>> {
+        let assets = self.assets.lock().await;
+        let mut assets = assets
+            .values()
+            .map(|model| Asset::try_from(model.clone()))
+            .collect::<Result<Vec<_>>>()?;
+        assets.sort_by_key(|b| std::cmp::Reverse(b.created_at));
+        Ok(assets
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect())
+    }
+
+    // Content version operations
+    pub async fn create_content_version(&self, version: &ContentVersion) -> Result<u32> {
+        let mut versions = self.content_versions.lock().await;
+        let mut model = ContentVersionModel::from(version.clone());
+        model.version = 1 + versions
+            .iter()
+            .filter(|existing| {
+                existing.content_kind == model.content_kind
+                    && existing.content_id == model.content_id
+            })
+            .map(|existing| existing.version)
+            .max()
+            .unwrap_or(0);
+        let number = model.version as u32;
+        versions.push(model);
+        Ok(number)
+    }
+
+    pub async fn delete_content_version(&self, id: &ContentVersionId) -> Result<()> {
+        self.content_versions
+            .lock()
+            .await
+            .retain(|model| model.id != id.0);
+        Ok(())
+    }
+
+    pub async fn find_content_versions(
+        &self,
+        kind: ContentKind,
+        content_id: &str,
+    ) -> Result<Vec<ContentVersion>> {
+        let versions = self.content_versions.lock().await;
+        let mut versions = versions
+            .iter()
+            .filter(|model| {
+                model.content_kind == kind.as_str() && model.content_id.to_string() == content_id
+            })
+            .map(|model| ContentVersion::try_from(model.clone()))
+            .collect::<Result<Vec<_>>>()?;
+        versions.sort_by_key(|b| std::cmp::Reverse(b.version));
+        Ok(versions)
+    }
 }
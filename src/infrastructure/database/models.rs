@@ -1,9 +1,12 @@
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 use uuid::Uuid;
 
 use crate::domain::value_objects::{
-    CodePracticeId, LessonId, LocalizedText, Points, QuestionId, TopicId, UserId,
+    AiCallId, ApiKeyId, AssetId, CodePracticeId, CodeSubmissionId, ContentVersionId, LessonId,
+    LessonSessionId, LocalizedText, Points, QuestionDraftId, QuestionId, QuizAttemptId, QuizId,
+    TopicId, TranslationId, UserId,
 };
 
 // Database models that map to PostgreSQL tables
@@ -20,18 +23,24 @@ pub struct UserModel {
     pub last_active_date: NaiveDate,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub preferred_language: String,
+    pub time_zone: String,
+    pub avatar_url: Option<String>,
+    pub pending_email: Option<String>,
+    pub email_verification_token_hash: Option<String>,
+    pub email_verification_expires_at: Option<DateTime<Utc>>,
+    pub deletion_requested_at: Option<DateTime<Utc>>,
+    pub deletion_scheduled_for: Option<DateTime<Utc>>,
+    pub is_content_editor: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct TopicModel {
     pub id: Uuid,
-    pub title_en: String,
-    pub title_id: String,
-    pub description_en: String,
-    pub description_id: String,
+    pub title: serde_json::Value,
+    pub description: serde_json::Value,
     pub order: i32,
-    pub required_skills_en: String,
-    pub required_skills_id: String,
+    pub required_skills: serde_json::Value,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -39,11 +48,10 @@ pub struct TopicModel {
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct LessonModel {
     pub id: Uuid,
-    pub title_en: String,
-    pub title_id: String,
+    pub title: serde_json::Value,
     pub topic_id: Uuid,
-    pub summary_en: String,
-    pub summary_id: String,
+    pub summary: serde_json::Value,
+    pub blocks: serde_json::Value,
     pub attribution_url: String,
     pub order: i32,
     pub created_at: DateTime<Utc>,
@@ -53,10 +61,8 @@ pub struct LessonModel {
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct QuestionModel {
     pub id: Uuid,
-    pub prompt_en: String,
-    pub prompt_id: String,
-    pub explanation_en: Option<String>,
-    pub explanation_id: Option<String>,
+    pub prompt: serde_json::Value,
+    pub explanation: Option<serde_json::Value>,
     pub topic_id: Uuid,
     pub difficulty: String,
     pub points: i32,
@@ -64,15 +70,14 @@ pub struct QuestionModel {
     pub question_data: serde_json::Value,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub image_asset_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct CodePracticeModel {
     pub id: Uuid,
-    pub title_en: String,
-    pub title_id: String,
-    pub description_en: String,
-    pub description_id: String,
+    pub title: serde_json::Value,
+    pub description: serde_json::Value,
     pub initial_code: String,
     pub expected_output: Option<String>,
     pub solution: String,
@@ -93,13 +98,17 @@ pub struct UserProgressModel {
     pub current_streak_days: i32,
     pub highest_streak_days: i32,
     pub last_active_date: NaiveDate,
-    pub daily_xp_cap: i32,
+    pub daily_xp_cap: Option<i32>,
     pub last_xp_reset_date: NaiveDate,
     pub completed_questions: serde_json::Value,
     pub completed_code_practices: serde_json::Value,
     pub lesson_stars: serde_json::Value,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub xp_earned_today: i32,
+    pub streak_freezes: i32,
+    pub repairable_streak_days: i32,
+    pub streak_repair_expires_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
@@ -151,6 +160,235 @@ pub struct LeaderboardEntryModel {
     pub rank: i64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ApiKeyModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub prefix: String,
+    pub secret_hash: String,
+    pub scopes: serde_json::Value,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AccountAuditEventModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub action: String,
+    pub details: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AchievementModel {
+    pub id: String,
+    pub title: serde_json::Value,
+    pub description: serde_json::Value,
+    pub icon: String,
+    pub rule: serde_json::Value,
+    pub xp_bonus: i32,
+    pub sort_order: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct UserAchievementModel {
+    pub user_id: Uuid,
+    pub achievement_id: String,
+    pub unlocked_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct LessonSessionModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub lesson_id: Uuid,
+    pub question_ids: serde_json::Value,
+    pub answers: serde_json::Value,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub revision: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TopicPrerequisiteModel {
+    pub topic_id: Uuid,
+    pub prerequisite_topic_id: Uuid,
+    pub min_stars: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct LessonPrerequisiteModel {
+    pub lesson_id: Uuid,
+    pub prerequisite_lesson_id: Uuid,
+    pub min_stars: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ReviewCardModel {
+    pub user_id: Uuid,
+    pub question_id: Uuid,
+    pub ease_factor: f64,
+    pub interval_days: i32,
+    pub repetitions: i32,
+    pub lapses: i32,
+    pub due_date: NaiveDate,
+    pub last_reviewed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct QuizModel {
+    pub id: Uuid,
+    pub title: serde_json::Value,
+    pub description: serde_json::Value,
+    pub topic_ids: serde_json::Value,
+    pub difficulties: serde_json::Value,
+    pub question_count: i32,
+    pub time_limit_secs: i32,
+    pub passing_score: i32,
+    pub max_attempts: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct QuizAttemptModel {
+    pub id: Uuid,
+    pub quiz_id: Uuid,
+    pub user_id: Uuid,
+    pub items: serde_json::Value,
+    pub answers: serde_json::Value,
+    pub started_at: DateTime<Utc>,
+    pub deadline: DateTime<Utc>,
+    pub submitted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct HintUsageModel {
+    pub user_id: Uuid,
+    pub code_practice_id: Uuid,
+    pub hints_revealed: i32,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CodeSubmissionModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub code_practice_id: Uuid,
+    pub code: String,
+    pub verdict: String,
+    pub runner_output: Option<String>,
+    pub submitted_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct QuestionDraftModel {
+    pub id: Uuid,
+    /// The draft question in the same layout as a `questions` row.
+    pub question: serde_json::Value,
+    pub status: String,
+    pub source: String,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TutorFeedbackModel {
+    pub cache_key: String,
+    pub language: String,
+    pub hint: String,
+    pub provider: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TutorUsageModel {
+    pub user_id: Uuid,
+    pub usage_date: NaiveDate,
+    pub requests: i32,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AiCallModel {
+    pub id: Uuid,
+    pub feature: String,
+    pub user_id: Option<Uuid>,
+    pub provider: String,
+    pub cached: bool,
+    pub succeeded: bool,
+    pub input_tokens: i32,
+    pub output_tokens: i32,
+    pub cost_micros: i64,
+    pub latency_ms: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AiUsageSummaryModel {
+    pub day: NaiveDate,
+    pub feature: String,
+    pub calls: i64,
+    pub cached_calls: i64,
+    pub failed_calls: i64,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub cost_micros: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct MachineTranslationModel {
+    pub id: Uuid,
+    pub content_kind: String,
+    pub content_id: Uuid,
+    pub field: String,
+    pub language: String,
+    pub source_text: String,
+    pub translated_text: String,
+    pub status: String,
+    pub provider: String,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AssetModel {
+    pub id: Uuid,
+    pub sha256: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub original_filename: String,
+    pub storage_key: String,
+    pub thumbnail_key: Option<String>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub uploaded_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ContentVersionModel {
+    pub id: Uuid,
+    pub content_kind: String,
+    pub content_id: Uuid,
+    pub version: i32,
+    pub snapshot: serde_json::Value,
+    pub author_id: Option<Uuid>,
+    pub author_name: String,
+    pub change_note: String,
+    pub created_at: DateTime<Utc>,
+}
+
 // Conversion traits from database models to domain entities
 
 impl From<UserModel> for crate::domain::entities::User {
@@ -167,6 +405,15 @@ impl From<UserModel> for crate::domain::entities::User {
             current_streak_days: model.current_streak_days as u32,
             highest_streak_days: model.highest_streak_days as u32,
             last_active_date: model.last_active_date,
+            preferred_language: model.preferred_language,
+            time_zone: model.time_zone,
+            avatar_url: model.avatar_url,
+            pending_email: model.pending_email.and_then(|email| Email::new(email).ok()),
+            email_verification_token_hash: model.email_verification_token_hash,
+            email_verification_expires_at: model.email_verification_expires_at,
+            deletion_requested_at: model.deletion_requested_at,
+            deletion_scheduled_for: model.deletion_scheduled_for,
+            is_content_editor: model.is_content_editor,
             created_at: model.created_at,
             updated_at: model.updated_at,
         }
@@ -177,11 +424,11 @@ impl From<TopicModel> for crate::domain::entities::Topic {
     fn from(model: TopicModel) -> Self {
         Self {
             id: TopicId::from(model.id),
-            title: LocalizedText::new(model.title_en, model.title_id),
-            description: LocalizedText::new(model.description_en, model.description_id),
+            title: serde_json::from_value(model.title).unwrap_or_default(),
+            description: serde_json::from_value(model.description).unwrap_or_default(),
             order: model.order as u32,
             lessons: Vec::new(), // Will be populated separately
-            required_skills: LocalizedText::new(model.required_skills_en, model.required_skills_id),
+            required_skills: serde_json::from_value(model.required_skills).unwrap_or_default(),
             created_at: model.created_at,
             updated_at: model.updated_at,
         }
@@ -192,10 +439,11 @@ impl From<LessonModel> for crate::domain::entities::Lesson {
     fn from(model: LessonModel) -> Self {
         Self {
             id: LessonId::from(model.id),
-            title: LocalizedText::new(model.title_en, model.title_id),
+            title: serde_json::from_value(model.title).unwrap_or_default(),
             topic_id: TopicId::from(model.topic_id),
-            summary: LocalizedText::new(model.summary_en, model.summary_id),
+            summary: serde_json::from_value(model.summary).unwrap_or_default(),
             questions: Vec::new(), // Will be populated separately
+            blocks: serde_json::from_value(model.blocks).unwrap_or_default(),
             attribution_url: model.attribution_url,
             order: model.order as u32,
             created_at: model.created_at,
@@ -204,46 +452,76 @@ impl From<LessonModel> for crate::domain::entities::Lesson {
     }
 }
 
+/// `question_type` codes allowed by the `questions` table, paired with the
+/// `QuestionType` variant whose fields are stored in `question_data`.
+static QUESTION_TYPE_CODES: [(&str, &str); 6] = [
+    ("mcq", "MultipleChoice"),
+    ("tf", "TrueFalse"),
+    ("fib", "FillInBlank"),
+    ("predict_output", "CodeOutputPrediction"),
+    ("code_fix", "CodeFix"),
+    ("code_write", "CodeWriting"),
+];
+
+fn question_type_data(question_type: &crate::domain::entities::QuestionType) -> serde_json::Value {
+    // Externally tagged: {"Variant": {fields}}; only the fields are stored
+    match serde_json::to_value(question_type) {
+        Ok(serde_json::Value::Object(tagged)) => tagged
+            .into_iter()
+            .next()
+            .map(|(_, fields)| fields)
+            .unwrap_or_default(),
+        _ => serde_json::Value::Object(serde_json::Map::new()),
+    }
+}
+
+/// Rows written before answer data was stored hold a JSON-encoded string such as
+/// `{"type": "multiple_choice", ...}`; those are read with the old layout.
+fn legacy_question_type(data: &serde_json::Value) -> crate::domain::entities::QuestionType {
+    use crate::domain::entities::QuestionType;
+
+    let data: serde_json::Value = data
+        .as_str()
+        .and_then(|encoded| serde_json::from_str(encoded).ok())
+        .unwrap_or_default();
+    match data.get("type").and_then(|v| v.as_str()) {
+        Some("multiple_choice") => QuestionType::MultipleChoice {
+            choices: data
+                .get("choices")
+                .and_then(|v| serde_json::from_value(v.clone()).ok())
+                .unwrap_or_else(|| LocalizedText::new("".to_string(), "".to_string())),
+            correct_index: data
+                .get("correct_index")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0) as u32,
+        },
+        _ => QuestionType::TrueFalse {
+            answer: data
+                .get("answer")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+        },
+    }
+}
+
 impl From<QuestionModel> for crate::domain::entities::Question {
     fn from(model: QuestionModel) -> Self {
-        use crate::domain::entities::QuestionType;
-
-        let question_type = match model.question_data.get("type").and_then(|v| v.as_str()) {
-            Some("multiple_choice") => {
-                let choices = model
-                    .question_data
-                    .get("choices")
-                    .and_then(|v| serde_json::from_value(v.clone()).ok())
-                    .unwrap_or_else(|| LocalizedText::new("".to_string(), "".to_string()));
-                let correct_index = model
-                    .question_data
-                    .get("correct_index")
-                    .and_then(|v| v.as_u64())
-                    .unwrap_or(0) as u32;
-                QuestionType::MultipleChoice {
-                    choices,
-                    correct_index,
-                }
-            }
-            Some("true_false") => {
-                let answer = model
-                    .question_data
-                    .get("answer")
-                    .and_then(|v| v.as_bool())
-                    .unwrap_or(false);
-                QuestionType::TrueFalse { answer }
-            }
-            _ => QuestionType::TrueFalse { answer: false }, // Default fallback
-        };
-
-        let explanation = match (model.explanation_en, model.explanation_id) {
-            (Some(en), Some(id)) => Some(LocalizedText::new(en, id)),
-            _ => None,
-        };
+        let question_type = QUESTION_TYPE_CODES
+            .iter()
+            .find(|(code, _)| *code == model.question_type)
+            .and_then(|(_, variant)| {
+                serde_json::from_value(serde_json::json!({ *variant: model.question_data.clone() }))
+                    .ok()
+            })
+            .unwrap_or_else(|| legacy_question_type(&model.question_data));
+
+        let explanation = model
+            .explanation
+            .and_then(|explanation| serde_json::from_value(explanation).ok());
 
         Self {
             id: QuestionId::from(model.id),
-            prompt: LocalizedText::new(model.prompt_en, model.prompt_id),
+            prompt: serde_json::from_value(model.prompt).unwrap_or_default(),
             explanation,
             topic_id: TopicId::from(model.topic_id),
             difficulty: model
@@ -252,6 +530,7 @@ impl From<QuestionModel> for crate::domain::entities::Question {
                 .unwrap_or(crate::domain::value_objects::Difficulty::Beginner),
             points: Points::new(model.points as u32),
             question_type,
+            image: model.image_asset_id.map(AssetId::from),
             created_at: model.created_at,
             updated_at: model.updated_at,
         }
@@ -264,8 +543,8 @@ impl From<CodePracticeModel> for crate::domain::entities::CodePractice {
 
         Self {
             id: CodePracticeId::from(model.id),
-            title: LocalizedText::new(model.title_en, model.title_id),
-            description: LocalizedText::new(model.description_en, model.description_id),
+            title: serde_json::from_value(model.title).unwrap_or_default(),
+            description: serde_json::from_value(model.description).unwrap_or_default(),
             initial_code: model.initial_code,
             expected_output: model.expected_output,
             solution: model.solution,
@@ -303,8 +582,12 @@ impl From<UserProgressModel> for crate::domain::entities::UserProgress {
             current_streak_days: model.current_streak_days as u32,
             highest_streak_days: model.highest_streak_days as u32,
             last_active_date: model.last_active_date,
-            daily_xp_cap: model.daily_xp_cap as u32,
+            daily_xp_cap: model.daily_xp_cap.map(|cap| cap as u32),
+            xp_earned_today: model.xp_earned_today as u32,
             last_xp_reset_date: model.last_xp_reset_date,
+            streak_freezes: model.streak_freezes as u32,
+            repairable_streak_days: model.repairable_streak_days as u32,
+            streak_repair_expires_at: model.streak_repair_expires_at,
             created_at: model.created_at,
             updated_at: model.updated_at,
         }
@@ -323,6 +606,347 @@ impl From<LeaderboardEntryModel> for crate::domain::entities::LeaderboardEntry {
     }
 }
 
+impl From<ApiKeyModel> for crate::domain::entities::ApiKey {
+    fn from(model: ApiKeyModel) -> Self {
+        Self {
+            id: ApiKeyId::from(model.id),
+            user_id: UserId::from(model.user_id),
+            name: model.name,
+            prefix: model.prefix,
+            secret_hash: model.secret_hash,
+            scopes: serde_json::from_value(model.scopes).unwrap_or_default(),
+            last_used_at: model.last_used_at,
+            revoked_at: model.revoked_at,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl From<AchievementModel> for crate::domain::entities::Achievement {
+    fn from(model: AchievementModel) -> Self {
+        use crate::domain::entities::AchievementRule;
+
+        Self {
+            id: model.id,
+            title: serde_json::from_value(model.title).unwrap_or_default(),
+            description: serde_json::from_value(model.description).unwrap_or_default(),
+            icon: model.icon,
+            // An unreadable rule must never unlock anything
+            rule: serde_json::from_value(model.rule).unwrap_or(AchievementRule::TotalXp {
+                threshold: u32::MAX,
+            }),
+            xp_bonus: model.xp_bonus as u32,
+            sort_order: model.sort_order,
+        }
+    }
+}
+
+impl From<UserAchievementModel> for crate::domain::entities::UserAchievement {
+    fn from(model: UserAchievementModel) -> Self {
+        Self {
+            user_id: UserId::from(model.user_id),
+            achievement_id: model.achievement_id,
+            unlocked_at: model.unlocked_at,
+        }
+    }
+}
+
+impl From<AccountAuditEventModel> for crate::domain::entities::AccountAuditEvent {
+    fn from(model: AccountAuditEventModel) -> Self {
+        use crate::domain::entities::AccountAuditAction;
+
+        Self {
+            id: model.id,
+            user_id: UserId::from(model.user_id),
+            action: AccountAuditAction::from_str(&model.action)
+                .unwrap_or(AccountAuditAction::DataExported),
+            details: model.details,
+            created_at: model.created_at,
+        }
+    }
+}
+
+impl From<LessonResultModel> for crate::domain::entities::user_progress::LessonResult {
+    fn from(model: LessonResultModel) -> Self {
+        Self {
+            lesson_id: LessonId::from(model.lesson_id),
+            // Per-question results live in the user's progress
+            question_results: Vec::new(),
+            completed_at: model.completed_at,
+            xp_earned: Points::new(model.xp_earned as u32),
+            perfect_score: model.perfect_score,
+        }
+    }
+}
+
+impl From<LessonSessionModel> for crate::domain::entities::LessonSession {
+    fn from(model: LessonSessionModel) -> Self {
+        Self {
+            id: LessonSessionId::from(model.id),
+            user_id: UserId::from(model.user_id),
+            lesson_id: LessonId::from(model.lesson_id),
+            question_ids: serde_json::from_value(model.question_ids).unwrap_or_default(),
+            answers: serde_json::from_value(model.answers).unwrap_or_default(),
+            started_at: model.started_at,
+            finished_at: model.finished_at,
+            revision: model.revision as u32,
+        }
+    }
+}
+
+impl From<TopicPrerequisiteModel> for crate::domain::entities::TopicPrerequisite {
+    fn from(model: TopicPrerequisiteModel) -> Self {
+        Self {
+            topic_id: TopicId::from(model.topic_id),
+            prerequisite_topic_id: TopicId::from(model.prerequisite_topic_id),
+            min_stars: model.min_stars as u32,
+            created_at: model.created_at,
+        }
+    }
+}
+
+impl From<LessonPrerequisiteModel> for crate::domain::entities::LessonPrerequisite {
+    fn from(model: LessonPrerequisiteModel) -> Self {
+        Self {
+            lesson_id: LessonId::from(model.lesson_id),
+            prerequisite_lesson_id: LessonId::from(model.prerequisite_lesson_id),
+            min_stars: model.min_stars as u32,
+            created_at: model.created_at,
+        }
+    }
+}
+
+impl From<ReviewCardModel> for crate::domain::entities::ReviewCard {
+    fn from(model: ReviewCardModel) -> Self {
+        Self {
+            user_id: UserId::from(model.user_id),
+            question_id: QuestionId::from(model.question_id),
+            ease_factor: model.ease_factor,
+            interval_days: model.interval_days as u32,
+            repetitions: model.repetitions as u32,
+            lapses: model.lapses as u32,
+            due_date: model.due_date,
+            last_reviewed_at: model.last_reviewed_at,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl From<QuizModel> for crate::domain::entities::Quiz {
+    fn from(model: QuizModel) -> Self {
+        Self {
+            id: QuizId::from(model.id),
+            title: serde_json::from_value(model.title).unwrap_or_default(),
+            description: serde_json::from_value(model.description).unwrap_or_default(),
+            topic_ids: serde_json::from_value(model.topic_ids).unwrap_or_default(),
+            difficulties: serde_json::from_value(model.difficulties).unwrap_or_default(),
+            question_count: model.question_count as u32,
+            time_limit_secs: model.time_limit_secs as u32,
+            passing_score: model.passing_score as u32,
+            max_attempts: model.max_attempts.map(|max| max as u32),
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl From<QuizAttemptModel> for crate::domain::entities::QuizAttempt {
+    fn from(model: QuizAttemptModel) -> Self {
+        Self {
+            id: QuizAttemptId::from(model.id),
+            quiz_id: QuizId::from(model.quiz_id),
+            user_id: UserId::from(model.user_id),
+            items: serde_json::from_value(model.items).unwrap_or_default(),
+            answers: serde_json::from_value(model.answers).unwrap_or_default(),
+            started_at: model.started_at,
+            deadline: model.deadline,
+            submitted_at: model.submitted_at,
+        }
+    }
+}
+
+impl From<HintUsageModel> for crate::domain::entities::HintUsage {
+    fn from(model: HintUsageModel) -> Self {
+        Self {
+            user_id: UserId::from(model.user_id),
+            code_practice_id: CodePracticeId::from(model.code_practice_id),
+            hints_revealed: model.hints_revealed as u32,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl From<CodeSubmissionModel> for crate::domain::entities::CodeSubmission {
+    fn from(model: CodeSubmissionModel) -> Self {
+        use crate::domain::entities::SubmissionVerdict;
+
+        Self {
+            id: CodeSubmissionId::from(model.id),
+            user_id: UserId::from(model.user_id),
+            code_practice_id: CodePracticeId::from(model.code_practice_id),
+            code: model.code,
+            verdict: SubmissionVerdict::from_str(&model.verdict)
+                .unwrap_or(SubmissionVerdict::WrongAnswer),
+            runner_output: model.runner_output,
+            submitted_at: model.submitted_at,
+        }
+    }
+}
+
+impl TryFrom<QuestionDraftModel> for crate::domain::entities::QuestionDraft {
+    type Error = anyhow::Error;
+
+    fn try_from(model: QuestionDraftModel) -> Result<Self, Self::Error> {
+        use crate::domain::entities::DraftStatus;
+
+        let question: QuestionModel = serde_json::from_value(model.question)
+            .map_err(|e| anyhow::anyhow!("Invalid draft question: {e}"))?;
+
+        Ok(Self {
+            id: QuestionDraftId::from(model.id),
+            question: question.into(),
+            status: DraftStatus::from_str(&model.status).unwrap_or(DraftStatus::Pending),
+            source: model.source,
+            created_at: model.created_at,
+            reviewed_at: model.reviewed_at,
+        })
+    }
+}
+
+impl From<TutorFeedbackModel> for crate::domain::entities::TutorFeedback {
+    fn from(model: TutorFeedbackModel) -> Self {
+        Self {
+            cache_key: model.cache_key,
+            language: model.language,
+            hint: model.hint,
+            provider: model.provider,
+            created_at: model.created_at,
+        }
+    }
+}
+
+impl From<TutorUsageModel> for crate::domain::entities::TutorUsage {
+    fn from(model: TutorUsageModel) -> Self {
+        Self {
+            user_id: UserId::from(model.user_id),
+            usage_date: model.usage_date,
+            requests: model.requests as u32,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl TryFrom<AiCallModel> for crate::domain::entities::AiCallRecord {
+    type Error = anyhow::Error;
+
+    fn try_from(model: AiCallModel) -> Result<Self, Self::Error> {
+        use crate::domain::entities::AiFeature;
+
+        Ok(Self {
+            id: AiCallId::from(model.id),
+            feature: AiFeature::from_str(&model.feature).map_err(|e| anyhow::anyhow!(e))?,
+            user_id: model.user_id.map(UserId::from),
+            provider: model.provider,
+            cached: model.cached,
+            succeeded: model.succeeded,
+            input_tokens: model.input_tokens as u32,
+            output_tokens: model.output_tokens as u32,
+            cost_micros: model.cost_micros as u64,
+            latency_ms: model.latency_ms as u32,
+            created_at: model.created_at,
+        })
+    }
+}
+
+impl TryFrom<AiUsageSummaryModel> for crate::domain::entities::AiUsageSummary {
+    type Error = anyhow::Error;
+
+    fn try_from(model: AiUsageSummaryModel) -> Result<Self, Self::Error> {
+        use crate::domain::entities::AiFeature;
+
+        Ok(Self {
+            day: model.day,
+            feature: AiFeature::from_str(&model.feature).map_err(|e| anyhow::anyhow!(e))?,
+            calls: model.calls as u32,
+            cached_calls: model.cached_calls as u32,
+            failed_calls: model.failed_calls as u32,
+            input_tokens: model.input_tokens as u64,
+            output_tokens: model.output_tokens as u64,
+            cost_micros: model.cost_micros as u64,
+        })
+    }
+}
+
+impl TryFrom<MachineTranslationModel> for crate::domain::entities::MachineTranslation {
+    type Error = anyhow::Error;
+
+    fn try_from(model: MachineTranslationModel) -> Result<Self, Self::Error> {
+        use crate::domain::entities::{ContentKind, TranslationStatus};
+
+        Ok(Self {
+            id: TranslationId::from(model.id),
+            kind: ContentKind::from_str(&model.content_kind).map_err(|e| anyhow::anyhow!(e))?,
+            content_id: model.content_id.to_string(),
+            field: model.field,
+            language: model.language,
+            source_text: model.source_text,
+            translated_text: model.translated_text,
+            status: TranslationStatus::from_str(&model.status)
+                .unwrap_or(TranslationStatus::NeedsReview),
+            provider: model.provider,
+            created_at: model.created_at,
+            reviewed_at: model.reviewed_at,
+        })
+    }
+}
+
+impl TryFrom<ContentVersionModel> for crate::domain::entities::ContentVersion {
+    type Error = anyhow::Error;
+
+    fn try_from(model: ContentVersionModel) -> Result<Self, Self::Error> {
+        use crate::domain::entities::{ContentAuthor, ContentKind};
+
+        Ok(Self {
+            id: ContentVersionId::from(model.id),
+            kind: ContentKind::from_str(&model.content_kind).map_err(|e| anyhow::anyhow!(e))?,
+            content_id: model.content_id.to_string(),
+            version: model.version as u32,
+            snapshot: model.snapshot,
+            author: ContentAuthor {
+                user_id: model.author_id.map(UserId::from),
+                name: model.author_name,
+            },
+            change_note: model.change_note,
+            created_at: model.created_at,
+        })
+    }
+}
+
+impl TryFrom<AssetModel> for crate::domain::entities::Asset {
+    type Error = anyhow::Error;
+
+    fn try_from(model: AssetModel) -> Result<Self, Self::Error> {
+        use crate::domain::entities::AssetType;
+
+        Ok(Self {
+            id: AssetId::from(model.id),
+            sha256: model.sha256,
+            asset_type: AssetType::from_content_type(&model.content_type)
+                .map_err(|e| anyhow::anyhow!(e))?,
+            size_bytes: model.size_bytes as u64,
+            original_filename: model.original_filename,
+            storage_key: model.storage_key,
+            thumbnail_key: model.thumbnail_key,
+            width: model.width.map(|width| width as u32),
+            height: model.height.map(|height| height as u32),
+            uploaded_by: model.uploaded_by.map(UserId::from),
+            created_at: model.created_at,
+        })
+    }
+}
+
 // Reverse conversions from domain entities to database models
 
 impl From<crate::domain::entities::User> for UserModel {
@@ -338,6 +962,15 @@ impl From<crate::domain::entities::User> for UserModel {
             last_active_date: entity.last_active_date,
             created_at: entity.created_at,
             updated_at: entity.updated_at,
+            preferred_language: entity.preferred_language,
+            time_zone: entity.time_zone,
+            avatar_url: entity.avatar_url,
+            pending_email: entity.pending_email.map(|email| email.as_str().to_string()),
+            email_verification_token_hash: entity.email_verification_token_hash,
+            email_verification_expires_at: entity.email_verification_expires_at,
+            deletion_requested_at: entity.deletion_requested_at,
+            deletion_scheduled_for: entity.deletion_scheduled_for,
+            is_content_editor: entity.is_content_editor,
         }
     }
 }
@@ -346,13 +979,10 @@ impl From<crate::domain::entities::Topic> for TopicModel {
     fn from(entity: crate::domain::entities::Topic) -> Self {
         Self {
             id: entity.id.0,
-            title_en: entity.title.en,
-            title_id: entity.title.id,
-            description_en: entity.description.en,
-            description_id: entity.description.id,
+            title: serde_json::to_value(&entity.title).unwrap_or_default(),
+            description: serde_json::to_value(&entity.description).unwrap_or_default(),
             order: entity.order as i32,
-            required_skills_en: entity.required_skills.en,
-            required_skills_id: entity.required_skills.id,
+            required_skills: serde_json::to_value(&entity.required_skills).unwrap_or_default(),
             created_at: entity.created_at,
             updated_at: entity.updated_at,
         }
@@ -363,11 +993,10 @@ impl From<crate::domain::entities::Lesson> for LessonModel {
     fn from(entity: crate::domain::entities::Lesson) -> Self {
         Self {
             id: entity.id.0,
-            title_en: entity.title.en,
-            title_id: entity.title.id,
+            title: serde_json::to_value(&entity.title).unwrap_or_default(),
             topic_id: entity.topic_id.0,
-            summary_en: entity.summary.en,
-            summary_id: entity.summary.id,
+            summary: serde_json::to_value(&entity.summary).unwrap_or_default(),
+            blocks: serde_json::to_value(&entity.blocks).unwrap_or_default(),
             attribution_url: entity.attribution_url,
             order: entity.order as i32,
             created_at: entity.created_at,
@@ -378,48 +1007,21 @@ impl From<crate::domain::entities::Lesson> for LessonModel {
 
 impl From<crate::domain::entities::Question> for QuestionModel {
     fn from(entity: crate::domain::entities::Question) -> Self {
-        let question_type_json = match &entity.question_type {
-            crate::domain::entities::QuestionType::MultipleChoice {
-                choices,
-                correct_index,
-            } => serde_json::json!({
-                "type": "multiple_choice",
-                "choices": choices,
-                "correct_index": correct_index
-            })
-            .to_string(),
-            crate::domain::entities::QuestionType::TrueFalse { answer } => serde_json::json!({
-                "type": "true_false",
-                "answer": answer
-            })
-            .to_string(),
-            _ => serde_json::json!({"type": "unknown"}).to_string(),
-        };
-
-        let (explanation_en, explanation_id) = match &entity.explanation {
-            Some(exp) => (Some(exp.en.clone()), Some(exp.id.clone())),
-            None => (None, None),
-        };
-
         Self {
             id: entity.id.0,
-            prompt_en: entity.prompt.en,
-            prompt_id: entity.prompt.id,
+            prompt: serde_json::to_value(&entity.prompt).unwrap_or_default(),
             topic_id: entity.topic_id.0,
             difficulty: entity.difficulty.to_string(),
             points: entity.points.value() as i32,
-            question_type: match &entity.question_type {
-                crate::domain::entities::QuestionType::MultipleChoice { .. } => {
-                    "multiple_choice".to_string()
-                }
-                crate::domain::entities::QuestionType::TrueFalse { .. } => "true_false".to_string(),
-                _ => "unknown".to_string(),
-            },
-            question_data: serde_json::Value::String(question_type_json),
-            explanation_en,
-            explanation_id,
+            question_type: entity.question_type.code().to_string(),
+            question_data: question_type_data(&entity.question_type),
+            explanation: entity
+                .explanation
+                .as_ref()
+                .and_then(|explanation| serde_json::to_value(explanation).ok()),
             created_at: entity.created_at,
             updated_at: entity.updated_at,
+            image_asset_id: entity.image.map(|image| image.0),
         }
     }
 }
@@ -428,10 +1030,8 @@ impl From<crate::domain::entities::CodePractice> for CodePracticeModel {
     fn from(entity: crate::domain::entities::CodePractice) -> Self {
         Self {
             id: entity.id.0,
-            title_en: entity.title.en,
-            title_id: entity.title.id,
-            description_en: entity.description.en,
-            description_id: entity.description.id,
+            title: serde_json::to_value(&entity.title).unwrap_or_default(),
+            description: serde_json::to_value(&entity.description).unwrap_or_default(),
             initial_code: entity.initial_code,
             expected_output: entity.expected_output,
             solution: entity.solution,
@@ -459,11 +1059,290 @@ impl From<crate::domain::entities::UserProgress> for UserProgressModel {
                 .unwrap_or_default(),
             completed_code_practices: serde_json::to_value(&entity.completed_code_practices)
                 .unwrap_or_default(),
-            daily_xp_cap: entity.daily_xp_cap as i32,
+            daily_xp_cap: entity.daily_xp_cap.map(|cap| cap as i32),
+            xp_earned_today: entity.xp_earned_today as i32,
             last_xp_reset_date: entity.last_xp_reset_date,
+            streak_freezes: entity.streak_freezes as i32,
+            repairable_streak_days: entity.repairable_streak_days as i32,
+            streak_repair_expires_at: entity.streak_repair_expires_at,
             lesson_stars: serde_json::to_value(&entity.lesson_stars).unwrap_or_default(),
             created_at: entity.created_at,
             updated_at: entity.updated_at,
         }
     }
 }
+
+impl From<crate::domain::entities::ApiKey> for ApiKeyModel {
+    fn from(entity: crate::domain::entities::ApiKey) -> Self {
+        Self {
+            id: entity.id.0,
+            user_id: entity.user_id.0,
+            name: entity.name,
+            prefix: entity.prefix,
+            secret_hash: entity.secret_hash,
+            scopes: serde_json::to_value(&entity.scopes).unwrap_or_default(),
+            last_used_at: entity.last_used_at,
+            revoked_at: entity.revoked_at,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+        }
+    }
+}
+
+impl From<crate::domain::entities::AccountAuditEvent> for AccountAuditEventModel {
+    fn from(entity: crate::domain::entities::AccountAuditEvent) -> Self {
+        Self {
+            id: entity.id,
+            user_id: entity.user_id.0,
+            action: entity.action.as_str().to_string(),
+            details: entity.details,
+            created_at: entity.created_at,
+        }
+    }
+}
+
+impl From<crate::domain::entities::Achievement> for AchievementModel {
+    fn from(entity: crate::domain::entities::Achievement) -> Self {
+        let now = Utc::now();
+        Self {
+            id: entity.id,
+            title: serde_json::to_value(&entity.title).unwrap_or_default(),
+            description: serde_json::to_value(&entity.description).unwrap_or_default(),
+            icon: entity.icon,
+            rule: serde_json::to_value(entity.rule).unwrap_or_default(),
+            xp_bonus: entity.xp_bonus as i32,
+            sort_order: entity.sort_order,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+impl From<crate::domain::entities::UserAchievement> for UserAchievementModel {
+    fn from(entity: crate::domain::entities::UserAchievement) -> Self {
+        Self {
+            user_id: entity.user_id.0,
+            achievement_id: entity.achievement_id,
+            unlocked_at: entity.unlocked_at,
+        }
+    }
+}
+
+impl From<crate::domain::entities::LessonSession> for LessonSessionModel {
+    fn from(entity: crate::domain::entities::LessonSession) -> Self {
+        Self {
+            id: entity.id.0,
+            user_id: entity.user_id.0,
+            lesson_id: entity.lesson_id.0,
+            question_ids: serde_json::to_value(&entity.question_ids).unwrap_or_default(),
+            answers: serde_json::to_value(&entity.answers).unwrap_or_default(),
+            started_at: entity.started_at,
+            finished_at: entity.finished_at,
+            revision: entity.revision as i32,
+        }
+    }
+}
+
+impl From<crate::domain::entities::TopicPrerequisite> for TopicPrerequisiteModel {
+    fn from(entity: crate::domain::entities::TopicPrerequisite) -> Self {
+        Self {
+            topic_id: entity.topic_id.0,
+            prerequisite_topic_id: entity.prerequisite_topic_id.0,
+            min_stars: entity.min_stars as i32,
+            created_at: entity.created_at,
+        }
+    }
+}
+
+impl From<crate::domain::entities::LessonPrerequisite> for LessonPrerequisiteModel {
+    fn from(entity: crate::domain::entities::LessonPrerequisite) -> Self {
+        Self {
+            lesson_id: entity.lesson_id.0,
+            prerequisite_lesson_id: entity.prerequisite_lesson_id.0,
+            min_stars: entity.min_stars as i32,
+            created_at: entity.created_at,
+        }
+    }
+}
+
+impl From<crate::domain::entities::ReviewCard> for ReviewCardModel {
+    fn from(entity: crate::domain::entities::ReviewCard) -> Self {
+        Self {
+            user_id: entity.user_id.0,
+            question_id: entity.question_id.0,
+            ease_factor: entity.ease_factor,
+            interval_days: entity.interval_days as i32,
+            repetitions: entity.repetitions as i32,
+            lapses: entity.lapses as i32,
+            due_date: entity.due_date,
+            last_reviewed_at: entity.last_reviewed_at,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+        }
+    }
+}
+
+impl From<crate::domain::entities::Quiz> for QuizModel {
+    fn from(entity: crate::domain::entities::Quiz) -> Self {
+        Self {
+            id: entity.id.0,
+            title: serde_json::to_value(&entity.title).unwrap_or_default(),
+            description: serde_json::to_value(&entity.description).unwrap_or_default(),
+            topic_ids: serde_json::to_value(&entity.topic_ids).unwrap_or_default(),
+            difficulties: serde_json::to_value(&entity.difficulties).unwrap_or_default(),
+            question_count: entity.question_count as i32,
+            time_limit_secs: entity.time_limit_secs as i32,
+            passing_score: entity.passing_score as i32,
+            max_attempts: entity.max_attempts.map(|max| max as i32),
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+        }
+    }
+}
+
+impl From<crate::domain::entities::QuizAttempt> for QuizAttemptModel {
+    fn from(entity: crate::domain::entities::QuizAttempt) -> Self {
+        Self {
+            id: entity.id.0,
+            quiz_id: entity.quiz_id.0,
+            user_id: entity.user_id.0,
+            items: serde_json::to_value(&entity.items).unwrap_or_default(),
+            answers: serde_json::to_value(&entity.answers).unwrap_or_default(),
+            started_at: entity.started_at,
+            deadline: entity.deadline,
+            submitted_at: entity.submitted_at,
+        }
+    }
+}
+
+impl From<crate::domain::entities::HintUsage> for HintUsageModel {
+    fn from(entity: crate::domain::entities::HintUsage) -> Self {
+        Self {
+            user_id: entity.user_id.0,
+            code_practice_id: entity.code_practice_id.0,
+            hints_revealed: entity.hints_revealed as i32,
+            updated_at: entity.updated_at,
+        }
+    }
+}
+
+impl From<crate::domain::entities::CodeSubmission> for CodeSubmissionModel {
+    fn from(entity: crate::domain::entities::CodeSubmission) -> Self {
+        Self {
+            id: entity.id.0,
+            user_id: entity.user_id.0,
+            code_practice_id: entity.code_practice_id.0,
+            code: entity.code,
+            verdict: entity.verdict.as_str().to_string(),
+            runner_output: entity.runner_output,
+            submitted_at: entity.submitted_at,
+        }
+    }
+}
+
+impl From<crate::domain::entities::QuestionDraft> for QuestionDraftModel {
+    fn from(entity: crate::domain::entities::QuestionDraft) -> Self {
+        Self {
+            id: entity.id.0,
+            question: serde_json::to_value(QuestionModel::from(entity.question))
+                .unwrap_or_default(),
+            status: entity.status.as_str().to_string(),
+            source: entity.source,
+            created_at: entity.created_at,
+            reviewed_at: entity.reviewed_at,
+        }
+    }
+}
+
+impl From<crate::domain::entities::TutorFeedback> for TutorFeedbackModel {
+    fn from(entity: crate::domain::entities::TutorFeedback) -> Self {
+        Self {
+            cache_key: entity.cache_key,
+            language: entity.language,
+            hint: entity.hint,
+            provider: entity.provider,
+            created_at: entity.created_at,
+        }
+    }
+}
+
+impl From<crate::domain::entities::TutorUsage> for TutorUsageModel {
+    fn from(entity: crate::domain::entities::TutorUsage) -> Self {
+        Self {
+            user_id: entity.user_id.0,
+            usage_date: entity.usage_date,
+            requests: entity.requests as i32,
+            updated_at: entity.updated_at,
+        }
+    }
+}
+
+impl From<crate::domain::entities::AiCallRecord> for AiCallModel {
+    fn from(entity: crate::domain::entities::AiCallRecord) -> Self {
+        Self {
+            id: entity.id.0,
+            feature: entity.feature.as_str().to_string(),
+            user_id: entity.user_id.map(|user_id| user_id.0),
+            provider: entity.provider,
+            cached: entity.cached,
+            succeeded: entity.succeeded,
+            input_tokens: entity.input_tokens as i32,
+            output_tokens: entity.output_tokens as i32,
+            cost_micros: entity.cost_micros as i64,
+            latency_ms: entity.latency_ms as i32,
+            created_at: entity.created_at,
+        }
+    }
+}
+
+impl From<crate::domain::entities::MachineTranslation> for MachineTranslationModel {
+    fn from(entity: crate::domain::entities::MachineTranslation) -> Self {
+        Self {
+            id: entity.id.0,
+            content_kind: entity.kind.as_str().to_string(),
+            content_id: Uuid::parse_str(&entity.content_id).unwrap_or_default(),
+            field: entity.field,
+            language: entity.language,
+            source_text: entity.source_text,
+            translated_text: entity.translated_text,
+            status: entity.status.as_str().to_string(),
+            provider: entity.provider,
+            created_at: entity.created_at,
+            reviewed_at: entity.reviewed_at,
+        }
+    }
+}
+
+impl From<crate::domain::entities::Asset> for AssetModel {
+    fn from(entity: crate::domain::entities::Asset) -> Self {
+        Self {
+            id: entity.id.0,
+            sha256: entity.sha256,
+            content_type: entity.asset_type.content_type().to_string(),
+            size_bytes: entity.size_bytes as i64,
+            original_filename: entity.original_filename,
+            storage_key: entity.storage_key,
+            thumbnail_key: entity.thumbnail_key,
+            width: entity.width.map(|width| width as i32),
+            height: entity.height.map(|height| height as i32),
+            uploaded_by: entity.uploaded_by.map(|user_id| user_id.0),
+            created_at: entity.created_at,
+        }
+    }
+}
+
+impl From<crate::domain::entities::ContentVersion> for ContentVersionModel {
+    fn from(entity: crate::domain::entities::ContentVersion) -> Self {
+        Self {
+            id: entity.id.0,
+            content_kind: entity.kind.as_str().to_string(),
+            content_id: Uuid::parse_str(&entity.content_id).unwrap_or_default(),
+            version: entity.version as i32,
+            snapshot: entity.snapshot,
+            author_id: entity.author.user_id.map(|user_id| user_id.0),
+            author_name: entity.author.name,
+            change_note: entity.change_note,
+            created_at: entity.created_at,
+        }
+    }
+}
@@ -0,0 +1,575 @@
+use anyhow::Result;
+use rand::Rng;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::domain::entities::question_draft::{
+    generated_question_schema, parse_generated_questions, GENERATED_QUESTION_TYPES,
+};
+use crate::domain::entities::{
+    AiCallRecord, AiFeature, AiRateLimited, GeneratedQuestion, MistakeContext, TutorError,
+};
+use crate::domain::repositories::AiUsageRepository;
+use crate::domain::value_objects::UserId;
+use crate::infrastructure::external_services::{
+    LlmCompletion, LlmHttpError, LlmOptions, LlmProvider, ResponseFormat,
+};
+use crate::shared::config::Config;
+use crate::shared::utils::{protect_code, stable_hash, TokenBucket};
+
+/// Requests made for one batch of questions before giving up on malformed replies.
+pub const QUESTION_GENERATION_ATTEMPTS: u32 = 3;
+
+/// Requests made for a tutor hint before giving up on hints that reveal the answer.
+pub const TUTOR_HINT_ATTEMPTS: u32 = 2;
+
+/// Requests made for a translation before giving up on replies that mangle code.
+pub const TRANSLATION_ATTEMPTS: u32 = 2;
+
+/// Replies kept in memory at most; expired ones are dropped first to make room.
+const MAX_CACHED_REPLIES: usize = 1000;
+
+/// Callers with a rate limit bucket at most before idle buckets are dropped.
+const MAX_TRACKED_CALLERS: usize = 10_000;
+
+/// Who an AI request is made for. Each caller has its own rate limit.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AiCaller {
+    User(UserId),
+    /// The admin AI pages, which share one limit.
+    Admin,
+}
+
+impl AiCaller {
+    fn user_id(&self) -> Option<UserId> {
+        match self {
+            AiCaller::User(user_id) => Some(user_id.clone()),
+            AiCaller::Admin => None,
+        }
+    }
+}
+
+/// Caching, rate limiting and retry behaviour of `AiService`.
+#[derive(Debug, Clone)]
+pub struct AiSettings {
+    /// How long identical requests are answered from memory; zero disables caching.
+    pub cache_ttl: Duration,
+    /// Zero disables the limit.
+    pub user_requests_per_minute: u32,
+    /// Zero disables the limit.
+    pub global_requests_per_minute: u32,
+    /// Retries after a rate-limited or failing provider, with jittered backoff.
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for each further one.
+    pub retry_base_delay: Duration,
+}
+
+impl Default for AiSettings {
+    fn default() -> Self {
+        Self {
+            cache_ttl: Duration::from_secs(3600),
+            user_requests_per_minute: 10,
+            global_requests_per_minute: 60,
+            max_retries: 3,
+            retry_base_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+impl AiSettings {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            cache_ttl: Duration::from_secs(config.ai_cache_ttl_secs),
+            user_requests_per_minute: config.ai_user_requests_per_minute,
+            global_requests_per_minute: config.ai_global_requests_per_minute,
+            max_retries: config.ai_max_retries,
+            ..Self::default()
+        }
+    }
+}
+
+struct CachedReply {
+    text: String,
+    expires_at: Instant,
+}
+
+#[derive(Default)]
+struct RateLimits {
+    global: Option<TokenBucket>,
+    callers: HashMap<AiCaller, TokenBucket>,
+}
+
+/// AI features on top of whichever `LlmProvider` is configured. Replies are
+/// cached, requests are rate limited per caller and globally, transient provider
+/// errors are retried, and every call is recorded for usage and cost reports.
+#[derive(Clone)]
+pub struct AiService {
+    provider: Arc<dyn LlmProvider>,
+    usage_repository: Arc<dyn AiUsageRepository>,
+    settings: AiSettings,
+    cache: Arc<Mutex<HashMap<String, CachedReply>>>,
+    limits: Arc<Mutex<RateLimits>>,
+}
+
+impl AiService {
+    pub fn new(
+        provider: Arc<dyn LlmProvider>,
+        usage_repository: Arc<dyn AiUsageRepository>,
+        settings: AiSettings,
+    ) -> Self {
+        Self {
+            provider,
+            usage_repository,
+            settings,
+            cache: Arc::new(Mutex::new(HashMap::new())),
+            limits: Arc::new(Mutex::new(RateLimits::default())),
+        }
+    }
+
+    /// Recorded as the source of generated content.
+    pub fn provider_name(&self) -> String {
+        self.provider.name()
+    }
+
+    /// Sends `prompt` for `feature`, answering identical requests from the cache.
+    /// Fails with `AiRateLimited` when the caller or the site is over its rate.
+    async fn complete(
+        &self,
+        caller: &AiCaller,
+        feature: AiFeature,
+        prompt: &str,
+        options: &LlmOptions,
+    ) -> Result<String> {
+        let provider = self.provider.name();
+        // Generated questions must differ between requests, so they are never cached
+        let cache_key = (feature != AiFeature::QuestionGeneration
+            && !self.settings.cache_ttl.is_zero())
+        .then(|| {
+            let format = match options.response_format {
+                ResponseFormat::Text => "text",
+                ResponseFormat::Json => "json",
+            };
+            stable_hash(&[feature.as_str(), &provider, format, prompt])
+        });
+        if let Some(text) = cache_key.as_deref().and_then(|key| self.cached(key)) {
+            self.record(AiCallRecord::new(
+                feature,
+                caller.user_id(),
+                provider,
+                true,
+                true,
+                0,
+                0,
+                0,
+            ))
+            .await;
+            return Ok(text);
+        }
+
+        self.acquire(caller)?;
+        let started = Instant::now();
+        let result = self.complete_with_retries(prompt, options).await;
+        let latency_ms = u32::try_from(started.elapsed().as_millis()).unwrap_or(u32::MAX);
+        let (input_tokens, output_tokens) = result
+            .as_ref()
+            .map_or((0, 0), |c| (c.input_tokens, c.output_tokens));
+        self.record(AiCallRecord::new(
+            feature,
+            caller.user_id(),
+            provider,
+            false,
+            result.is_ok(),
+            input_tokens,
+            output_tokens,
+            latency_ms,
+        ))
+        .await;
+
+        let completion = result?;
+        if let Some(key) = cache_key {
+            self.store(key, &completion.text);
+        }
+        Ok(completion.text)
+    }
+
+    async fn complete_with_retries(
+        &self,
+        prompt: &str,
+        options: &LlmOptions,
+    ) -> Result<LlmCompletion> {
+        let mut attempt = 0;
+        loop {
+            match self.provider.complete(prompt, options).await {
+                Err(e)
+                    if attempt < self.settings.max_retries
+                        && e.downcast_ref::<LlmHttpError>()
+                            .is_some_and(LlmHttpError::is_retryable) =>
+                {
+                    tokio::time::sleep(self.backoff(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Exponential backoff with up to half of each delay taken off at random, so
+    /// requests that failed together do not retry together.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let delay = self
+            .settings
+            .retry_base_delay
+            .saturating_mul(1 << attempt.min(10));
+        delay.mul_f64(rand::thread_rng().gen_range(0.5..=1.0))
+    }
+
+    /// Takes a request from the caller's and the global bucket, or neither when
+    /// either is empty.
+    fn acquire(&self, caller: &AiCaller) -> Result<(), AiRateLimited> {
+        let now = Instant::now();
+        let mut guard = self.limits.lock().unwrap();
+        let limits = &mut *guard;
+        if limits.callers.len() >= MAX_TRACKED_CALLERS {
+            limits.callers.retain(|_, bucket| !bucket.is_full(now));
+        }
+
+        let global = (self.settings.global_requests_per_minute > 0).then(|| {
+            limits.global.get_or_insert_with(|| {
+                TokenBucket::per_minute(self.settings.global_requests_per_minute, now)
+            })
+        });
+        let own = (self.settings.user_requests_per_minute > 0).then(|| {
+            limits.callers.entry(caller.clone()).or_insert_with(|| {
+                TokenBucket::per_minute(self.settings.user_requests_per_minute, now)
+            })
+        });
+        let mut buckets: Vec<&mut TokenBucket> = global.into_iter().chain(own).collect();
+
+        let wait = buckets
+            .iter_mut()
+            .filter_map(|bucket| bucket.check(now).err())
+            .max();
+        if let Some(wait) = wait {
+            return Err(AiRateLimited {
+                retry_after_secs: wait.as_secs() + u64::from(wait.subsec_nanos() > 0),
+            });
+        }
+        buckets.iter_mut().for_each(|bucket| bucket.take());
+        Ok(())
+    }
+
+    fn cached(&self, key: &str) -> Option<String> {
+        let mut cache = self.cache.lock().unwrap();
+        match cache.get(key) {
+            Some(reply) if reply.expires_at > Instant::now() => Some(reply.text.clone()),
+            Some(_) => {
+                cache.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn store(&self, key: String, text: &str) {
+        let now = Instant::now();
+        let mut cache = self.cache.lock().unwrap();
+        if cache.len() >= MAX_CACHED_REPLIES {
+            cache.retain(|_, reply| reply.expires_at > now);
+        }
+        if cache.len() >= MAX_CACHED_REPLIES {
+            let oldest = cache
+                .iter()
+                .min_by_key(|(_, reply)| reply.expires_at)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                cache.remove(&oldest);
+            }
+        }
+        cache.insert(
+            key,
+            CachedReply {
+                text: text.to_string(),
+                expires_at: now + self.settings.cache_ttl,
+            },
+        );
+    }
+
+    async fn record(&self, call: AiCallRecord) {
+        if let Err(e) = self.usage_repository.record(&call).await {
+            tracing::warn!("Failed to record AI usage: {}", e);
+        }
+    }
+
+    /// Generates questions as validated JSON, of one type or of any type when
+    /// `question_type` is `None`. Replies that are malformed or fail validation are
+    /// retried with the problem pointed out, up to `QUESTION_GENERATION_ATTEMPTS`.
+    pub async fn generate_questions(
+        &self,
+        caller: &AiCaller,
+        topic: &str,
+        difficulty: &str,
+        question_type: Option<&str>,
+        count: u32,
+    ) -> Result<Vec<GeneratedQuestion>> {
+        let type_codes: Vec<&str> = match question_type {
+            Some(code) => vec![code],
+            None => GENERATED_QUESTION_TYPES.to_vec(),
+        };
+        let schemas = type_codes
+            .iter()
+            .map(|code| {
+                generated_question_schema(code)
+                    .ok_or_else(|| anyhow::anyhow!("Unsupported question type: {code}"))
+            })
+            .collect::<Result<Vec<_>>>()?
+            .join("\n");
+        let prompt = format!(
+            "Generate {count} quiz questions about the Rust topic \"{topic}\" at {difficulty} \
+            difficulty. Write every prompt, explanation and choice in English (_en fields) \
+            and Indonesian (_id fields). Reply with only a JSON object of the form \
+            {{\"questions\": [...]}} whose questions each match one of these shapes:\n{schemas}",
+        );
+
+        let mut request = prompt.clone();
+        let mut last_error = String::new();
+        for _ in 0..QUESTION_GENERATION_ATTEMPTS {
+            let reply = self
+                .complete(
+                    caller,
+                    AiFeature::QuestionGeneration,
+                    &request,
+                    &LlmOptions::json(),
+                )
+                .await?;
+            match parse_generated_questions(&reply).and_then(|questions| {
+                for (index, question) in questions.iter().enumerate() {
+                    question
+                        .validate()
+                        .map_err(|e| format!("Question {}: {e}", index + 1))?;
+                    if !type_codes.contains(&question.type_code()) {
+                        return Err(format!(
+                            "Question {} has type {}, which was not requested",
+                            index + 1,
+                            question.type_code()
+                        ));
+                    }
+                }
+                Ok(questions)
+            }) {
+                Ok(mut questions) => {
+                    questions.truncate(count as usize);
+                    return Ok(questions);
+                }
+                Err(e) => {
+                    request = format!(
+                        "{prompt}\n\nYour previous reply was rejected: {e}. Reply again with \
+                        only the corrected JSON object."
+                    );
+                    last_error = e;
+                }
+            }
+        }
+
+        Err(anyhow::anyhow!(
+            "{} returned no valid questions after {} attempts: {}",
+            self.provider.name(),
+            QUESTION_GENERATION_ATTEMPTS,
+            last_error
+        ))
+    }
+
+    pub async fn validate_code(
+        &self,
+        caller: &AiCaller,
+        code: &str,
+        expected_output: &str,
+    ) -> Result<bool> {
+        let prompt = format!(
+            "Analyze this Rust code and determine if it would produce the expected output.\n\n\
+            Code:\n```rust\n{code}\n```\n\n\
+            Expected output: {expected_output}\n\n\
+            Please respond with only 'TRUE' if the code produces the expected output, \
+            or 'FALSE' if it doesn't. Be precise in your analysis.",
+        );
+
+        let response = self
+            .complete(
+                caller,
+                AiFeature::CodeValidation,
+                &prompt,
+                &LlmOptions::default(),
+            )
+            .await?;
+
+        // Parse the response to determine if code is valid
+        let is_valid = response.trim().to_uppercase().contains("TRUE");
+
+        Ok(is_valid)
+    }
+
+    pub async fn generate_code_explanation(&self, caller: &AiCaller, code: &str) -> Result<String> {
+        let prompt = format!(
+            "Explain this Rust code in detail, including:\n\
+            1. What the code does\n\
+            2. How it works step by step\n\
+            3. Key Rust concepts used\n\
+            4. Any potential improvements or best practices\n\n\
+            Code:\n```rust\n{code}\n```",
+        );
+
+        self.complete(
+            caller,
+            AiFeature::CodeExplanation,
+            &prompt,
+            &LlmOptions::default(),
+        )
+        .await
+    }
+
+    pub async fn suggest_code_improvements(&self, caller: &AiCaller, code: &str) -> Result<String> {
+        let prompt = format!(
+            "Review this Rust code and suggest improvements for:\n\
+            1. Performance optimization\n\
+            2. Code readability\n\
+            3. Rust best practices\n\
+            4. Error handling\n\
+            5. Memory safety\n\n\
+            Code:\n```rust\n{code}\n```\n\n\
+            Provide specific suggestions with examples where possible.",
+        );
+
+        self.complete(
+            caller,
+            AiFeature::CodeReview,
+            &prompt,
+            &LlmOptions::default(),
+        )
+        .await
+    }
+
+    /// A short hint, in `language`, on what went wrong with a learner's answer.
+    /// Hints that give the answer away are requested again, then refused with
+    /// `TutorError::NoSafeHint`; provider failures are `TutorError::Unavailable`.
+    pub async fn explain_mistake(
+        &self,
+        caller: &AiCaller,
+        context: &MistakeContext,
+        language: &str,
+    ) -> Result<String> {
+        let language_name = language_name(language);
+        let mut details = format!("Question:\n{}\n", context.task);
+        if let Some(code) = &context.code {
+            details.push_str(&format!(
+                "\nCode shown with the question:\n```rust\n{code}\n```\n"
+            ));
+        }
+        if let Some(correct_answer) = &context.correct_answer {
+            details.push_str(&format!(
+                "\nCorrect answer (do not reveal):\n{correct_answer}\n"
+            ));
+        }
+        if let Some(explanation) = &context.explanation {
+            details.push_str(&format!(
+                "\nAuthor's explanation (do not quote):\n{explanation}\n"
+            ));
+        }
+        details.push_str(&format!(
+            "\nLearner's answer:\n{}\n",
+            context.learner_answer
+        ));
+        if let Some(diagnostics) = &context.diagnostics {
+            details.push_str(&format!("\nCompiler and test output:\n{diagnostics}\n"));
+        }
+        let prompt = format!(
+            "You are a patient Rust tutor. Explain the learner's mistake below with a short \
+            hint in {language_name}, at most three sentences. Point to the concept or the part \
+            of their answer to look at again. Never state the correct answer and never write \
+            the solution code.\n\n{details}",
+        );
+
+        let mut request = prompt.clone();
+        for _ in 0..TUTOR_HINT_ATTEMPTS {
+            let hint = self
+                .complete(
+                    caller,
+                    AiFeature::TutorHint,
+                    &request,
+                    &LlmOptions::default(),
+                )
+                .await
+                .map_err(|e| {
+                    if e.is::<AiRateLimited>() {
+                        e
+                    } else {
+                        TutorError::Unavailable(e.to_string()).into()
+                    }
+                })?;
+            if !context.reveals_answer(&hint) {
+                return Ok(hint.trim().to_string());
+            }
+            request = format!(
+                "{prompt}\n\nYour previous hint gave the answer away. Write a new hint that \
+                only nudges the learner toward it."
+            );
+        }
+
+        Err(TutorError::NoSafeHint.into())
+    }
+
+    /// Translates English course text into `language`. Code blocks and inline
+    /// code are kept out of the model's reach and put back unchanged; replies
+    /// that lose them are requested again.
+    pub async fn translate(&self, caller: &AiCaller, text: &str, language: &str) -> Result<String> {
+        let protected = protect_code(text);
+        let mut prompt = format!(
+            "Translate the following text from a Rust programming course from English to \
+            {}. Keep the Markdown formatting and the line breaks as they are, keep Rust \
+            terms such as ownership or borrowing recognizable, and reply with only the \
+            translation.",
+            language_name(language)
+        );
+        if protected.has_code() {
+            prompt.push_str(" Copy placeholders such as ⟦C0⟧ exactly as they appear.");
+        }
+        prompt.push_str(&format!("\n\nText:\n{}", protected.text));
+        let options = LlmOptions {
+            temperature: 0.2,
+            ..LlmOptions::default()
+        };
+
+        let mut request = prompt.clone();
+        let mut last_error = String::new();
+        for _ in 0..TRANSLATION_ATTEMPTS {
+            let reply = self
+                .complete(caller, AiFeature::Translation, &request, &options)
+                .await?;
+            match protected.restore(reply.trim()) {
+                Ok(translation) => return Ok(translation),
+                Err(e) => {
+                    request = format!(
+                        "{prompt}\n\nYour previous translation was rejected: {e}. Translate \
+                        again and copy every placeholder exactly once."
+                    );
+                    last_error = e;
+                }
+            }
+        }
+
+        Err(anyhow::anyhow!(
+            "{} could not translate the text without changing its code: {}",
+            self.provider.name(),
+            last_error
+        ))
+    }
+}
+
+/// How prompts name a locale. Models know the common tags, so anything else
+/// is passed as the tag itself.
+fn language_name(language: &str) -> String {
+    match language {
+        "en" => "English".to_string(),
+        "id" => "Indonesian".to_string(),
+        _ => format!("the language with the BCP 47 tag {language}"),
+    }
+}
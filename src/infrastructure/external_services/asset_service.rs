@@ -0,0 +1,225 @@
+use anyhow::Result;
+use image::{ImageFormat, ImageReader, Limits};
+use sha2::{Digest, Sha256};
+use std::io::Cursor;
+use std::sync::Arc;
+
+use crate::domain::entities::{Asset, AssetError, AssetType};
+use crate::domain::repositories::AssetRepository;
+use crate::domain::value_objects::{AssetId, UserId};
+use crate::infrastructure::external_services::AssetStorage;
+use crate::shared::config::Config;
+
+/// Images wider or taller than this are refused rather than decoded.
+const MAX_IMAGE_DIMENSION: u32 = 12_000;
+
+/// Memory an image may take once decoded.
+const MAX_IMAGE_ALLOC_BYTES: u64 = 512 * 1024 * 1024;
+
+/// Limits on uploads and the size of generated thumbnails.
+#[derive(Debug, Clone)]
+pub struct AssetSettings {
+    pub max_bytes: u64,
+    /// Longest side of a thumbnail, in pixels.
+    pub thumbnail_size: u32,
+}
+
+impl Default for AssetSettings {
+    fn default() -> Self {
+        Self {
+            max_bytes: 10 * 1024 * 1024,
+            thumbnail_size: 320,
+        }
+    }
+}
+
+impl AssetSettings {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            max_bytes: config.asset_max_bytes,
+            ..Self::default()
+        }
+    }
+}
+
+/// Uploaded files: checks and stores them, makes thumbnails of images, and
+/// reads them back for serving.
+#[derive(Clone)]
+pub struct AssetService {
+    storage: Arc<dyn AssetStorage>,
+    repository: Arc<dyn AssetRepository>,
+    settings: AssetSettings,
+}
+
+impl AssetService {
+    pub fn new(
+        storage: Arc<dyn AssetStorage>,
+        repository: Arc<dyn AssetRepository>,
+        settings: AssetSettings,
+    ) -> Self {
+        Self {
+            storage,
+            repository,
+            settings,
+        }
+    }
+
+    pub fn max_bytes(&self) -> u64 {
+        self.settings.max_bytes
+    }
+
+    pub fn storage_name(&self) -> String {
+        self.storage.name()
+    }
+
+    /// Stores an uploaded file. Uploading content that is already stored
+    /// returns the existing asset. Fails with an [`AssetError`] when the file is
+    /// empty, too large, of a type that is not allowed, or a broken image.
+    pub async fn upload(
+        &self,
+        filename: &str,
+        data: Vec<u8>,
+        uploaded_by: Option<UserId>,
+    ) -> Result<Asset> {
+        if data.is_empty() {
+            return Err(AssetError::Empty.into());
+        }
+        if data.len() as u64 > self.settings.max_bytes {
+            return Err(AssetError::TooLarge {
+                max_bytes: self.settings.max_bytes,
+            }
+            .into());
+        }
+        let asset_type = AssetType::sniff(&data).ok_or(AssetError::UnsupportedType)?;
+
+        let sha256 = hex::encode(Sha256::digest(&data));
+        if let Some(existing) = self.repository.find_by_sha256(&sha256).await? {
+            return Ok(existing);
+        }
+
+        let mut asset = Asset::new(sha256, asset_type, data.len() as u64, filename, uploaded_by);
+
+        // Decoding checks that images are what they claim to be before storing anything
+        let (data, thumbnail) = if asset_type.is_image() {
+            let thumbnail_size = self.settings.thumbnail_size;
+            let (data, thumbnail) = tokio::task::spawn_blocking(move || {
+                let thumbnail = make_thumbnail(&data, asset_type, thumbnail_size);
+                (data, thumbnail)
+            })
+            .await?;
+            (data, Some(thumbnail.map_err(AssetError::InvalidImage)?))
+        } else {
+            (data, None)
+        };
+
+        self.storage
+            .put(&asset.storage_key, data, asset_type.content_type())
+            .await?;
+        if let Some((width, height, png)) = thumbnail {
+            asset.add_thumbnail(width, height);
+            if let Some(thumbnail_key) = &asset.thumbnail_key {
+                if let Err(e) = self
+                    .storage
+                    .put(thumbnail_key, png, AssetType::Png.content_type())
+                    .await
+                {
+                    self.discard_stored(&asset).await;
+                    return Err(e);
+                }
+            }
+        }
+
+        if let Err(e) = self.repository.create(&asset).await {
+            // The same file uploaded concurrently; its content is already stored
+            if let Some(existing) = self.repository.find_by_sha256(&asset.sha256).await? {
+                return Ok(existing);
+            }
+            self.discard_stored(&asset).await;
+            return Err(e);
+        }
+        Ok(asset)
+    }
+
+    /// Removes the stored content of an asset that was never recorded. The
+    /// upload has already failed, so cleanup errors are only logged.
+    async fn discard_stored(&self, asset: &Asset) {
+        // Content is stored by hash, so a concurrent upload of the same file may
+        // have recorded its asset since; an orphaned blob is better than a broken one
+        match self.repository.find_by_sha256(&asset.sha256).await {
+            Ok(None) => {}
+            Ok(Some(_)) => return,
+            Err(e) => {
+                tracing::warn!("Keeping unrecorded asset {}: {e}", asset.storage_key);
+                return;
+            }
+        }
+
+        let keys = std::iter::once(&asset.storage_key).chain(asset.thumbnail_key.as_ref());
+        for key in keys {
+            if let Err(e) = self.storage.delete(key).await {
+                tracing::warn!("Failed to remove unrecorded asset {}: {}", key, e);
+            }
+        }
+    }
+
+    pub async fn find(&self, id: &AssetId) -> Result<Option<Asset>> {
+        self.repository.find_by_id(id).await
+    }
+
+    /// The asset and its content, or `None` when either is missing.
+    pub async fn open(&self, id: &AssetId) -> Result<Option<(Asset, Vec<u8>)>> {
+        let Some(asset) = self.repository.find_by_id(id).await? else {
+            return Ok(None);
+        };
+        let data = self.storage.get(&asset.storage_key).await?;
+        Ok(data.map(|data| (asset, data)))
+    }
+
+    /// The asset and its PNG thumbnail, or `None` when it has none.
+    pub async fn open_thumbnail(&self, id: &AssetId) -> Result<Option<(Asset, Vec<u8>)>> {
+        let Some(asset) = self.repository.find_by_id(id).await? else {
+            return Ok(None);
+        };
+        let Some(thumbnail_key) = &asset.thumbnail_key else {
+            return Ok(None);
+        };
+        let data = self.storage.get(thumbnail_key).await?;
+        Ok(data.map(|data| (asset, data)))
+    }
+
+    /// Newest first.
+    pub async fn list(&self, limit: u32, offset: u32) -> Result<Vec<Asset>> {
+        self.repository.list(limit, offset).await
+    }
+}
+
+/// Decodes an image within the size limits and returns its dimensions and a
+/// PNG thumbnail no larger than `size` on either side.
+fn make_thumbnail(
+    data: &[u8],
+    asset_type: AssetType,
+    size: u32,
+) -> Result<(u32, u32, Vec<u8>), String> {
+    let format = match asset_type {
+        AssetType::Png => ImageFormat::Png,
+        AssetType::Jpeg => ImageFormat::Jpeg,
+        AssetType::Gif => ImageFormat::Gif,
+        AssetType::Webp => ImageFormat::WebP,
+        AssetType::Pdf => return Err("not an image".to_string()),
+    };
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_IMAGE_DIMENSION);
+    limits.max_image_height = Some(MAX_IMAGE_DIMENSION);
+    limits.max_alloc = Some(MAX_IMAGE_ALLOC_BYTES);
+
+    let mut reader = ImageReader::with_format(Cursor::new(data), format);
+    reader.limits(limits);
+    let image = reader.decode().map_err(|e| e.to_string())?;
+
+    let mut png = Vec::new();
+    image
+        .thumbnail(size, size)
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .map_err(|e| e.to_string())?;
+    Ok((image.width(), image.height(), png))
+}
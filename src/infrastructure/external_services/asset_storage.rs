@@ -0,0 +1,36 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::infrastructure::external_services::{LocalAssetStorage, S3AssetStorage, S3Credentials};
+use crate::shared::config::{AssetStorageKind, Config};
+
+/// Where the bytes of uploaded files live. Keys are relative, `/`-separated
+/// paths such as `ab/ab12….png`.
+#[async_trait]
+pub trait AssetStorage: Send + Sync + 'static {
+    /// Backend and location, e.g. `local:uploads`, for logs and the admin pages.
+    fn name(&self) -> String;
+    /// Stores `data` under `key`, replacing anything already there.
+    async fn put(&self, key: &str, data: Vec<u8>, content_type: &str) -> Result<()>;
+    /// `None` when nothing is stored under `key`.
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>>;
+    /// Succeeds when nothing is stored under `key`.
+    async fn delete(&self, key: &str) -> Result<()>;
+}
+
+/// The storage selected by `ASSET_STORAGE`.
+pub fn asset_storage_from_config(config: &Config) -> Result<Arc<dyn AssetStorage>> {
+    Ok(match config.asset_storage {
+        AssetStorageKind::Local => Arc::new(LocalAssetStorage::new(&config.asset_local_dir)),
+        AssetStorageKind::S3 => Arc::new(S3AssetStorage::new(
+            config.s3_endpoint.clone().unwrap_or_default(),
+            config.s3_bucket.clone().unwrap_or_default(),
+            S3Credentials {
+                access_key_id: config.s3_access_key_id.clone().unwrap_or_default(),
+                secret_access_key: config.s3_secret_access_key.clone().unwrap_or_default(),
+                region: config.s3_region.clone(),
+            },
+        )?),
+    })
+}
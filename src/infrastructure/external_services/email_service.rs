@@ -201,6 +201,82 @@ This email was sent to {email}. If you didn't request a password reset, please i
             .await
     }
 
+    pub async fn send_email_verification_email(
+        &self,
+        email: &str,
+        name: &str,
+        verification_token: &str,
+    ) -> Result<()> {
+        let subject = "Confirm your new email - Rust Learning Platform";
+        let verify_url = format!("http://localhost:3000/verify-email?token={verification_token}");
+
+        let html_body = format!(
+            r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="utf-8">
+    <title>Confirm your new email</title>
+    <style>
+        body {{ font-family: Arial, sans-serif; line-height: 1.6; color: #333; }}
+        .container {{ max-width: 600px; margin: 0 auto; padding: 20px; }}
+        .header {{ background-color: #f8f9fa; padding: 20px; text-align: center; border-radius: 8px; }}
+        .content {{ padding: 20px; }}
+        .button {{ display: inline-block; background-color: #007bff; color: white; padding: 12px 24px; text-decoration: none; border-radius: 4px; margin: 20px 0; }}
+        .footer {{ text-align: center; color: #666; font-size: 14px; margin-top: 30px; }}
+    </style>
+</head>
+<body>
+    <div class="container">
+        <div class="header">
+            <h1>📧 Confirm your new email</h1>
+        </div>
+        <div class="content">
+            <h2>Hello {name}!</h2>
+            <p>You asked to use this address for your Rust Learning Platform account.</p>
+
+            <p>Click the button below to confirm the change:</p>
+            <a href="{verify_url}" class="button">Confirm Email</a>
+
+            <p>This link will expire in 24 hours. Until then your account keeps using your current email address.</p>
+
+            <p>If the button doesn't work, copy and paste this link into your browser:</p>
+            <p style="word-break: break-all; background-color: #f8f9fa; padding: 10px; border-radius: 4px;">{verify_url}</p>
+
+            <p>Best regards,<br><strong>The Rust Learning Team</strong></p>
+        </div>
+        <div class="footer">
+            <p>This email was sent to {email}. If you didn't request this change, please ignore this email.</p>
+            <p>&copy; 2024 Rust Learning Platform. All rights reserved.</p>
+        </div>
+    </div>
+</body>
+</html>"#,
+        );
+
+        let text_body = format!(
+            r#"Confirm your new email - Rust Learning Platform
+
+Hello {name}!
+
+You asked to use this address for your Rust Learning Platform account.
+
+Open the link below to confirm the change:
+{verify_url}
+
+This link will expire in 24 hours. Until then your account keeps using your current email address.
+
+Best regards,
+The Rust Learning Team
+
+---
+This email was sent to {email}. If you didn't request this change, please ignore this email.
+© 2024 Rust Learning Platform. All rights reserved."#,
+        );
+
+        self.send_email(email, subject, &html_body, &text_body)
+            .await
+    }
+
     pub async fn send_achievement_email(
         &self,
         email: &str,
@@ -0,0 +1,159 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::infrastructure::external_services::{
+    LlmCompletion, LlmHttpError, LlmOptions, LlmProvider, ResponseFormat,
+};
+
+/// Google Gemini through the Generative Language REST API.
+#[derive(Debug, Clone)]
+pub struct GeminiProvider {
+    client: Client,
+    api_key: String,
+    api_url: String,
+    model: String,
+}
+
+#[derive(Serialize)]
+struct GeminiRequest {
+    contents: Vec<Content>,
+    generation_config: GenerationConfig,
+}
+
+#[derive(Serialize)]
+struct Content {
+    parts: Vec<Part>,
+}
+
+#[derive(Serialize)]
+struct Part {
+    text: String,
+}
+
+#[derive(Serialize)]
+struct GenerationConfig {
+    temperature: f32,
+    top_k: u32,
+    top_p: f32,
+    max_output_tokens: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_mime_type: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GeminiResponse {
+    candidates: Vec<Candidate>,
+    #[serde(rename = "usageMetadata")]
+    usage_metadata: Option<UsageMetadata>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UsageMetadata {
+    prompt_token_count: Option<u32>,
+    candidates_token_count: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct Candidate {
+    content: ResponseContent,
+}
+
+#[derive(Deserialize)]
+struct ResponseContent {
+    parts: Vec<ResponsePart>,
+}
+
+#[derive(Deserialize)]
+struct ResponsePart {
+    text: String,
+}
+
+impl GeminiProvider {
+    pub fn new(api_key: String, api_url: String, model: String) -> Self {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .expect("Failed to create HTTP client");
+
+        Self {
+            client,
+            api_key,
+            api_url,
+            model,
+        }
+    }
+}
+
+#[async_trait]
+impl LlmProvider for GeminiProvider {
+    fn name(&self) -> String {
+        format!("gemini/{}", self.model)
+    }
+
+    async fn complete(&self, prompt: &str, options: &LlmOptions) -> Result<LlmCompletion> {
+        let url = format!(
+            "{}/models/{}:generateContent?key={}",
+            self.api_url, self.model, self.api_key
+        );
+
+        let request = GeminiRequest {
+            contents: vec![Content {
+                parts: vec![Part {
+                    text: prompt.to_string(),
+                }],
+            }],
+            generation_config: GenerationConfig {
+                temperature: options.temperature,
+                top_k: 40,
+                top_p: 0.95,
+                max_output_tokens: options.max_output_tokens,
+                response_mime_type: (options.response_format == ResponseFormat::Json)
+                    .then(|| "application/json".to_string()),
+            },
+        };
+
+        let response = self
+            .client
+            .post(&url)
+            .json(&request)
+            .send()
+            .await
+            .context("Failed to send request to Gemini API")?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            return Err(LlmHttpError { status, body }.into());
+        }
+
+        let gemini_response: GeminiResponse = response
+            .json()
+            .await
+            .context("Failed to parse Gemini API response")?;
+
+        let text = gemini_response
+            .candidates
+            .first()
+            .and_then(|candidate| candidate.content.parts.first())
+            .map(|part| part.text.clone())
+            .ok_or_else(|| anyhow::anyhow!("No response content from Gemini API"))?;
+
+        let estimate = LlmCompletion::estimated(prompt, text);
+        let usage = gemini_response.usage_metadata;
+        Ok(LlmCompletion {
+            input_tokens: usage
+                .as_ref()
+                .and_then(|u| u.prompt_token_count)
+                .unwrap_or(estimate.input_tokens),
+            output_tokens: usage
+                .as_ref()
+                .and_then(|u| u.candidates_token_count)
+                .unwrap_or(estimate.output_tokens),
+            text: estimate.text,
+        })
+    }
+}
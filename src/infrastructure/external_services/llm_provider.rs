@@ -0,0 +1,125 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::infrastructure::external_services::{
+    GeminiProvider, MockLlmProvider, OpenAiCompatibleProvider,
+};
+use crate::shared::config::{Config, LlmProviderKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseFormat {
+    Text,
+    /// The reply must be a single JSON value.
+    Json,
+}
+
+#[derive(Debug, Clone)]
+pub struct LlmOptions {
+    pub temperature: f32,
+    pub max_output_tokens: u32,
+    pub response_format: ResponseFormat,
+}
+
+impl Default for LlmOptions {
+    fn default() -> Self {
+        Self {
+            temperature: 0.7,
+            max_output_tokens: 2048,
+            response_format: ResponseFormat::Text,
+        }
+    }
+}
+
+impl LlmOptions {
+    /// Default options asking for a JSON reply.
+    pub fn json() -> Self {
+        Self {
+            response_format: ResponseFormat::Json,
+            ..Self::default()
+        }
+    }
+}
+
+/// A reply and the tokens it was billed for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LlmCompletion {
+    pub text: String,
+    pub input_tokens: u32,
+    pub output_tokens: u32,
+}
+
+impl LlmCompletion {
+    /// For backends that report no usage: roughly four characters per token,
+    /// close enough for English prose and code.
+    pub fn estimated(prompt: &str, text: String) -> Self {
+        let tokens = |s: &str| u32::try_from(s.chars().count().div_ceil(4)).unwrap_or(u32::MAX);
+        Self {
+            input_tokens: tokens(prompt),
+            output_tokens: tokens(&text),
+            text,
+        }
+    }
+}
+
+/// A backend answered with an error status.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LlmHttpError {
+    pub status: u16,
+    pub body: String,
+}
+
+impl LlmHttpError {
+    /// Rate limiting and server errors are usually transient.
+    pub fn is_retryable(&self) -> bool {
+        self.status == 429 || self.status >= 500
+    }
+}
+
+impl fmt::Display for LlmHttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "LLM request failed with status {}: {}",
+            self.status, self.body
+        )
+    }
+}
+
+impl std::error::Error for LlmHttpError {}
+
+/// A text-generation backend.
+#[async_trait]
+pub trait LlmProvider: Send + Sync + 'static {
+    /// Backend and model, e.g. `gemini/gemini-pro`, recorded with generated content.
+    fn name(&self) -> String;
+    /// Fails with an [`LlmHttpError`] when the backend answers with an error status.
+    async fn complete(&self, prompt: &str, options: &LlmOptions) -> Result<LlmCompletion>;
+}
+
+/// The provider selected by `LLM_PROVIDER`.
+pub fn llm_provider_from_config(config: &Config) -> Result<Arc<dyn LlmProvider>> {
+    Ok(match config.llm_provider {
+        LlmProviderKind::Gemini => Arc::new(GeminiProvider::new(
+            config.gemini_api_key.clone().unwrap_or_default(),
+            config.gemini_api_url.clone(),
+            config.gemini_model.clone(),
+        )),
+        LlmProviderKind::OpenAiCompatible => Arc::new(OpenAiCompatibleProvider::new(
+            config.openai_api_url.clone(),
+            config.openai_api_key.clone(),
+            config.openai_model.clone(),
+        )),
+        LlmProviderKind::Mock => {
+            tracing::warn!(
+                "Using the mock LLM provider; AI features return canned replies. \
+                 Set GEMINI_API_KEY or LLM_PROVIDER for a real backend"
+            );
+            match &config.llm_mock_fixtures {
+                Some(path) => Arc::new(MockLlmProvider::from_file(path)?),
+                None => Arc::new(MockLlmProvider::new()),
+            }
+        }
+    })
+}
@@ -0,0 +1,80 @@
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+use crate::infrastructure::external_services::AssetStorage;
+
+/// Files in a directory on the server's disk, one per key.
+#[derive(Debug, Clone)]
+pub struct LocalAssetStorage {
+    root: PathBuf,
+}
+
+impl LocalAssetStorage {
+    pub fn new(root: impl AsRef<Path>) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Refuses keys that could name a file outside the root.
+    fn path_for(&self, key: &str) -> Result<PathBuf> {
+        let valid = !key.is_empty()
+            && !key.starts_with('/')
+            && !key.contains('\\')
+            && key
+                .split('/')
+                .all(|segment| !segment.is_empty() && segment != "." && segment != "..");
+        if !valid {
+            return Err(anyhow!("Invalid asset key: {key}"));
+        }
+        Ok(self.root.join(key))
+    }
+}
+
+#[async_trait]
+impl AssetStorage for LocalAssetStorage {
+    fn name(&self) -> String {
+        format!("local:{}", self.root.display())
+    }
+
+    async fn put(&self, key: &str, data: Vec<u8>, _content_type: &str) -> Result<()> {
+        let path = self.path_for(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+
+        // Written aside and renamed, so readers never see a partial file
+        let temp_path = path.with_extension(format!("tmp-{}", Uuid::new_v4()));
+        tokio::fs::write(&temp_path, data)
+            .await
+            .with_context(|| format!("Failed to write {}", temp_path.display()))?;
+        if let Err(e) = tokio::fs::rename(&temp_path, &path).await {
+            let _ = tokio::fs::remove_file(&temp_path).await;
+            return Err(e).with_context(|| format!("Failed to write {}", path.display()));
+        }
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let path = self.path_for(key)?;
+        match tokio::fs::read(&path).await {
+            Ok(data) => Ok(Some(data)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        let path = self.path_for(key)?;
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e).with_context(|| format!("Failed to delete {}", path.display())),
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use crate::domain::entities::question_draft::GENERATED_QUESTION_TYPES;
+use crate::infrastructure::external_services::{
+    LlmCompletion, LlmHttpError, LlmOptions, LlmProvider, ResponseFormat,
+};
+
+/// Prompts kept for inspection; older ones are dropped.
+pub const MAX_RECORDED_PROMPTS: usize = 100;
+
+/// A canned reply for prompts containing `contains`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LlmFixture {
+    pub contains: String,
+    pub reply: String,
+}
+
+/// One valid question of each generated type, served for question requests.
+const QUESTION_FIXTURES: &str = r#"[
+    {"type": "mcq", "prompt_en": "Which keyword makes a variable binding mutable?", "prompt_id": "Kata kunci mana yang membuat binding variabel dapat diubah?",
+     "explanation_en": "Bindings are immutable unless declared with mut.", "explanation_id": "Binding tidak dapat diubah kecuali dideklarasikan dengan mut.",
+     "choices_en": ["let", "mut", "const", "static"], "choices_id": ["let", "mut", "const", "static"], "correct_index": 1},
+    {"type": "tf", "prompt_en": "Rust frees memory with a garbage collector.", "prompt_id": "Rust membebaskan memori dengan garbage collector.",
+     "explanation_en": "Memory is freed when its owner goes out of scope.", "explanation_id": "Memori dibebaskan saat pemiliknya keluar dari scope.",
+     "answer": false},
+    {"type": "fib", "prompt_en": "A shared reference to x is written ___x.", "prompt_id": "Referensi bersama ke x ditulis ___x.",
+     "explanation_en": "The & operator borrows a value.", "explanation_id": "Operator & meminjam sebuah nilai.",
+     "acceptable_answers": ["&"]},
+    {"type": "predict_output", "prompt_en": "What does this program print?", "prompt_id": "Apa yang dicetak program ini?",
+     "explanation_en": "Integer division truncates toward zero.", "explanation_id": "Pembagian bilangan bulat dibulatkan ke arah nol.",
+     "code": "fn main() {\n    println!(\"{}\", 7 / 2);\n}", "expected_stdout": "3"},
+    {"type": "code_fix", "prompt_en": "Which change makes this compile?", "prompt_id": "Perubahan mana yang membuat kode ini dapat dikompilasi?",
+     "explanation_en": "The vector must be mutable to push to it.", "explanation_id": "Vektor harus mutable agar bisa menambah elemen.",
+     "code": "fn main() {\n    let v = Vec::new();\n    v.push(1);\n}", "choices": ["let mut v = Vec::new();", "let v = vec![];", "v.insert(1);"], "correct_index": 0},
+    {"type": "code_write", "prompt_en": "Return the sum of two numbers.", "prompt_id": "Kembalikan jumlah dua bilangan.",
+     "explanation_en": "Add the parameters and return the result.", "explanation_id": "Jumlahkan parameter dan kembalikan hasilnya.",
+     "scaffold": "fn add(a: i32, b: i32) -> i32 {\n    todo!()\n}", "must_include": ["a + b"], "must_not_include": ["todo!"]}
+]"#;
+
+/// Built-in replies for the prompts `AiService` sends.
+fn default_fixtures() -> Vec<LlmFixture> {
+    [
+        ("respond with only 'TRUE'", "TRUE"),
+        (
+            "Explain this Rust code",
+            "This is a mock explanation. Set LLM_PROVIDER to gemini or openai for real answers.",
+        ),
+        (
+            "Explain the learner's mistake",
+            "This is a mock hint: compare your answer with what the question asks.",
+        ),
+        (
+            "Review this Rust code",
+            "This is a mock review. Set LLM_PROVIDER to gemini or openai for real suggestions.",
+        ),
+    ]
+    .into_iter()
+    .map(|(contains, reply)| LlmFixture {
+        contains: contains.to_string(),
+        reply: reply.to_string(),
+    })
+    .collect()
+}
+
+/// Deterministic offline provider for development and tests. Prompts are answered
+/// from fixtures, first match wins; question requests get one fixture question per
+/// requested type; translation requests get their text back, marked; anything else
+/// is echoed back. Token counts are estimated.
+#[derive(Debug, Clone)]
+pub struct MockLlmProvider {
+    fixtures: Vec<LlmFixture>,
+    prompts: Arc<Mutex<VecDeque<String>>>,
+    failures: Arc<Mutex<VecDeque<u16>>>,
+}
+
+impl MockLlmProvider {
+    pub fn new() -> Self {
+        Self::with_fixtures(Vec::new())
+    }
+
+    /// Custom fixtures take precedence over the built-in ones.
+    pub fn with_fixtures(fixtures: Vec<LlmFixture>) -> Self {
+        Self {
+            fixtures: fixtures.into_iter().chain(default_fixtures()).collect(),
+            prompts: Arc::new(Mutex::new(VecDeque::new())),
+            failures: Arc::new(Mutex::new(VecDeque::new())),
+        }
+    }
+
+    /// Loads fixtures from a JSON array of `{"contains": ..., "reply": ...}`.
+    pub fn from_file(path: &str) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read LLM fixtures from {path}"))?;
+        let fixtures: Vec<LlmFixture> =
+            serde_json::from_str(&content).context("Invalid LLM fixtures file")?;
+        Ok(Self::with_fixtures(fixtures))
+    }
+
+    /// The last [`MAX_RECORDED_PROMPTS`] prompts received, oldest first.
+    pub fn prompts(&self) -> Vec<String> {
+        self.prompts.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Makes the next request fail with `status`, as a rate-limited or broken
+    /// backend would. Queued failures are served in order.
+    pub fn fail_next(&self, status: u16) {
+        self.failures.lock().unwrap().push_back(status);
+    }
+
+    fn translation_reply(prompt: &str) -> Option<String> {
+        if !prompt.starts_with("Translate the following") {
+            return None;
+        }
+        let (_, text) = prompt.split_once("\n\nText:\n")?;
+        let text = text.split("\n\nYour previous translation").next()?;
+        Some(format!("[mock translation] {text}"))
+    }
+
+    fn question_reply(prompt: &str) -> Option<String> {
+        let requested: Vec<&str> = GENERATED_QUESTION_TYPES
+            .iter()
+            .copied()
+            .filter(|code| prompt.contains(&format!(r#"{{"type": "{code}""#)))
+            .collect();
+        if requested.is_empty() {
+            return None;
+        }
+        let fixtures: Vec<serde_json::Value> =
+            serde_json::from_str(QUESTION_FIXTURES).unwrap_or_default();
+        let questions: Vec<serde_json::Value> = fixtures
+            .into_iter()
+            .filter(|q| q["type"].as_str().is_some_and(|t| requested.contains(&t)))
+            .collect();
+        Some(serde_json::json!({ "questions": questions }).to_string())
+    }
+}
+
+impl Default for MockLlmProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl LlmProvider for MockLlmProvider {
+    fn name(&self) -> String {
+        "mock".to_string()
+    }
+
+    async fn complete(&self, prompt: &str, options: &LlmOptions) -> Result<LlmCompletion> {
+        {
+            let mut prompts = self.prompts.lock().unwrap();
+            if prompts.len() == MAX_RECORDED_PROMPTS {
+                prompts.pop_front();
+            }
+            prompts.push_back(prompt.to_string());
+        }
+        if let Some(status) = self.failures.lock().unwrap().pop_front() {
+            return Err(LlmHttpError {
+                status,
+                body: "mock failure".to_string(),
+            }
+            .into());
+        }
+
+        let reply =
+            if let Some(fixture) = self.fixtures.iter().find(|f| prompt.contains(&f.contains)) {
+                fixture.reply.clone()
+            } else if let Some(reply) = Self::translation_reply(prompt) {
+                reply
+            } else if let Some(reply) = (options.response_format == ResponseFormat::Json)
+                .then(|| Self::question_reply(prompt))
+                .flatten()
+            {
+                reply
+            } else {
+                format!("[mock] {}", prompt.lines().next().unwrap_or_default())
+            };
+        Ok(LlmCompletion::estimated(prompt, reply))
+    }
+}
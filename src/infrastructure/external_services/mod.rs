@@ -1,8 +1,24 @@
 // External services module
 // This module handles integrations with external services
 
+pub mod ai_service;
+pub mod asset_service;
+pub mod asset_storage;
 pub mod email_service;
-pub mod gemini_service;
+pub mod gemini_provider;
+pub mod llm_provider;
+pub mod local_asset_storage;
+pub mod mock_llm_provider;
+pub mod openai_compatible_provider;
+pub mod s3_asset_storage;
 
+pub use ai_service::*;
+pub use asset_service::*;
+pub use asset_storage::*;
 pub use email_service::*;
-pub use gemini_service::*;
+pub use gemini_provider::*;
+pub use llm_provider::*;
+pub use local_asset_storage::*;
+pub use mock_llm_provider::*;
+pub use openai_compatible_provider::*;
+pub use s3_asset_storage::*;
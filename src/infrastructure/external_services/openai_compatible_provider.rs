@@ -0,0 +1,145 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::infrastructure::external_services::{
+    LlmCompletion, LlmHttpError, LlmOptions, LlmProvider, ResponseFormat,
+};
+
+/// Any server speaking the OpenAI chat completions API, including local ones
+/// such as llama.cpp's server or Ollama (`http://localhost:11434/v1`).
+#[derive(Debug, Clone)]
+pub struct OpenAiCompatibleProvider {
+    client: Client,
+    api_url: String,
+    /// Local servers usually need none.
+    api_key: Option<String>,
+    model: String,
+}
+
+#[derive(Serialize)]
+struct ChatRequest<'a> {
+    model: &'a str,
+    messages: Vec<ChatMessage<'a>>,
+    temperature: f32,
+    max_tokens: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<ChatResponseFormat>,
+}
+
+#[derive(Serialize)]
+struct ChatMessage<'a> {
+    role: &'a str,
+    content: &'a str,
+}
+
+#[derive(Serialize)]
+struct ChatResponseFormat {
+    #[serde(rename = "type")]
+    format_type: &'static str,
+}
+
+#[derive(Deserialize)]
+struct ChatResponse {
+    choices: Vec<ChatChoice>,
+    /// Some local servers leave this out.
+    usage: Option<ChatUsage>,
+}
+
+#[derive(Deserialize)]
+struct ChatUsage {
+    prompt_tokens: u32,
+    completion_tokens: u32,
+}
+
+#[derive(Deserialize)]
+struct ChatChoice {
+    message: ChatReply,
+}
+
+#[derive(Deserialize)]
+struct ChatReply {
+    content: Option<String>,
+}
+
+impl OpenAiCompatibleProvider {
+    pub fn new(api_url: String, api_key: Option<String>, model: String) -> Self {
+        // Local models can be slow to answer on modest hardware
+        let client = Client::builder()
+            .timeout(Duration::from_secs(120))
+            .build()
+            .expect("Failed to create HTTP client");
+
+        Self {
+            client,
+            api_url: api_url.trim_end_matches('/').to_string(),
+            api_key,
+            model,
+        }
+    }
+}
+
+#[async_trait]
+impl LlmProvider for OpenAiCompatibleProvider {
+    fn name(&self) -> String {
+        format!("openai/{}", self.model)
+    }
+
+    async fn complete(&self, prompt: &str, options: &LlmOptions) -> Result<LlmCompletion> {
+        let request = ChatRequest {
+            model: &self.model,
+            messages: vec![ChatMessage {
+                role: "user",
+                content: prompt,
+            }],
+            temperature: options.temperature,
+            max_tokens: options.max_output_tokens,
+            response_format: (options.response_format == ResponseFormat::Json).then_some(
+                ChatResponseFormat {
+                    format_type: "json_object",
+                },
+            ),
+        };
+
+        let mut builder = self
+            .client
+            .post(format!("{}/chat/completions", self.api_url))
+            .json(&request);
+        if let Some(api_key) = &self.api_key {
+            builder = builder.bearer_auth(api_key);
+        }
+        let response = builder
+            .send()
+            .await
+            .context("Failed to send request to the LLM server")?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            return Err(LlmHttpError { status, body }.into());
+        }
+
+        let chat_response: ChatResponse = response
+            .json()
+            .await
+            .context("Failed to parse LLM server response")?;
+
+        let text = chat_response
+            .choices
+            .into_iter()
+            .next()
+            .and_then(|choice| choice.message.content)
+            .ok_or_else(|| anyhow::anyhow!("No response content from the LLM server"))?;
+
+        Ok(match chat_response.usage {
+            Some(usage) => LlmCompletion {
+                text,
+                input_tokens: usage.prompt_tokens,
+                output_tokens: usage.completion_tokens,
+            },
+            None => LlmCompletion::estimated(prompt, text),
+        })
+    }
+}
@@ -0,0 +1,218 @@
+use anyhow::{anyhow, Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::{Client, Method, StatusCode, Url};
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+
+use crate::infrastructure::external_services::AssetStorage;
+
+/// Key and region requests to an S3-compatible store are signed with.
+#[derive(Debug, Clone)]
+pub struct S3Credentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub region: String,
+}
+
+/// Objects in a bucket on AWS S3 or any compatible store (MinIO, R2, Ceph…),
+/// addressed path-style as `{endpoint}/{bucket}/{key}` so custom endpoints
+/// need no DNS setup.
+#[derive(Debug, Clone)]
+pub struct S3AssetStorage {
+    client: Client,
+    endpoint: Url,
+    bucket: String,
+    credentials: S3Credentials,
+}
+
+impl S3AssetStorage {
+    pub fn new(endpoint: String, bucket: String, credentials: S3Credentials) -> Result<Self> {
+        let endpoint = Url::parse(endpoint.trim_end_matches('/'))
+            .with_context(|| format!("Invalid S3 endpoint: {endpoint}"))?;
+        if endpoint.host_str().is_none() {
+            return Err(anyhow!("S3 endpoint has no host: {endpoint}"));
+        }
+        let client = Client::builder()
+            .timeout(Duration::from_secs(60))
+            .build()
+            .context("Failed to create HTTP client")?;
+
+        Ok(Self {
+            client,
+            endpoint,
+            bucket,
+            credentials,
+        })
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        key: &str,
+        body: Vec<u8>,
+        content_type: Option<&str>,
+    ) -> Result<reqwest::Response> {
+        let base_path = self.endpoint.path().trim_end_matches('/');
+        let path = uri_encode_path(&format!("{base_path}/{}/{key}", self.bucket));
+        let mut url = self.endpoint.clone();
+        url.set_path(&path);
+
+        let host = match (self.endpoint.host_str(), self.endpoint.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            (None, _) => unreachable!("endpoint host is checked in new"),
+        };
+        let now = Utc::now();
+        let payload_hash = hex::encode(Sha256::digest(&body));
+        let mut headers = vec![
+            ("host".to_string(), host),
+            ("x-amz-content-sha256".to_string(), payload_hash.clone()),
+            (
+                "x-amz-date".to_string(),
+                now.format(AMZ_DATE_FORMAT).to_string(),
+            ),
+        ];
+        if let Some(content_type) = content_type {
+            headers.push(("content-type".to_string(), content_type.to_string()));
+        }
+        let authorization = sign_s3_request(
+            &self.credentials,
+            method.as_str(),
+            &path,
+            &headers,
+            &payload_hash,
+            now,
+        );
+
+        let mut request = self.client.request(method, url).body(body);
+        // reqwest sets Host itself, from the same URL
+        for (name, value) in headers.iter().filter(|(name, _)| name != "host") {
+            request = request.header(name.as_str(), value.as_str());
+        }
+        request
+            .header("authorization", authorization)
+            .send()
+            .await
+            .context("S3 request failed")
+    }
+}
+
+async fn error_status(response: reqwest::Response, action: &str, key: &str) -> anyhow::Error {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    anyhow!("S3 {action} of {key} failed with status {status}: {body}")
+}
+
+#[async_trait]
+impl AssetStorage for S3AssetStorage {
+    fn name(&self) -> String {
+        format!("s3:{}/{}", self.endpoint, self.bucket)
+    }
+
+    async fn put(&self, key: &str, data: Vec<u8>, content_type: &str) -> Result<()> {
+        let response = self
+            .send(Method::PUT, key, data, Some(content_type))
+            .await?;
+        if !response.status().is_success() {
+            return Err(error_status(response, "upload", key).await);
+        }
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let response = self.send(Method::GET, key, Vec::new(), None).await?;
+        match response.status() {
+            StatusCode::NOT_FOUND => Ok(None),
+            status if status.is_success() => Ok(Some(response.bytes().await?.to_vec())),
+            _ => Err(error_status(response, "download", key).await),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        let response = self.send(Method::DELETE, key, Vec::new(), None).await?;
+        if !(response.status().is_success() || response.status() == StatusCode::NOT_FOUND) {
+            return Err(error_status(response, "delete", key).await);
+        }
+        Ok(())
+    }
+}
+
+const AMZ_DATE_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+/// The `Authorization` header for a request under AWS Signature Version 4.
+/// `path` must already be URI-encoded and `headers` must hold every header to
+/// sign, with lowercase names, including `host`, `x-amz-date` and
+/// `x-amz-content-sha256`. `payload_hash` is the hex SHA-256 of the body.
+pub fn sign_s3_request(
+    credentials: &S3Credentials,
+    method: &str,
+    path: &str,
+    headers: &[(String, String)],
+    payload_hash: &str,
+    now: DateTime<Utc>,
+) -> String {
+    let amz_date = now.format(AMZ_DATE_FORMAT).to_string();
+    let date = now.format("%Y%m%d").to_string();
+    let scope = format!("{date}/{}/s3/aws4_request", credentials.region);
+
+    let mut headers: Vec<(String, String)> = headers
+        .iter()
+        .map(|(name, value)| (name.to_lowercase(), value.trim().to_string()))
+        .collect();
+    headers.sort();
+    let canonical_headers: String = headers
+        .iter()
+        .map(|(name, value)| format!("{name}:{value}\n"))
+        .collect();
+    let signed_headers = headers
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>()
+        .join(";");
+
+    // No query string: every request here addresses a single object
+    let canonical_request =
+        format!("{method}\n{path}\n\n{canonical_headers}\n{signed_headers}\n{payload_hash}");
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}",
+        hex::encode(Sha256::digest(canonical_request.as_bytes()))
+    );
+
+    let signing_key = [credentials.region.as_str(), "s3", "aws4_request"]
+        .iter()
+        .fold(
+            hmac_sha256(
+                format!("AWS4{}", credentials.secret_access_key).as_bytes(),
+                &date,
+            ),
+            |key, part| hmac_sha256(&key, part),
+        );
+    let signature = hex::encode(hmac_sha256(&signing_key, &string_to_sign));
+
+    format!(
+        "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+        credentials.access_key_id
+    )
+}
+
+fn hmac_sha256(key: &[u8], message: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(message.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Percent-encodes everything in `path` but unreserved characters and `/`, as
+/// S3 expects in canonical requests.
+fn uri_encode_path(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~' | b'/') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
@@ -0,0 +1,58 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::entities::AccountAuditEvent;
+use crate::domain::repositories::AccountAuditRepository;
+use crate::domain::value_objects::UserId;
+use crate::infrastructure::database::models::AccountAuditEventModel;
+
+pub struct AccountAuditRepositoryImpl {
+    pool: PgPool,
+}
+
+impl AccountAuditRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AccountAuditRepository for AccountAuditRepositoryImpl {
+    async fn create(&self, event: &AccountAuditEvent) -> Result<()> {
+        let event_model = AccountAuditEventModel::from(event.clone());
+
+        sqlx::query!(
+            r#"
+            INSERT INTO account_audit_events (id, user_id, action, details, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+            event_model.id,
+            event_model.user_id,
+            event_model.action,
+            event_model.details,
+            event_model.created_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<AccountAuditEvent>> {
+        let event_models = sqlx::query_as!(
+            AccountAuditEventModel,
+            "SELECT * FROM account_audit_events WHERE user_id = $1 ORDER BY created_at ASC",
+            user_id.0
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(event_models
+            .into_iter()
+            .map(AccountAuditEvent::from)
+            .collect())
+    }
+}
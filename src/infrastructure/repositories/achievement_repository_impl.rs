@@ -0,0 +1,99 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::entities::{Achievement, UserAchievement};
+use crate::domain::repositories::AchievementRepository;
+use crate::domain::value_objects::UserId;
+use crate::infrastructure::database::models::{AchievementModel, UserAchievementModel};
+
+pub struct AchievementRepositoryImpl {
+    pool: PgPool,
+}
+
+impl AchievementRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AchievementRepository for AchievementRepositoryImpl {
+    async fn list_definitions(&self) -> Result<Vec<Achievement>> {
+        let achievement_models = sqlx::query_as!(
+            AchievementModel,
+            "SELECT * FROM achievements ORDER BY sort_order ASC, id ASC"
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(achievement_models
+            .into_iter()
+            .map(Achievement::from)
+            .collect())
+    }
+
+    async fn upsert_definition(&self, achievement: &Achievement) -> Result<()> {
+        let achievement_model = AchievementModel::from(achievement.clone());
+
+        sqlx::query!(
+            r#"
+            INSERT INTO achievements (id, title, description, icon, rule, xp_bonus, sort_order, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (id) DO UPDATE
+            SET title = EXCLUDED.title, description = EXCLUDED.description,
+                icon = EXCLUDED.icon, rule = EXCLUDED.rule, xp_bonus = EXCLUDED.xp_bonus,
+                sort_order = EXCLUDED.sort_order, updated_at = EXCLUDED.updated_at
+            "#,
+            achievement_model.id,
+            achievement_model.title,
+            achievement_model.description,
+            achievement_model.icon,
+            achievement_model.rule,
+            achievement_model.xp_bonus,
+            achievement_model.sort_order,
+            achievement_model.created_at,
+            achievement_model.updated_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn find_unlocked_by_user(&self, user_id: &UserId) -> Result<Vec<UserAchievement>> {
+        let user_achievement_models = sqlx::query_as!(
+            UserAchievementModel,
+            "SELECT * FROM user_achievements WHERE user_id = $1 ORDER BY unlocked_at ASC",
+            user_id.0
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(user_achievement_models
+            .into_iter()
+            .map(UserAchievement::from)
+            .collect())
+    }
+
+    async fn award(&self, user_achievement: &UserAchievement) -> Result<bool> {
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO user_achievements (user_id, achievement_id, unlocked_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_id, achievement_id) DO NOTHING
+            "#,
+            user_achievement.user_id.0,
+            user_achievement.achievement_id,
+            user_achievement.unlocked_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+}
@@ -0,0 +1,98 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::domain::entities::{AiCallRecord, AiUsageSummary};
+use crate::domain::repositories::AiUsageRepository;
+use crate::domain::value_objects::UserId;
+use crate::infrastructure::database::models::{AiCallModel, AiUsageSummaryModel};
+
+pub struct AiUsageRepositoryImpl {
+    pool: PgPool,
+}
+
+impl AiUsageRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AiUsageRepository for AiUsageRepositoryImpl {
+    async fn record(&self, call: &AiCallRecord) -> Result<()> {
+        let call_model = AiCallModel::from(call.clone());
+
+        sqlx::query!(
+            r#"
+            INSERT INTO ai_calls (
+                id, feature, user_id, provider, cached, succeeded,
+                input_tokens, output_tokens, cost_micros, latency_ms, created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            "#,
+            call_model.id,
+            call_model.feature,
+            call_model.user_id,
+            call_model.provider,
+            call_model.cached,
+            call_model.succeeded,
+            call_model.input_tokens,
+            call_model.output_tokens,
+            call_model.cost_micros,
+            call_model.latency_ms,
+            call_model.created_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn daily_summary(&self, since: DateTime<Utc>) -> Result<Vec<AiUsageSummary>> {
+        let summary_models = sqlx::query_as!(
+            AiUsageSummaryModel,
+            r#"
+            SELECT
+                (created_at AT TIME ZONE 'UTC')::DATE AS "day!",
+                feature,
+                COUNT(*) AS "calls!",
+                COUNT(*) FILTER (WHERE cached) AS "cached_calls!",
+                COUNT(*) FILTER (WHERE NOT succeeded) AS "failed_calls!",
+                COALESCE(SUM(input_tokens), 0)::BIGINT AS "input_tokens!",
+                COALESCE(SUM(output_tokens), 0)::BIGINT AS "output_tokens!",
+                COALESCE(SUM(cost_micros), 0)::BIGINT AS "cost_micros!"
+            FROM ai_calls
+            WHERE created_at >= $1
+            GROUP BY 1, feature
+            ORDER BY 1 DESC, feature
+            "#,
+            since
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        summary_models
+            .into_iter()
+            .map(AiUsageSummary::try_from)
+            .collect()
+    }
+
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<AiCallRecord>> {
+        let call_models = sqlx::query_as!(
+            AiCallModel,
+            "SELECT * FROM ai_calls WHERE user_id = $1 ORDER BY created_at DESC",
+            user_id.0
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        call_models
+            .into_iter()
+            .map(AiCallRecord::try_from)
+            .collect()
+    }
+}
@@ -0,0 +1,113 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::entities::ApiKey;
+use crate::domain::repositories::ApiKeyRepository;
+use crate::domain::value_objects::{ApiKeyId, UserId};
+use crate::infrastructure::database::models::ApiKeyModel;
+
+pub struct ApiKeyRepositoryImpl {
+    pool: PgPool,
+}
+
+impl ApiKeyRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for ApiKeyRepositoryImpl {
+    async fn create(&self, api_key: &ApiKey) -> Result<()> {
+        let api_key_model = ApiKeyModel::from(api_key.clone());
+
+        sqlx::query!(
+            r#"
+            INSERT INTO api_keys (id, user_id, name, prefix, secret_hash, scopes, last_used_at, revoked_at, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+            api_key_model.id,
+            api_key_model.user_id,
+            api_key_model.name,
+            api_key_model.prefix,
+            api_key_model.secret_hash,
+            api_key_model.scopes,
+            api_key_model.last_used_at,
+            api_key_model.revoked_at,
+            api_key_model.created_at,
+            api_key_model.updated_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &ApiKeyId) -> Result<Option<ApiKey>> {
+        let api_key_model =
+            sqlx::query_as!(ApiKeyModel, "SELECT * FROM api_keys WHERE id = $1", id.0)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(api_key_model.map(ApiKey::from))
+    }
+
+    async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>> {
+        let api_key_model = sqlx::query_as!(
+            ApiKeyModel,
+            "SELECT * FROM api_keys WHERE prefix = $1",
+            prefix
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(api_key_model.map(ApiKey::from))
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<ApiKey>> {
+        let api_key_models = sqlx::query_as!(
+            ApiKeyModel,
+            "SELECT * FROM api_keys WHERE user_id = $1 ORDER BY created_at DESC",
+            user_id.0
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(api_key_models.into_iter().map(ApiKey::from).collect())
+    }
+
+    async fn update(&self, api_key: &ApiKey) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE api_keys
+            SET name = $2, scopes = $3, last_used_at = $4, revoked_at = $5, updated_at = $6
+            WHERE id = $1
+            "#,
+            api_key.id.0,
+            api_key.name,
+            serde_json::to_value(&api_key.scopes).unwrap_or(serde_json::Value::Array(vec![])),
+            api_key.last_used_at,
+            api_key.revoked_at,
+            api_key.updated_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: &ApiKeyId) -> Result<()> {
+        sqlx::query!("DELETE FROM api_keys WHERE id = $1", id.0)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+}
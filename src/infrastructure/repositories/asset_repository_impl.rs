@@ -0,0 +1,83 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::entities::Asset;
+use crate::domain::repositories::AssetRepository;
+use crate::domain::value_objects::AssetId;
+use crate::infrastructure::database::models::AssetModel;
+
+pub struct AssetRepositoryImpl {
+    pool: PgPool,
+}
+
+impl AssetRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AssetRepository for AssetRepositoryImpl {
+    async fn create(&self, asset: &Asset) -> Result<()> {
+        let model = AssetModel::from(asset.clone());
+
+        sqlx::query!(
+            r#"
+            INSERT INTO assets (
+                id, sha256, content_type, size_bytes, original_filename, storage_key,
+                thumbnail_key, width, height, uploaded_by, created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            "#,
+            model.id,
+            model.sha256,
+            model.content_type,
+            model.size_bytes,
+            model.original_filename,
+            model.storage_key,
+            model.thumbnail_key,
+            model.width,
+            model.height,
+            model.uploaded_by,
+            model.created_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &AssetId) -> Result<Option<Asset>> {
+        let model = sqlx::query_as!(AssetModel, "SELECT * FROM assets WHERE id = $1", id.0)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        model.map(Asset::try_from).transpose()
+    }
+
+    async fn find_by_sha256(&self, sha256: &str) -> Result<Option<Asset>> {
+        let model = sqlx::query_as!(AssetModel, "SELECT * FROM assets WHERE sha256 = $1", sha256)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        model.map(Asset::try_from).transpose()
+    }
+
+    async fn list(&self, limit: u32, offset: u32) -> Result<Vec<Asset>> {
+        let models = sqlx::query_as!(
+            AssetModel,
+            "SELECT * FROM assets ORDER BY created_at DESC LIMIT $1 OFFSET $2",
+            limit as i64,
+            offset as i64
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        models.into_iter().map(Asset::try_from).collect()
+    }
+}
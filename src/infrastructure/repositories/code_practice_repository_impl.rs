@@ -22,10 +22,8 @@ impl CodePracticeRepository for CodePracticeRepositoryImpl {
     async fn create(&self, code_practice: &CodePractice) -> Result<()> {
         let code_practice_model = CodePracticeModel {
             id: code_practice.id.0,
-            title_en: code_practice.title.en.clone(),
-            title_id: code_practice.title.id.clone(),
-            description_en: code_practice.description.en.clone(),
-            description_id: code_practice.description.id.clone(),
+            title: serde_json::to_value(&code_practice.title)?,
+            description: serde_json::to_value(&code_practice.description)?,
             initial_code: code_practice.initial_code.clone(),
             expected_output: code_practice.expected_output.clone(),
             solution: code_practice.solution.clone(),
@@ -42,14 +40,12 @@ impl CodePracticeRepository for CodePracticeRepositoryImpl {
 
         sqlx::query!(
             r#"
-            INSERT INTO code_practices (id, title_en, title_id, description_en, description_id, initial_code, expected_output, solution, hints, difficulty, category, lesson_id, topic_id, points, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+            INSERT INTO code_practices (id, title, description, initial_code, expected_output, solution, hints, difficulty, category, lesson_id, topic_id, points, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
             "#,
             code_practice_model.id,
-            code_practice_model.title_en,
-            code_practice_model.title_id,
-            code_practice_model.description_en,
-            code_practice_model.description_id,
+            code_practice_model.title,
+            code_practice_model.description,
             code_practice_model.initial_code,
             code_practice_model.expected_output,
             code_practice_model.solution,
@@ -134,16 +130,14 @@ impl CodePracticeRepository for CodePracticeRepositoryImpl {
         sqlx::query!(
             r#"
             UPDATE code_practices 
-            SET title_en = $2, title_id = $3, description_en = $4, description_id = $5, 
-                initial_code = $6, expected_output = $7, solution = $8, hints = $9, 
-                difficulty = $10, category = $11, lesson_id = $12, topic_id = $13, points = $14, updated_at = $15
+            SET title = $2, description = $3, initial_code = $4, expected_output = $5, solution = $6,
+                hints = $7, difficulty = $8, category = $9, lesson_id = $10, topic_id = $11, points = $12,
+                updated_at = $13
             WHERE id = $1
             "#,
             code_practice.id.0,
-            code_practice.title.en,
-            code_practice.title.id,
-            code_practice.description.en,
-            code_practice.description.id,
+            serde_json::to_value(&code_practice.title)?,
+            serde_json::to_value(&code_practice.description)?,
             code_practice.initial_code,
             code_practice.expected_output,
             code_practice.solution,
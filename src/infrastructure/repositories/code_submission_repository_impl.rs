@@ -0,0 +1,121 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::entities::CodeSubmission;
+use crate::domain::repositories::CodeSubmissionRepository;
+use crate::domain::value_objects::{CodePracticeId, CodeSubmissionId, UserId};
+use crate::infrastructure::database::models::CodeSubmissionModel;
+
+pub struct CodeSubmissionRepositoryImpl {
+    pool: PgPool,
+}
+
+impl CodeSubmissionRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CodeSubmissionRepository for CodeSubmissionRepositoryImpl {
+    async fn create(&self, submission: &CodeSubmission) -> Result<()> {
+        let submission_model = CodeSubmissionModel::from(submission.clone());
+
+        sqlx::query!(
+            r#"
+            INSERT INTO code_submissions (id, user_id, code_practice_id, code, verdict, runner_output, submitted_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+            submission_model.id,
+            submission_model.user_id,
+            submission_model.code_practice_id,
+            submission_model.code,
+            submission_model.verdict,
+            submission_model.runner_output,
+            submission_model.submitted_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &CodeSubmissionId) -> Result<Option<CodeSubmission>> {
+        let submission_model = sqlx::query_as!(
+            CodeSubmissionModel,
+            "SELECT * FROM code_submissions WHERE id = $1",
+            id.0
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(submission_model.map(CodeSubmission::from))
+    }
+
+    async fn list_by_user_and_practice(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<Vec<CodeSubmission>> {
+        let submission_models = sqlx::query_as!(
+            CodeSubmissionModel,
+            r#"
+            SELECT * FROM code_submissions
+            WHERE user_id = $1 AND code_practice_id = $2
+            ORDER BY submitted_at DESC
+            "#,
+            user_id.0,
+            code_practice_id.0
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(submission_models
+            .into_iter()
+            .map(CodeSubmission::from)
+            .collect())
+    }
+
+    async fn list_by_practice(
+        &self,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<Vec<CodeSubmission>> {
+        let submission_models = sqlx::query_as!(
+            CodeSubmissionModel,
+            r#"
+            SELECT * FROM code_submissions
+            WHERE code_practice_id = $1
+            ORDER BY submitted_at DESC
+            "#,
+            code_practice_id.0
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(submission_models
+            .into_iter()
+            .map(CodeSubmission::from)
+            .collect())
+    }
+
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<CodeSubmission>> {
+        let submission_models = sqlx::query_as!(
+            CodeSubmissionModel,
+            "SELECT * FROM code_submissions WHERE user_id = $1 ORDER BY submitted_at DESC",
+            user_id.0
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(submission_models
+            .into_iter()
+            .map(CodeSubmission::from)
+            .collect())
+    }
+}
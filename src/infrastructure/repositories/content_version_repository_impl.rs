@@ -0,0 +1,129 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::{ContentKind, ContentVersion};
+use crate::domain::repositories::ContentVersionRepository;
+use crate::domain::value_objects::ContentVersionId;
+use crate::infrastructure::database::models::ContentVersionModel;
+
+pub struct ContentVersionRepositoryImpl {
+    pool: PgPool,
+}
+
+impl ContentVersionRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ContentVersionRepository for ContentVersionRepositoryImpl {
+    async fn create(&self, version: &ContentVersion) -> Result<u32> {
+        let model = ContentVersionModel::from(version.clone());
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        // Versions of one item are recorded one at a time, so each reads the
+        // number the previous one committed
+        sqlx::query!(
+            "SELECT pg_advisory_xact_lock(hashtext($1 || ':' || $2::UUID::text))",
+            model.content_kind,
+            model.content_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        let number = sqlx::query_scalar!(
+            r#"
+            INSERT INTO content_versions (
+                id, content_kind, content_id, version, snapshot, author_id, author_name,
+                change_note, created_at
+            )
+            SELECT $1, $2::VARCHAR, $3::UUID, COALESCE(MAX(version), 0) + 1, $4, $5, $6, $7, $8
+            FROM content_versions
+            WHERE content_kind = $2 AND content_id = $3
+            RETURNING version
+            "#,
+            model.id,
+            model.content_kind,
+            model.content_id,
+            model.snapshot,
+            model.author_id,
+            model.author_name,
+            model.change_note,
+            model.created_at
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(number as u32)
+    }
+
+    async fn delete(&self, id: &ContentVersionId) -> Result<()> {
+        sqlx::query!("DELETE FROM content_versions WHERE id = $1", id.0)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn list_for_content(
+        &self,
+        kind: ContentKind,
+        content_id: &str,
+    ) -> Result<Vec<ContentVersion>> {
+        let content_id = Uuid::parse_str(content_id)?;
+        let models = sqlx::query_as!(
+            ContentVersionModel,
+            r#"
+            SELECT * FROM content_versions
+            WHERE content_kind = $1 AND content_id = $2
+            ORDER BY version DESC
+            "#,
+            kind.as_str(),
+            content_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        models.into_iter().map(ContentVersion::try_from).collect()
+    }
+
+    async fn find(
+        &self,
+        kind: ContentKind,
+        content_id: &str,
+        version: u32,
+    ) -> Result<Option<ContentVersion>> {
+        let content_id = Uuid::parse_str(content_id)?;
+        let model = sqlx::query_as!(
+            ContentVersionModel,
+            r#"
+            SELECT * FROM content_versions
+            WHERE content_kind = $1 AND content_id = $2 AND version = $3
+            "#,
+            kind.as_str(),
+            content_id,
+            version as i32
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        model.map(ContentVersion::try_from).transpose()
+    }
+}
@@ -0,0 +1,75 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::entities::HintUsage;
+use crate::domain::repositories::HintUsageRepository;
+use crate::domain::value_objects::{CodePracticeId, UserId};
+use crate::infrastructure::database::models::HintUsageModel;
+
+pub struct HintUsageRepositoryImpl {
+    pool: PgPool,
+}
+
+impl HintUsageRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl HintUsageRepository for HintUsageRepositoryImpl {
+    async fn find(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<Option<HintUsage>> {
+        let usage_model = sqlx::query_as!(
+            HintUsageModel,
+            "SELECT * FROM code_practice_hint_usage WHERE user_id = $1 AND code_practice_id = $2",
+            user_id.0,
+            code_practice_id.0
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(usage_model.map(HintUsage::from))
+    }
+
+    async fn save(&self, usage: &HintUsage) -> Result<()> {
+        let usage_model = HintUsageModel::from(usage.clone());
+
+        sqlx::query!(
+            r#"
+            INSERT INTO code_practice_hint_usage (user_id, code_practice_id, hints_revealed, updated_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (user_id, code_practice_id) DO UPDATE SET
+                hints_revealed = EXCLUDED.hints_revealed,
+                updated_at = EXCLUDED.updated_at
+            "#,
+            usage_model.user_id,
+            usage_model.code_practice_id,
+            usage_model.hints_revealed,
+            usage_model.updated_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<HintUsage>> {
+        let usage_models = sqlx::query_as!(
+            HintUsageModel,
+            "SELECT * FROM code_practice_hint_usage WHERE user_id = $1 ORDER BY updated_at DESC",
+            user_id.0
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(usage_models.into_iter().map(HintUsage::from).collect())
+    }
+}
@@ -2,6 +2,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 use sqlx::PgPool;
 
+use crate::domain::entities::user::ANONYMIZED_DISPLAY_NAME;
 use crate::domain::entities::LeaderboardEntry;
 use crate::domain::repositories::LeaderboardRepository;
 use crate::domain::value_objects::UserId;
@@ -25,7 +26,8 @@ impl LeaderboardRepository for LeaderboardRepositoryImpl {
             r#"
             SELECT
                 u.id as user_id,
-                u.display_name,
+                CASE WHEN u.deletion_scheduled_for IS NULL THEN u.display_name
+                     ELSE $2 END as "display_name!",
                 COALESCE(up.total_xp, 0) as "total_xp!",
                 COALESCE(up.total_xp, 0) as "xp_this_week!",
                 ROW_NUMBER() OVER (ORDER BY COALESCE(up.total_xp, 0) DESC) as "rank!"
@@ -34,7 +36,8 @@ impl LeaderboardRepository for LeaderboardRepositoryImpl {
             ORDER BY COALESCE(up.total_xp, 0) DESC
             LIMIT $1
             "#,
-            limit as i64
+            limit as i64,
+            ANONYMIZED_DISPLAY_NAME
         )
         .fetch_all(&self.pool)
         .await
@@ -52,7 +55,8 @@ impl LeaderboardRepository for LeaderboardRepositoryImpl {
             r#"
             SELECT
                 u.id as user_id,
-                u.display_name,
+                CASE WHEN u.deletion_scheduled_for IS NULL THEN u.display_name
+                     ELSE $2 END as "display_name!",
                 COALESCE(up.total_xp, 0) as "total_xp!",
                 COALESCE(up.total_xp, 0) as "xp_this_week!",
                 ROW_NUMBER() OVER (ORDER BY COALESCE(up.total_xp, 0) DESC) as "rank!"
@@ -61,7 +65,8 @@ impl LeaderboardRepository for LeaderboardRepositoryImpl {
             ORDER BY COALESCE(up.total_xp, 0) DESC
             LIMIT $1
             "#,
-            limit as i64
+            limit as i64,
+            ANONYMIZED_DISPLAY_NAME
         )
         .fetch_all(&self.pool)
         .await
@@ -1,10 +1,12 @@
 use anyhow::Result;
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres, Transaction};
+use std::collections::HashMap;
+use uuid::Uuid;
 
 use crate::domain::entities::Lesson;
 use crate::domain::repositories::LessonRepository;
-use crate::domain::value_objects::{LessonId, TopicId};
+use crate::domain::value_objects::{LessonId, QuestionId, TopicId};
 use crate::infrastructure::database::models::LessonModel;
 
 pub struct LessonRepositoryImpl {
@@ -15,6 +17,59 @@ impl LessonRepositoryImpl {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    /// Fills in `Lesson.questions`, which live in `lesson_questions`.
+    async fn attach_questions(&self, lessons: &mut [Lesson]) -> Result<()> {
+        if lessons.is_empty() {
+            return Ok(());
+        }
+
+        let lesson_ids: Vec<Uuid> = lessons.iter().map(|lesson| lesson.id.0).collect();
+        let rows = sqlx::query!(
+            "SELECT lesson_id, question_id FROM lesson_questions WHERE lesson_id = ANY($1) ORDER BY position ASC",
+            &lesson_ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        let mut questions_by_lesson: HashMap<Uuid, Vec<QuestionId>> = HashMap::new();
+        for row in rows {
+            questions_by_lesson
+                .entry(row.lesson_id)
+                .or_default()
+                .push(QuestionId::from(row.question_id));
+        }
+        for lesson in lessons.iter_mut() {
+            lesson.questions = questions_by_lesson.remove(&lesson.id.0).unwrap_or_default();
+        }
+
+        Ok(())
+    }
+
+    async fn replace_questions(tx: &mut Transaction<'_, Postgres>, lesson: &Lesson) -> Result<()> {
+        sqlx::query!(
+            "DELETE FROM lesson_questions WHERE lesson_id = $1",
+            lesson.id.0
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        for (position, question_id) in lesson.questions.iter().enumerate() {
+            sqlx::query!(
+                "INSERT INTO lesson_questions (lesson_id, question_id, position) VALUES ($1, $2, $3)",
+                lesson.id.0,
+                question_id.0,
+                position as i32
+            )
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -22,37 +77,46 @@ impl LessonRepository for LessonRepositoryImpl {
     async fn create(&self, lesson: &Lesson) -> Result<()> {
         let lesson_model = LessonModel {
             id: lesson.id.0,
-            title_en: lesson.title.en.clone(),
-            title_id: lesson.title.id.clone(),
+            title: serde_json::to_value(&lesson.title)?,
             topic_id: lesson.topic_id.0,
-            summary_en: lesson.summary.en.clone(),
-            summary_id: lesson.summary.id.clone(),
+            summary: serde_json::to_value(&lesson.summary)?,
+            blocks: serde_json::to_value(&lesson.blocks)?,
             attribution_url: lesson.attribution_url.clone(),
             order: lesson.order as i32,
             created_at: lesson.created_at,
             updated_at: lesson.updated_at,
         };
 
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
         sqlx::query!(
             r#"
-            INSERT INTO lessons (id, title_en, title_id, topic_id, summary_en, summary_id, attribution_url, "order", created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            INSERT INTO lessons (id, title, topic_id, summary, blocks, attribution_url, "order", created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             "#,
             lesson_model.id,
-            lesson_model.title_en,
-            lesson_model.title_id,
+            lesson_model.title,
             lesson_model.topic_id,
-            lesson_model.summary_en,
-            lesson_model.summary_id,
+            lesson_model.summary,
+            lesson_model.blocks,
             lesson_model.attribution_url,
             lesson_model.order,
             lesson_model.created_at,
             lesson_model.updated_at
         )
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await
         .map_err(|e| anyhow::anyhow!("Database error: {}", e))?;
 
+        Self::replace_questions(&mut tx, lesson).await?;
+        tx.commit()
+            .await
+            .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
         Ok(())
     }
 
@@ -63,7 +127,9 @@ impl LessonRepository for LessonRepositoryImpl {
                 .await
                 .map_err(|e| anyhow::anyhow!("Database error: {}", e))?;
 
-        Ok(lesson_model.map(|m| Lesson::from(m)))
+        let mut lessons: Vec<Lesson> = lesson_model.into_iter().map(Lesson::from).collect();
+        self.attach_questions(&mut lessons).await?;
+        Ok(lessons.pop())
     }
 
     async fn find_by_topic_id(&self, topic_id: &TopicId) -> Result<Vec<Lesson>> {
@@ -76,31 +142,43 @@ impl LessonRepository for LessonRepositoryImpl {
         .await
         .map_err(|e| anyhow::anyhow!("Database error: {}", e))?;
 
-        Ok(lesson_models.into_iter().map(Lesson::from).collect())
+        let mut lessons: Vec<Lesson> = lesson_models.into_iter().map(Lesson::from).collect();
+        self.attach_questions(&mut lessons).await?;
+        Ok(lessons)
     }
 
     async fn update(&self, lesson: &Lesson) -> Result<()> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
         sqlx::query!(
             r#"
             UPDATE lessons 
-            SET title_en = $2, title_id = $3, topic_id = $4, summary_en = $5, summary_id = $6, 
-                attribution_url = $7, "order" = $8, updated_at = $9
+            SET title = $2, topic_id = $3, summary = $4, blocks = $5, attribution_url = $6,
+                "order" = $7, updated_at = $8
             WHERE id = $1
             "#,
             lesson.id.0,
-            lesson.title.en,
-            lesson.title.id,
+            serde_json::to_value(&lesson.title)?,
             lesson.topic_id.0,
-            lesson.summary.en,
-            lesson.summary.id,
+            serde_json::to_value(&lesson.summary)?,
+            serde_json::to_value(&lesson.blocks)?,
             lesson.attribution_url,
             lesson.order as i32,
             lesson.updated_at
         )
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await
         .map_err(|e| anyhow::anyhow!("Database error: {}", e))?;
 
+        Self::replace_questions(&mut tx, lesson).await?;
+        tx.commit()
+            .await
+            .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
         Ok(())
     }
 
@@ -124,6 +202,8 @@ impl LessonRepository for LessonRepositoryImpl {
         .await
         .map_err(|e| anyhow::anyhow!("Database error: {}", e))?;
 
-        Ok(lesson_models.into_iter().map(Lesson::from).collect())
+        let mut lessons: Vec<Lesson> = lesson_models.into_iter().map(Lesson::from).collect();
+        self.attach_questions(&mut lessons).await?;
+        Ok(lessons)
     }
 }
@@ -0,0 +1,94 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::entities::LessonSession;
+use crate::domain::repositories::LessonSessionRepository;
+use crate::domain::value_objects::{LessonSessionId, UserId};
+use crate::infrastructure::database::models::LessonSessionModel;
+
+pub struct LessonSessionRepositoryImpl {
+    pool: PgPool,
+}
+
+impl LessonSessionRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LessonSessionRepository for LessonSessionRepositoryImpl {
+    async fn create(&self, session: &LessonSession) -> Result<()> {
+        let session_model = LessonSessionModel::from(session.clone());
+
+        sqlx::query!(
+            r#"
+            INSERT INTO lesson_sessions (id, user_id, lesson_id, question_ids, answers, started_at, finished_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+            session_model.id,
+            session_model.user_id,
+            session_model.lesson_id,
+            session_model.question_ids,
+            session_model.answers,
+            session_model.started_at,
+            session_model.finished_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &LessonSessionId) -> Result<Option<LessonSession>> {
+        let session_model = sqlx::query_as!(
+            LessonSessionModel,
+            "SELECT * FROM lesson_sessions WHERE id = $1",
+            id.0
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(session_model.map(LessonSession::from))
+    }
+
+    async fn update(&self, session: &LessonSession) -> Result<bool> {
+        let session_model = LessonSessionModel::from(session.clone());
+
+        let result = sqlx::query!(
+            r#"
+            UPDATE lesson_sessions
+            SET answers = $2, finished_at = $3, revision = revision + 1
+            WHERE id = $1 AND revision = $4
+            "#,
+            session_model.id,
+            session_model.answers,
+            session_model.finished_at,
+            session_model.revision
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<LessonSession>> {
+        let session_models = sqlx::query_as!(
+            LessonSessionModel,
+            "SELECT * FROM lesson_sessions WHERE user_id = $1 ORDER BY started_at DESC",
+            user_id.0
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(session_models
+            .into_iter()
+            .map(LessonSession::from)
+            .collect())
+    }
+}
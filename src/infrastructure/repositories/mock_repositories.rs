@@ -1,9 +1,11 @@
+use crate::domain::entities::user_progress::LessonResult;
 use crate::domain::entities::*;
 use crate::domain::repositories::*;
 use crate::domain::value_objects::*;
 use crate::infrastructure::database::mock_connection::MockDatabaseConnection;
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDate;
 use std::sync::Arc;
 
 // Mock User Repository
@@ -42,6 +44,10 @@ impl UserRepository for MockUserRepositoryImpl {
     async fn list(&self, limit: u32, offset: u32) -> Result<Vec<User>> {
         self.db.list_users(limit, offset).await
     }
+
+    async fn find_due_for_deletion(&self, now: chrono::DateTime<chrono::Utc>) -> Result<Vec<User>> {
+        self.db.find_users_due_for_deletion(now).await
+    }
 }
 
 // Mock Topic Repository
@@ -141,6 +147,10 @@ impl QuestionRepository for MockQuestionRepositoryImpl {
         self.db.find_question_by_id(id).await
     }
 
+    async fn find_by_ids(&self, ids: &[QuestionId]) -> Result<Vec<Question>> {
+        self.db.find_questions_by_ids(ids).await
+    }
+
     async fn find_by_topic_id(&self, topic_id: &TopicId) -> Result<Vec<Question>> {
         self.db.find_questions_by_topic_id(topic_id).await
     }
@@ -233,6 +243,26 @@ impl UserProgressRepository for MockUserProgressRepositoryImpl {
         self.db.update_user_progress(progress).await
     }
 
+    async fn update_if_unchanged(
+        &self,
+        progress: &UserProgress,
+        loaded_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<bool> {
+        self.db
+            .update_user_progress_if_unchanged(progress, loaded_at)
+            .await
+    }
+
+    async fn update_streak_if_unchanged(
+        &self,
+        progress: &UserProgress,
+        loaded_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<bool> {
+        self.db
+            .update_user_streak_if_unchanged(progress, loaded_at)
+            .await
+    }
+
     async fn delete(&self, user_id: &UserId) -> Result<()> {
         self.db.delete_user_progress(user_id).await
     }
@@ -241,6 +271,22 @@ impl UserProgressRepository for MockUserProgressRepositoryImpl {
         // For demo purposes, return empty list
         Ok(Vec::new())
     }
+
+    async fn list_running_streaks(
+        &self,
+        after: Option<&UserId>,
+        limit: u32,
+    ) -> Result<Vec<UserProgress>> {
+        self.db.list_running_streaks(after, limit).await
+    }
+
+    async fn record_lesson_result(&self, user_id: &UserId, result: &LessonResult) -> Result<()> {
+        self.db.record_lesson_result(user_id, result).await
+    }
+
+    async fn find_lesson_results(&self, user_id: &UserId) -> Result<Vec<LessonResult>> {
+        self.db.find_lesson_results(user_id).await
+    }
 }
 
 // Mock Leaderboard Repository
@@ -268,3 +314,574 @@ impl LeaderboardRepository for MockLeaderboardRepositoryImpl {
         self.db.get_user_rank(user_id).await
     }
 }
+
+// Mock API Key Repository
+pub struct MockApiKeyRepositoryImpl {
+    db: Arc<MockDatabaseConnection>,
+}
+
+impl MockApiKeyRepositoryImpl {
+    pub fn new(db: Arc<MockDatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for MockApiKeyRepositoryImpl {
+    async fn create(&self, api_key: &ApiKey) -> Result<()> {
+        self.db.create_api_key(api_key).await
+    }
+
+    async fn find_by_id(&self, id: &ApiKeyId) -> Result<Option<ApiKey>> {
+        self.db.find_api_key_by_id(id).await
+    }
+
+    async fn find_by_prefix(&self, prefix: &str) -> Result<Option<ApiKey>> {
+        self.db.find_api_key_by_prefix(prefix).await
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<ApiKey>> {
+        self.db.find_api_keys_by_user_id(user_id).await
+    }
+
+    async fn update(&self, api_key: &ApiKey) -> Result<()> {
+        self.db.update_api_key(api_key).await
+    }
+
+    async fn delete(&self, id: &ApiKeyId) -> Result<()> {
+        self.db.delete_api_key(id).await
+    }
+}
+
+// Mock Account Audit Repository
+pub struct MockAccountAuditRepositoryImpl {
+    db: Arc<MockDatabaseConnection>,
+}
+
+impl MockAccountAuditRepositoryImpl {
+    pub fn new(db: Arc<MockDatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AccountAuditRepository for MockAccountAuditRepositoryImpl {
+    async fn create(&self, event: &AccountAuditEvent) -> Result<()> {
+        self.db.create_account_audit_event(event).await
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<AccountAuditEvent>> {
+        self.db.find_account_audit_events_by_user_id(user_id).await
+    }
+}
+
+// Mock Achievement Repository
+pub struct MockAchievementRepositoryImpl {
+    db: Arc<MockDatabaseConnection>,
+}
+
+impl MockAchievementRepositoryImpl {
+    pub fn new(db: Arc<MockDatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AchievementRepository for MockAchievementRepositoryImpl {
+    async fn list_definitions(&self) -> Result<Vec<Achievement>> {
+        self.db.list_achievements().await
+    }
+
+    async fn upsert_definition(&self, achievement: &Achievement) -> Result<()> {
+        self.db.upsert_achievement(achievement).await
+    }
+
+    async fn find_unlocked_by_user(&self, user_id: &UserId) -> Result<Vec<UserAchievement>> {
+        self.db.find_user_achievements(user_id).await
+    }
+
+    async fn award(&self, user_achievement: &UserAchievement) -> Result<bool> {
+        self.db.award_achievement(user_achievement).await
+    }
+}
+
+// Mock Lesson Session Repository
+pub struct MockLessonSessionRepositoryImpl {
+    db: Arc<MockDatabaseConnection>,
+}
+
+impl MockLessonSessionRepositoryImpl {
+    pub fn new(db: Arc<MockDatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl LessonSessionRepository for MockLessonSessionRepositoryImpl {
+    async fn create(&self, session: &LessonSession) -> Result<()> {
+        self.db.create_lesson_session(session).await
+    }
+
+    async fn find_by_id(&self, id: &LessonSessionId) -> Result<Option<LessonSession>> {
+        self.db.find_lesson_session_by_id(id).await
+    }
+
+    async fn update(&self, session: &LessonSession) -> Result<bool> {
+        self.db.update_lesson_session(session).await
+    }
+
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<LessonSession>> {
+        self.db.find_lesson_sessions_by_user(user_id).await
+    }
+}
+
+// Mock Prerequisite Repository
+pub struct MockPrerequisiteRepositoryImpl {
+    db: Arc<MockDatabaseConnection>,
+}
+
+impl MockPrerequisiteRepositoryImpl {
+    pub fn new(db: Arc<MockDatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl PrerequisiteRepository for MockPrerequisiteRepositoryImpl {
+    async fn list_topic_prerequisites(&self) -> Result<Vec<TopicPrerequisite>> {
+        self.db.list_topic_prerequisites().await
+    }
+
+    async fn save_topic_prerequisite(&self, prerequisite: &TopicPrerequisite) -> Result<()> {
+        self.db.save_topic_prerequisite(prerequisite).await
+    }
+
+    async fn delete_topic_prerequisite(
+        &self,
+        topic_id: &TopicId,
+        prerequisite_topic_id: &TopicId,
+    ) -> Result<()> {
+        self.db
+            .delete_topic_prerequisite(topic_id, prerequisite_topic_id)
+            .await
+    }
+
+    async fn list_lesson_prerequisites(&self) -> Result<Vec<LessonPrerequisite>> {
+        self.db.list_lesson_prerequisites().await
+    }
+
+    async fn save_lesson_prerequisite(&self, prerequisite: &LessonPrerequisite) -> Result<()> {
+        self.db.save_lesson_prerequisite(prerequisite).await
+    }
+
+    async fn delete_lesson_prerequisite(
+        &self,
+        lesson_id: &LessonId,
+        prerequisite_lesson_id: &LessonId,
+    ) -> Result<()> {
+        self.db
+            .delete_lesson_prerequisite(lesson_id, prerequisite_lesson_id)
+            .await
+    }
+}
+
+// Mock Review Card Repository
+pub struct MockReviewCardRepositoryImpl {
+    db: Arc<MockDatabaseConnection>,
+}
+
+impl MockReviewCardRepositoryImpl {
+    pub fn new(db: Arc<MockDatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ReviewCardRepository for MockReviewCardRepositoryImpl {
+    async fn find(&self, user_id: &UserId, question_id: &QuestionId) -> Result<Option<ReviewCard>> {
+        self.db.find_review_card(user_id, question_id).await
+    }
+
+    async fn save(&self, card: &ReviewCard) -> Result<()> {
+        self.db.save_review_card(card).await
+    }
+
+    async fn find_due(
+        &self,
+        user_id: &UserId,
+        today: NaiveDate,
+        limit: u32,
+    ) -> Result<Vec<ReviewCard>> {
+        let mut due = self.db.find_due_review_cards(user_id, today).await?;
+        due.truncate(limit as usize);
+        Ok(due)
+    }
+
+    async fn count_due(&self, user_id: &UserId, today: NaiveDate) -> Result<u64> {
+        Ok(self.db.find_due_review_cards(user_id, today).await?.len() as u64)
+    }
+
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<ReviewCard>> {
+        self.db.find_review_cards_by_user(user_id).await
+    }
+}
+
+// Mock Quiz Repository
+pub struct MockQuizRepositoryImpl {
+    db: Arc<MockDatabaseConnection>,
+}
+
+impl MockQuizRepositoryImpl {
+    pub fn new(db: Arc<MockDatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl QuizRepository for MockQuizRepositoryImpl {
+    async fn create(&self, quiz: &Quiz) -> Result<()> {
+        self.db.create_quiz(quiz).await
+    }
+
+    async fn find_by_id(&self, id: &QuizId) -> Result<Option<Quiz>> {
+        self.db.find_quiz_by_id(id).await
+    }
+
+    async fn list(&self) -> Result<Vec<Quiz>> {
+        self.db.list_quizzes().await
+    }
+
+    async fn delete(&self, id: &QuizId) -> Result<()> {
+        self.db.delete_quiz(id).await
+    }
+}
+
+// Mock Quiz Attempt Repository
+pub struct MockQuizAttemptRepositoryImpl {
+    db: Arc<MockDatabaseConnection>,
+}
+
+impl MockQuizAttemptRepositoryImpl {
+    pub fn new(db: Arc<MockDatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl QuizAttemptRepository for MockQuizAttemptRepositoryImpl {
+    async fn create(&self, attempt: &QuizAttempt) -> Result<()> {
+        self.db.save_quiz_attempt(attempt).await
+    }
+
+    async fn create_if_allowed(
+        &self,
+        attempt: &QuizAttempt,
+        max_attempts: Option<u32>,
+    ) -> Result<bool> {
+        self.db
+            .create_quiz_attempt_if_allowed(attempt, max_attempts)
+            .await
+    }
+
+    async fn find_by_id(&self, id: &QuizAttemptId) -> Result<Option<QuizAttempt>> {
+        self.db.find_quiz_attempt_by_id(id).await
+    }
+
+    async fn update(&self, attempt: &QuizAttempt) -> Result<()> {
+        self.db.save_quiz_attempt(attempt).await
+    }
+
+    async fn list_by_user_and_quiz(
+        &self,
+        user_id: &UserId,
+        quiz_id: &QuizId,
+    ) -> Result<Vec<QuizAttempt>> {
+        self.db.find_quiz_attempts(user_id, quiz_id).await
+    }
+
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<QuizAttempt>> {
+        self.db.find_quiz_attempts_by_user(user_id).await
+    }
+}
+
+// Mock Hint Usage Repository
+pub struct MockHintUsageRepositoryImpl {
+    db: Arc<MockDatabaseConnection>,
+}
+
+impl MockHintUsageRepositoryImpl {
+    pub fn new(db: Arc<MockDatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl HintUsageRepository for MockHintUsageRepositoryImpl {
+    async fn find(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<Option<HintUsage>> {
+        self.db.find_hint_usage(user_id, code_practice_id).await
+    }
+
+    async fn save(&self, usage: &HintUsage) -> Result<()> {
+        self.db.save_hint_usage(usage).await
+    }
+
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<HintUsage>> {
+        self.db.find_hint_usage_by_user(user_id).await
+    }
+}
+
+// Mock Code Submission Repository
+pub struct MockCodeSubmissionRepositoryImpl {
+    db: Arc<MockDatabaseConnection>,
+}
+
+impl MockCodeSubmissionRepositoryImpl {
+    pub fn new(db: Arc<MockDatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl CodeSubmissionRepository for MockCodeSubmissionRepositoryImpl {
+    async fn create(&self, submission: &CodeSubmission) -> Result<()> {
+        self.db.save_code_submission(submission).await
+    }
+
+    async fn find_by_id(&self, id: &CodeSubmissionId) -> Result<Option<CodeSubmission>> {
+        self.db.find_code_submission_by_id(id).await
+    }
+
+    async fn list_by_user_and_practice(
+        &self,
+        user_id: &UserId,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<Vec<CodeSubmission>> {
+        self.db
+            .find_code_submissions(user_id, code_practice_id)
+            .await
+    }
+
+    async fn list_by_practice(
+        &self,
+        code_practice_id: &CodePracticeId,
+    ) -> Result<Vec<CodeSubmission>> {
+        self.db
+            .find_code_submissions_by_practice(code_practice_id)
+            .await
+    }
+
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<CodeSubmission>> {
+        self.db.find_code_submissions_by_user(user_id).await
+    }
+}
+
+pub struct MockQuestionDraftRepositoryImpl {
+    db: Arc<MockDatabaseConnection>,
+}
+
+impl MockQuestionDraftRepositoryImpl {
+    pub fn new(db: Arc<MockDatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl QuestionDraftRepository for MockQuestionDraftRepositoryImpl {
+    async fn create(&self, draft: &QuestionDraft) -> Result<()> {
+        self.db.save_question_draft(draft).await
+    }
+
+    async fn find_by_id(&self, id: &QuestionDraftId) -> Result<Option<QuestionDraft>> {
+        self.db.find_question_draft_by_id(id).await
+    }
+
+    async fn list_pending(&self) -> Result<Vec<QuestionDraft>> {
+        self.db.find_pending_question_drafts().await
+    }
+
+    async fn update(&self, draft: &QuestionDraft) -> Result<()> {
+        self.db.save_question_draft(draft).await
+    }
+}
+
+// Mock Tutor Feedback Repository
+pub struct MockTutorFeedbackRepositoryImpl {
+    db: Arc<MockDatabaseConnection>,
+}
+
+impl MockTutorFeedbackRepositoryImpl {
+    pub fn new(db: Arc<MockDatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl TutorFeedbackRepository for MockTutorFeedbackRepositoryImpl {
+    async fn find_cached(&self, cache_key: &str) -> Result<Option<TutorFeedback>> {
+        self.db.find_cached_tutor_feedback(cache_key).await
+    }
+
+    async fn save_cached(&self, feedback: &TutorFeedback) -> Result<()> {
+        self.db.save_cached_tutor_feedback(feedback).await
+    }
+
+    async fn find_usage(
+        &self,
+        user_id: &UserId,
+        usage_date: NaiveDate,
+    ) -> Result<Option<TutorUsage>> {
+        self.db.find_tutor_usage(user_id, usage_date).await
+    }
+
+    async fn reserve_request(
+        &self,
+        user_id: &UserId,
+        usage_date: NaiveDate,
+        quota: u32,
+    ) -> Result<Option<TutorUsage>> {
+        self.db
+            .reserve_tutor_request(user_id, usage_date, quota)
+            .await
+    }
+
+    async fn release_request(&self, user_id: &UserId, usage_date: NaiveDate) -> Result<()> {
+        self.db.release_tutor_request(user_id, usage_date).await
+    }
+
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<TutorUsage>> {
+        self.db.find_tutor_usage_by_user(user_id).await
+    }
+}
+
+// Mock AI Usage Repository
+pub struct MockAiUsageRepositoryImpl {
+    db: Arc<MockDatabaseConnection>,
+}
+
+impl MockAiUsageRepositoryImpl {
+    pub fn new(db: Arc<MockDatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AiUsageRepository for MockAiUsageRepositoryImpl {
+    async fn record(&self, call: &AiCallRecord) -> Result<()> {
+        self.db.record_ai_call(call).await
+    }
+
+    async fn daily_summary(
+        &self,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<AiUsageSummary>> {
+        self.db.summarize_ai_calls(since).await
+    }
+
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<AiCallRecord>> {
+        self.db.find_ai_calls_by_user(user_id).await
+    }
+}
+
+// Mock Translation Repository
+pub struct MockTranslationRepositoryImpl {
+    db: Arc<MockDatabaseConnection>,
+}
+
+impl MockTranslationRepositoryImpl {
+    pub fn new(db: Arc<MockDatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl TranslationRepository for MockTranslationRepositoryImpl {
+    async fn create(&self, translation: &MachineTranslation) -> Result<()> {
+        self.db.save_machine_translation(translation).await
+    }
+
+    async fn find_by_id(&self, id: &TranslationId) -> Result<Option<MachineTranslation>> {
+        self.db.find_machine_translation_by_id(id).await
+    }
+
+    async fn list_pending(&self) -> Result<Vec<MachineTranslation>> {
+        self.db.find_pending_machine_translations().await
+    }
+
+    async fn update(&self, translation: &MachineTranslation) -> Result<()> {
+        self.db.save_machine_translation(translation).await
+    }
+}
+
+pub struct MockAssetRepositoryImpl {
+    db: Arc<MockDatabaseConnection>,
+}
+
+impl MockAssetRepositoryImpl {
+    pub fn new(db: Arc<MockDatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AssetRepository for MockAssetRepositoryImpl {
+    async fn create(&self, asset: &Asset) -> Result<()> {
+        self.db.create_asset(asset).await
+    }
+
+    async fn find_by_id(&self, id: &AssetId) -> Result<Option<Asset>> {
+        self.db.find_asset_by_id(id).await
+    }
+
+    async fn find_by_sha256(&self, sha256: &str) -> Result<Option<Asset>> {
+        self.db.find_asset_by_sha256(sha256).await
+    }
+
+    async fn list(&self, limit: u32, offset: u32) -> Result<Vec<Asset>> {
+        self.db.list_assets(limit, offset).await
+    }
+}
+
+pub struct MockContentVersionRepositoryImpl {
+    db: Arc<MockDatabaseConnection>,
+}
+
+impl MockContentVersionRepositoryImpl {
+    pub fn new(db: Arc<MockDatabaseConnection>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ContentVersionRepository for MockContentVersionRepositoryImpl {
+    async fn create(&self, version: &ContentVersion) -> Result<u32> {
+        self.db.create_content_version(version).await
+    }
+
+    async fn delete(&self, id: &ContentVersionId) -> Result<()> {
+        self.db.delete_content_version(id).await
+    }
+
+    async fn list_for_content(
+        &self,
+        kind: ContentKind,
+        content_id: &str,
+    ) -> Result<Vec<ContentVersion>> {
+        self.db.find_content_versions(kind, content_id).await
+    }
+
+    async fn find(
+        &self,
+        kind: ContentKind,
+        content_id: &str,
+        version: u32,
+    ) -> Result<Option<ContentVersion>> {
+        let versions = self.db.find_content_versions(kind, content_id).await?;
+        Ok(versions
+            .into_iter()
+            .find(|existing| existing.version == version))
+    }
+}
@@ -1,9 +1,25 @@
+pub mod account_audit_repository_impl;
+pub mod achievement_repository_impl;
+pub mod ai_usage_repository_impl;
+pub mod api_key_repository_impl;
+pub mod asset_repository_impl;
 pub mod code_practice_repository_impl;
+pub mod code_submission_repository_impl;
+pub mod content_version_repository_impl;
+pub mod hint_usage_repository_impl;
 pub mod leaderboard_repository_impl;
 pub mod lesson_repository_impl;
+pub mod lesson_session_repository_impl;
 pub mod notification_repository_impl;
+pub mod prerequisite_repository_impl;
+pub mod question_draft_repository_impl;
 pub mod question_repository_impl;
+pub mod quiz_attempt_repository_impl;
+pub mod quiz_repository_impl;
+pub mod review_card_repository_impl;
 pub mod topic_repository_impl;
+pub mod translation_repository_impl;
+pub mod tutor_feedback_repository_impl;
 pub mod user_progress_repository_impl;
 pub mod user_repository_impl;
 
@@ -13,11 +29,27 @@ mod simple_mock_tests;
 #[cfg(test)]
 mod tests;
 
+pub use account_audit_repository_impl::AccountAuditRepositoryImpl;
+pub use achievement_repository_impl::AchievementRepositoryImpl;
+pub use ai_usage_repository_impl::AiUsageRepositoryImpl;
+pub use api_key_repository_impl::ApiKeyRepositoryImpl;
+pub use asset_repository_impl::AssetRepositoryImpl;
 pub use code_practice_repository_impl::CodePracticeRepositoryImpl;
+pub use code_submission_repository_impl::CodeSubmissionRepositoryImpl;
+pub use content_version_repository_impl::ContentVersionRepositoryImpl;
+pub use hint_usage_repository_impl::HintUsageRepositoryImpl;
 pub use leaderboard_repository_impl::LeaderboardRepositoryImpl;
 pub use lesson_repository_impl::LessonRepositoryImpl;
+pub use lesson_session_repository_impl::LessonSessionRepositoryImpl;
 pub use notification_repository_impl::NotificationRepositoryImpl;
+pub use prerequisite_repository_impl::PrerequisiteRepositoryImpl;
+pub use question_draft_repository_impl::QuestionDraftRepositoryImpl;
 pub use question_repository_impl::QuestionRepositoryImpl;
+pub use quiz_attempt_repository_impl::QuizAttemptRepositoryImpl;
+pub use quiz_repository_impl::QuizRepositoryImpl;
+pub use review_card_repository_impl::ReviewCardRepositoryImpl;
 pub use topic_repository_impl::TopicRepositoryImpl;
+pub use translation_repository_impl::TranslationRepositoryImpl;
+pub use tutor_feedback_repository_impl::TutorFeedbackRepositoryImpl;
 pub use user_progress_repository_impl::UserProgressRepositoryImpl;
 pub use user_repository_impl::UserRepositoryImpl;
@@ -102,6 +102,16 @@ impl NotificationRepository for NotificationRepositoryImpl {
         Ok(())
     }
 
+    async fn delete_by_user_id(
+        &self,
+        user_id: &UserId,
+    ) -> Result<u32, Box<dyn Error + Send + Sync>> {
+        let mut notifications = self.notifications.write().await;
+        let before = notifications.len();
+        notifications.retain(|_, notification| notification.user_id != *user_id);
+        Ok((before - notifications.len()) as u32)
+    }
+
     async fn delete_old_notifications(
         &self,
         days_old: u32,
@@ -0,0 +1,127 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::entities::{LessonPrerequisite, TopicPrerequisite};
+use crate::domain::repositories::PrerequisiteRepository;
+use crate::domain::value_objects::{LessonId, TopicId};
+use crate::infrastructure::database::models::{LessonPrerequisiteModel, TopicPrerequisiteModel};
+
+pub struct PrerequisiteRepositoryImpl {
+    pool: PgPool,
+}
+
+impl PrerequisiteRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PrerequisiteRepository for PrerequisiteRepositoryImpl {
+    async fn list_topic_prerequisites(&self) -> Result<Vec<TopicPrerequisite>> {
+        let prerequisite_models = sqlx::query_as!(
+            TopicPrerequisiteModel,
+            "SELECT * FROM topic_prerequisites ORDER BY created_at ASC"
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(prerequisite_models
+            .into_iter()
+            .map(TopicPrerequisite::from)
+            .collect())
+    }
+
+    async fn save_topic_prerequisite(&self, prerequisite: &TopicPrerequisite) -> Result<()> {
+        let prerequisite_model = TopicPrerequisiteModel::from(prerequisite.clone());
+
+        sqlx::query!(
+            r#"
+            INSERT INTO topic_prerequisites (topic_id, prerequisite_topic_id, min_stars, created_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (topic_id, prerequisite_topic_id) DO UPDATE SET min_stars = EXCLUDED.min_stars
+            "#,
+            prerequisite_model.topic_id,
+            prerequisite_model.prerequisite_topic_id,
+            prerequisite_model.min_stars,
+            prerequisite_model.created_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn delete_topic_prerequisite(
+        &self,
+        topic_id: &TopicId,
+        prerequisite_topic_id: &TopicId,
+    ) -> Result<()> {
+        sqlx::query!(
+            "DELETE FROM topic_prerequisites WHERE topic_id = $1 AND prerequisite_topic_id = $2",
+            topic_id.0,
+            prerequisite_topic_id.0
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn list_lesson_prerequisites(&self) -> Result<Vec<LessonPrerequisite>> {
+        let prerequisite_models = sqlx::query_as!(
+            LessonPrerequisiteModel,
+            "SELECT * FROM lesson_prerequisites ORDER BY created_at ASC"
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(prerequisite_models
+            .into_iter()
+            .map(LessonPrerequisite::from)
+            .collect())
+    }
+
+    async fn save_lesson_prerequisite(&self, prerequisite: &LessonPrerequisite) -> Result<()> {
+        let prerequisite_model = LessonPrerequisiteModel::from(prerequisite.clone());
+
+        sqlx::query!(
+            r#"
+            INSERT INTO lesson_prerequisites (lesson_id, prerequisite_lesson_id, min_stars, created_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (lesson_id, prerequisite_lesson_id) DO UPDATE SET min_stars = EXCLUDED.min_stars
+            "#,
+            prerequisite_model.lesson_id,
+            prerequisite_model.prerequisite_lesson_id,
+            prerequisite_model.min_stars,
+            prerequisite_model.created_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn delete_lesson_prerequisite(
+        &self,
+        lesson_id: &LessonId,
+        prerequisite_lesson_id: &LessonId,
+    ) -> Result<()> {
+        sqlx::query!(
+            "DELETE FROM lesson_prerequisites WHERE lesson_id = $1 AND prerequisite_lesson_id = $2",
+            lesson_id.0,
+            prerequisite_lesson_id.0
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+}
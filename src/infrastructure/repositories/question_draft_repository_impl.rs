@@ -0,0 +1,92 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::entities::QuestionDraft;
+use crate::domain::repositories::QuestionDraftRepository;
+use crate::domain::value_objects::QuestionDraftId;
+use crate::infrastructure::database::models::QuestionDraftModel;
+
+pub struct QuestionDraftRepositoryImpl {
+    pool: PgPool,
+}
+
+impl QuestionDraftRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl QuestionDraftRepository for QuestionDraftRepositoryImpl {
+    async fn create(&self, draft: &QuestionDraft) -> Result<()> {
+        let draft_model = QuestionDraftModel::from(draft.clone());
+
+        sqlx::query!(
+            r#"
+            INSERT INTO question_drafts (id, question, status, source, created_at, reviewed_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+            draft_model.id,
+            draft_model.question,
+            draft_model.status,
+            draft_model.source,
+            draft_model.created_at,
+            draft_model.reviewed_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &QuestionDraftId) -> Result<Option<QuestionDraft>> {
+        let draft_model = sqlx::query_as!(
+            QuestionDraftModel,
+            "SELECT * FROM question_drafts WHERE id = $1",
+            id.0
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        draft_model.map(QuestionDraft::try_from).transpose()
+    }
+
+    async fn list_pending(&self) -> Result<Vec<QuestionDraft>> {
+        let draft_models = sqlx::query_as!(
+            QuestionDraftModel,
+            "SELECT * FROM question_drafts WHERE status = 'pending' ORDER BY created_at"
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        draft_models
+            .into_iter()
+            .map(QuestionDraft::try_from)
+            .collect()
+    }
+
+    async fn update(&self, draft: &QuestionDraft) -> Result<()> {
+        let draft_model = QuestionDraftModel::from(draft.clone());
+
+        sqlx::query!(
+            r#"
+            UPDATE question_drafts
+            SET question = $2, status = $3, reviewed_at = $4
+            WHERE id = $1
+            "#,
+            draft_model.id,
+            draft_model.question,
+            draft_model.status,
+            draft_model.reviewed_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+}
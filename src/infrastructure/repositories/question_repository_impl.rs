@@ -20,36 +20,22 @@ impl QuestionRepositoryImpl {
 #[async_trait]
 impl QuestionRepository for QuestionRepositoryImpl {
     async fn create(&self, question: &Question) -> Result<()> {
-        let question_model = QuestionModel {
-            id: question.id.0,
-            prompt_en: question.prompt.en.clone(),
-            prompt_id: question.prompt.id.clone(),
-            explanation_en: question.explanation.as_ref().map(|e| e.en.clone()),
-            explanation_id: question.explanation.as_ref().map(|e| e.id.clone()),
-            topic_id: question.topic_id.0,
-            difficulty: question.difficulty.to_string(),
-            points: question.points.value() as i32,
-            question_type: format!("{:?}", question.question_type),
-            question_data: serde_json::Value::Object(serde_json::Map::new()), // TODO: Store actual question data
-            created_at: question.created_at,
-            updated_at: question.updated_at,
-        };
+        let question_model = QuestionModel::from(question.clone());
 
         sqlx::query!(
             r#"
-            INSERT INTO questions (id, prompt_en, prompt_id, explanation_en, explanation_id, topic_id, difficulty, points, question_type, question_data, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            INSERT INTO questions (id, prompt, explanation, topic_id, difficulty, points, question_type, question_data, image_asset_id, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
             "#,
             question_model.id,
-            question_model.prompt_en,
-            question_model.prompt_id,
-            question_model.explanation_en,
-            question_model.explanation_id,
+            question_model.prompt,
+            question_model.explanation,
             question_model.topic_id,
             question_model.difficulty,
             question_model.points,
             question_model.question_type,
             question_model.question_data,
+            question_model.image_asset_id,
             question_model.created_at,
             question_model.updated_at
         )
@@ -70,6 +56,20 @@ impl QuestionRepository for QuestionRepositoryImpl {
         Ok(question_model.map(|m| Question::from(m)))
     }
 
+    async fn find_by_ids(&self, ids: &[QuestionId]) -> Result<Vec<Question>> {
+        let ids: Vec<uuid::Uuid> = ids.iter().map(|id| id.0).collect();
+        let question_models = sqlx::query_as!(
+            QuestionModel,
+            "SELECT * FROM questions WHERE id = ANY($1)",
+            &ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(question_models.into_iter().map(Question::from).collect())
+    }
+
     async fn find_by_topic_id(&self, topic_id: &TopicId) -> Result<Vec<Question>> {
         let question_models = sqlx::query_as!(
             QuestionModel,
@@ -97,23 +97,25 @@ impl QuestionRepository for QuestionRepositoryImpl {
     }
 
     async fn update(&self, question: &Question) -> Result<()> {
+        let question_model = QuestionModel::from(question.clone());
+
         sqlx::query!(
             r#"
             UPDATE questions 
-            SET prompt_en = $2, prompt_id = $3, explanation_en = $4, explanation_id = $5, 
-                topic_id = $6, difficulty = $7, points = $8, question_type = $9, updated_at = $10
+            SET prompt = $2, explanation = $3, topic_id = $4, difficulty = $5, points = $6,
+                question_type = $7, question_data = $8, image_asset_id = $9, updated_at = $10
             WHERE id = $1
             "#,
-            question.id.0,
-            question.prompt.en,
-            question.prompt.id,
-            question.explanation.as_ref().map(|e| e.en.as_str()),
-            question.explanation.as_ref().map(|e| e.id.as_str()),
-            question.topic_id.0,
-            question.difficulty.to_string(),
-            question.points.value() as i32,
-            format!("{:?}", question.question_type),
-            question.updated_at
+            question_model.id,
+            question_model.prompt,
+            question_model.explanation,
+            question_model.topic_id,
+            question_model.difficulty,
+            question_model.points,
+            question_model.question_type,
+            question_model.question_data,
+            question_model.image_asset_id,
+            question_model.updated_at
         )
         .execute(&self.pool)
         .await
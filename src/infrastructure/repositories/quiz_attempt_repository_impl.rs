@@ -0,0 +1,177 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::entities::QuizAttempt;
+use crate::domain::repositories::QuizAttemptRepository;
+use crate::domain::value_objects::{QuizAttemptId, QuizId, UserId};
+use crate::infrastructure::database::models::QuizAttemptModel;
+
+pub struct QuizAttemptRepositoryImpl {
+    pool: PgPool,
+}
+
+impl QuizAttemptRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl QuizAttemptRepository for QuizAttemptRepositoryImpl {
+    async fn create(&self, attempt: &QuizAttempt) -> Result<()> {
+        let attempt_model = QuizAttemptModel::from(attempt.clone());
+
+        sqlx::query!(
+            r#"
+            INSERT INTO quiz_attempts (id, quiz_id, user_id, items, answers, started_at, deadline, submitted_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            attempt_model.id,
+            attempt_model.quiz_id,
+            attempt_model.user_id,
+            attempt_model.items,
+            attempt_model.answers,
+            attempt_model.started_at,
+            attempt_model.deadline,
+            attempt_model.submitted_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn create_if_allowed(
+        &self,
+        attempt: &QuizAttempt,
+        max_attempts: Option<u32>,
+    ) -> Result<bool> {
+        let attempt_model = QuizAttemptModel::from(attempt.clone());
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        // Held until commit, so a concurrent start counts this attempt
+        sqlx::query!(
+            "SELECT pg_advisory_xact_lock(hashtext('quiz_attempt:' || $1::UUID::text || ':' || $2::UUID::text))",
+            attempt_model.user_id,
+            attempt_model.quiz_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        let existing = sqlx::query!(
+            r#"
+            SELECT COUNT(*) AS "started!",
+                   COUNT(*) FILTER (WHERE submitted_at IS NULL AND deadline > $3) AS "open!"
+            FROM quiz_attempts
+            WHERE user_id = $1 AND quiz_id = $2
+            "#,
+            attempt_model.user_id,
+            attempt_model.quiz_id,
+            attempt_model.started_at
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+        if existing.open > 0 || max_attempts.is_some_and(|max| existing.started >= i64::from(max)) {
+            return Ok(false);
+        }
+
+        sqlx::query!(
+            r#"
+            INSERT INTO quiz_attempts (id, quiz_id, user_id, items, answers, started_at, deadline, submitted_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            attempt_model.id,
+            attempt_model.quiz_id,
+            attempt_model.user_id,
+            attempt_model.items,
+            attempt_model.answers,
+            attempt_model.started_at,
+            attempt_model.deadline,
+            attempt_model.submitted_at
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+        Ok(true)
+    }
+
+    async fn find_by_id(&self, id: &QuizAttemptId) -> Result<Option<QuizAttempt>> {
+        let attempt_model = sqlx::query_as!(
+            QuizAttemptModel,
+            "SELECT * FROM quiz_attempts WHERE id = $1",
+            id.0
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(attempt_model.map(QuizAttempt::from))
+    }
+
+    async fn update(&self, attempt: &QuizAttempt) -> Result<()> {
+        let attempt_model = QuizAttemptModel::from(attempt.clone());
+
+        sqlx::query!(
+            r#"
+            UPDATE quiz_attempts
+            SET answers = $2, submitted_at = $3
+            WHERE id = $1
+            "#,
+            attempt_model.id,
+            attempt_model.answers,
+            attempt_model.submitted_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn list_by_user_and_quiz(
+        &self,
+        user_id: &UserId,
+        quiz_id: &QuizId,
+    ) -> Result<Vec<QuizAttempt>> {
+        let attempt_models = sqlx::query_as!(
+            QuizAttemptModel,
+            r#"
+            SELECT * FROM quiz_attempts
+            WHERE user_id = $1 AND quiz_id = $2
+            ORDER BY started_at DESC
+            "#,
+            user_id.0,
+            quiz_id.0
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(attempt_models.into_iter().map(QuizAttempt::from).collect())
+    }
+
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<QuizAttempt>> {
+        let attempt_models = sqlx::query_as!(
+            QuizAttemptModel,
+            "SELECT * FROM quiz_attempts WHERE user_id = $1 ORDER BY started_at DESC",
+            user_id.0
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(attempt_models.into_iter().map(QuizAttempt::from).collect())
+    }
+}
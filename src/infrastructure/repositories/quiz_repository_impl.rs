@@ -0,0 +1,79 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::entities::Quiz;
+use crate::domain::repositories::QuizRepository;
+use crate::domain::value_objects::QuizId;
+use crate::infrastructure::database::models::QuizModel;
+
+pub struct QuizRepositoryImpl {
+    pool: PgPool,
+}
+
+impl QuizRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl QuizRepository for QuizRepositoryImpl {
+    async fn create(&self, quiz: &Quiz) -> Result<()> {
+        let quiz_model = QuizModel::from(quiz.clone());
+
+        sqlx::query!(
+            r#"
+            INSERT INTO quizzes (
+                id, title, description, topic_ids, difficulties, question_count,
+                time_limit_secs, passing_score, max_attempts, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            "#,
+            quiz_model.id,
+            quiz_model.title,
+            quiz_model.description,
+            quiz_model.topic_ids,
+            quiz_model.difficulties,
+            quiz_model.question_count,
+            quiz_model.time_limit_secs,
+            quiz_model.passing_score,
+            quiz_model.max_attempts,
+            quiz_model.created_at,
+            quiz_model.updated_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &QuizId) -> Result<Option<Quiz>> {
+        let quiz_model = sqlx::query_as!(QuizModel, "SELECT * FROM quizzes WHERE id = $1", id.0)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(quiz_model.map(Quiz::from))
+    }
+
+    async fn list(&self) -> Result<Vec<Quiz>> {
+        let quiz_models =
+            sqlx::query_as!(QuizModel, "SELECT * FROM quizzes ORDER BY created_at DESC")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(quiz_models.into_iter().map(Quiz::from).collect())
+    }
+
+    async fn delete(&self, id: &QuizId) -> Result<()> {
+        sqlx::query!("DELETE FROM quizzes WHERE id = $1", id.0)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+}
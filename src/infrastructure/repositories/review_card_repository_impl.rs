@@ -0,0 +1,124 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use crate::domain::entities::ReviewCard;
+use crate::domain::repositories::ReviewCardRepository;
+use crate::domain::value_objects::{QuestionId, UserId};
+use crate::infrastructure::database::models::ReviewCardModel;
+
+pub struct ReviewCardRepositoryImpl {
+    pool: PgPool,
+}
+
+impl ReviewCardRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ReviewCardRepository for ReviewCardRepositoryImpl {
+    async fn find(&self, user_id: &UserId, question_id: &QuestionId) -> Result<Option<ReviewCard>> {
+        let card_model = sqlx::query_as!(
+            ReviewCardModel,
+            "SELECT * FROM review_cards WHERE user_id = $1 AND question_id = $2",
+            user_id.0,
+            question_id.0
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(card_model.map(ReviewCard::from))
+    }
+
+    async fn save(&self, card: &ReviewCard) -> Result<()> {
+        let card_model = ReviewCardModel::from(card.clone());
+
+        sqlx::query!(
+            r#"
+            INSERT INTO review_cards (
+                user_id, question_id, ease_factor, interval_days, repetitions, lapses,
+                due_date, last_reviewed_at, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (user_id, question_id) DO UPDATE SET
+                ease_factor = EXCLUDED.ease_factor,
+                interval_days = EXCLUDED.interval_days,
+                repetitions = EXCLUDED.repetitions,
+                lapses = EXCLUDED.lapses,
+                due_date = EXCLUDED.due_date,
+                last_reviewed_at = EXCLUDED.last_reviewed_at,
+                updated_at = EXCLUDED.updated_at
+            "#,
+            card_model.user_id,
+            card_model.question_id,
+            card_model.ease_factor,
+            card_model.interval_days,
+            card_model.repetitions,
+            card_model.lapses,
+            card_model.due_date,
+            card_model.last_reviewed_at,
+            card_model.created_at,
+            card_model.updated_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn find_due(
+        &self,
+        user_id: &UserId,
+        today: NaiveDate,
+        limit: u32,
+    ) -> Result<Vec<ReviewCard>> {
+        let card_models = sqlx::query_as!(
+            ReviewCardModel,
+            r#"
+            SELECT * FROM review_cards
+            WHERE user_id = $1 AND due_date <= $2
+            ORDER BY due_date ASC, ease_factor ASC
+            LIMIT $3
+            "#,
+            user_id.0,
+            today,
+            limit as i64
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(card_models.into_iter().map(ReviewCard::from).collect())
+    }
+
+    async fn count_due(&self, user_id: &UserId, today: NaiveDate) -> Result<u64> {
+        let count = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!" FROM review_cards WHERE user_id = $1 AND due_date <= $2"#,
+            user_id.0,
+            today
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(count as u64)
+    }
+
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<ReviewCard>> {
+        let card_models = sqlx::query_as!(
+            ReviewCardModel,
+            "SELECT * FROM review_cards WHERE user_id = $1 ORDER BY due_date ASC",
+            user_id.0
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(card_models.into_iter().map(ReviewCard::from).collect())
+    }
+}
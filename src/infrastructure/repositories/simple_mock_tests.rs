@@ -96,12 +96,12 @@ mod tests {
         // Test finding topic by ID
         let found_topic = db.find_topic_by_id(&topic.id.to_string()).unwrap();
         assert!(found_topic.is_some());
-        assert_eq!(found_topic.unwrap().title.en, "Rust Basics");
+        assert_eq!(found_topic.unwrap().title.english(), "Rust Basics");
 
         // Test listing topics
         let topics = db.list_topics().unwrap();
         assert_eq!(topics.len(), 1);
-        assert_eq!(topics[0].title.en, "Rust Basics");
+        assert_eq!(topics[0].title.english(), "Rust Basics");
     }
 
     #[test]
@@ -135,8 +135,8 @@ mod tests {
 
         // Test LocalizedText creation
         let text = LocalizedText::new("Hello".to_string(), "Halo".to_string());
-        assert_eq!(text.en, "Hello");
-        assert_eq!(text.id, "Halo");
+        assert_eq!(text.english(), "Hello");
+        assert_eq!(text.get("id"), "Halo");
     }
 
     #[test]
@@ -163,7 +163,7 @@ mod tests {
                 "Pengetahuan pemrograman".to_string(),
             ),
         );
-        assert_eq!(topic.title.en, "Rust Basics");
+        assert_eq!(topic.title.english(), "Rust Basics");
         assert_eq!(topic.order, 1);
 
         // Test Lesson creation
@@ -177,7 +177,7 @@ mod tests {
             "https://example.com".to_string(),
             1,
         );
-        assert_eq!(lesson.title.en, "Variables");
+        assert_eq!(lesson.title.english(), "Variables");
         assert_eq!(lesson.topic_id, topic.id);
 
         // Test Question creation
@@ -197,7 +197,7 @@ mod tests {
                 correct_index: 0,
             },
         );
-        assert_eq!(question.prompt.en, "What is a variable?");
+        assert_eq!(question.prompt.english(), "What is a variable?");
         assert_eq!(question.difficulty, Difficulty::Beginner);
         assert_eq!(question.points.value(), 10);
 
@@ -216,7 +216,7 @@ mod tests {
             topic.id.clone(),
             Points::new(15),
         );
-        assert_eq!(code_practice.title.en, "Create a variable");
+        assert_eq!(code_practice.title.english(), "Create a variable");
         assert_eq!(code_practice.difficulty, Difficulty::Beginner);
         assert_eq!(code_practice.points.value(), 15);
 
@@ -262,11 +262,11 @@ mod tests {
         // Verify topic exists
         let found_topic = db.find_topic_by_id(&topic.id.to_string()).unwrap();
         assert!(found_topic.is_some());
-        assert_eq!(found_topic.unwrap().title.en, "Rust Basics");
+        assert_eq!(found_topic.unwrap().title.english(), "Rust Basics");
 
         // Verify topic list
         let topics = db.list_topics().unwrap();
         assert_eq!(topics.len(), 1);
-        assert_eq!(topics[0].title.en, "Rust Basics");
+        assert_eq!(topics[0].title.english(), "Rust Basics");
     }
 }
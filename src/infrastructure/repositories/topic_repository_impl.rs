@@ -22,12 +22,9 @@ impl TopicRepository for TopicRepositoryImpl {
     async fn create(&self, topic: &Topic) -> Result<()> {
         let topic_model = TopicModel {
             id: topic.id.0,
-            title_en: topic.title.en.clone(),
-            title_id: topic.title.id.clone(),
-            description_en: topic.description.en.clone(),
-            description_id: topic.description.id.clone(),
-            required_skills_en: topic.required_skills.en.clone(),
-            required_skills_id: topic.required_skills.id.clone(),
+            title: serde_json::to_value(&topic.title)?,
+            description: serde_json::to_value(&topic.description)?,
+            required_skills: serde_json::to_value(&topic.required_skills)?,
             order: topic.order as i32,
             created_at: topic.created_at,
             updated_at: topic.updated_at,
@@ -35,16 +32,13 @@ impl TopicRepository for TopicRepositoryImpl {
 
         sqlx::query!(
             r#"
-            INSERT INTO topics (id, title_en, title_id, description_en, description_id, required_skills_en, required_skills_id, "order", created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            INSERT INTO topics (id, title, description, required_skills, "order", created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
             "#,
             topic_model.id,
-            topic_model.title_en,
-            topic_model.title_id,
-            topic_model.description_en,
-            topic_model.description_id,
-            topic_model.required_skills_en,
-            topic_model.required_skills_id,
+            topic_model.title,
+            topic_model.description,
+            topic_model.required_skills,
             topic_model.order,
             topic_model.created_at,
             topic_model.updated_at
@@ -69,17 +63,13 @@ impl TopicRepository for TopicRepositoryImpl {
         sqlx::query!(
             r#"
             UPDATE topics 
-            SET title_en = $2, title_id = $3, description_en = $4, description_id = $5, 
-                required_skills_en = $6, required_skills_id = $7, "order" = $8, updated_at = $9
+            SET title = $2, description = $3, required_skills = $4, "order" = $5, updated_at = $6
             WHERE id = $1
             "#,
             topic.id.0,
-            topic.title.en,
-            topic.title.id,
-            topic.description.en,
-            topic.description.id,
-            topic.required_skills.en,
-            topic.required_skills.id,
+            serde_json::to_value(&topic.title)?,
+            serde_json::to_value(&topic.description)?,
+            serde_json::to_value(&topic.required_skills)?,
             topic.order as i32,
             topic.updated_at
         )
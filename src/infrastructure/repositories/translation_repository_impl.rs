@@ -0,0 +1,100 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::entities::MachineTranslation;
+use crate::domain::repositories::TranslationRepository;
+use crate::domain::value_objects::TranslationId;
+use crate::infrastructure::database::models::MachineTranslationModel;
+
+pub struct TranslationRepositoryImpl {
+    pool: PgPool,
+}
+
+impl TranslationRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TranslationRepository for TranslationRepositoryImpl {
+    async fn create(&self, translation: &MachineTranslation) -> Result<()> {
+        let model = MachineTranslationModel::from(translation.clone());
+
+        sqlx::query!(
+            r#"
+            INSERT INTO machine_translations (
+                id, content_kind, content_id, field, language, source_text, translated_text,
+                status, provider, created_at, reviewed_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            "#,
+            model.id,
+            model.content_kind,
+            model.content_id,
+            model.field,
+            model.language,
+            model.source_text,
+            model.translated_text,
+            model.status,
+            model.provider,
+            model.created_at,
+            model.reviewed_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &TranslationId) -> Result<Option<MachineTranslation>> {
+        let model = sqlx::query_as!(
+            MachineTranslationModel,
+            "SELECT * FROM machine_translations WHERE id = $1",
+            id.0
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        model.map(MachineTranslation::try_from).transpose()
+    }
+
+    async fn list_pending(&self) -> Result<Vec<MachineTranslation>> {
+        let models = sqlx::query_as!(
+            MachineTranslationModel,
+            "SELECT * FROM machine_translations WHERE status = 'needs_review' ORDER BY created_at"
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        models
+            .into_iter()
+            .map(MachineTranslation::try_from)
+            .collect()
+    }
+
+    async fn update(&self, translation: &MachineTranslation) -> Result<()> {
+        let model = MachineTranslationModel::from(translation.clone());
+
+        sqlx::query!(
+            r#"
+            UPDATE machine_translations
+            SET translated_text = $2, status = $3, reviewed_at = $4
+            WHERE id = $1
+            "#,
+            model.id,
+            model.translated_text,
+            model.status,
+            model.reviewed_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+}
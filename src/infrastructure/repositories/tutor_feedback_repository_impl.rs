@@ -0,0 +1,140 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use crate::domain::entities::{TutorFeedback, TutorUsage};
+use crate::domain::repositories::TutorFeedbackRepository;
+use crate::domain::value_objects::UserId;
+use crate::infrastructure::database::models::{TutorFeedbackModel, TutorUsageModel};
+
+pub struct TutorFeedbackRepositoryImpl {
+    pool: PgPool,
+}
+
+impl TutorFeedbackRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TutorFeedbackRepository for TutorFeedbackRepositoryImpl {
+    async fn find_cached(&self, cache_key: &str) -> Result<Option<TutorFeedback>> {
+        let feedback_model = sqlx::query_as!(
+            TutorFeedbackModel,
+            "SELECT * FROM tutor_feedback_cache WHERE cache_key = $1",
+            cache_key
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(feedback_model.map(TutorFeedback::from))
+    }
+
+    async fn save_cached(&self, feedback: &TutorFeedback) -> Result<()> {
+        let feedback_model = TutorFeedbackModel::from(feedback.clone());
+
+        sqlx::query!(
+            r#"
+            INSERT INTO tutor_feedback_cache (cache_key, language, hint, provider, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (cache_key) DO UPDATE SET
+                language = EXCLUDED.language,
+                hint = EXCLUDED.hint,
+                provider = EXCLUDED.provider,
+                created_at = EXCLUDED.created_at
+            "#,
+            feedback_model.cache_key,
+            feedback_model.language,
+            feedback_model.hint,
+            feedback_model.provider,
+            feedback_model.created_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn find_usage(
+        &self,
+        user_id: &UserId,
+        usage_date: NaiveDate,
+    ) -> Result<Option<TutorUsage>> {
+        let usage_model = sqlx::query_as!(
+            TutorUsageModel,
+            "SELECT * FROM tutor_usage WHERE user_id = $1 AND usage_date = $2",
+            user_id.0,
+            usage_date
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(usage_model.map(TutorUsage::from))
+    }
+
+    async fn reserve_request(
+        &self,
+        user_id: &UserId,
+        usage_date: NaiveDate,
+        quota: u32,
+    ) -> Result<Option<TutorUsage>> {
+        // The conflict branch only increments below the quota; a first request of
+        // the day is only inserted when there is any quota at all
+        let usage_model = sqlx::query_as!(
+            TutorUsageModel,
+            r#"
+            INSERT INTO tutor_usage (user_id, usage_date, requests, updated_at)
+            SELECT $1, $2, 1, NOW()
+            WHERE $3 > 0
+            ON CONFLICT (user_id, usage_date) DO UPDATE SET
+                requests = tutor_usage.requests + 1,
+                updated_at = NOW()
+            WHERE tutor_usage.requests < $3
+            RETURNING *
+            "#,
+            user_id.0,
+            usage_date,
+            quota as i32
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(usage_model.map(TutorUsage::from))
+    }
+
+    async fn release_request(&self, user_id: &UserId, usage_date: NaiveDate) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE tutor_usage
+            SET requests = requests - 1, updated_at = NOW()
+            WHERE user_id = $1 AND usage_date = $2 AND requests > 0
+            "#,
+            user_id.0,
+            usage_date
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn list_by_user(&self, user_id: &UserId) -> Result<Vec<TutorUsage>> {
+        let usage_models = sqlx::query_as!(
+            TutorUsageModel,
+            "SELECT * FROM tutor_usage WHERE user_id = $1 ORDER BY usage_date DESC",
+            user_id.0
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(usage_models.into_iter().map(TutorUsage::from).collect())
+    }
+}
@@ -1,11 +1,13 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 
+use crate::domain::entities::user_progress::LessonResult;
 use crate::domain::entities::UserProgress;
 use crate::domain::repositories::UserProgressRepository;
 use crate::domain::value_objects::UserId;
-use crate::infrastructure::database::models::UserProgressModel;
+use crate::infrastructure::database::models::{LessonResultModel, UserProgressModel};
 
 pub struct UserProgressRepositoryImpl {
     pool: PgPool,
@@ -20,28 +22,23 @@ impl UserProgressRepositoryImpl {
 #[async_trait]
 impl UserProgressRepository for UserProgressRepositoryImpl {
     async fn create(&self, progress: &UserProgress) -> Result<()> {
-        let progress_model = UserProgressModel {
-            user_id: progress.user_id.0,
-            total_xp: progress.total_xp.value() as i32,
-            current_streak_days: progress.current_streak_days as i32,
-            highest_streak_days: progress.highest_streak_days as i32,
-            last_active_date: progress.last_active_date,
-            daily_xp_cap: 1000, // TODO: Make this configurable
-            last_xp_reset_date: progress.last_active_date,
-            completed_questions: serde_json::to_value(&progress.completed_questions)
-                .unwrap_or(serde_json::Value::Array(vec![])),
-            completed_code_practices: serde_json::to_value(&progress.completed_code_practices)
-                .unwrap_or(serde_json::Value::Array(vec![])),
-            lesson_stars: serde_json::to_value(&progress.lesson_stars)
-                .unwrap_or(serde_json::Value::Object(serde_json::Map::new())),
-            created_at: progress.created_at,
-            updated_at: progress.updated_at,
-        };
+        let progress_model = UserProgressModel::from(progress.clone());
 
+        // `users` keeps a copy of the XP and streak for account and admin pages;
+        // it is written in the same statement so the two cannot drift apart
         sqlx::query!(
             r#"
-            INSERT INTO user_progress (user_id, total_xp, current_streak_days, highest_streak_days, last_active_date, daily_xp_cap, last_xp_reset_date, completed_questions, completed_code_practices, lesson_stars, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            WITH progress AS (
+                INSERT INTO user_progress (user_id, total_xp, current_streak_days, highest_streak_days, last_active_date, daily_xp_cap, xp_earned_today, last_xp_reset_date, streak_freezes, repairable_streak_days, streak_repair_expires_at, completed_questions, completed_code_practices, lesson_stars, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+                RETURNING user_id, total_xp, current_streak_days, highest_streak_days, last_active_date
+            )
+            UPDATE users
+            SET total_xp = progress.total_xp, current_streak_days = progress.current_streak_days,
+                highest_streak_days = progress.highest_streak_days,
+                last_active_date = progress.last_active_date
+            FROM progress
+            WHERE users.id = progress.user_id
             "#,
             progress_model.user_id,
             progress_model.total_xp,
@@ -49,7 +46,11 @@ impl UserProgressRepository for UserProgressRepositoryImpl {
             progress_model.highest_streak_days,
             progress_model.last_active_date,
             progress_model.daily_xp_cap,
+            progress_model.xp_earned_today,
             progress_model.last_xp_reset_date,
+            progress_model.streak_freezes,
+            progress_model.repairable_streak_days,
+            progress_model.streak_repair_expires_at,
             progress_model.completed_questions,
             progress_model.completed_code_practices,
             progress_model.lesson_stars,
@@ -77,13 +78,25 @@ impl UserProgressRepository for UserProgressRepositoryImpl {
     }
 
     async fn update(&self, progress: &UserProgress) -> Result<()> {
+        // Mirrors the XP and streak into `users` in the same statement, as in `create`
         sqlx::query!(
             r#"
-            UPDATE user_progress
-            SET total_xp = $2, current_streak_days = $3, highest_streak_days = $4,
-                last_active_date = $5, completed_questions = $6, completed_code_practices = $7,
-                lesson_stars = $8, updated_at = $9
-            WHERE user_id = $1
+            WITH progress AS (
+                UPDATE user_progress
+                SET total_xp = $2, current_streak_days = $3, highest_streak_days = $4,
+                    last_active_date = $5, completed_questions = $6, completed_code_practices = $7,
+                    lesson_stars = $8, daily_xp_cap = $9, xp_earned_today = $10, last_xp_reset_date = $11,
+                    streak_freezes = $12, repairable_streak_days = $13, streak_repair_expires_at = $14,
+                    updated_at = $15
+                WHERE user_id = $1
+                RETURNING user_id, total_xp, current_streak_days, highest_streak_days, last_active_date
+            )
+            UPDATE users
+            SET total_xp = progress.total_xp, current_streak_days = progress.current_streak_days,
+                highest_streak_days = progress.highest_streak_days,
+                last_active_date = progress.last_active_date
+            FROM progress
+            WHERE users.id = progress.user_id
             "#,
             progress.user_id.0,
             progress.total_xp.value() as i32,
@@ -96,6 +109,12 @@ impl UserProgressRepository for UserProgressRepositoryImpl {
                 .unwrap_or(serde_json::Value::Array(vec![])),
             serde_json::to_value(&progress.lesson_stars)
                 .unwrap_or(serde_json::Value::Object(serde_json::Map::new())),
+            progress.daily_xp_cap.map(|cap| cap as i32),
+            progress.xp_earned_today as i32,
+            progress.last_xp_reset_date,
+            progress.streak_freezes as i32,
+            progress.repairable_streak_days as i32,
+            progress.streak_repair_expires_at,
             progress.updated_at
         )
         .execute(&self.pool)
@@ -105,6 +124,96 @@ impl UserProgressRepository for UserProgressRepositoryImpl {
         Ok(())
     }
 
+    async fn update_if_unchanged(
+        &self,
+        progress: &UserProgress,
+        loaded_at: DateTime<Utc>,
+    ) -> Result<bool> {
+        // Same statement as `update`, guarded by the `updated_at` the copy was loaded with
+        let saved = sqlx::query!(
+            r#"
+            WITH progress AS (
+                UPDATE user_progress
+                SET total_xp = $2, current_streak_days = $3, highest_streak_days = $4,
+                    last_active_date = $5, completed_questions = $6, completed_code_practices = $7,
+                    lesson_stars = $8, daily_xp_cap = $9, xp_earned_today = $10, last_xp_reset_date = $11,
+                    streak_freezes = $12, repairable_streak_days = $13, streak_repair_expires_at = $14,
+                    updated_at = $15
+                WHERE user_id = $1 AND updated_at = $16
+                RETURNING user_id, total_xp, current_streak_days, highest_streak_days, last_active_date
+            )
+            UPDATE users
+            SET total_xp = progress.total_xp, current_streak_days = progress.current_streak_days,
+                highest_streak_days = progress.highest_streak_days,
+                last_active_date = progress.last_active_date
+            FROM progress
+            WHERE users.id = progress.user_id
+            RETURNING users.id
+            "#,
+            progress.user_id.0,
+            progress.total_xp.value() as i32,
+            progress.current_streak_days as i32,
+            progress.highest_streak_days as i32,
+            progress.last_active_date,
+            serde_json::to_value(&progress.completed_questions)
+                .unwrap_or(serde_json::Value::Array(vec![])),
+            serde_json::to_value(&progress.completed_code_practices)
+                .unwrap_or(serde_json::Value::Array(vec![])),
+            serde_json::to_value(&progress.lesson_stars)
+                .unwrap_or(serde_json::Value::Object(serde_json::Map::new())),
+            progress.daily_xp_cap.map(|cap| cap as i32),
+            progress.xp_earned_today as i32,
+            progress.last_xp_reset_date,
+            progress.streak_freezes as i32,
+            progress.repairable_streak_days as i32,
+            progress.streak_repair_expires_at,
+            progress.updated_at,
+            loaded_at
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(saved.is_some())
+    }
+
+    async fn update_streak_if_unchanged(
+        &self,
+        progress: &UserProgress,
+        loaded_at: DateTime<Utc>,
+    ) -> Result<bool> {
+        let saved = sqlx::query!(
+            r#"
+            WITH progress AS (
+                UPDATE user_progress
+                SET current_streak_days = $2, last_active_date = $3, streak_freezes = $4,
+                    repairable_streak_days = $5, streak_repair_expires_at = $6, updated_at = $7
+                WHERE user_id = $1 AND updated_at = $8
+                RETURNING user_id, current_streak_days, last_active_date
+            )
+            UPDATE users
+            SET current_streak_days = progress.current_streak_days,
+                last_active_date = progress.last_active_date
+            FROM progress
+            WHERE users.id = progress.user_id
+            RETURNING users.id
+            "#,
+            progress.user_id.0,
+            progress.current_streak_days as i32,
+            progress.last_active_date,
+            progress.streak_freezes as i32,
+            progress.repairable_streak_days as i32,
+            progress.streak_repair_expires_at,
+            progress.updated_at,
+            loaded_at
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(saved.is_some())
+    }
+
     async fn delete(&self, user_id: &UserId) -> Result<()> {
         sqlx::query!("DELETE FROM user_progress WHERE user_id = $1", user_id.0)
             .execute(&self.pool)
@@ -137,8 +246,12 @@ impl UserProgressRepository for UserProgressRepositoryImpl {
                 completed_code_practices: serde_json::from_value(row.completed_code_practices)
                     .unwrap_or_default(),
                 lesson_stars: serde_json::from_value(row.lesson_stars).unwrap_or_default(),
-                daily_xp_cap: row.daily_xp_cap as u32,
+                daily_xp_cap: row.daily_xp_cap.map(|cap| cap as u32),
+                xp_earned_today: row.xp_earned_today as u32,
                 last_xp_reset_date: row.last_xp_reset_date,
+                streak_freezes: row.streak_freezes as u32,
+                repairable_streak_days: row.repairable_streak_days as u32,
+                streak_repair_expires_at: row.streak_repair_expires_at,
                 created_at: row.created_at,
                 updated_at: row.updated_at,
             };
@@ -147,4 +260,85 @@ impl UserProgressRepository for UserProgressRepositoryImpl {
 
         Ok(progress_list)
     }
+
+    async fn list_running_streaks(
+        &self,
+        after: Option<&UserId>,
+        limit: u32,
+    ) -> Result<Vec<UserProgress>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT user_id, total_xp, current_streak_days, highest_streak_days, last_active_date,
+                   daily_xp_cap, xp_earned_today, last_xp_reset_date, streak_freezes,
+                   repairable_streak_days, streak_repair_expires_at, created_at, updated_at
+            FROM user_progress
+            WHERE current_streak_days > 0 AND ($1::UUID IS NULL OR user_id > $1)
+            ORDER BY user_id
+            LIMIT $2
+            "#,
+            after.map(|user_id| user_id.0),
+            limit as i64
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| UserProgress {
+                user_id: UserId(row.user_id),
+                total_xp: crate::domain::value_objects::Points::new(row.total_xp as u32),
+                current_streak_days: row.current_streak_days as u32,
+                highest_streak_days: row.highest_streak_days as u32,
+                last_active_date: row.last_active_date,
+                completed_questions: Vec::new(),
+                completed_code_practices: Vec::new(),
+                lesson_stars: Vec::new(),
+                daily_xp_cap: row.daily_xp_cap.map(|cap| cap as u32),
+                xp_earned_today: row.xp_earned_today as u32,
+                last_xp_reset_date: row.last_xp_reset_date,
+                streak_freezes: row.streak_freezes as u32,
+                repairable_streak_days: row.repairable_streak_days as u32,
+                streak_repair_expires_at: row.streak_repair_expires_at,
+                created_at: row.created_at,
+                updated_at: row.updated_at,
+            })
+            .collect())
+    }
+
+    async fn record_lesson_result(&self, user_id: &UserId, result: &LessonResult) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO lesson_results (user_id, lesson_id, xp_earned, perfect_score, completed_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_id, lesson_id) DO UPDATE
+            SET xp_earned = lesson_results.xp_earned + EXCLUDED.xp_earned,
+                perfect_score = lesson_results.perfect_score OR EXCLUDED.perfect_score,
+                completed_at = EXCLUDED.completed_at
+            "#,
+            user_id.0,
+            result.lesson_id.0,
+            result.xp_earned.value() as i32,
+            result.perfect_score,
+            result.completed_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(())
+    }
+
+    async fn find_lesson_results(&self, user_id: &UserId) -> Result<Vec<LessonResult>> {
+        let result_models = sqlx::query_as!(
+            LessonResultModel,
+            "SELECT * FROM lesson_results WHERE user_id = $1 ORDER BY completed_at DESC",
+            user_id.0
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(result_models.into_iter().map(LessonResult::from).collect())
+    }
 }
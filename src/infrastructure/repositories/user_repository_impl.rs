@@ -1,5 +1,6 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 
 use crate::domain::entities::User;
@@ -20,23 +21,14 @@ impl UserRepositoryImpl {
 #[async_trait]
 impl UserRepository for UserRepositoryImpl {
     async fn create(&self, user: &User) -> Result<()> {
-        let user_model = UserModel {
-            id: user.id.0,
-            email: user.email.as_str().to_string(),
-            password_hash: user.password.as_str().to_string(),
-            display_name: user.display_name.clone(),
-            total_xp: user.total_xp.value() as i32,
-            current_streak_days: user.current_streak_days as i32,
-            highest_streak_days: user.highest_streak_days as i32,
-            last_active_date: user.last_active_date,
-            created_at: user.created_at,
-            updated_at: user.updated_at,
-        };
+        let user_model = UserModel::from(user.clone());
 
         sqlx::query!(
             r#"
-            INSERT INTO users (id, email, password_hash, display_name, total_xp, current_streak_days, highest_streak_days, last_active_date, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            INSERT INTO users (id, email, password_hash, display_name, total_xp, current_streak_days, highest_streak_days, last_active_date,
+                               preferred_language, time_zone, avatar_url, pending_email, email_verification_token_hash, email_verification_expires_at,
+                               deletion_requested_at, deletion_scheduled_for, is_content_editor, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
             "#,
             user_model.id,
             user_model.email,
@@ -46,6 +38,15 @@ impl UserRepository for UserRepositoryImpl {
             user_model.current_streak_days,
             user_model.highest_streak_days,
             user_model.last_active_date,
+            user_model.preferred_language,
+            user_model.time_zone,
+            user_model.avatar_url,
+            user_model.pending_email,
+            user_model.email_verification_token_hash,
+            user_model.email_verification_expires_at,
+            user_model.deletion_requested_at,
+            user_model.deletion_scheduled_for,
+            user_model.is_content_editor,
             user_model.created_at,
             user_model.updated_at
         )
@@ -79,22 +80,34 @@ impl UserRepository for UserRepositoryImpl {
     }
 
     async fn update(&self, user: &User) -> Result<()> {
+        let user_model = UserModel::from(user.clone());
+
+        // XP and streak columns mirror `user_progress` and are written with it,
+        // so a stale user loaded before a progress update cannot overwrite them
         sqlx::query!(
             r#"
             UPDATE users
-            SET email = $2, password_hash = $3, display_name = $4, total_xp = $5,
-                current_streak_days = $6, highest_streak_days = $7, last_active_date = $8, updated_at = $9
+            SET email = $2, password_hash = $3, display_name = $4,
+                preferred_language = $5, time_zone = $6, avatar_url = $7, pending_email = $8,
+                email_verification_token_hash = $9, email_verification_expires_at = $10,
+                deletion_requested_at = $11, deletion_scheduled_for = $12, is_content_editor = $13,
+                updated_at = $14
             WHERE id = $1
             "#,
-            user.id.0,
-            user.email.as_str(),
-            user.password.as_str(),
-            user.display_name,
-            user.total_xp.value() as i32,
-            user.current_streak_days as i32,
-            user.highest_streak_days as i32,
-            user.last_active_date,
-            user.updated_at
+            user_model.id,
+            user_model.email,
+            user_model.password_hash,
+            user_model.display_name,
+            user_model.preferred_language,
+            user_model.time_zone,
+            user_model.avatar_url,
+            user_model.pending_email,
+            user_model.email_verification_token_hash,
+            user_model.email_verification_expires_at,
+            user_model.deletion_requested_at,
+            user_model.deletion_scheduled_for,
+            user_model.is_content_editor,
+            user_model.updated_at
         )
         .execute(&self.pool)
         .await
@@ -125,4 +138,17 @@ impl UserRepository for UserRepositoryImpl {
 
         Ok(user_models.into_iter().map(User::from).collect())
     }
+
+    async fn find_due_for_deletion(&self, now: DateTime<Utc>) -> Result<Vec<User>> {
+        let user_models = sqlx::query_as!(
+            UserModel,
+            "SELECT * FROM users WHERE deletion_scheduled_for <= $1",
+            now
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Database error: {e}"))?;
+
+        Ok(user_models.into_iter().map(User::from).collect())
+    }
 }
@@ -7,6 +7,7 @@ mod shared;
 use anyhow::Result;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+use application::jobs::spawn_background_jobs;
 use application::state::AppState;
 use presentation::routes::create_app_router;
 use shared::config::Config;
@@ -32,6 +33,9 @@ async fn main() -> Result<()> {
     // Create AppState from configuration
     let app_state = AppState::from_config(&config).await?;
 
+    // Start periodic maintenance jobs
+    spawn_background_jobs(app_state.clone());
+
     // Build application using modular router
     let app = create_app_router(app_state);
 
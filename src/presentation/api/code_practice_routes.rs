@@ -0,0 +1,21 @@
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use crate::application::state::AppState;
+use crate::presentation::handlers::code_practice_handlers::{
+    get_code_practice_hints_handler, reveal_next_hint_handler,
+};
+
+pub fn code_practice_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/code-practices/{id}/hints",
+            get(get_code_practice_hints_handler),
+        )
+        .route(
+            "/code-practices/{id}/hints/next",
+            post(reveal_next_hint_handler),
+        )
+}
@@ -0,0 +1,12 @@
+use axum::{routing::get, Router};
+
+use crate::application::state::AppState;
+use crate::presentation::handlers::content_handlers::{
+    list_editor_code_practices_handler, list_editor_questions_handler,
+};
+
+pub fn editor_routes() -> Router<AppState> {
+    Router::new()
+        .route("/questions", get(list_editor_questions_handler))
+        .route("/code-practices", get(list_editor_code_practices_handler))
+}
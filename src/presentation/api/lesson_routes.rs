@@ -0,0 +1,8 @@
+use axum::{routing::get, Router};
+
+use crate::application::state::AppState;
+use crate::presentation::handlers::content_handlers::get_lesson_handler;
+
+pub fn lesson_routes() -> Router<AppState> {
+    Router::new().route("/lessons/{id}", get(get_lesson_handler))
+}
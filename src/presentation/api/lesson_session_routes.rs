@@ -0,0 +1,19 @@
+use axum::{routing::post, Router};
+
+use crate::application::state::AppState;
+use crate::presentation::handlers::lesson_session_handlers::{
+    finish_lesson_session_handler, start_lesson_session_handler, submit_lesson_answer_handler,
+};
+
+pub fn lesson_session_routes() -> Router<AppState> {
+    Router::new()
+        .route("/lessons/{id}/sessions", post(start_lesson_session_handler))
+        .route(
+            "/lesson-sessions/{id}/answers",
+            post(submit_lesson_answer_handler),
+        )
+        .route(
+            "/lesson-sessions/{id}/finish",
+            post(finish_lesson_session_handler),
+        )
+}
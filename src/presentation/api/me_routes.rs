@@ -0,0 +1,62 @@
+use axum::{
+    routing::{delete, get, post},
+    Router,
+};
+
+use crate::application::state::AppState;
+use crate::presentation::handlers::account_handlers::{
+    change_email_handler, change_password_handler, get_profile_handler, update_profile_handler,
+    verify_email_handler,
+};
+use crate::presentation::handlers::achievement_handlers::get_my_achievements_handler;
+use crate::presentation::handlers::api_key_handlers::{
+    create_api_key_handler, list_api_keys_handler, revoke_api_key_handler,
+};
+use crate::presentation::handlers::code_practice_handlers::{
+    diff_my_code_submissions_handler, list_my_code_submissions_handler,
+};
+use crate::presentation::handlers::learning_path_handlers::get_my_path_handler;
+use crate::presentation::handlers::privacy_handlers::{
+    cancel_deletion_handler, export_my_data_handler, request_deletion_handler,
+};
+use crate::presentation::handlers::progress_handlers::{
+    buy_streak_freeze_handler, get_my_streak_handler, repair_streak_handler,
+};
+use crate::presentation::handlers::review_handlers::{
+    get_my_review_handler, submit_review_answer_handler,
+};
+use crate::presentation::handlers::tutor_handlers::get_my_tutor_quota_handler;
+
+pub fn me_routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(get_profile_handler).patch(update_profile_handler))
+        .route("/password", post(change_password_handler))
+        .route("/email", post(change_email_handler))
+        .route("/email/verify", post(verify_email_handler))
+        .route("/export", get(export_my_data_handler))
+        .route(
+            "/deletion",
+            post(request_deletion_handler).delete(cancel_deletion_handler),
+        )
+        .route("/achievements", get(get_my_achievements_handler))
+        .route("/path", get(get_my_path_handler))
+        .route(
+            "/code-practices/{id}/submissions",
+            get(list_my_code_submissions_handler),
+        )
+        .route(
+            "/code-practices/{id}/submissions/diff",
+            get(diff_my_code_submissions_handler),
+        )
+        .route("/review", get(get_my_review_handler))
+        .route("/review/answers", post(submit_review_answer_handler))
+        .route("/tutor", get(get_my_tutor_quota_handler))
+        .route("/streak", get(get_my_streak_handler))
+        .route("/streak/freezes", post(buy_streak_freeze_handler))
+        .route("/streak/repair", post(repair_streak_handler))
+        .route(
+            "/api-keys",
+            get(list_api_keys_handler).post(create_api_key_handler),
+        )
+        .route("/api-keys/{id}", delete(revoke_api_key_handler))
+}
@@ -1,7 +1,21 @@
 pub mod auth_routes;
+pub mod code_practice_routes;
 pub mod content_routes;
+pub mod editor_routes;
+pub mod lesson_routes;
+pub mod lesson_session_routes;
+pub mod me_routes;
 pub mod progress_routes;
+pub mod quiz_routes;
+pub mod tutor_routes;
 
 pub use auth_routes::*;
+pub use code_practice_routes::*;
 pub use content_routes::*;
+pub use editor_routes::*;
+pub use lesson_routes::*;
+pub use lesson_session_routes::*;
+pub use me_routes::*;
 pub use progress_routes::*;
+pub use quiz_routes::*;
+pub use tutor_routes::*;
@@ -1,8 +1,12 @@
 use axum::{routing::get, Router};
 
 use crate::application::state::AppState;
-use crate::presentation::handlers::progress_handlers::get_leaderboard_handler;
+use crate::presentation::handlers::progress_handlers::{
+    get_leaderboard_handler, get_my_progress_handler,
+};
 
 pub fn progress_routes() -> Router<AppState> {
-    Router::new().route("/leaderboard", get(get_leaderboard_handler))
+    Router::new()
+        .route("/leaderboard", get(get_leaderboard_handler))
+        .route("/me", get(get_my_progress_handler))
 }
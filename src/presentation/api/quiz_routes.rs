@@ -0,0 +1,28 @@
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use crate::application::state::AppState;
+use crate::presentation::handlers::quiz_handlers::{
+    get_quiz_attempt_handler, list_quiz_attempts_handler, list_quizzes_handler,
+    start_quiz_attempt_handler, submit_quiz_answer_handler, submit_quiz_attempt_handler,
+};
+
+pub fn quiz_routes() -> Router<AppState> {
+    Router::new()
+        .route("/quizzes", get(list_quizzes_handler))
+        .route(
+            "/quizzes/{id}/attempts",
+            get(list_quiz_attempts_handler).post(start_quiz_attempt_handler),
+        )
+        .route("/quiz-attempts/{id}", get(get_quiz_attempt_handler))
+        .route(
+            "/quiz-attempts/{id}/answers",
+            post(submit_quiz_answer_handler),
+        )
+        .route(
+            "/quiz-attempts/{id}/submit",
+            post(submit_quiz_attempt_handler),
+        )
+}
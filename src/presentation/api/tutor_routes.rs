@@ -0,0 +1,18 @@
+use axum::{routing::post, Router};
+
+use crate::application::state::AppState;
+use crate::presentation::handlers::tutor_handlers::{
+    explain_code_submission_handler, explain_quiz_answer_handler,
+};
+
+pub fn tutor_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/quiz-attempts/{id}/questions/{question_id}/explain",
+            post(explain_quiz_answer_handler),
+        )
+        .route(
+            "/code-submissions/{id}/explain",
+            post(explain_code_submission_handler),
+        )
+}
@@ -0,0 +1,113 @@
+use axum::extract::{FromRequestParts, OptionalFromRequestParts};
+use axum::http::{header::AUTHORIZATION, request::Parts};
+
+use crate::application::state::AppState;
+use crate::domain::services::api_key_service::is_api_key;
+use crate::domain::value_objects::{ApiKeyId, ApiScope, UserId};
+use crate::shared::errors::AppError;
+
+/// How the caller proved who they are.
+#[derive(Debug, Clone)]
+pub enum AuthCredential {
+    Jwt,
+    ApiKey {
+        key_id: ApiKeyId,
+        scopes: Vec<ApiScope>,
+    },
+}
+
+/// Authenticated caller, extracted from `Authorization: Bearer <token>`.
+/// The token may be either a JWT issued at login or a personal API key.
+#[derive(Debug, Clone)]
+pub struct AuthUser {
+    pub user_id: UserId,
+    pub credential: AuthCredential,
+}
+
+impl AuthUser {
+    /// JWT sessions carry every scope; API keys only the ones they were created with.
+    pub fn require_scope(&self, scope: ApiScope) -> Result<(), AppError> {
+        match &self.credential {
+            AuthCredential::Jwt => Ok(()),
+            AuthCredential::ApiKey { scopes, .. } if scopes.contains(&scope) => Ok(()),
+            AuthCredential::ApiKey { .. } => Err(AppError::Authorization(format!(
+                "API key is missing the '{scope}' scope"
+            ))),
+        }
+    }
+
+    /// Rejects API keys for account-level operations such as managing keys.
+    pub fn require_session(&self) -> Result<(), AppError> {
+        match self.credential {
+            AuthCredential::Jwt => Ok(()),
+            AuthCredential::ApiKey { .. } => Err(AppError::Authorization(
+                "This operation requires a login session".to_string(),
+            )),
+        }
+    }
+}
+
+impl FromRequestParts<AppState> for AuthUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let token = parts
+            .headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .ok_or_else(|| AppError::Authentication("Missing bearer token".to_string()))?;
+
+        if is_api_key(token) {
+            let api_key = state
+                .api_key_service
+                .authenticate(token)
+                .await
+                .map_err(|e| AppError::Authentication(e.to_string()))?;
+
+            return Ok(Self {
+                user_id: api_key.user_id,
+                credential: AuthCredential::ApiKey {
+                    key_id: api_key.id,
+                    scopes: api_key.scopes,
+                },
+            });
+        }
+
+        let claims = state
+            .auth_service
+            .verify_token(token)
+            .await
+            .map_err(|e| AppError::Authentication(e.to_string()))?;
+        let user_id = UserId::from_string(claims.sub)
+            .map_err(|e| AppError::Authentication(format!("Invalid token subject: {e}")))?;
+
+        Ok(Self {
+            user_id,
+            credential: AuthCredential::Jwt,
+        })
+    }
+}
+
+/// Anonymous when no `Authorization` header is sent. A credential that is sent
+/// must still be valid, so a bad token is rejected rather than ignored.
+impl OptionalFromRequestParts<AppState> for AuthUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Option<Self>, Self::Rejection> {
+        if !parts.headers.contains_key(AUTHORIZATION) {
+            return Ok(None);
+        }
+        <Self as FromRequestParts<AppState>>::from_request_parts(parts, state)
+            .await
+            .map(Some)
+    }
+}
@@ -0,0 +1,41 @@
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+
+use crate::application::state::AppState;
+use crate::domain::value_objects::UserId;
+use crate::presentation::extractors::AuthUser;
+use crate::shared::errors::AppError;
+
+/// Logged-in user allowed to see full content, answers included. API keys are
+/// rejected so a leaked key cannot expose answers.
+#[derive(Debug, Clone)]
+pub struct ContentEditor {
+    pub user_id: UserId,
+}
+
+impl FromRequestParts<AppState> for ContentEditor {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let auth = AuthUser::from_request_parts(parts, state).await?;
+        auth.require_session()?;
+
+        let user = state
+            .user_repository
+            .find_by_id(&auth.user_id)
+            .await?
+            .ok_or_else(|| AppError::Authentication("User not found".to_string()))?;
+        if !user.is_content_editor {
+            return Err(AppError::Authorization(
+                "This operation requires content editor access".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            user_id: auth.user_id,
+        })
+    }
+}
@@ -0,0 +1,7 @@
+pub mod auth_user;
+pub mod content_editor;
+pub mod request_locale;
+
+pub use auth_user::*;
+pub use content_editor::*;
+pub use request_locale::*;
@@ -0,0 +1,48 @@
+use axum::extract::{FromRequestParts, Query};
+use axum::http::{header::ACCEPT_LANGUAGE, request::Parts};
+use serde::Deserialize;
+
+use crate::shared::errors::AppError;
+use crate::shared::utils::{normalize_locale, parse_accept_language, DEFAULT_LOCALE};
+
+#[derive(Debug, Deserialize)]
+struct LangQuery {
+    lang: Option<String>,
+}
+
+/// Locales to render content in, most preferred first: the `lang` query
+/// parameter alone, else every `Accept-Language` entry, else English. Text is
+/// looked up in each with its fallbacks, as `LocalizedText::get` describes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestLocale(pub Vec<String>);
+
+impl RequestLocale {
+    pub fn tags(&self) -> Vec<&str> {
+        self.0.iter().map(String::as_str).collect()
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for RequestLocale {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let lang = Query::<LangQuery>::try_from_uri(&parts.uri)
+            .ok()
+            .and_then(|Query(query)| query.lang);
+        if let Some(lang) = lang {
+            return normalize_locale(&lang)
+                .map(|locale| Self(vec![locale]))
+                .ok_or_else(|| AppError::Validation(format!("Invalid locale: {lang}")));
+        }
+
+        let accepted = parts
+            .headers
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .map(parse_accept_language)
+            .filter(|locales| !locales.is_empty());
+        Ok(Self(
+            accepted.unwrap_or_else(|| vec![DEFAULT_LOCALE.to_string()]),
+        ))
+    }
+}
@@ -0,0 +1,79 @@
+use axum::{extract::State, response::Json};
+use serde_json::{json, Value};
+
+use crate::application::state::AppState;
+use crate::application::use_cases::account_use_cases::{
+    ChangeEmailRequest, ChangePasswordRequest, EmailChangeResponse, ProfileResponse,
+    UpdateProfileRequest, VerifyEmailRequest,
+};
+use crate::presentation::extractors::AuthUser;
+use crate::shared::errors::{AppError, Result};
+
+pub async fn get_profile_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<Json<ProfileResponse>> {
+    auth.require_session()?;
+    let response = state
+        .account_use_cases
+        .get_profile(&auth.user_id)
+        .await
+        .map_err(|e| AppError::NotFound(e.to_string()))?;
+    Ok(Json(response))
+}
+
+pub async fn update_profile_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Json(payload): Json<UpdateProfileRequest>,
+) -> Result<Json<ProfileResponse>> {
+    auth.require_session()?;
+    let response = state
+        .account_use_cases
+        .update_profile(&auth.user_id, payload)
+        .await
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+    Ok(Json(response))
+}
+
+pub async fn change_password_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Json(payload): Json<ChangePasswordRequest>,
+) -> Result<Json<Value>> {
+    auth.require_session()?;
+    state
+        .account_use_cases
+        .change_password(&auth.user_id, payload)
+        .await
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+    Ok(Json(json!({ "message": "Password updated" })))
+}
+
+pub async fn change_email_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Json(payload): Json<ChangeEmailRequest>,
+) -> Result<Json<EmailChangeResponse>> {
+    auth.require_session()?;
+    let response = state
+        .account_use_cases
+        .request_email_change(&auth.user_id, payload)
+        .await
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+    Ok(Json(response))
+}
+
+pub async fn verify_email_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Json(payload): Json<VerifyEmailRequest>,
+) -> Result<Json<ProfileResponse>> {
+    auth.require_session()?;
+    let response = state
+        .account_use_cases
+        .confirm_email_change(&auth.user_id, payload)
+        .await
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+    Ok(Json(response))
+}
@@ -0,0 +1,20 @@
+use axum::{extract::State, response::Json};
+
+use crate::application::state::AppState;
+use crate::application::use_cases::achievement_use_cases::AchievementsResponse;
+use crate::domain::value_objects::ApiScope;
+use crate::presentation::extractors::{AuthUser, RequestLocale};
+use crate::shared::errors::Result;
+
+pub async fn get_my_achievements_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    locale: RequestLocale,
+) -> Result<Json<AchievementsResponse>> {
+    auth.require_scope(ApiScope::ProgressRead)?;
+    let response = state
+        .achievement_use_cases
+        .list_achievements(&auth.user_id, &locale.tags())
+        .await?;
+    Ok(Json(response))
+}
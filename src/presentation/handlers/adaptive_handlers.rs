@@ -0,0 +1,25 @@
+use axum::{
+    extract::{Path, State},
+    response::Json,
+};
+
+use crate::application::state::AppState;
+use crate::application::use_cases::adaptive_use_cases::NextQuestionResponse;
+use crate::domain::value_objects::ApiScope;
+use crate::presentation::extractors::{AuthUser, RequestLocale};
+use crate::shared::errors::Result;
+
+pub async fn next_question_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    locale: RequestLocale,
+    Path(topic_id): Path<String>,
+) -> Result<Json<NextQuestionResponse>> {
+    auth.require_scope(ApiScope::ContentRead)?;
+    auth.require_scope(ApiScope::ProgressRead)?;
+    let response = state
+        .adaptive_use_cases
+        .next_question(&auth.user_id, &topic_id, &locale.tags())
+        .await?;
+    Ok(Json(response))
+}
@@ -0,0 +1,48 @@
+use axum::{
+    extract::{Path, State},
+    response::Json,
+};
+
+use crate::application::state::AppState;
+use crate::application::use_cases::api_key_use_cases::{
+    ApiKeyResponse, CreateApiKeyRequest, CreatedApiKeyResponse,
+};
+use crate::presentation::extractors::AuthUser;
+use crate::shared::errors::{AppError, Result};
+
+pub async fn create_api_key_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Json(payload): Json<CreateApiKeyRequest>,
+) -> Result<Json<CreatedApiKeyResponse>> {
+    auth.require_session()?;
+    let response = state
+        .api_key_use_cases
+        .create_api_key(&auth.user_id, payload)
+        .await
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+    Ok(Json(response))
+}
+
+pub async fn list_api_keys_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<Json<Vec<ApiKeyResponse>>> {
+    auth.require_session()?;
+    let response = state.api_key_use_cases.list_api_keys(&auth.user_id).await?;
+    Ok(Json(response))
+}
+
+pub async fn revoke_api_key_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(key_id): Path<String>,
+) -> Result<Json<ApiKeyResponse>> {
+    auth.require_session()?;
+    let response = state
+        .api_key_use_cases
+        .revoke_api_key(&auth.user_id, &key_id)
+        .await
+        .map_err(|e| AppError::NotFound(e.to_string()))?;
+    Ok(Json(response))
+}
@@ -0,0 +1,112 @@
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use std::str::FromStr;
+
+use crate::application::state::AppState;
+use crate::domain::entities::Asset;
+use crate::domain::value_objects::AssetId;
+use crate::shared::errors::{AppError, Result};
+
+/// Asset content never changes under an ID, so clients may cache it for good.
+const ASSET_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+pub async fn get_asset_handler(
+    State(state): State<AppState>,
+    Path(asset_id): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response> {
+    let asset_id = parse_asset_id(&asset_id)?;
+    let (asset, data) = state
+        .asset_service
+        .open(&asset_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Asset not found".to_string()))?;
+    let etag = format!("\"{}\"", asset.sha256);
+    Ok(asset_response(
+        &asset,
+        &etag,
+        asset.asset_type.content_type(),
+        data,
+        &headers,
+    ))
+}
+
+pub async fn get_asset_thumbnail_handler(
+    State(state): State<AppState>,
+    Path(asset_id): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response> {
+    let asset_id = parse_asset_id(&asset_id)?;
+    let (asset, data) = state
+        .asset_service
+        .open_thumbnail(&asset_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Thumbnail not found".to_string()))?;
+    let etag = format!("\"{}-thumbnail\"", asset.sha256);
+    Ok(asset_response(&asset, &etag, "image/png", data, &headers))
+}
+
+fn parse_asset_id(asset_id: &str) -> Result<AssetId> {
+    // A malformed ID cannot name an asset
+    AssetId::from_str(asset_id).map_err(|_| AppError::NotFound("Asset not found".to_string()))
+}
+
+pub(crate) fn asset_response(
+    asset: &Asset,
+    etag: &str,
+    content_type: &str,
+    data: Vec<u8>,
+    request_headers: &HeaderMap,
+) -> Response {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::CACHE_CONTROL,
+        HeaderValue::from_static(ASSET_CACHE_CONTROL),
+    );
+    if let Ok(value) = HeaderValue::from_str(etag) {
+        headers.insert(header::ETAG, value);
+    }
+
+    let not_modified = request_headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value
+                .split(',')
+                .any(|tag| matches!(tag.trim(), "*") || tag.trim().trim_start_matches("W/") == etag)
+        });
+    if not_modified {
+        return (StatusCode::NOT_MODIFIED, headers).into_response();
+    }
+
+    if let Ok(value) = HeaderValue::from_str(content_type) {
+        headers.insert(header::CONTENT_TYPE, value);
+    }
+    // Browsers must not second-guess the sniffed type
+    headers.insert(
+        header::X_CONTENT_TYPE_OPTIONS,
+        HeaderValue::from_static("nosniff"),
+    );
+    if !asset.asset_type.is_image() {
+        // Documents are downloaded rather than rendered on the app's origin,
+        // and are sandboxed should a browser render them anyway
+        let disposition = format!(
+            "attachment; filename=\"{}\"",
+            asset.original_filename.replace(
+                |c: char| !c.is_ascii() || c.is_ascii_control() || c == '"' || c == '\\',
+                "_"
+            )
+        );
+        if let Ok(value) = HeaderValue::from_str(&disposition) {
+            headers.insert(header::CONTENT_DISPOSITION, value);
+        }
+        headers.insert(
+            header::CONTENT_SECURITY_POLICY,
+            HeaderValue::from_static("sandbox"),
+        );
+    }
+    (StatusCode::OK, headers, data).into_response()
+}
@@ -0,0 +1,67 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Json,
+};
+
+use crate::application::state::AppState;
+use crate::application::use_cases::code_practice_use_cases::{
+    CodeSubmissionResponse, HintStatusResponse, SubmissionDiffQuery, SubmissionDiffResponse,
+};
+use crate::domain::value_objects::ApiScope;
+use crate::presentation::extractors::{AuthUser, RequestLocale};
+use crate::shared::errors::Result;
+
+pub async fn get_code_practice_hints_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    locale: RequestLocale,
+    Path(code_practice_id): Path<String>,
+) -> Result<Json<HintStatusResponse>> {
+    auth.require_scope(ApiScope::ProgressRead)?;
+    let response = state
+        .code_practice_use_cases
+        .get_hints(&auth.user_id, &code_practice_id, &locale.tags())
+        .await?;
+    Ok(Json(response))
+}
+
+pub async fn reveal_next_hint_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    locale: RequestLocale,
+    Path(code_practice_id): Path<String>,
+) -> Result<Json<HintStatusResponse>> {
+    auth.require_session()?;
+    let response = state
+        .code_practice_use_cases
+        .reveal_next_hint(&auth.user_id, &code_practice_id, &locale.tags())
+        .await?;
+    Ok(Json(response))
+}
+
+pub async fn list_my_code_submissions_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(code_practice_id): Path<String>,
+) -> Result<Json<Vec<CodeSubmissionResponse>>> {
+    auth.require_scope(ApiScope::ProgressRead)?;
+    let response = state
+        .code_practice_use_cases
+        .list_submissions(&auth.user_id, &code_practice_id)
+        .await?;
+    Ok(Json(response))
+}
+
+pub async fn diff_my_code_submissions_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(code_practice_id): Path<String>,
+    Query(query): Query<SubmissionDiffQuery>,
+) -> Result<Json<SubmissionDiffResponse>> {
+    auth.require_scope(ApiScope::ProgressRead)?;
+    let response = state
+        .code_practice_use_cases
+        .diff_submissions(&auth.user_id, &code_practice_id, query)
+        .await?;
+    Ok(Json(response))
+}
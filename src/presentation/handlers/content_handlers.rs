@@ -1,13 +1,16 @@
 use axum::{
-    extract::{Query, State},
+    extract::{Path, Query, State},
     response::Json,
 };
 use serde::Deserialize;
 
 use crate::application::state::AppState;
 use crate::application::use_cases::content_use_cases::{
-    CodePracticeResponse, LessonResponse, QuestionResponse, TopicResponse,
+    CodePracticeResponse, EditorCodePracticeResponse, EditorQuestionResponse, LessonDetailResponse,
+    LessonResponse, QuestionResponse, TopicResponse,
 };
+use crate::domain::value_objects::ApiScope;
+use crate::presentation::extractors::{AuthUser, ContentEditor, RequestLocale};
 use crate::shared::errors::Result;
 
 #[derive(Debug, Deserialize)]
@@ -29,37 +32,102 @@ pub struct CodePracticeQuery {
 
 pub async fn list_topics_handler(
     State(state): State<AppState>,
+    auth: Option<AuthUser>,
+    locale: RequestLocale,
 ) -> Result<Json<Vec<TopicResponse>>> {
-    let topics = state.content_use_cases.get_topics().await?;
+    if let Some(auth) = &auth {
+        auth.require_scope(ApiScope::ContentRead)?;
+    }
+    let topics = state.content_use_cases.get_topics(&locale.tags()).await?;
     Ok(Json(topics))
 }
 
 pub async fn list_lessons_handler(
     State(state): State<AppState>,
+    auth: Option<AuthUser>,
+    locale: RequestLocale,
     Query(params): Query<TopicQuery>,
 ) -> Result<Json<Vec<LessonResponse>>> {
-    let lessons = state.content_use_cases.get_lessons(params.topic_id).await?;
+    if let Some(auth) = &auth {
+        auth.require_scope(ApiScope::ContentRead)?;
+    }
+    let lessons = state
+        .content_use_cases
+        .get_lessons(params.topic_id, &locale.tags())
+        .await?;
     Ok(Json(lessons))
 }
 
+pub async fn get_lesson_handler(
+    State(state): State<AppState>,
+    auth: Option<AuthUser>,
+    locale: RequestLocale,
+    Path(lesson_id): Path<String>,
+) -> Result<Json<LessonDetailResponse>> {
+    if let Some(auth) = &auth {
+        auth.require_scope(ApiScope::ContentRead)?;
+    }
+    let lesson = state
+        .content_use_cases
+        .get_lesson(&lesson_id, &locale.tags())
+        .await?;
+    Ok(Json(lesson))
+}
+
 pub async fn list_questions_handler(
     State(state): State<AppState>,
+    auth: Option<AuthUser>,
+    locale: RequestLocale,
     Query(params): Query<QuestionQuery>,
 ) -> Result<Json<Vec<QuestionResponse>>> {
+    if let Some(auth) = &auth {
+        auth.require_scope(ApiScope::ContentRead)?;
+    }
     let questions = state
         .content_use_cases
-        .get_questions(params.topic_id, params.difficulty)
+        .get_questions(params.topic_id, params.difficulty, &locale.tags())
         .await?;
     Ok(Json(questions))
 }
 
 pub async fn list_code_practices_handler(
     State(state): State<AppState>,
+    auth: Option<AuthUser>,
+    locale: RequestLocale,
     Query(params): Query<CodePracticeQuery>,
 ) -> Result<Json<Vec<CodePracticeResponse>>> {
+    if let Some(auth) = &auth {
+        auth.require_scope(ApiScope::ContentRead)?;
+    }
+    let code_practices = state
+        .content_use_cases
+        .get_code_practices(params.topic_id, params.lesson_id, &locale.tags())
+        .await?;
+    Ok(Json(code_practices))
+}
+
+pub async fn list_editor_questions_handler(
+    State(state): State<AppState>,
+    _editor: ContentEditor,
+    locale: RequestLocale,
+    Query(params): Query<QuestionQuery>,
+) -> Result<Json<Vec<EditorQuestionResponse>>> {
+    let questions = state
+        .content_use_cases
+        .get_editor_questions(params.topic_id, params.difficulty, &locale.tags())
+        .await?;
+    Ok(Json(questions))
+}
+
+pub async fn list_editor_code_practices_handler(
+    State(state): State<AppState>,
+    _editor: ContentEditor,
+    locale: RequestLocale,
+    Query(params): Query<CodePracticeQuery>,
+) -> Result<Json<Vec<EditorCodePracticeResponse>>> {
     let code_practices = state
         .content_use_cases
-        .get_code_practices(params.topic_id, params.lesson_id)
+        .get_editor_code_practices(params.topic_id, params.lesson_id, &locale.tags())
         .await?;
     Ok(Json(code_practices))
 }
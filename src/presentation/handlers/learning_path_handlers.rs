@@ -0,0 +1,20 @@
+use axum::{extract::State, response::Json};
+
+use crate::application::state::AppState;
+use crate::application::use_cases::learning_path_use_cases::PathResponse;
+use crate::domain::value_objects::ApiScope;
+use crate::presentation::extractors::{AuthUser, RequestLocale};
+use crate::shared::errors::Result;
+
+pub async fn get_my_path_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    locale: RequestLocale,
+) -> Result<Json<PathResponse>> {
+    auth.require_scope(ApiScope::ProgressRead)?;
+    let response = state
+        .learning_path_use_cases
+        .get_path(&auth.user_id, &locale.tags())
+        .await?;
+    Ok(Json(response))
+}
@@ -0,0 +1,56 @@
+use axum::{
+    extract::{Path, State},
+    response::Json,
+};
+
+use crate::application::state::AppState;
+use crate::application::use_cases::lesson_session_use_cases::{
+    AnswerResponse, LessonCompletionResponse, LessonSessionResponse, SubmitAnswerRequest,
+};
+use crate::presentation::extractors::{AuthUser, RequestLocale};
+use crate::shared::errors::{AppError, Result};
+
+pub async fn start_lesson_session_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    locale: RequestLocale,
+    Path(lesson_id): Path<String>,
+) -> Result<Json<LessonSessionResponse>> {
+    auth.require_session()?;
+    let response = state
+        .lesson_session_use_cases
+        .start_session(&auth.user_id, &lesson_id, &locale.tags())
+        .await
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+    Ok(Json(response))
+}
+
+pub async fn submit_lesson_answer_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    locale: RequestLocale,
+    Path(session_id): Path<String>,
+    Json(payload): Json<SubmitAnswerRequest>,
+) -> Result<Json<AnswerResponse>> {
+    auth.require_session()?;
+    let response = state
+        .lesson_session_use_cases
+        .submit_answer(&auth.user_id, &session_id, payload, &locale.tags())
+        .await
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+    Ok(Json(response))
+}
+
+pub async fn finish_lesson_session_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(session_id): Path<String>,
+) -> Result<Json<LessonCompletionResponse>> {
+    auth.require_session()?;
+    let response = state
+        .lesson_session_use_cases
+        .finish_session(&auth.user_id, &session_id)
+        .await
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+    Ok(Json(response))
+}
@@ -1,10 +1,34 @@
+pub mod account_handlers;
+pub mod achievement_handlers;
+pub mod adaptive_handlers;
+pub mod api_key_handlers;
+pub mod asset_handlers;
 pub mod auth_handlers;
+pub mod code_practice_handlers;
 pub mod content_handlers;
+pub mod learning_path_handlers;
+pub mod lesson_session_handlers;
+pub mod privacy_handlers;
 pub mod progress_handlers;
+pub mod quiz_handlers;
+pub mod review_handlers;
+pub mod tutor_handlers;
 
 #[cfg(test)]
 mod simple_tests;
 
+pub use account_handlers::*;
+pub use achievement_handlers::*;
+pub use adaptive_handlers::*;
+pub use api_key_handlers::*;
+pub use asset_handlers::*;
 pub use auth_handlers::*;
+pub use code_practice_handlers::*;
 pub use content_handlers::*;
+pub use learning_path_handlers::*;
+pub use lesson_session_handlers::*;
+pub use privacy_handlers::*;
 pub use progress_handlers::*;
+pub use quiz_handlers::*;
+pub use review_handlers::*;
+pub use tutor_handlers::*;
@@ -0,0 +1,55 @@
+use axum::{
+    extract::State,
+    http::header,
+    response::{IntoResponse, Json},
+};
+
+use crate::application::state::AppState;
+use crate::application::use_cases::privacy_use_cases::{
+    DeleteAccountRequest, DeletionStatusResponse,
+};
+use crate::presentation::extractors::AuthUser;
+use crate::shared::errors::{AppError, Result};
+
+pub async fn export_my_data_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<impl IntoResponse> {
+    auth.require_session()?;
+    let export = state.privacy_use_cases.export_data(&auth.user_id).await?;
+
+    let filename = format!(
+        "rust-learning-export-{}.json",
+        chrono::Utc::now().format("%Y%m%d")
+    );
+    let disposition = format!("attachment; filename=\"{filename}\"");
+
+    Ok(([(header::CONTENT_DISPOSITION, disposition)], Json(export)))
+}
+
+pub async fn request_deletion_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Json(payload): Json<DeleteAccountRequest>,
+) -> Result<Json<DeletionStatusResponse>> {
+    auth.require_session()?;
+    let response = state
+        .privacy_use_cases
+        .request_deletion(&auth.user_id, payload)
+        .await
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+    Ok(Json(response))
+}
+
+pub async fn cancel_deletion_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<Json<DeletionStatusResponse>> {
+    auth.require_session()?;
+    let response = state
+        .privacy_use_cases
+        .cancel_deletion(&auth.user_id)
+        .await
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+    Ok(Json(response))
+}
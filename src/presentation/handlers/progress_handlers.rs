@@ -5,8 +5,12 @@ use axum::{
 use serde::Deserialize;
 
 use crate::application::state::AppState;
-use crate::application::use_cases::progress_use_cases::LeaderboardResponse;
-use crate::shared::errors::Result;
+use crate::application::use_cases::progress_use_cases::{
+    LeaderboardResponse, StreakStatusResponse, UserProgressResponse,
+};
+use crate::domain::value_objects::ApiScope;
+use crate::presentation::extractors::AuthUser;
+use crate::shared::errors::{AppError, Result};
 
 #[derive(Debug, Deserialize)]
 pub struct LeaderboardQuery {
@@ -23,3 +27,53 @@ pub async fn get_leaderboard_handler(
         .await?;
     Ok(Json(response))
 }
+
+pub async fn get_my_progress_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<Json<UserProgressResponse>> {
+    auth.require_scope(ApiScope::ProgressRead)?;
+    let response = state
+        .progress_use_cases
+        .get_user_progress(&auth.user_id)
+        .await?;
+    Ok(Json(response))
+}
+
+pub async fn get_my_streak_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<Json<StreakStatusResponse>> {
+    auth.require_scope(ApiScope::ProgressRead)?;
+    let response = state
+        .progress_use_cases
+        .get_streak_status(&auth.user_id)
+        .await?;
+    Ok(Json(response))
+}
+
+pub async fn buy_streak_freeze_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<Json<StreakStatusResponse>> {
+    auth.require_session()?;
+    let response = state
+        .progress_use_cases
+        .buy_streak_freeze(&auth.user_id)
+        .await
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+    Ok(Json(response))
+}
+
+pub async fn repair_streak_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<Json<StreakStatusResponse>> {
+    auth.require_session()?;
+    let response = state
+        .progress_use_cases
+        .repair_streak(&auth.user_id)
+        .await
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+    Ok(Json(response))
+}
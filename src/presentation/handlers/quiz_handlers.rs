@@ -0,0 +1,94 @@
+use axum::{
+    extract::{Path, State},
+    response::Json,
+};
+
+use crate::application::state::AppState;
+use crate::application::use_cases::quiz_use_cases::{
+    QuizAnswerSavedResponse, QuizAttemptResponse, QuizAttemptSummaryResponse, QuizResponse,
+    SubmitQuizAnswerRequest,
+};
+use crate::domain::value_objects::ApiScope;
+use crate::presentation::extractors::{AuthUser, RequestLocale};
+use crate::shared::errors::Result;
+
+pub async fn list_quizzes_handler(
+    State(state): State<AppState>,
+    auth: Option<AuthUser>,
+    locale: RequestLocale,
+) -> Result<Json<Vec<QuizResponse>>> {
+    if let Some(auth) = &auth {
+        auth.require_scope(ApiScope::ContentRead)?;
+    }
+    let quizzes = state.quiz_use_cases.list_quizzes(&locale.tags()).await?;
+    Ok(Json(quizzes))
+}
+
+pub async fn start_quiz_attempt_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    locale: RequestLocale,
+    Path(quiz_id): Path<String>,
+) -> Result<Json<QuizAttemptResponse>> {
+    auth.require_session()?;
+    let response = state
+        .quiz_use_cases
+        .start_attempt(&auth.user_id, &quiz_id, &locale.tags())
+        .await?;
+    Ok(Json(response))
+}
+
+pub async fn list_quiz_attempts_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(quiz_id): Path<String>,
+) -> Result<Json<Vec<QuizAttemptSummaryResponse>>> {
+    auth.require_scope(ApiScope::ProgressRead)?;
+    let response = state
+        .quiz_use_cases
+        .list_attempts(&auth.user_id, &quiz_id)
+        .await?;
+    Ok(Json(response))
+}
+
+pub async fn get_quiz_attempt_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    locale: RequestLocale,
+    Path(attempt_id): Path<String>,
+) -> Result<Json<QuizAttemptResponse>> {
+    auth.require_scope(ApiScope::ProgressRead)?;
+    let response = state
+        .quiz_use_cases
+        .get_attempt(&auth.user_id, &attempt_id, &locale.tags())
+        .await?;
+    Ok(Json(response))
+}
+
+pub async fn submit_quiz_answer_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(attempt_id): Path<String>,
+    Json(payload): Json<SubmitQuizAnswerRequest>,
+) -> Result<Json<QuizAnswerSavedResponse>> {
+    auth.require_session()?;
+    let response = state
+        .quiz_use_cases
+        .submit_answer(&auth.user_id, &attempt_id, payload)
+        .await?;
+    Ok(Json(response))
+}
+
+pub async fn submit_quiz_attempt_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    locale: RequestLocale,
+    Path(attempt_id): Path<String>,
+) -> Result<Json<QuizAttemptResponse>> {
+    auth.require_session()?;
+    let response = state
+        .quiz_use_cases
+        .submit_attempt(&auth.user_id, &attempt_id, &locale.tags())
+        .await?;
+    Ok(Json(response))
+}
@@ -0,0 +1,37 @@
+use axum::{extract::State, response::Json};
+
+use crate::application::state::AppState;
+use crate::application::use_cases::review_use_cases::{
+    ReviewAnswerResponse, ReviewSetResponse, SubmitReviewRequest,
+};
+use crate::domain::value_objects::ApiScope;
+use crate::presentation::extractors::{AuthUser, RequestLocale};
+use crate::shared::errors::{AppError, Result};
+
+pub async fn get_my_review_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    locale: RequestLocale,
+) -> Result<Json<ReviewSetResponse>> {
+    auth.require_scope(ApiScope::ProgressRead)?;
+    let response = state
+        .review_use_cases
+        .get_review_set(&auth.user_id, &locale.tags())
+        .await?;
+    Ok(Json(response))
+}
+
+pub async fn submit_review_answer_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    locale: RequestLocale,
+    Json(payload): Json<SubmitReviewRequest>,
+) -> Result<Json<ReviewAnswerResponse>> {
+    auth.require_session()?;
+    let response = state
+        .review_use_cases
+        .submit_review(&auth.user_id, payload, &locale.tags())
+        .await
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+    Ok(Json(response))
+}
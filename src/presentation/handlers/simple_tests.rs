@@ -114,4 +114,107 @@ mod tests {
         assert!(json.contains("100"));
         assert!(json.contains("500"));
     }
+
+    #[test]
+    fn test_api_keys_only_reach_routes_for_their_scopes() {
+        use crate::presentation::extractors::{AuthCredential, AuthUser};
+
+        let progress_key = AuthUser {
+            user_id: UserId::new(),
+            credential: AuthCredential::ApiKey {
+                key_id: ApiKeyId::new(),
+                scopes: vec![ApiScope::ProgressRead],
+            },
+        };
+        assert!(progress_key.require_scope(ApiScope::ProgressRead).is_ok());
+        assert!(progress_key.require_scope(ApiScope::ContentRead).is_err());
+
+        let session = AuthUser {
+            user_id: UserId::new(),
+            credential: AuthCredential::Jwt,
+        };
+        assert!(session.require_scope(ApiScope::ContentRead).is_ok());
+    }
+
+    #[test]
+    fn test_domain_errors_keep_their_status_through_anyhow() {
+        use crate::shared::errors::{AppError, DomainError};
+        use axum::{http::StatusCode, response::IntoResponse};
+
+        let status = |error: anyhow::Error| AppError::from(error).into_response().status();
+
+        assert_eq!(
+            status(DomainError::NotFound("Quiz not found".to_string()).into()),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            status(DomainError::Invalid("No attempts left".to_string()).into()),
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            status(anyhow::anyhow!("connection refused")),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_locale_keeps_every_accepted_language() {
+        use crate::presentation::extractors::RequestLocale;
+        use axum::{extract::FromRequestParts, http::Request};
+
+        let locale = |uri: &str, accept: Option<&str>| {
+            let mut request = Request::builder().uri(uri);
+            if let Some(accept) = accept {
+                request = request.header("Accept-Language", accept);
+            }
+            let (mut parts, _) = request.body(()).unwrap().into_parts();
+            async move { RequestLocale::from_request_parts(&mut parts, &()).await }
+        };
+
+        let accepted = locale("/topics", Some("fr;q=0.9, id;q=0.8, pt-br"))
+            .await
+            .unwrap();
+        assert_eq!(accepted.tags(), ["pt-BR", "fr", "id"]);
+        let chosen = locale("/topics?lang=id", Some("fr")).await.unwrap();
+        assert_eq!(chosen.tags(), ["id"]);
+        assert_eq!(locale("/topics", None).await.unwrap().tags(), ["en"]);
+        assert!(locale("/topics?lang=*", None).await.is_err());
+    }
+
+    fn response_for(asset_type: AssetType, filename: &str) -> axum::response::Response {
+        use crate::presentation::handlers::asset_handlers::asset_response;
+
+        let asset = Asset::new("ab".repeat(32), asset_type, 3, filename, None);
+        asset_response(
+            &asset,
+            "\"etag\"",
+            asset_type.content_type(),
+            vec![1, 2, 3],
+            &axum::http::HeaderMap::new(),
+        )
+    }
+
+    #[test]
+    fn test_documents_are_downloaded_in_a_sandbox() {
+        use axum::http::header;
+
+        let response = response_for(AssetType::Pdf, "notes \u{e9}.pdf");
+        let headers = response.headers();
+        assert_eq!(
+            headers[header::CONTENT_DISPOSITION],
+            "attachment; filename=\"notes _.pdf\""
+        );
+        assert_eq!(headers[header::CONTENT_SECURITY_POLICY], "sandbox");
+        assert_eq!(headers[header::X_CONTENT_TYPE_OPTIONS], "nosniff");
+    }
+
+    #[test]
+    fn test_images_are_shown_inline() {
+        use axum::http::header;
+
+        let response = response_for(AssetType::Png, "diagram.png");
+        let headers = response.headers();
+        assert!(headers.get(header::CONTENT_DISPOSITION).is_none());
+        assert_eq!(headers[header::CONTENT_TYPE], "image/png");
+    }
 }
@@ -0,0 +1,67 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Json,
+};
+
+use crate::application::state::AppState;
+use crate::application::use_cases::tutor_use_cases::{
+    TutorHintResponse, TutorQuery, TutorQuotaResponse,
+};
+use crate::domain::entities::{AiRateLimited, TutorError};
+use crate::domain::value_objects::ApiScope;
+use crate::presentation::extractors::AuthUser;
+use crate::shared::errors::{AppError, Result};
+
+/// Rate limits and quota are reported as such; everything else keeps its
+/// usual status.
+fn tutor_error(error: anyhow::Error) -> AppError {
+    if error.is::<AiRateLimited>() {
+        return AppError::TooManyRequests(error.to_string());
+    }
+    match error.downcast_ref::<TutorError>() {
+        Some(TutorError::QuotaExceeded { .. }) => AppError::TooManyRequests(error.to_string()),
+        Some(TutorError::NoSafeHint | TutorError::Unavailable(_)) => {
+            AppError::Internal(error.to_string())
+        }
+        None => AppError::from(error),
+    }
+}
+
+pub async fn explain_quiz_answer_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path((attempt_id, question_id)): Path<(String, String)>,
+    Query(query): Query<TutorQuery>,
+) -> Result<Json<TutorHintResponse>> {
+    auth.require_session()?;
+    let response = state
+        .tutor_use_cases
+        .explain_quiz_answer(&auth.user_id, &attempt_id, &question_id, query)
+        .await
+        .map_err(tutor_error)?;
+    Ok(Json(response))
+}
+
+pub async fn explain_code_submission_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    Path(submission_id): Path<String>,
+    Query(query): Query<TutorQuery>,
+) -> Result<Json<TutorHintResponse>> {
+    auth.require_session()?;
+    let response = state
+        .tutor_use_cases
+        .explain_code_submission(&auth.user_id, &submission_id, query)
+        .await
+        .map_err(tutor_error)?;
+    Ok(Json(response))
+}
+
+pub async fn get_my_tutor_quota_handler(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<Json<TutorQuotaResponse>> {
+    auth.require_scope(ApiScope::ProgressRead)?;
+    let response = state.tutor_use_cases.get_quota(&auth.user_id).await?;
+    Ok(Json(response))
+}
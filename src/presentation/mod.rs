@@ -1,8 +1,10 @@
 pub mod api;
+pub mod extractors;
 pub mod handlers;
 pub mod routes;
 pub mod web;
 
 pub use api::*;
+pub use extractors::*;
 pub use handlers::*;
 pub use web::*;
@@ -1,3 +1,4 @@
+use axum::extract::DefaultBodyLimit;
 use axum::routing::{get, post};
 use axum::Router;
 
@@ -7,13 +8,15 @@ use crate::presentation::web::ai_features::{
     generate_quiz_form_handler, generate_quiz_post_handler, improve_code_form_handler,
     improve_code_post_handler, validate_code_form_handler, validate_code_post_handler,
 };
+use crate::presentation::web::ai_usage::ai_usage_handler;
 use crate::presentation::web::analytics::analytics_dashboard_handler;
 use crate::presentation::web::audit_logging::audit_logging_handler;
 use crate::presentation::web::bulk_operations::{
     bulk_operations_form_handler, bulk_operations_handler,
 };
 use crate::presentation::web::content_management::{
-    content_management_handler, content_versions_handler,
+    content_management_handler, content_version_diff_handler, content_version_handler,
+    content_versions_handler, restore_content_version_handler,
 };
 use crate::presentation::web::data_validation::data_validation_handler;
 use crate::presentation::web::export_import::{
@@ -25,10 +28,17 @@ use crate::presentation::web::notifications::{
     create_notification_handler, delete_notification_handler, mark_notification_read_handler,
     notifications_handler,
 };
+use crate::presentation::web::question_drafts::{
+    accept_question_draft_handler, edit_question_draft_handler, question_drafts_handler,
+    reject_question_draft_handler, update_question_draft_post_handler,
+};
 use crate::presentation::web::search_filter::{
     search_code_practices_handler, search_lessons_handler, search_questions_handler,
     search_topics_handler, search_users_handler,
 };
+use crate::presentation::web::translations::{
+    review_translations_handler, translate_missing_handler, translations_handler,
+};
 use crate::presentation::web::user_management::{
     user_management_handler, user_permissions_handler,
 };
@@ -42,6 +52,15 @@ pub fn advanced_routes() -> Router<AppState> {
         .route("/admin/ai/validate-code", get(validate_code_form_handler).post(validate_code_post_handler))
         .route("/admin/ai/explain-code", get(explain_code_form_handler).post(explain_code_post_handler))
         .route("/admin/ai/improve-code", get(improve_code_form_handler).post(improve_code_post_handler))
+        .route("/admin/ai/usage", get(ai_usage_handler))
+        .route("/admin/question-drafts", get(question_drafts_handler))
+        .route("/admin/question-drafts/{id}/edit", get(edit_question_draft_handler))
+        .route("/admin/question-drafts/{id}/update", post(update_question_draft_post_handler))
+        .route("/admin/question-drafts/{id}/accept", get(accept_question_draft_handler))
+        .route("/admin/question-drafts/{id}/reject", get(reject_question_draft_handler))
+        .route("/admin/translations", get(translations_handler))
+        .route("/admin/translations/translate", post(translate_missing_handler))
+        .route("/admin/translations/review", post(review_translations_handler))
 
         // Search and Filter routes
         .route("/admin/search/topics", get(search_topics_handler))
@@ -70,7 +89,11 @@ pub fn advanced_routes() -> Router<AppState> {
 
         // File Upload routes
         .route("/admin/file-upload", get(file_upload_handler))
-        .route("/admin/file-upload/upload", post(upload_file_handler))
+        .route(
+            "/admin/file-upload/upload",
+            // Uploads are limited per file by the asset service instead
+            post(upload_file_handler).layer(DefaultBodyLimit::disable()),
+        )
 
         // User Profile routes
         .route("/admin/users/{id}/profile", get(user_profile_handler))
@@ -83,6 +106,12 @@ pub fn advanced_routes() -> Router<AppState> {
         .route("/admin/users/{id}/permissions", get(user_permissions_handler))
         .route("/admin/content-management", get(content_management_handler))
         .route("/admin/content/{id}/versions", get(content_versions_handler))
+        .route("/admin/content/{id}/versions/diff", get(content_version_diff_handler))
+        .route("/admin/content/{id}/versions/{version}", get(content_version_handler))
+        .route(
+            "/admin/content/{id}/versions/{version}/restore",
+            post(restore_content_version_handler),
+        )
         .route("/admin/data-validation", get(data_validation_handler))
         .route("/admin/audit-logs", get(audit_logging_handler))
 }
@@ -2,7 +2,10 @@ use axum::Router;
 
 use crate::application::state::AppState;
 use crate::presentation::api::{
-    auth_routes::auth_routes, content_routes::content_routes, progress_routes::progress_routes,
+    auth_routes::auth_routes, code_practice_routes::code_practice_routes,
+    content_routes::content_routes, editor_routes::editor_routes, lesson_routes::lesson_routes,
+    lesson_session_routes::lesson_session_routes, me_routes::me_routes,
+    progress_routes::progress_routes, quiz_routes::quiz_routes, tutor_routes::tutor_routes,
 };
 
 pub fn api_routes() -> Router<AppState> {
@@ -11,6 +14,20 @@ pub fn api_routes() -> Router<AppState> {
         .nest("/api/auth", auth_routes())
         // Content API routes
         .nest("/api/content", content_routes())
+        // Full content, answers included, for content editors
+        .nest("/api/editor", editor_routes())
         // Progress API routes
         .nest("/api/progress", progress_routes())
+        // Current user API routes
+        .nest("/api/me", me_routes())
+        // Lesson content API routes
+        .nest("/api", lesson_routes())
+        // Lesson session API routes
+        .nest("/api", lesson_session_routes())
+        // Quiz API routes
+        .nest("/api", quiz_routes())
+        // Code practice hint API routes
+        .nest("/api", code_practice_routes())
+        // AI tutor feedback on wrong answers
+        .nest("/api", tutor_routes())
 }
@@ -0,0 +1,13 @@
+use axum::{routing::get, Router};
+
+use crate::application::state::AppState;
+use crate::presentation::handlers::asset_handlers::{
+    get_asset_handler, get_asset_thumbnail_handler,
+};
+
+/// Uploaded files, served publicly so lessons and questions can embed them.
+pub fn asset_routes() -> Router<AppState> {
+    Router::new()
+        .route("/assets/{id}", get(get_asset_handler))
+        .route("/assets/{id}/thumbnail", get(get_asset_thumbnail_handler))
+}
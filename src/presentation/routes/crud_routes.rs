@@ -6,14 +6,24 @@ use crate::presentation::web::code_practices::{
     code_practices_handler, create_code_practice_handler, create_code_practice_post_handler,
     delete_code_practice_handler, edit_code_practice_handler, update_code_practice_post_handler,
 };
+use crate::presentation::web::code_similarity::{
+    code_similarity_handler, compare_code_submissions_handler,
+};
 use crate::presentation::web::lessons::{
     create_lesson_handler, create_lesson_post_handler, delete_lesson_handler, edit_lesson_handler,
     lessons_handler, update_lesson_post_handler,
 };
+use crate::presentation::web::prerequisites::{
+    add_lesson_prerequisite_post_handler, add_topic_prerequisite_post_handler,
+    delete_lesson_prerequisite_handler, delete_topic_prerequisite_handler, prerequisites_handler,
+};
 use crate::presentation::web::questions::{
     create_question_handler, create_question_post_handler, delete_question_handler,
     edit_question_handler, questions_handler, update_question_post_handler,
 };
+use crate::presentation::web::quizzes::{
+    create_quiz_post_handler, delete_quiz_handler, quizzes_handler,
+};
 use crate::presentation::web::topics::{
     create_topic_handler, create_topic_post_handler, delete_topic_handler, edit_topic_handler,
     topics_handler, update_topic_post_handler,
@@ -64,4 +74,18 @@ pub fn crud_routes() -> Router<AppState> {
         .route("/admin/code-practices/{id}/edit", get(edit_code_practice_handler))
         .route("/admin/code-practices/{id}/update", post(update_code_practice_post_handler))
         .route("/admin/code-practices/{id}/delete", get(delete_code_practice_handler))
+        .route("/admin/code-practices/{id}/similarity", get(code_similarity_handler))
+        .route("/admin/code-practices/{id}/similarity/compare", get(compare_code_submissions_handler))
+
+        // Prerequisite graph routes
+        .route("/admin/prerequisites", get(prerequisites_handler))
+        .route("/admin/prerequisites/topics", post(add_topic_prerequisite_post_handler))
+        .route("/admin/prerequisites/topics/{id}/{prerequisite_id}/delete", get(delete_topic_prerequisite_handler))
+        .route("/admin/prerequisites/lessons", post(add_lesson_prerequisite_post_handler))
+        .route("/admin/prerequisites/lessons/{id}/{prerequisite_id}/delete", get(delete_lesson_prerequisite_handler))
+
+        // Quiz routes
+        .route("/admin/quizzes", get(quizzes_handler))
+        .route("/admin/quizzes", post(create_quiz_post_handler))
+        .route("/admin/quizzes/{id}/delete", get(delete_quiz_handler))
 }
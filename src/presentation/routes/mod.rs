@@ -1,6 +1,7 @@
 pub mod admin_routes;
 pub mod advanced_routes;
 pub mod api_routes;
+pub mod asset_routes;
 pub mod crud_routes;
 pub mod health_routes;
 
@@ -15,6 +16,7 @@ use crate::application::state::AppState;
 
 use self::admin_routes::admin_routes;
 use self::api_routes::api_routes;
+use self::asset_routes::asset_routes;
 use self::health_routes::health_routes;
 
 pub fn create_app_router(app_state: AppState) -> Router {
@@ -23,6 +25,8 @@ pub fn create_app_router(app_state: AppState) -> Router {
         .merge(health_routes())
         // API routes
         .merge(api_routes())
+        // Uploaded files
+        .merge(asset_routes())
         // Admin routes
         .merge(admin_routes())
         // Middleware layers
@@ -1,12 +1,20 @@
 use crate::application::state::AppState;
-use crate::shared::errors::Result;
+use crate::domain::entities::question_draft::GENERATED_QUESTION_TYPES;
+use crate::domain::entities::AiRateLimited;
+use crate::domain::value_objects::{Difficulty, Points, TopicId};
+use crate::infrastructure::external_services::AiCaller;
+use crate::shared::errors::{AppError, Result};
+use crate::shared::utils::escape_html;
 use axum::{extract::State, response::Html, Form};
 
 #[derive(serde::Deserialize)]
 pub struct GenerateQuizForm {
-    pub topic: String,
+    pub topic_id: String,
     pub difficulty: String,
+    /// A question type code, or `any` to let the model mix types.
+    pub question_type: String,
     pub count: u32,
+    pub points: u32,
 }
 
 #[derive(serde::Deserialize)]
@@ -25,6 +33,15 @@ pub struct ImproveCodeForm {
     pub code: String,
 }
 
+/// Rate limits are reported as such; anything else is an internal error.
+fn ai_error(error: anyhow::Error) -> AppError {
+    if error.is::<AiRateLimited>() {
+        AppError::TooManyRequests(error.to_string())
+    } else {
+        AppError::Anyhow(error)
+    }
+}
+
 pub async fn ai_features_handler(State(_state): State<AppState>) -> Result<Html<String>> {
     let html = format!(
         r#"<!DOCTYPE html>
@@ -55,6 +72,7 @@ pub async fn ai_features_handler(State(_state): State<AppState>) -> Result<Html<
             <h3>📝 Quiz Generator</h3>
             <p>Generate custom quiz questions on any Rust topic with AI assistance.</p>
             <a href="/admin/ai/generate-quiz" class="button">Generate Quiz Questions</a>
+            <a href="/admin/question-drafts" class="button secondary">Review Drafts</a>
         </div>
 
         <div class="feature-card">
@@ -75,6 +93,18 @@ pub async fn ai_features_handler(State(_state): State<AppState>) -> Result<Html<
             <a href="/admin/ai/improve-code" class="button">Improve Code</a>
         </div>
 
+        <div class="feature-card">
+            <h3>🌐 Translation Assistant</h3>
+            <p>Machine-translate content that has no Indonesian text yet and approve the translations in bulk.</p>
+            <a href="/admin/translations" class="button">Review Translations</a>
+        </div>
+
+        <div class="feature-card">
+            <h3>📊 Usage and Costs</h3>
+            <p>See AI calls, cache hits, tokens and estimated cost per day and per feature.</p>
+            <a href="/admin/ai/usage" class="button">View Usage</a>
+        </div>
+
         <div style="text-align: center; margin-top: 40px;">
             <a href="/admin" class="button secondary">← Back to Dashboard</a>
         </div>
@@ -86,7 +116,25 @@ pub async fn ai_features_handler(State(_state): State<AppState>) -> Result<Html<
     Ok(Html(html))
 }
 
-pub async fn generate_quiz_form_handler(State(_state): State<AppState>) -> Result<Html<String>> {
+pub async fn generate_quiz_form_handler(State(state): State<AppState>) -> Result<Html<String>> {
+    let topic_options: String = state
+        .topic_repository
+        .list_by_order()
+        .await?
+        .iter()
+        .map(|t| {
+            format!(
+                r#"<option value="{}">{}</option>"#,
+                t.id.to_string(),
+                escape_html(t.title.get("en"))
+            )
+        })
+        .collect();
+    let type_options: String = GENERATED_QUESTION_TYPES
+        .iter()
+        .map(|code| format!(r#"<option value="{code}">{code}</option>"#))
+        .collect();
+
     let html = format!(
         r#"<!DOCTYPE html>
 <html>
@@ -107,12 +155,14 @@ pub async fn generate_quiz_form_handler(State(_state): State<AppState>) -> Resul
 <body>
     <div class="form-container">
         <h1>📝 Generate Quiz Questions</h1>
-        <p>Use AI to generate custom quiz questions on any Rust topic.</p>
+        <p>Use AI to generate custom quiz questions on any Rust topic. Generated questions are queued as drafts for review.</p>
 
         <form action="/admin/ai/generate-quiz" method="POST">
             <div class="form-group">
-                <label for="topic">Topic:</label>
-                <input type="text" id="topic" name="topic" required placeholder="e.g., Ownership, Borrowing, Error Handling">
+                <label for="topic_id">Topic:</label>
+                <select id="topic_id" name="topic_id" required>
+                    {topic_options}
+                </select>
             </div>
 
             <div class="form-group">
@@ -125,11 +175,24 @@ pub async fn generate_quiz_form_handler(State(_state): State<AppState>) -> Resul
                 </select>
             </div>
 
+            <div class="form-group">
+                <label for="question_type">Question Type:</label>
+                <select id="question_type" name="question_type" required>
+                    <option value="any">Any</option>
+                    {type_options}
+                </select>
+            </div>
+
             <div class="form-group">
                 <label for="count">Number of Questions:</label>
                 <input type="number" id="count" name="count" min="1" max="10" value="5" required>
             </div>
 
+            <div class="form-group">
+                <label for="points">Points per Question:</label>
+                <input type="number" id="points" name="points" min="1" value="10" required>
+            </div>
+
             <button type="submit">Generate Questions</button>
         </form>
 
@@ -146,18 +209,46 @@ pub async fn generate_quiz_post_handler(
     State(state): State<AppState>,
     Form(form): Form<GenerateQuizForm>,
 ) -> Result<Html<String>> {
-    // Generate quiz questions using Gemini
-    let questions = state
-        .gemini_service
-        .generate_quiz_questions(&form.topic, &form.difficulty, form.count)
+    let topic_id = TopicId::from_string(form.topic_id)
+        .map_err(|e| anyhow::anyhow!("Invalid topic ID: {e}"))?;
+    let topic = state
+        .topic_repository
+        .find_by_id(&topic_id)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Topic not found"))?;
+    let difficulty = Difficulty::from_str(&form.difficulty).map_err(|e| anyhow::anyhow!(e))?;
+    let question_type = (form.question_type != "any").then_some(form.question_type.as_str());
+
+    // Generate quiz questions using the configured LLM provider and queue them for review
+    let generated = state
+        .ai_service
+        .generate_questions(
+            &AiCaller::Admin,
+            topic.title.get("en"),
+            difficulty.as_str(),
+            question_type,
+            form.count.clamp(1, 10),
+        )
+        .await
+        .map_err(ai_error)?;
+    let drafts = state
+        .question_draft_service
+        .create_drafts(
+            generated,
+            &topic_id,
+            difficulty,
+            Points::new(form.points),
+            &state.ai_service.provider_name(),
+        )
         .await?;
 
     let mut questions_html = String::new();
-    for (i, question) in questions.iter().enumerate() {
+    for (i, draft) in drafts.iter().enumerate() {
         questions_html.push_str(&format!(
-            "<div class=\"question\"><h4>Question {}</h4><pre>{}</pre></div>",
+            "<div class=\"question\"><h4>Question {} ({})</h4><pre>{}</pre></div>",
             i + 1,
-            question
+            draft.question.question_type.code(),
+            escape_html(draft.question.prompt.get("en"))
         ));
     }
 
@@ -184,22 +275,25 @@ pub async fn generate_quiz_post_handler(
         <div class="success">✅ Quiz Questions Generated Successfully!</div>
 
         <h2>Generated Questions for: {} ({})</h2>
-        <p><strong>Topic:</strong> {}</p>
-        <p><strong>Difficulty:</strong> {}</p>
         <p><strong>Count:</strong> {}</p>
+        <p>The questions were added to the review queue as drafts.</p>
 
         <div class="questions">
             {}
         </div>
 
         <div style="text-align: center; margin-top: 40px;">
+            <a href="/admin/question-drafts" class="button">Review Drafts</a>
             <a href="/admin/ai/generate-quiz" class="button">Generate More Questions</a>
             <a href="/admin/ai" class="button secondary">← Back to AI Features</a>
         </div>
     </div>
 </body>
 </html>"#,
-        form.topic, form.difficulty, form.topic, form.difficulty, form.count, questions_html
+        escape_html(topic.title.get("en")),
+        difficulty.as_str(),
+        drafts.len(),
+        questions_html
     );
 
     Ok(Html(html))
@@ -256,11 +350,12 @@ pub async fn validate_code_post_handler(
     State(state): State<AppState>,
     Form(form): Form<ValidateCodeForm>,
 ) -> Result<Html<String>> {
-    // Validate code using Gemini
+    // Validate code using the configured LLM provider
     let is_valid = state
-        .gemini_service
-        .validate_code(&form.code, &form.expected_output)
-        .await?;
+        .ai_service
+        .validate_code(&AiCaller::Admin, &form.code, &form.expected_output)
+        .await
+        .map_err(ai_error)?;
 
     let result_class = if is_valid { "success" } else { "error" };
     let result_text = if is_valid {
@@ -360,11 +455,12 @@ pub async fn explain_code_post_handler(
     State(state): State<AppState>,
     Form(form): Form<ExplainCodeForm>,
 ) -> Result<Html<String>> {
-    // Explain code using Gemini
+    // Explain code using the configured LLM provider
     let explanation = state
-        .gemini_service
-        .generate_code_explanation(&form.code)
-        .await?;
+        .ai_service
+        .generate_code_explanation(&AiCaller::Admin, &form.code)
+        .await
+        .map_err(ai_error)?;
 
     let html = format!(
         r#"<!DOCTYPE html>
@@ -457,11 +553,12 @@ pub async fn improve_code_post_handler(
     State(state): State<AppState>,
     Form(form): Form<ImproveCodeForm>,
 ) -> Result<Html<String>> {
-    // Get code improvements using Gemini
+    // Get code improvements using the configured LLM provider
     let improvements = state
-        .gemini_service
-        .suggest_code_improvements(&form.code)
-        .await?;
+        .ai_service
+        .suggest_code_improvements(&AiCaller::Admin, &form.code)
+        .await
+        .map_err(ai_error)?;
 
     let html = format!(
         r#"<!DOCTYPE html>
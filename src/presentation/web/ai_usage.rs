@@ -0,0 +1,133 @@
+use crate::application::state::AppState;
+use crate::domain::entities::{AiFeature, AiUsageSummary};
+use crate::shared::errors::Result;
+use crate::shared::utils::escape_html;
+use axum::{
+    extract::{Query, State},
+    response::Html,
+};
+use chrono::{Duration, Utc};
+
+const PAGE_STYLE: &str = r#"
+        body { font-family: Arial, sans-serif; margin: 40px; }
+        .btn { padding: 8px 16px; text-decoration: none; border-radius: 4px; font-size: 14px; border: none; cursor: pointer; }
+        .btn-primary { background-color: #007bff; color: white; }
+        table { width: 100%; border-collapse: collapse; margin: 20px 0 30px; }
+        th, td { border: 1px solid #ddd; padding: 12px; text-align: left; }
+        th { background-color: #f8f9fa; font-weight: bold; }
+        tr:nth-child(even) { background-color: #f9f9f9; }
+        input { padding: 8px; border: 1px solid #ddd; border-radius: 4px; width: 80px; }
+        .note { color: #6c757d; }
+"#;
+
+/// Days shown when the query does not say.
+const DEFAULT_USAGE_DAYS: u32 = 14;
+
+#[derive(serde::Deserialize)]
+pub struct AiUsageParams {
+    pub days: Option<u32>,
+}
+
+/// USD micros as dollars, to a hundredth of a cent.
+fn format_cost(micros: u64) -> String {
+    format!("${}.{:04}", micros / 1_000_000, micros % 1_000_000 / 100)
+}
+
+fn usage_row(first_cell: &str, summary: &AiUsageSummary) -> String {
+    format!(
+        r#"<tr>
+                <td>{first_cell}</td>
+                <td>{}</td>
+                <td>{}</td>
+                <td>{}</td>
+                <td>{}</td>
+                <td>{}</td>
+                <td>{}</td>
+            </tr>"#,
+        summary.calls,
+        summary.cached_calls,
+        summary.failed_calls,
+        summary.input_tokens,
+        summary.output_tokens,
+        format_cost(summary.cost_micros)
+    )
+}
+
+pub async fn ai_usage_handler(
+    State(state): State<AppState>,
+    Query(params): Query<AiUsageParams>,
+) -> Result<Html<String>> {
+    let days = params.days.unwrap_or(DEFAULT_USAGE_DAYS).clamp(1, 365);
+    let since = (Utc::now() - Duration::days(i64::from(days) - 1))
+        .date_naive()
+        .and_hms_opt(0, 0, 0)
+        .unwrap_or_default()
+        .and_utc();
+    let summaries = state.ai_usage_repository.daily_summary(since).await?;
+
+    let mut total = AiUsageSummary::empty(since.date_naive(), AiFeature::QuestionGeneration);
+    let mut feature_rows = String::new();
+    for feature in AiFeature::all() {
+        let mut feature_total = AiUsageSummary::empty(since.date_naive(), feature);
+        for summary in summaries.iter().filter(|s| s.feature == feature) {
+            feature_total.merge(summary);
+        }
+        total.merge(&feature_total);
+        feature_rows.push_str(&usage_row(feature.label(), &feature_total));
+    }
+    feature_rows.push_str(&usage_row("<strong>Total</strong>", &total));
+
+    let mut daily_rows = String::new();
+    for summary in &summaries {
+        daily_rows.push_str(&usage_row(
+            &format!(
+                "{} · {}",
+                summary.day.format("%Y-%m-%d"),
+                summary.feature.label()
+            ),
+            summary,
+        ));
+    }
+    if daily_rows.is_empty() {
+        daily_rows
+            .push_str(r#"<tr><td colspan="7" class="note">No AI calls in this period.</td></tr>"#);
+    }
+
+    let columns = "<th>Calls</th><th>Cached</th><th>Failed</th><th>Input tokens</th><th>Output tokens</th><th>Cost</th>";
+    let html = format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+    <title>AI Usage</title>
+    <style>{PAGE_STYLE}</style>
+</head>
+<body>
+    <h1>📊 AI Usage</h1>
+    <p class="note">Provider: {provider}. Days are in UTC. Cached calls cost nothing; costs use list prices and are estimates for backends that report no token counts.</p>
+
+    <form method="get">
+        <label for="days">Days</label>
+        <input type="number" id="days" name="days" min="1" max="365" value="{days}">
+        <button type="submit" class="btn btn-primary">Apply</button>
+    </form>
+
+    <h2>By Feature</h2>
+    <table>
+        <thead><tr><th>Feature</th>{columns}</tr></thead>
+        <tbody>{feature_rows}</tbody>
+    </table>
+
+    <h2>By Day</h2>
+    <table>
+        <thead><tr><th>Day · Feature</th>{columns}</tr></thead>
+        <tbody>{daily_rows}</tbody>
+    </table>
+
+    <a href="/admin/ai" class="btn btn-primary">← Back to AI Features</a>
+</body>
+</html>"#,
+        provider = escape_html(&state.ai_service.provider_name()),
+    );
+
+    Ok(Html(html))
+}
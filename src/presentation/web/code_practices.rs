@@ -1,7 +1,8 @@
 use crate::application::state::AppState;
 use crate::domain::entities::code_practice::CodePractice;
+use crate::domain::entities::{ContentAuthor, TranslatableContent};
 use crate::domain::value_objects::{
-    CodePracticeId, Difficulty, LessonId, LocalizedText, Points, TopicId,
+    CodePracticeId, Difficulty, LessonId, LocalizedText, Points, TopicId, AUTHORED_LOCALES,
 };
 use crate::shared::errors::Result;
 use axum::{extract::State, response::Html, Form};
@@ -23,6 +24,9 @@ pub struct CreateCodePracticeForm {
     pub lesson_id: String,
     pub topic_id: String,
     pub points: u32,
+    /// Why the item was changed, kept with its previous version. Only on the edit form.
+    #[serde(default)]
+    pub change_note: String,
 }
 
 pub async fn code_practices_handler(State(state): State<AppState>) -> Result<Html<String>> {
@@ -42,6 +46,7 @@ pub async fn code_practices_handler(State(state): State<AppState>) -> Result<Htm
                 <td>{}</td>
                 <td>
                     <a href="/admin/code-practices/{}/edit" class="btn btn-sm btn-primary">Edit</a>
+                    <a href="/admin/code-practices/{}/similarity" class="btn btn-sm btn-primary">Similarity</a>
                     <a href="/admin/code-practices/{}/delete" class="btn btn-sm btn-danger" onclick="return confirm('Are you sure?')">Delete</a>
                 </td>
             </tr>"#,
@@ -57,6 +62,7 @@ pub async fn code_practices_handler(State(state): State<AppState>) -> Result<Htm
             practice.lesson_id.to_string(),
             practice.topic_id.to_string(),
             practice.id.to_string(),
+            practice.id.to_string(),
             practice.id.to_string()
         ));
     }
@@ -547,6 +553,11 @@ pub async fn edit_code_practice_handler(
                 <input type="number" id="points" name="points" min="1" max="1000" value="{}" required>
             </div>
 
+            <div class="form-group">
+                <label for="change_note">Change note (optional):</label>
+                <input type="text" id="change_note" name="change_note" placeholder="What changed and why">
+            </div>
+
             <button type="submit">Update Code Practice</button>
         </form>
 
@@ -627,8 +638,10 @@ pub async fn update_code_practice_post_handler(
         .ok_or_else(|| anyhow::anyhow!("Code practice not found"))?;
 
     // Update fields
-    code_practice.title = title;
-    code_practice.description = description;
+    code_practice.title.apply_edit(title, AUTHORED_LOCALES);
+    code_practice
+        .description
+        .apply_edit(description, AUTHORED_LOCALES);
     code_practice.initial_code = form.initial_code;
     code_practice.solution = form.solution;
     code_practice.difficulty = difficulty;
@@ -654,8 +667,12 @@ pub async fn update_code_practice_post_handler(
 
     // Save to database
     state
-        .code_practice_repository
-        .update(&code_practice)
+        .content_version_service
+        .update_content(
+            &TranslatableContent::CodePractice(code_practice.clone()),
+            ContentAuthor::admin(),
+            &form.change_note,
+        )
         .await?;
 
     // Return success page
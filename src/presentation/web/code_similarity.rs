@@ -0,0 +1,240 @@
+use crate::application::state::AppState;
+use crate::domain::entities::CodeSubmission;
+use crate::domain::services::plagiarism_service::DEFAULT_SIMILARITY_THRESHOLD;
+use crate::domain::value_objects::{CodePracticeId, CodeSubmissionId, UserId};
+use crate::shared::errors::Result;
+use crate::shared::utils::escape_html;
+use axum::{
+    extract::{Path, Query, State},
+    response::Html,
+};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+const PAGE_STYLE: &str = r#"
+        body { font-family: Arial, sans-serif; margin: 40px; }
+        .btn { padding: 8px 16px; text-decoration: none; border-radius: 4px; font-size: 14px; border: none; cursor: pointer; }
+        .btn-primary { background-color: #007bff; color: white; }
+        .btn-sm { padding: 6px 12px; font-size: 12px; }
+        table { width: 100%; border-collapse: collapse; margin: 20px 0 30px; }
+        th, td { border: 1px solid #ddd; padding: 12px; text-align: left; }
+        th { background-color: #f8f9fa; font-weight: bold; }
+        tr:nth-child(even) { background-color: #f9f9f9; }
+        input { padding: 8px; border: 1px solid #ddd; border-radius: 4px; width: 80px; }
+        .note { color: #6c757d; }
+        .side-by-side { display: grid; grid-template-columns: repeat(2, minmax(0, 1fr)); gap: 20px; }
+        pre { background-color: #f8f9fa; border: 1px solid #ddd; border-radius: 4px; padding: 15px; overflow-x: auto; font-size: 13px; }
+"#;
+
+#[derive(serde::Deserialize)]
+pub struct SimilarityParams {
+    pub threshold: Option<u32>,
+}
+
+#[derive(serde::Deserialize)]
+pub struct CompareParams {
+    pub a: String,
+    /// A submission ID, or `solution` (the default) for the practice's solution.
+    pub b: Option<String>,
+}
+
+async fn user_names(
+    state: &AppState,
+    submissions: &[&CodeSubmission],
+) -> Result<HashMap<UserId, String>> {
+    let mut names = HashMap::new();
+    for submission in submissions {
+        if names.contains_key(&submission.user_id) {
+            continue;
+        }
+        let name = state
+            .user_repository
+            .find_by_id(&submission.user_id)
+            .await?
+            .map_or_else(|| submission.user_id.to_string(), |u| u.display_name);
+        names.insert(submission.user_id.clone(), name);
+    }
+    Ok(names)
+}
+
+pub async fn code_similarity_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(params): Query<SimilarityParams>,
+) -> Result<Html<String>> {
+    let code_practice_id = CodePracticeId::from_string(&id)
+        .map_err(|e| anyhow::anyhow!("Invalid code practice ID: {e}"))?;
+    let threshold = params
+        .threshold
+        .unwrap_or(DEFAULT_SIMILARITY_THRESHOLD)
+        .min(100);
+
+    let report = state
+        .plagiarism_service
+        .similarity_report(&code_practice_id, threshold)
+        .await?;
+    let practice_id = report.code_practice.id.to_string();
+
+    let mut flagged: Vec<&CodeSubmission> = report
+        .pairs
+        .iter()
+        .flat_map(|pair| [&pair.first, &pair.second])
+        .collect();
+    flagged.extend(report.solution_matches.iter().map(|m| &m.submission));
+    let names = user_names(&state, &flagged).await?;
+
+    let mut pair_rows = String::new();
+    for pair in &report.pairs {
+        pair_rows.push_str(&format!(
+            r#"<tr>
+                <td>{}%</td>
+                <td>{}</td>
+                <td>{}</td>
+                <td><a href="/admin/code-practices/{}/similarity/compare?a={}&b={}" class="btn btn-sm btn-primary">Compare</a></td>
+            </tr>"#,
+            pair.similarity,
+            escape_html(&names[&pair.first.user_id]),
+            escape_html(&names[&pair.second.user_id]),
+            practice_id,
+            pair.first.id,
+            pair.second.id
+        ));
+    }
+    if pair_rows.is_empty() {
+        pair_rows.push_str(
+            r#"<tr><td colspan="4" class="note">No pairs at or above the threshold.</td></tr>"#,
+        );
+    }
+
+    let mut solution_rows = String::new();
+    for matched in &report.solution_matches {
+        solution_rows.push_str(&format!(
+            r#"<tr>
+                <td>{}%</td>
+                <td>{}</td>
+                <td>{}</td>
+                <td><a href="/admin/code-practices/{}/similarity/compare?a={}&b=solution" class="btn btn-sm btn-primary">Compare</a></td>
+            </tr>"#,
+            matched.similarity,
+            escape_html(&names[&matched.submission.user_id]),
+            matched.submission.submitted_at.format("%Y-%m-%d %H:%M"),
+            practice_id,
+            matched.submission.id
+        ));
+    }
+    if solution_rows.is_empty() {
+        solution_rows.push_str(r#"<tr><td colspan="4" class="note">No submissions at or above the threshold.</td></tr>"#);
+    }
+
+    let html = format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+    <title>Code Similarity</title>
+    <style>{PAGE_STYLE}</style>
+</head>
+<body>
+    <h1>🔍 Code Similarity: {title}</h1>
+    <p class="note">Compares each learner's latest submission ({compared} learners). Identifiers, literals, comments and whitespace are ignored.</p>
+
+    <form method="get">
+        <label for="threshold">Threshold (%)</label>
+        <input type="number" id="threshold" name="threshold" min="0" max="100" value="{threshold}">
+        <button type="submit" class="btn btn-primary">Apply</button>
+    </form>
+
+    <h2>Similar Submissions</h2>
+    <table>
+        <thead><tr><th>Similarity</th><th>Learner</th><th>Learner</th><th>Actions</th></tr></thead>
+        <tbody>{pair_rows}</tbody>
+    </table>
+
+    <h2>Matches With the Solution</h2>
+    <table>
+        <thead><tr><th>Similarity</th><th>Learner</th><th>Submitted</th><th>Actions</th></tr></thead>
+        <tbody>{solution_rows}</tbody>
+    </table>
+
+    <a href="/admin/code-practices" class="btn btn-primary">← Back to Code Practices</a>
+</body>
+</html>"#,
+        title = escape_html(report.code_practice.title.get("en")),
+        compared = report.submissions_compared,
+    );
+
+    Ok(Html(html))
+}
+
+pub async fn compare_code_submissions_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(params): Query<CompareParams>,
+) -> Result<Html<String>> {
+    let code_practice_id = CodePracticeId::from_string(&id)
+        .map_err(|e| anyhow::anyhow!("Invalid code practice ID: {e}"))?;
+    let left_id = CodeSubmissionId::from_str(&params.a)
+        .map_err(|e| anyhow::anyhow!("Invalid submission ID: {e}"))?;
+    let right_id = match params.b.as_deref() {
+        None | Some("solution") => None,
+        Some(b) => Some(
+            CodeSubmissionId::from_str(b)
+                .map_err(|e| anyhow::anyhow!("Invalid submission ID: {e}"))?,
+        ),
+    };
+
+    let comparison = state
+        .plagiarism_service
+        .compare(&code_practice_id, &left_id, right_id.as_ref())
+        .await?;
+
+    let mut shown = vec![&comparison.left];
+    shown.extend(comparison.right.as_ref());
+    let names = user_names(&state, &shown).await?;
+
+    let heading = |submission: &CodeSubmission| {
+        format!(
+            "{} · {} · {}",
+            escape_html(&names[&submission.user_id]),
+            submission.verdict.as_str(),
+            submission.submitted_at.format("%Y-%m-%d %H:%M")
+        )
+    };
+    let right_heading = comparison
+        .right
+        .as_ref()
+        .map_or_else(|| "Reference solution".to_string(), heading);
+
+    let html = format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+    <title>Compare Submissions</title>
+    <style>{PAGE_STYLE}</style>
+</head>
+<body>
+    <h1>🔍 {title}: {similarity}% similar</h1>
+
+    <div class="side-by-side">
+        <div>
+            <h3>{left_heading}</h3>
+            <pre>{left_code}</pre>
+        </div>
+        <div>
+            <h3>{right_heading}</h3>
+            <pre>{right_code}</pre>
+        </div>
+    </div>
+
+    <a href="/admin/code-practices/{practice_id}/similarity" class="btn btn-primary">← Back to Similarity Report</a>
+</body>
+</html>"#,
+        title = escape_html(comparison.code_practice.title.get("en")),
+        similarity = comparison.similarity,
+        left_heading = heading(&comparison.left),
+        left_code = escape_html(&comparison.left.code),
+        right_code = escape_html(&comparison.right_code),
+        practice_id = comparison.code_practice.id.to_string(),
+    );
+
+    Ok(Html(html))
+}
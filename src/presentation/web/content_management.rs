@@ -1,10 +1,14 @@
 use crate::application::state::AppState;
-use crate::shared::errors::Result;
+use crate::domain::entities::{diff_snapshots, ContentAuthor, TranslatableContent};
+use crate::shared::errors::{AppError, Result};
+use crate::shared::utils::escape_html;
 use axum::{
     extract::{Path, Query, State},
     response::Html,
+    Form,
 };
 use serde::Deserialize;
+use serde_json::Value;
 
 #[derive(Debug, Deserialize)]
 pub struct ContentFilterParams {
@@ -62,7 +66,7 @@ pub async fn content_management_handler(
                     </div>
                 </td>
             </tr>"#,
-            topic.title.en,
+            topic.title.english(),
             status_badge,
             topic.created_at.format("%Y-%m-%d"),
             topic.updated_at.format("%Y-%m-%d"),
@@ -105,7 +109,7 @@ pub async fn content_management_handler(
                     </div>
                 </td>
             </tr>"#,
-            lesson.title.en,
+            lesson.title.english(),
             status_badge,
             lesson.created_at.format("%Y-%m-%d"),
             lesson.updated_at.format("%Y-%m-%d"),
@@ -148,7 +152,7 @@ pub async fn content_management_handler(
                     </div>
                 </td>
             </tr>"#,
-            question.prompt.en,
+            question.prompt.english(),
             status_badge,
             question.created_at.format("%Y-%m-%d"),
             question.updated_at.format("%Y-%m-%d"),
@@ -487,51 +491,253 @@ pub async fn content_management_handler(
     Ok(Html(html))
 }
 
-pub async fn content_versions_handler(
-    State(_state): State<AppState>,
-    Path(_content_id): Path<String>,
-) -> Result<Html<String>> {
-    let html = "<!DOCTYPE html>
-<html>
-<head>
-    <title>Content Versions</title>
-    <style>
+const VERSIONS_STYLE: &str = r#"
         body { font-family: Arial, sans-serif; margin: 20px; }
         .container { max-width: 1000px; margin: 0 auto; }
         .version-item { background: #f8f9fa; padding: 20px; margin: 10px 0; border-radius: 10px; }
-        .btn { padding: 8px 16px; margin: 5px; text-decoration: none; border-radius: 5px; }
+        .meta { color: #6c757d; }
+        .btn { padding: 8px 16px; margin: 5px; text-decoration: none; border-radius: 5px; border: none; cursor: pointer; font-size: 14px; }
         .btn-primary { background: #007bff; color: white; }
         .btn-secondary { background: #6c757d; color: white; }
         .btn-success { background: #28a745; color: white; }
-    </style>
+        form.restore { display: inline; }
+        form.restore input[type=text] { padding: 7px; border: 1px solid #ddd; border-radius: 4px; width: 260px; }
+        table { width: 100%; border-collapse: collapse; margin: 20px 0; }
+        th, td { border: 1px solid #ddd; padding: 10px; text-align: left; vertical-align: top; }
+        th { background-color: #f8f9fa; }
+        td.value { white-space: pre-wrap; font-family: monospace; }
+        td.before { background: #fdecea; }
+        td.after { background: #e6f4ea; }
+        pre { background: #f8f9fa; padding: 20px; border-radius: 10px; overflow-x: auto; }
+"#;
+
+/// Either side of a comparison: a version number or `current`.
+#[derive(Debug, Deserialize)]
+pub struct VersionDiffParams {
+    pub from: String,
+    pub to: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RestoreVersionForm {
+    #[serde(default)]
+    pub change_note: String,
+}
+
+fn versions_page(title: &str, body: &str) -> Html<String> {
+    Html(format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+    <title>{title}</title>
+    <style>{VERSIONS_STYLE}</style>
 </head>
 <body>
-    <div class=\"container\">
-        <h1>Content Versions</h1>
-        <a href=\"/admin/content\">← Back to Content Management</a>
+    <div class="container">
+{body}
+    </div>
+</body>
+</html>"#
+    ))
+}
+
+async fn find_content(state: &AppState, content_id: &str) -> Result<TranslatableContent> {
+    state
+        .content_version_service
+        .find_content(content_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Content not found".to_string()))
+}
+
+fn parse_version(version: &str) -> Result<u32> {
+    version
+        .trim()
+        .parse()
+        .map_err(|_| AppError::Validation(format!("Invalid version: {version}")))
+}
+
+/// A snapshot value as shown in a diff: strings as is, anything else as JSON.
+fn display_value(value: &Option<Value>) -> String {
+    match value {
+        None => "(none)".to_string(),
+        Some(Value::String(text)) => escape_html(text),
+        Some(value) => escape_html(&value.to_string()),
+    }
+}
+
+pub async fn content_versions_handler(
+    State(state): State<AppState>,
+    Path(content_id): Path<String>,
+) -> Result<Html<String>> {
+    let content = find_content(&state, &content_id).await?;
+    let kind = content.kind();
+    let versions = state
+        .content_version_service
+        .history(kind, &content_id)
+        .await?;
 
-        <div class=\"version-item\">
-            <h3>Version 3.0 (Current)</h3>
-            <p>Updated by Admin • 2024-01-15 14:30:00</p>
+    let mut versions_html = String::new();
+    // Newest first, so each version's successor is the one before it
+    let mut next = "current".to_string();
+    for version in &versions {
+        let note = if version.change_note.is_empty() {
+            String::new()
+        } else {
+            format!("<p>{}</p>", escape_html(&version.change_note))
+        };
+        versions_html.push_str(&format!(
+            r#"        <div class="version-item">
+            <h3>Version {number}</h3>
+            <p class="meta">Replaced by {author} • {created_at}</p>
+            {note}
             <div>
-                <a href=\"#\" class=\"btn btn-primary\">View</a>
-                <a href=\"#\" class=\"btn btn-secondary\">Compare</a>
-                <a href=\"#\" class=\"btn btn-success\">Restore</a>
+                <a href="/admin/content/{content_id}/versions/{number}" class="btn btn-primary">View</a>
+                <a href="/admin/content/{content_id}/versions/diff?from={number}&to={next}" class="btn btn-secondary">Compare</a>
+                <form class="restore" action="/admin/content/{content_id}/versions/{number}/restore" method="POST">
+                    <input type="text" name="change_note" placeholder="Why restore this version?">
+                    <button type="submit" class="btn btn-success">Restore</button>
+                </form>
             </div>
         </div>
+"#,
+            number = version.version,
+            author = escape_html(&version.author.name),
+            created_at = version.created_at.format("%Y-%m-%d %H:%M:%S"),
+            content_id = escape_html(&content_id),
+        ));
+        next = version.version.to_string();
+    }
+    if versions.is_empty() {
+        versions_html.push_str("        <p class=\"meta\">No earlier versions; this item has not been changed since it was created.</p>\n");
+    }
 
-        <div class=\"version-item\">
-            <h3>Version 2.1</h3>
-            <p>Updated by Admin • 2024-01-10 09:15:00</p>
-            <div>
-                <a href=\"#\" class=\"btn btn-primary\">View</a>
-                <a href=\"#\" class=\"btn btn-secondary\">Compare</a>
-                <a href=\"#\" class=\"btn btn-success\">Restore</a>
-            </div>
+    let body = format!(
+        r#"        <h1>Versions of {label}: {title}</h1>
+        <a href="/admin/content-management">← Back to Content Management</a>
+
+        <div class="version-item">
+            <h3>Current</h3>
+            <p class="meta">Last updated {updated_at}</p>
         </div>
-    </div>
-</body>
-</html>";
+{versions_html}"#,
+        label = kind.label(),
+        title = escape_html(content.title()),
+        updated_at = content
+            .snapshot()
+            .get("updated_at")
+            .and_then(Value::as_str)
+            .unwrap_or_default(),
+    );
+    Ok(versions_page("Content Versions", &body))
+}
+
+pub async fn content_version_handler(
+    State(state): State<AppState>,
+    Path((content_id, version)): Path<(String, String)>,
+) -> Result<Html<String>> {
+    let content = find_content(&state, &content_id).await?;
+    let version = state
+        .content_version_service
+        .get_version(content.kind(), &content_id, parse_version(&version)?)
+        .await?;
+    let snapshot = serde_json::to_string_pretty(&version.snapshot)
+        .map_err(|e| anyhow::anyhow!("Failed to format version: {e}"))?;
 
-    Ok(Html(html.to_string()))
+    let body = format!(
+        r#"        <h1>{label}: {title}, version {number}</h1>
+        <a href="/admin/content/{content_id}/versions">← Back to Versions</a>
+        <p class="meta">Replaced by {author} • {created_at}</p>
+        <pre>{snapshot}</pre>"#,
+        label = content.kind().label(),
+        title = escape_html(content.title()),
+        number = version.version,
+        content_id = escape_html(&content_id),
+        author = escape_html(&version.author.name),
+        created_at = version.created_at.format("%Y-%m-%d %H:%M:%S"),
+        snapshot = escape_html(&snapshot),
+    );
+    Ok(versions_page("Content Version", &body))
+}
+
+pub async fn content_version_diff_handler(
+    State(state): State<AppState>,
+    Path(content_id): Path<String>,
+    Query(params): Query<VersionDiffParams>,
+) -> Result<Html<String>> {
+    let content = find_content(&state, &content_id).await?;
+    let to = params.to.unwrap_or_else(|| "current".to_string());
+
+    let mut snapshots = Vec::new();
+    for side in [&params.from, &to] {
+        let snapshot = if side == "current" {
+            content.snapshot()
+        } else {
+            state
+                .content_version_service
+                .get_version(content.kind(), &content_id, parse_version(side)?)
+                .await?
+                .snapshot
+        };
+        snapshots.push(snapshot);
+    }
+    let changes = diff_snapshots(&snapshots[0], &snapshots[1]);
+
+    let rows: String = changes
+        .iter()
+        .map(|change| {
+            format!(
+                r#"<tr><td>{}</td><td class="value before">{}</td><td class="value after">{}</td></tr>"#,
+                escape_html(&change.field),
+                display_value(&change.before),
+                display_value(&change.after)
+            )
+        })
+        .collect();
+    let table = if changes.is_empty() {
+        "<p class=\"meta\">No differences.</p>".to_string()
+    } else {
+        format!("<table><tr><th>Field</th><th>Before</th><th>After</th></tr>{rows}</table>")
+    };
+
+    let body = format!(
+        r#"        <h1>{label}: {title}</h1>
+        <a href="/admin/content/{content_id}/versions">← Back to Versions</a>
+        <h2>Changes from {from} to {to}</h2>
+        {table}"#,
+        label = content.kind().label(),
+        title = escape_html(content.title()),
+        content_id = escape_html(&content_id),
+        from = escape_html(&params.from),
+        to = escape_html(&to),
+    );
+    Ok(versions_page("Compare Versions", &body))
+}
+
+pub async fn restore_content_version_handler(
+    State(state): State<AppState>,
+    Path((content_id, version)): Path<(String, String)>,
+    Form(form): Form<RestoreVersionForm>,
+) -> Result<Html<String>> {
+    let kind = find_content(&state, &content_id).await?.kind();
+    let version = parse_version(&version)?;
+    let restored = state
+        .content_version_service
+        .restore(
+            kind,
+            &content_id,
+            version,
+            ContentAuthor::admin(),
+            &form.change_note,
+        )
+        .await?;
+
+    let body = format!(
+        r#"        <h1>✅ Version {version} Restored</h1>
+        <p>{label} "{title}" is back to version {version}. The state it replaced was kept as a new version.</p>
+        <a href="/admin/content/{content_id}/versions">← Back to Versions</a>"#,
+        label = kind.label(),
+        title = escape_html(restored.title()),
+        content_id = escape_html(&content_id),
+    );
+    Ok(versions_page("Version Restored", &body))
 }
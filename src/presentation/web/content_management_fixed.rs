@@ -62,7 +62,7 @@ pub async fn content_management_handler(
                     </div>
                 </td>
             </tr>"#,
-            topic.title.en,
+            topic.title.english(),
             status_badge,
             topic.created_at.format("%Y-%m-%d"),
             topic.updated_at.format("%Y-%m-%d"),
@@ -105,7 +105,7 @@ pub async fn content_management_handler(
                     </div>
                 </td>
             </tr>"#,
-            lesson.title.en,
+            lesson.title.english(),
             status_badge,
             lesson.created_at.format("%Y-%m-%d"),
             lesson.updated_at.format("%Y-%m-%d"),
@@ -148,7 +148,7 @@ pub async fn content_management_handler(
                     </div>
                 </td>
             </tr>"#,
-            question.prompt.en,
+            question.prompt.english(),
             status_badge,
             question.created_at.format("%Y-%m-%d"),
             question.updated_at.format("%Y-%m-%d"),
@@ -91,6 +91,7 @@ pub async fn dashboard_handler(State(state): State<AppState>) -> Result<Html<Str
                 <a href="/admin/lessons" class="btn btn-primary">View All Lessons</a>
                 <a href="/admin/search/lessons" class="btn btn-info">Search Lessons</a>
                 <a href="/admin/lessons/new" class="btn btn-success">Create New Lesson</a>
+                <a href="/admin/prerequisites" class="btn btn-info">Edit Prerequisites</a>
             </div>
         </div>
 
@@ -100,6 +101,7 @@ pub async fn dashboard_handler(State(state): State<AppState>) -> Result<Html<Str
                 <a href="/admin/questions" class="btn btn-primary">View All Questions</a>
                 <a href="/admin/search/questions" class="btn btn-info">Search Questions</a>
                 <a href="/admin/questions/new" class="btn btn-success">Create New Question</a>
+                <a href="/admin/quizzes" class="btn btn-info">Timed Quizzes</a>
             </div>
         </div>
 
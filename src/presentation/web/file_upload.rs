@@ -1,11 +1,24 @@
 use crate::application::state::AppState;
-use crate::shared::errors::Result;
+use crate::domain::entities::{Asset, AssetError};
+use crate::shared::errors::{AppError, Result};
+use crate::shared::utils::escape_html;
 use axum::{
     extract::{Multipart, State},
     response::Html,
 };
 
-pub async fn file_upload_handler() -> Result<Html<String>> {
+/// Most recent uploads listed in the library.
+const LIBRARY_SIZE: u32 = 50;
+
+pub async fn file_upload_handler(State(state): State<AppState>) -> Result<Html<String>> {
+    let assets = state.asset_service.list(LIBRARY_SIZE, 0).await?;
+    let library = if assets.is_empty() {
+        r#"<p class="file-size">Nothing has been uploaded yet.</p>"#.to_string()
+    } else {
+        assets.iter().map(asset_item).collect::<Vec<_>>().join("")
+    };
+    let max_size = format_size(state.asset_service.max_bytes());
+
     let html = format!(
         r#"<!DOCTYPE html>
 <html lang="en">
@@ -170,6 +183,17 @@ pub async fn file_upload_handler() -> Result<Html<String>> {
         .back-btn:hover {{
             text-decoration: underline;
         }}
+        .file-thumbnail {{
+            width: 64px;
+            height: 64px;
+            object-fit: contain;
+            margin-right: 15px;
+        }}
+        .file-url {{
+            font-family: monospace;
+            font-size: 0.85em;
+            color: #555;
+        }}
     </style>
 </head>
 <body>
@@ -184,19 +208,23 @@ pub async fn file_upload_handler() -> Result<Html<String>> {
 
             <div class="upload-section">
                 <h2>📤 Upload Files</h2>
-                <div class="upload-form" id="uploadForm">
-                    <div class="upload-icon">📁</div>
-                    <h3>Drag & Drop Files Here</h3>
-                    <p>or click to browse files</p>
-                    <input type="file" id="fileInput" class="file-input" multiple accept="image/*,.pdf,.doc,.docx,.txt,.md">
-                    <button class="upload-btn" onclick="document.getElementById('fileInput').click()">
-                        Choose Files
-                    </button>
-                </div>
+                <form method="post" action="/admin/file-upload/upload" enctype="multipart/form-data">
+                    <div class="upload-form" id="uploadForm">
+                        <div class="upload-icon">📁</div>
+                        <h3>Drag & Drop Files Here</h3>
+                        <p>or click to browse files</p>
+                        <input type="file" id="fileInput" name="files" class="file-input" multiple accept="image/png,image/jpeg,image/gif,image/webp,application/pdf">
+                        <button type="button" class="upload-btn" onclick="document.getElementById('fileInput').click()">
+                            Choose Files
+                        </button>
+                    </div>
 
-                <div class="file-list" id="fileList">
-                    <!-- Files will be listed here -->
-                </div>
+                    <div class="file-list" id="fileList">
+                        <!-- Files will be listed here -->
+                    </div>
+
+                    <button type="submit" class="upload-btn" id="uploadButton" disabled>Upload</button>
+                </form>
             </div>
 
             <div class="upload-section">
@@ -204,22 +232,20 @@ pub async fn file_upload_handler() -> Result<Html<String>> {
                 <div class="upload-types">
                     <div class="upload-type">
                         <h3>🖼️ Images</h3>
-                        <p>JPG, PNG, GIF, SVG<br>Max size: 10MB</p>
+                        <p>PNG, JPEG, GIF, WebP<br>Max size: {max_size}</p>
                     </div>
                     <div class="upload-type">
                         <h3>📄 Documents</h3>
-                        <p>PDF, DOC, DOCX, TXT, MD<br>Max size: 50MB</p>
-                    </div>
-                    <div class="upload-type">
-                        <h3>💻 Code Files</h3>
-                        <p>Rust, Python, JS, HTML, CSS<br>Max size: 5MB</p>
-                    </div>
-                    <div class="upload-type">
-                        <h3>📊 Data Files</h3>
-                        <p>JSON, CSV, XML<br>Max size: 20MB</p>
+                        <p>PDF<br>Max size: {max_size}</p>
                     </div>
                 </div>
             </div>
+
+            <div class="upload-section">
+                <h2>🗂️ Recent Uploads</h2>
+                <p class="file-size">Use an asset's URL in lesson image blocks, or its ID on a question.</p>
+                {library}
+            </div>
         </div>
     </div>
 
@@ -227,6 +253,7 @@ pub async fn file_upload_handler() -> Result<Html<String>> {
         const uploadForm = document.getElementById('uploadForm');
         const fileInput = document.getElementById('fileInput');
         const fileList = document.getElementById('fileList');
+        const uploadButton = document.getElementById('uploadButton');
 
         // Drag and drop functionality
         uploadForm.addEventListener('dragover', (e) => {{
@@ -241,47 +268,42 @@ pub async fn file_upload_handler() -> Result<Html<String>> {
         uploadForm.addEventListener('drop', (e) => {{
             e.preventDefault();
             uploadForm.classList.remove('dragover');
-            const files = e.dataTransfer.files;
-            handleFiles(files);
+            fileInput.files = e.dataTransfer.files;
+            showFiles();
         }});
 
-        fileInput.addEventListener('change', (e) => {{
-            handleFiles(e.target.files);
-        }});
+        fileInput.addEventListener('change', showFiles);
 
-        function handleFiles(files) {{
-            Array.from(files).forEach(file => {{
-                addFileToList(file);
-            }});
-        }}
+        // Lists the files that will be uploaded with the form
+        function showFiles() {{
+            fileList.replaceChildren();
+            Array.from(fileInput.files).forEach(file => {{
+                const fileItem = document.createElement('div');
+                fileItem.className = 'file-item';
 
-        function addFileToList(file) {{
-            const fileItem = document.createElement('div');
-            fileItem.className = 'file-item';
+                const fileIcon = document.createElement('div');
+                fileIcon.className = 'file-icon';
+                fileIcon.textContent = getFileIcon(file.type);
 
-            const fileIcon = getFileIcon(file.type);
-            const fileSize = formatFileSize(file.size);
-
-            fileItem.innerHTML = `
-                <div class="file-icon">${{fileIcon}}</div>
-                <div class="file-info">
-                    <div class="file-name">${{file.name}}</div>
-                    <div class="file-size">${{fileSize}}</div>
-                </div>
-                <div class="file-actions">
-                    <button class="btn btn-primary" onclick="uploadFile(this)">Upload</button>
-                    <button class="btn btn-danger" onclick="removeFile(this)">Remove</button>
-                </div>
-            `;
+                const fileInfo = document.createElement('div');
+                fileInfo.className = 'file-info';
+                const fileName = document.createElement('div');
+                fileName.className = 'file-name';
+                fileName.textContent = file.name;
+                const fileSize = document.createElement('div');
+                fileSize.className = 'file-size';
+                fileSize.textContent = formatFileSize(file.size);
+                fileInfo.append(fileName, fileSize);
 
-            fileList.appendChild(fileItem);
+                fileItem.append(fileIcon, fileInfo);
+                fileList.appendChild(fileItem);
+            }});
+            uploadButton.disabled = fileInput.files.length === 0;
         }}
 
         function getFileIcon(type) {{
             if (type.startsWith('image/')) return '🖼️';
             if (type.includes('pdf')) return '📄';
-            if (type.includes('document') || type.includes('text')) return '📝';
-            if (type.includes('code') || type.includes('script')) return '💻';
             return '📁';
         }}
 
@@ -292,26 +314,6 @@ pub async fn file_upload_handler() -> Result<Html<String>> {
             const i = Math.floor(Math.log(bytes) / Math.log(k));
             return parseFloat((bytes / Math.pow(k, i)).toFixed(2)) + ' ' + sizes[i];
         }}
-
-        function uploadFile(button) {{
-            const fileItem = button.closest('.file-item');
-            const fileName = fileItem.querySelector('.file-name').textContent;
-
-            button.textContent = 'Uploading...';
-            button.disabled = true;
-
-            // Simulate upload
-            setTimeout(() => {{
-                button.textContent = 'Uploaded ✓';
-                button.style.background = '#28a745';
-                fileItem.style.opacity = '0.7';
-            }}, 2000);
-        }}
-
-        function removeFile(button) {{
-            const fileItem = button.closest('.file-item');
-            fileItem.remove();
-        }}
     </script>
 </body>
 </html>"#
@@ -321,18 +323,58 @@ pub async fn file_upload_handler() -> Result<Html<String>> {
 }
 
 pub async fn upload_file_handler(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     mut multipart: Multipart,
 ) -> Result<Html<String>> {
-    let mut uploaded_files = Vec::new();
+    let max_bytes = state.asset_service.max_bytes();
+    let mut results = Vec::new();
+
+    while let Some(mut field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| AppError::Validation(format!("Invalid upload: {e}")))?
+    {
+        let Some(filename) = field.file_name().map(str::to_string) else {
+            continue;
+        };
 
-    while let Some(field) = multipart.next_field().await.unwrap() {
-        if let Some(filename) = field.file_name() {
-            let filename = filename.to_string();
-            let data = field.bytes().await.unwrap();
-            uploaded_files.push((filename, data.len()));
+        // Read in chunks so an oversized file is refused without buffering all of it
+        let mut data = Vec::new();
+        let mut too_large = false;
+        while let Some(chunk) = field
+            .chunk()
+            .await
+            .map_err(|e| AppError::Validation(format!("Invalid upload: {e}")))?
+        {
+            if (data.len() + chunk.len()) as u64 > max_bytes {
+                too_large = true;
+                break;
+            }
+            data.extend_from_slice(&chunk);
         }
+
+        let result = if too_large {
+            Err(AssetError::TooLarge { max_bytes }.to_string())
+        } else {
+            state
+                .asset_service
+                .upload(&filename, data, None)
+                .await
+                .map_err(|e| match e.downcast_ref::<AssetError>() {
+                    Some(asset_error) => asset_error.to_string(),
+                    None => {
+                        tracing::error!("Failed to store upload {}: {:#}", filename, e);
+                        "The file could not be stored".to_string()
+                    }
+                })
+        };
+        results.push((filename, result));
+    }
+
+    if results.is_empty() {
+        return Err(AppError::Validation("No files were uploaded".to_string()));
     }
+    let uploaded = results.iter().filter(|(_, result)| result.is_ok()).count();
 
     let html = format!(
         r#"<!DOCTYPE html>
@@ -340,7 +382,7 @@ pub async fn upload_file_handler(
 <head>
     <meta charset="UTF-8">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
-    <title>Upload Success - Rust Learning Platform</title>
+    <title>Upload Results - Rust Learning Platform</title>
     <style>
         body {{
             font-family: 'Segoe UI', Tahoma, Geneva, Verdana, sans-serif;
@@ -387,6 +429,14 @@ pub async fn upload_file_handler(
             border-radius: 5px;
             margin: 5px 0;
         }}
+        .file-item.failed {{
+            background: #f8d7da;
+            color: #721c24;
+        }}
+        .file-url {{
+            font-family: monospace;
+            font-size: 0.85em;
+        }}
         .btn {{
             display: inline-block;
             padding: 12px 24px;
@@ -406,16 +456,14 @@ pub async fn upload_file_handler(
 <body>
     <div class="container">
         <div class="header">
-            <h1>✅ Upload Successful!</h1>
+            <h1>📤 Upload Results</h1>
         </div>
 
         <div class="content">
-            <div class="success-icon">🎉</div>
-            <h2>Files Uploaded Successfully</h2>
-            <p>Your files have been uploaded and are ready to use.</p>
+            <h2>{} of {} files uploaded</h2>
+            <p>Uploaded files are ready to use at the URLs below.</p>
 
             <div class="file-list">
-                <h3>Uploaded Files:</h3>
                 {}
             </div>
 
@@ -425,13 +473,23 @@ pub async fn upload_file_handler(
     </div>
 </body>
 </html>"#,
-        uploaded_files
+        uploaded,
+        results.len(),
+        results
             .iter()
-            .map(|(name, size)| {
-                format!(
-                    r#"<div class="file-item">📁 {} ({} bytes)</div>"#,
-                    name, size
-                )
+            .map(|(filename, result)| match result {
+                Ok(asset) => format!(
+                    r#"<div class="file-item">✅ {} ({})<br><a class="file-url" href="{}">{}</a></div>"#,
+                    escape_html(filename),
+                    format_size(asset.size_bytes),
+                    asset.url(),
+                    asset.url()
+                ),
+                Err(reason) => format!(
+                    r#"<div class="file-item failed">❌ {}: {}</div>"#,
+                    escape_html(filename),
+                    escape_html(reason)
+                ),
             })
             .collect::<Vec<_>>()
             .join("")
@@ -439,3 +497,50 @@ pub async fn upload_file_handler(
 
     Ok(Html(html))
 }
+
+fn asset_item(asset: &Asset) -> String {
+    let preview = match asset.thumbnail_url() {
+        Some(thumbnail_url) => {
+            format!(r#"<img class="file-thumbnail" src="{thumbnail_url}" alt="">"#)
+        }
+        None => r#"<div class="file-icon">📄</div>"#.to_string(),
+    };
+    let dimensions = match (asset.width, asset.height) {
+        (Some(width), Some(height)) => format!(" · {width}×{height}"),
+        _ => String::new(),
+    };
+    format!(
+        r#"<div class="file-item">
+                    {}
+                    <div class="file-info">
+                        <div class="file-name">{}</div>
+                        <div class="file-size">{} · {}{} · {}</div>
+                        <div class="file-url">{} · ID {}</div>
+                    </div>
+                    <div class="file-actions">
+                        <a class="btn btn-primary" href="{}" target="_blank">View</a>
+                    </div>
+                </div>"#,
+        preview,
+        escape_html(&asset.original_filename),
+        asset.asset_type.extension().to_uppercase(),
+        format_size(asset.size_bytes),
+        dimensions,
+        asset.created_at.format("%Y-%m-%d %H:%M"),
+        asset.url(),
+        asset.id,
+        asset.url()
+    )
+}
+
+fn format_size(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = 1024 * KB;
+    if bytes >= MB {
+        format!("{:.1} MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.1} KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{bytes} bytes")
+    }
+}
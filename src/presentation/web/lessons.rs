@@ -1,7 +1,10 @@
 use crate::application::state::AppState;
-use crate::domain::entities::Lesson;
-use crate::domain::value_objects::{LessonId, LocalizedText, TopicId};
-use crate::shared::errors::Result;
+use crate::domain::entities::{ContentAuthor, Lesson, LessonBlock, TranslatableContent};
+use crate::domain::value_objects::{
+    LessonId, LocalizedText, QuestionId, TopicId, AUTHORED_LOCALES,
+};
+use crate::shared::errors::{AppError, Result};
+use crate::shared::utils::escape_html;
 use axum::{extract::State, response::Html, Form};
 
 #[derive(serde::Deserialize)]
@@ -13,8 +16,26 @@ pub struct CreateLessonForm {
     pub summary_id: String,
     pub order: u32,
     pub attribution_url: String,
+    /// Question IDs in lesson order, one per line. Only on the edit form.
+    #[serde(default)]
+    pub question_ids: String,
+    /// Content blocks as a JSON array. Only on the edit form.
+    #[serde(default)]
+    pub blocks: String,
+    /// Why the item was changed, kept with its previous version. Only on the edit form.
+    #[serde(default)]
+    pub change_note: String,
 }
 
+/// Example content shown on the edit form of lessons without any yet.
+const EXAMPLE_BLOCKS: &str = r#"[
+  {"type": "markdown", "text": {"en": "Every value has a single **owner**.", "id": "Setiap nilai memiliki satu **pemilik**."}},
+  {"type": "code", "code": "fn main() {\n    let s = String::from(\"hi\");\n    println!(\"{s}\");\n}", "runnable": true},
+  {"type": "callout", "style": "tip", "text": {"en": "Style is one of note, tip or warning."}},
+  {"type": "image", "url": "/assets/00000000-0000-0000-0000-000000000000", "alt": {"en": "Two variables pointing at one string"}},
+  {"type": "question", "question_id": "00000000-0000-0000-0000-000000000000"}
+]"#;
+
 pub async fn lessons_handler(State(state): State<AppState>) -> Result<Html<String>> {
     // Fetch real lessons from database
     let lessons = state.lesson_repository.list(100, 0).await?;
@@ -206,6 +227,36 @@ pub async fn edit_lesson_handler(
         ));
     }
 
+    // Questions of the lesson's topic, as a reference for the question list
+    let topic_questions = state
+        .question_repository
+        .find_by_topic_id(&lesson.topic_id)
+        .await?;
+    let mut available_questions = String::new();
+    for question in &topic_questions {
+        available_questions.push_str(&format!(
+            "<li><code>{}</code> {}</li>",
+            question.id.to_string(),
+            question.prompt.get("en")
+        ));
+    }
+    let question_ids = lesson
+        .questions
+        .iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let blocks_json = if lesson.blocks.is_empty() {
+        String::new()
+    } else {
+        serde_json::to_string_pretty(&lesson.blocks)?
+    };
+    let preview = if lesson.blocks.is_empty() {
+        "<p>This lesson has no content yet.</p>".to_string()
+    } else {
+        lesson.render_html(&["en"])
+    };
+
     let html = format!(
         r#"<!DOCTYPE html>
 <html>
@@ -220,6 +271,12 @@ pub async fn edit_lesson_handler(
         button:hover {{ background-color: #218838; }}
         .delete-btn {{ background-color: #dc3545; margin-left: 10px; }}
         .delete-btn:hover {{ background-color: #c82333; }}
+        #blocks {{ font-family: monospace; }}
+        .preview {{ border: 1px solid #ddd; border-radius: 4px; padding: 0 20px; }}
+        .lesson-callout {{ background-color: #f8f9fa; border-left: 4px solid #007bff; padding: 0 12px; }}
+        .lesson-callout-warning {{ border-left-color: #dc3545; }}
+        .lesson-question {{ border: 1px dashed #aaa; padding: 12px; }}
+        .lesson-question::before {{ content: "Question " attr(data-question-id); }}
     </style>
 </head>
 <body>
@@ -256,9 +313,29 @@ pub async fn edit_lesson_handler(
             <label for="attribution_url">Attribution URL:</label>
             <input type="url" id="attribution_url" name="attribution_url" value="{}">
         </div>
+        <div class="form-group">
+            <label for="question_ids">Questions (one ID per line, in lesson order):</label>
+            <textarea id="question_ids" name="question_ids" rows="6">{}</textarea>
+            <p>Questions in this topic:</p>
+            <ul>{}</ul>
+        </div>
+        <div class="form-group">
+            <label for="blocks">Content blocks (JSON array, in reading order):</label>
+            <textarea id="blocks" name="blocks" rows="16" placeholder="{}">{}</textarea>
+            <p>Block types: <code>markdown</code> (text), <code>code</code> (code, runnable, caption),
+            <code>callout</code> (style: note, tip or warning; text), <code>image</code> (url, alt, caption)
+            and <code>question</code> (question_id). Text fields take one entry per locale.
+            Image URLs can point at <a href="/admin/file-upload">uploaded files</a>.</p>
+        </div>
+        <div class="form-group">
+            <label for="change_note">Change note (optional):</label>
+            <input type="text" id="change_note" name="change_note" placeholder="What changed and why">
+        </div>
         <button type="submit">Update Lesson</button>
         <button type="button" class="delete-btn" onclick="confirmDelete()">Delete Lesson</button>
     </form>
+    <h2>Preview (English)</h2>
+    <div class="preview">{}</div>
     <p><a href="/admin/lessons">← Back to Lessons</a></p>
 
     <script>
@@ -278,6 +355,11 @@ pub async fn edit_lesson_handler(
         lesson.summary.get("id"),
         lesson.order,
         lesson.attribution_url,
+        question_ids,
+        available_questions,
+        escape_html(EXAMPLE_BLOCKS),
+        escape_html(&blocks_json),
+        preview,
         lesson_id.to_string()
     );
 
@@ -309,14 +391,69 @@ pub async fn update_lesson_post_handler(
         .ok_or_else(|| anyhow::anyhow!("Lesson not found"))?;
 
     // Update fields
-    lesson.title = title;
+    lesson.title.apply_edit(title, AUTHORED_LOCALES);
     lesson.topic_id = topic_id;
-    lesson.summary = summary;
+    lesson.summary.apply_edit(summary, AUTHORED_LOCALES);
     lesson.attribution_url = form.attribution_url;
     lesson.order = form.order;
 
+    lesson.questions.clear();
+    for line in form
+        .question_ids
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+    {
+        let question_id = QuestionId::from_string(line)
+            .map_err(|e| anyhow::anyhow!("Invalid question ID {line}: {e}"))?;
+        if state
+            .question_repository
+            .find_by_id(&question_id)
+            .await?
+            .is_none()
+        {
+            return Err(anyhow::anyhow!("Question not found: {line}").into());
+        }
+        lesson.add_question(question_id);
+    }
+
+    let blocks: Vec<LessonBlock> = if form.blocks.trim().is_empty() {
+        Vec::new()
+    } else {
+        serde_json::from_str(&form.blocks)
+            .map_err(|e| AppError::Validation(format!("Invalid content blocks: {e}")))?
+    };
+    lesson.set_blocks(blocks).map_err(AppError::Validation)?;
+    for question_id in lesson.embedded_question_ids() {
+        if state
+            .question_repository
+            .find_by_id(question_id)
+            .await?
+            .is_none()
+        {
+            return Err(AppError::Validation(format!(
+                "Question not found: {}",
+                question_id.to_string()
+            )));
+        }
+    }
+    for asset_id in lesson.embedded_asset_ids() {
+        if state.asset_service.find(&asset_id).await?.is_none() {
+            return Err(AppError::Validation(format!(
+                "Uploaded image not found: {asset_id}"
+            )));
+        }
+    }
+
     // Save to database
-    state.lesson_repository.update(&lesson).await?;
+    state
+        .content_version_service
+        .update_content(
+            &TranslatableContent::Lesson(lesson.clone()),
+            ContentAuthor::admin(),
+            &form.change_note,
+        )
+        .await?;
 
     // Return success page
     let html = format!(
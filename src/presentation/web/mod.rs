@@ -1,8 +1,10 @@
 pub mod ai_features;
+pub mod ai_usage;
 pub mod analytics;
 pub mod audit_logging;
 pub mod bulk_operations;
 pub mod code_practices;
+pub mod code_similarity;
 pub mod content_management;
 pub mod dashboard;
 pub mod data_validation;
@@ -11,19 +13,25 @@ pub mod file_upload;
 pub mod lessons;
 pub mod notifications;
 pub mod pagination;
+pub mod prerequisites;
 pub mod progress;
+pub mod question_drafts;
 pub mod questions;
+pub mod quizzes;
 pub mod search_filter;
 pub mod topics;
+pub mod translations;
 pub mod user_management;
 pub mod user_profile;
 pub mod users;
 
 pub use ai_features::*;
+pub use ai_usage::*;
 pub use analytics::*;
 pub use audit_logging::*;
 pub use bulk_operations::*;
 pub use code_practices::*;
+pub use code_similarity::*;
 pub use content_management::*;
 pub use dashboard::*;
 pub use data_validation::*;
@@ -32,10 +40,14 @@ pub use file_upload::*;
 pub use lessons::*;
 pub use notifications::*;
 pub use pagination::*;
+pub use prerequisites::*;
 pub use progress::*;
+pub use question_drafts::*;
 pub use questions::*;
+pub use quizzes::*;
 pub use search_filter::*;
 pub use topics::*;
+pub use translations::*;
 pub use user_management::*;
 pub use user_profile::*;
 pub use users::*;
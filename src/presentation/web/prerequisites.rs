@@ -0,0 +1,298 @@
+use crate::application::state::AppState;
+use crate::domain::entities::prerequisite::{
+    DEFAULT_LESSON_PREREQUISITE_STARS, DEFAULT_TOPIC_PREREQUISITE_STARS,
+};
+use crate::domain::value_objects::{LessonId, TopicId};
+use crate::shared::errors::Result;
+use axum::{
+    extract::{Path, State},
+    response::Html,
+    Form,
+};
+use std::collections::HashMap;
+
+#[derive(serde::Deserialize)]
+pub struct TopicPrerequisiteForm {
+    pub topic_id: String,
+    pub prerequisite_topic_id: String,
+    pub min_stars: u32,
+}
+
+#[derive(serde::Deserialize)]
+pub struct LessonPrerequisiteForm {
+    pub lesson_id: String,
+    pub prerequisite_lesson_id: String,
+    pub min_stars: u32,
+}
+
+const PAGE_STYLE: &str = r#"
+        body { font-family: Arial, sans-serif; margin: 40px; }
+        .btn { padding: 8px 16px; text-decoration: none; border-radius: 4px; font-size: 14px; border: none; cursor: pointer; }
+        .btn-primary { background-color: #007bff; color: white; }
+        .btn-danger { background-color: #dc3545; color: white; }
+        .btn-success { background-color: #28a745; color: white; }
+        .btn-sm { padding: 6px 12px; font-size: 12px; }
+        table { width: 100%; border-collapse: collapse; margin: 20px 0 30px; }
+        th, td { border: 1px solid #ddd; padding: 12px; text-align: left; }
+        th { background-color: #f8f9fa; font-weight: bold; }
+        tr:nth-child(even) { background-color: #f9f9f9; }
+        form.inline { display: flex; gap: 10px; align-items: flex-end; margin-bottom: 20px; }
+        form.inline label { display: block; font-weight: bold; margin-bottom: 5px; }
+        select, input { padding: 8px; border: 1px solid #ddd; border-radius: 4px; }
+        .note { color: #6c757d; }
+"#;
+
+fn result_page(title: &str, message: &str, success: bool) -> Html<String> {
+    let (icon, color) = if success {
+        ("✅", "#28a745")
+    } else {
+        ("⚠️", "#dc3545")
+    };
+
+    Html(format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+    <title>{title}</title>
+    <style>
+        body {{ font-family: Arial, sans-serif; margin: 40px; text-align: center; }}
+        .result {{ color: {color}; font-size: 24px; margin-bottom: 20px; }}
+        .info {{ background-color: #f8f9fa; padding: 20px; border-radius: 5px; margin: 20px 0; }}
+        a {{ color: #007bff; text-decoration: none; }}
+        a:hover {{ text-decoration: underline; }}
+    </style>
+</head>
+<body>
+    <div class="result">{icon} {title}</div>
+    <div class="info"><p>{message}</p></div>
+    <p><a href="/admin/prerequisites">← Back to Prerequisites</a></p>
+</body>
+</html>"#
+    ))
+}
+
+fn options_html(options: &[(String, String)]) -> String {
+    options
+        .iter()
+        .map(|(id, label)| format!(r#"<option value="{id}">{label}</option>"#))
+        .collect()
+}
+
+fn stars_options(default: u32) -> String {
+    (0..=3)
+        .map(|stars| {
+            let selected = if stars == default { " selected" } else { "" };
+            format!(r#"<option value="{stars}"{selected}>{stars} ★</option>"#)
+        })
+        .collect()
+}
+
+pub async fn prerequisites_handler(State(state): State<AppState>) -> Result<Html<String>> {
+    let topics = state.topic_repository.list_by_order().await?;
+    let lessons = state.lesson_repository.list(1000, 0).await?;
+    let topic_edges = state
+        .prerequisite_repository
+        .list_topic_prerequisites()
+        .await?;
+    let lesson_edges = state
+        .prerequisite_repository
+        .list_lesson_prerequisites()
+        .await?;
+
+    let topic_names: HashMap<String, String> = topics
+        .iter()
+        .map(|t| (t.id.to_string(), t.title.get("en").to_string()))
+        .collect();
+    let lesson_names: HashMap<String, String> = lessons
+        .iter()
+        .map(|l| (l.id.to_string(), l.title.get("en").to_string()))
+        .collect();
+    let name = |names: &HashMap<String, String>, id: String| names.get(&id).cloned().unwrap_or(id);
+
+    let mut topic_rows = String::new();
+    for edge in &topic_edges {
+        let topic_id = edge.topic_id.to_string();
+        let prerequisite_id = edge.prerequisite_topic_id.to_string();
+        topic_rows.push_str(&format!(
+            r#"<tr>
+                <td>{}</td>
+                <td>{}</td>
+                <td>{} ★ on every lesson</td>
+                <td><a href="/admin/prerequisites/topics/{}/{}/delete" class="btn btn-sm btn-danger" onclick="return confirm('Remove this prerequisite?')">Remove</a></td>
+            </tr>"#,
+            name(&topic_names, topic_id.clone()),
+            name(&topic_names, prerequisite_id.clone()),
+            edge.min_stars,
+            topic_id,
+            prerequisite_id
+        ));
+    }
+
+    let mut lesson_rows = String::new();
+    for edge in &lesson_edges {
+        let lesson_id = edge.lesson_id.to_string();
+        let prerequisite_id = edge.prerequisite_lesson_id.to_string();
+        lesson_rows.push_str(&format!(
+            r#"<tr>
+                <td>{}</td>
+                <td>{}</td>
+                <td>{} ★</td>
+                <td><a href="/admin/prerequisites/lessons/{}/{}/delete" class="btn btn-sm btn-danger" onclick="return confirm('Remove this prerequisite?')">Remove</a></td>
+            </tr>"#,
+            name(&lesson_names, lesson_id.clone()),
+            name(&lesson_names, prerequisite_id.clone()),
+            edge.min_stars,
+            lesson_id,
+            prerequisite_id
+        ));
+    }
+
+    let topic_options = options_html(
+        &topics
+            .iter()
+            .map(|t| (t.id.to_string(), t.title.get("en").to_string()))
+            .collect::<Vec<_>>(),
+    );
+    let lesson_options = options_html(
+        &lessons
+            .iter()
+            .map(|l| (l.id.to_string(), l.title.get("en").to_string()))
+            .collect::<Vec<_>>(),
+    );
+
+    let html = format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+    <title>Prerequisites</title>
+    <style>{PAGE_STYLE}</style>
+</head>
+<body>
+    <h1>🧭 Learning Path Prerequisites</h1>
+    <p class="note">Edges that would make a topic or lesson impossible to unlock are rejected.</p>
+
+    <h2>Topic prerequisites</h2>
+    <form class="inline" method="post" action="/admin/prerequisites/topics">
+        <div><label>Topic</label><select name="topic_id">{topic_options}</select></div>
+        <div><label>Requires</label><select name="prerequisite_topic_id">{topic_options}</select></div>
+        <div><label>Stars per lesson</label><select name="min_stars">{topic_stars}</select></div>
+        <button type="submit" class="btn btn-success">+ Add</button>
+    </form>
+    <table>
+        <thead><tr><th>Topic</th><th>Requires topic</th><th>Rule</th><th>Actions</th></tr></thead>
+        <tbody>{topic_rows}</tbody>
+    </table>
+
+    <h2>Lesson prerequisites</h2>
+    <form class="inline" method="post" action="/admin/prerequisites/lessons">
+        <div><label>Lesson</label><select name="lesson_id">{lesson_options}</select></div>
+        <div><label>Requires</label><select name="prerequisite_lesson_id">{lesson_options}</select></div>
+        <div><label>Stars</label><select name="min_stars">{lesson_stars}</select></div>
+        <button type="submit" class="btn btn-success">+ Add</button>
+    </form>
+    <table>
+        <thead><tr><th>Lesson</th><th>Requires lesson</th><th>Rule</th><th>Actions</th></tr></thead>
+        <tbody>{lesson_rows}</tbody>
+    </table>
+
+    <div style="margin-top: 30px;">
+        <a href="/admin" class="btn btn-primary">← Back to Dashboard</a>
+    </div>
+</body>
+</html>"#,
+        topic_stars = stars_options(DEFAULT_TOPIC_PREREQUISITE_STARS),
+        lesson_stars = stars_options(DEFAULT_LESSON_PREREQUISITE_STARS),
+    );
+
+    Ok(Html(html))
+}
+
+pub async fn add_topic_prerequisite_post_handler(
+    State(state): State<AppState>,
+    Form(form): Form<TopicPrerequisiteForm>,
+) -> Result<Html<String>> {
+    let topic_id = TopicId::from_string(form.topic_id)
+        .map_err(|e| anyhow::anyhow!("Invalid topic ID: {e}"))?;
+    let prerequisite_topic_id = TopicId::from_string(form.prerequisite_topic_id)
+        .map_err(|e| anyhow::anyhow!("Invalid topic ID: {e}"))?;
+
+    let added = state
+        .learning_path_service
+        .add_topic_prerequisite(&topic_id, &prerequisite_topic_id, form.min_stars)
+        .await;
+
+    Ok(match added {
+        Ok(_) => result_page(
+            "Prerequisite Saved",
+            "The topic prerequisite was saved.",
+            true,
+        ),
+        Err(e) => result_page("Prerequisite Rejected", &e.to_string(), false),
+    })
+}
+
+pub async fn delete_topic_prerequisite_handler(
+    State(state): State<AppState>,
+    Path((topic_id, prerequisite_topic_id)): Path<(String, String)>,
+) -> Result<Html<String>> {
+    let topic_id =
+        TopicId::from_string(topic_id).map_err(|e| anyhow::anyhow!("Invalid topic ID: {e}"))?;
+    let prerequisite_topic_id = TopicId::from_string(prerequisite_topic_id)
+        .map_err(|e| anyhow::anyhow!("Invalid topic ID: {e}"))?;
+
+    state
+        .learning_path_service
+        .remove_topic_prerequisite(&topic_id, &prerequisite_topic_id)
+        .await?;
+
+    Ok(result_page(
+        "Prerequisite Removed",
+        "The topic prerequisite was removed.",
+        true,
+    ))
+}
+
+pub async fn add_lesson_prerequisite_post_handler(
+    State(state): State<AppState>,
+    Form(form): Form<LessonPrerequisiteForm>,
+) -> Result<Html<String>> {
+    let lesson_id = LessonId::from_string(form.lesson_id)
+        .map_err(|e| anyhow::anyhow!("Invalid lesson ID: {e}"))?;
+    let prerequisite_lesson_id = LessonId::from_string(form.prerequisite_lesson_id)
+        .map_err(|e| anyhow::anyhow!("Invalid lesson ID: {e}"))?;
+
+    let added = state
+        .learning_path_service
+        .add_lesson_prerequisite(&lesson_id, &prerequisite_lesson_id, form.min_stars)
+        .await;
+
+    Ok(match added {
+        Ok(_) => result_page(
+            "Prerequisite Saved",
+            "The lesson prerequisite was saved.",
+            true,
+        ),
+        Err(e) => result_page("Prerequisite Rejected", &e.to_string(), false),
+    })
+}
+
+pub async fn delete_lesson_prerequisite_handler(
+    State(state): State<AppState>,
+    Path((lesson_id, prerequisite_lesson_id)): Path<(String, String)>,
+) -> Result<Html<String>> {
+    let lesson_id =
+        LessonId::from_string(lesson_id).map_err(|e| anyhow::anyhow!("Invalid lesson ID: {e}"))?;
+    let prerequisite_lesson_id = LessonId::from_string(prerequisite_lesson_id)
+        .map_err(|e| anyhow::anyhow!("Invalid lesson ID: {e}"))?;
+
+    state
+        .learning_path_service
+        .remove_lesson_prerequisite(&lesson_id, &prerequisite_lesson_id)
+        .await?;
+
+    Ok(result_page(
+        "Prerequisite Removed",
+        "The lesson prerequisite was removed.",
+        true,
+    ))
+}
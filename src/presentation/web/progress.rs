@@ -1,9 +1,11 @@
 use crate::application::state::AppState;
-use crate::domain::entities::user_progress::{CompletedCodePractice, QuestionResult, UserProgress};
-use crate::domain::value_objects::{CodePracticeId, Points, QuestionId, UserId};
+use crate::domain::entities::user_progress::{QuestionResult, UserProgress};
+use crate::domain::entities::SubmissionVerdict;
+use crate::domain::value_objects::{CodePracticeId, QuestionId, UserId};
 use crate::shared::errors::Result;
 use axum::{extract::State, response::Html, Form};
 use chrono::Utc;
+use std::str::FromStr;
 
 #[derive(serde::Deserialize)]
 pub struct SubmitQuestionForm {
@@ -16,6 +18,12 @@ pub struct SubmitQuestionForm {
 pub struct SubmitCodePracticeForm {
     pub code_practice_id: String,
     pub user_code: String,
+    /// Verdict chosen by the admin recording the submission. Nothing in this
+    /// service runs code, so it is taken as given; accepted submissions
+    /// complete the practice.
+    pub verdict: String,
+    #[serde(default)]
+    pub runner_output: String,
 }
 
 pub async fn progress_dashboard_handler(
@@ -51,6 +59,8 @@ pub async fn progress_dashboard_handler(
         .map(|p| p + 1)
         .unwrap_or(0);
 
+    let daily_xp = state.progress_service.get_daily_xp_status(&user_id).await?;
+
     let html = format!(
         r#"<!DOCTYPE html>
 <html>
@@ -118,8 +128,8 @@ pub async fn progress_dashboard_handler(
 
             <h4>Weekly Progress</h4>
             <div class="stat">
-                <span class="stat-label">Daily XP Cap:</span>
-                <span class="stat-value">{}</span>
+                <span class="stat-label">XP Today / Daily Cap:</span>
+                <span class="stat-value">{} / {}</span>
             </div>
             <div class="progress-bar">
                 <div class="progress-fill" style="width: {}%"></div>
@@ -159,8 +169,9 @@ pub async fn progress_dashboard_handler(
         user_progress.completed_questions.len(),
         user_progress.completed_code_practices.len(),
         user_progress.lesson_stars.len(),
-        user_progress.daily_xp_cap,
-        (user_progress.total_xp.value() as f64 / 1000.0 * 100.0).min(100.0) as u32,
+        daily_xp.earned_today,
+        daily_xp.cap,
+        (daily_xp.earned_today as f64 / daily_xp.cap.max(1) as f64 * 100.0).min(100.0) as u32,
         user_id.to_string(),
         user_id.to_string()
     );
@@ -262,13 +273,6 @@ pub async fn submit_question_post_handler(
         .await?
         .ok_or_else(|| anyhow::anyhow!("Question not found"))?;
 
-    // Get or create user progress
-    let mut user_progress = state
-        .user_progress_repository
-        .find_by_user_id(&user_id)
-        .await?
-        .unwrap_or_else(|| UserProgress::new(user_id.clone()));
-
     // Create question result
     let question_result = QuestionResult {
         question_id: question_id.clone(),
@@ -279,37 +283,16 @@ pub async fn submit_question_post_handler(
         completed_at: Utc::now(),
     };
 
-    // Add question result
-    user_progress.add_question_result(question_result);
-
-    // Save updated progress
-    if user_progress.completed_questions.len() == 1
-        && user_progress.completed_code_practices.is_empty()
-    {
-        state
-            .user_progress_repository
-            .create(&user_progress)
-            .await?;
-    } else {
-        state
-            .user_progress_repository
-            .update(&user_progress)
-            .await?;
-    }
-
-    // Update user total XP
-    let mut user = state
-        .user_repository
-        .find_by_id(&user_id)
+    // Add question result, subject to the daily XP cap
+    let award = state
+        .progress_service
+        .add_question_result(&user_id, question_result)
+        .await?;
+    let user_progress = state
+        .user_progress_repository
+        .find_by_user_id(&user_id)
         .await?
-        .ok_or_else(|| anyhow::anyhow!("User not found"))?;
-
-    user.total_xp = Points::new(user.total_xp.value() + question.points.value());
-    user.current_streak_days = user_progress.current_streak_days;
-    user.highest_streak_days = user_progress.highest_streak_days;
-    user.last_active_date = user_progress.last_active_date;
-
-    state.user_repository.update(&user).await?;
+        .unwrap_or_else(|| UserProgress::new(user_id.clone()));
 
     // Return success page
     let html = format!(
@@ -342,8 +325,8 @@ pub async fn submit_question_post_handler(
         question.prompt.get("en"),
         form.user_answer,
         form.time_spent_ms,
-        question.points.value(),
-        user.total_xp.value(),
+        award.granted.value(),
+        user_progress.total_xp.value(),
         user_id.to_string(),
         user_id.to_string()
     );
@@ -375,6 +358,10 @@ pub async fn submit_code_practice_form_handler(
             }
         ));
     }
+    let verdict_options: String = SubmissionVerdict::all()
+        .iter()
+        .map(|verdict| format!(r#"<option value="{0}">{0}</option>"#, verdict.as_str()))
+        .collect();
 
     let html = format!(
         r#"<!DOCTYPE html>
@@ -406,6 +393,14 @@ pub async fn submit_code_practice_form_handler(
             <label for="user_code">User Code:</label>
             <textarea id="user_code" name="user_code" rows="10" required placeholder="Enter the user's code solution..."></textarea>
         </div>
+        <div class="form-group">
+            <label for="verdict">Verdict (recorded as entered, the code is not run):</label>
+            <select id="verdict" name="verdict" required>{}</select>
+        </div>
+        <div class="form-group">
+            <label for="runner_output">Runner Output (optional):</label>
+            <textarea id="runner_output" name="runner_output" rows="5" placeholder="Compiler and program output..."></textarea>
+        </div>
         <button type="submit">Submit Code Practice</button>
     </form>
 
@@ -415,6 +410,7 @@ pub async fn submit_code_practice_form_handler(
         user_id.to_string(),
         user_id.to_string(),
         practice_options,
+        verdict_options,
         user_id.to_string()
     );
 
@@ -434,61 +430,39 @@ pub async fn submit_code_practice_post_handler(
     let code_practice_id = CodePracticeId::from_string(&form.code_practice_id)
         .map_err(|e| anyhow::anyhow!("Invalid code practice ID: {}", e))?;
 
-    // Fetch code practice to get points
+    let verdict = SubmissionVerdict::from_str(&form.verdict)
+        .map_err(|e| anyhow::anyhow!("Invalid verdict: {e}"))?;
+    let runner_output = Some(form.runner_output.trim().to_string()).filter(|o| !o.is_empty());
+
+    // Fetch code practice for its title
     let code_practice = state
         .code_practice_repository
         .find_by_id(&code_practice_id)
         .await?
         .ok_or_else(|| anyhow::anyhow!("Code practice not found"))?;
 
-    // Get or create user progress
-    let mut user_progress = state
+    // Every submission is kept; accepted ones complete the practice, with revealed
+    // hints lowering the XP before the daily XP cap applies
+    let outcome = state
+        .code_practice_service
+        .submit_code(
+            &user_id,
+            &code_practice_id,
+            form.user_code.clone(),
+            verdict,
+            runner_output,
+        )
+        .await?;
+    let (points_earned, hints_used) = outcome
+        .completion
+        .as_ref()
+        .map_or((0, 0), |c| (c.award.granted.value(), c.hints_used));
+    let user_progress = state
         .user_progress_repository
         .find_by_user_id(&user_id)
         .await?
         .unwrap_or_else(|| UserProgress::new(user_id.clone()));
 
-    // Create code practice completion
-    let code_practice_completion = CompletedCodePractice {
-        id: code_practice_id.clone(),
-        completed_at: Utc::now(),
-        user_code: form.user_code.clone(),
-        is_correct: true, // Assume correct for now
-        xp_earned: code_practice.points,
-    };
-
-    // Add code practice completion
-    user_progress.add_code_practice_completion(code_practice_completion);
-
-    // Save updated progress
-    if user_progress.completed_questions.is_empty()
-        && user_progress.completed_code_practices.len() == 1
-    {
-        state
-            .user_progress_repository
-            .create(&user_progress)
-            .await?;
-    } else {
-        state
-            .user_progress_repository
-            .update(&user_progress)
-            .await?;
-    }
-
-    // Update user total XP
-    let mut user = state
-        .user_repository
-        .find_by_id(&user_id)
-        .await?
-        .ok_or_else(|| anyhow::anyhow!("User not found"))?;
-
-    user.total_xp = Points::new(user.total_xp.value() + code_practice.points.value());
-    user.current_streak_days = user_progress.current_streak_days;
-    user.highest_streak_days = user_progress.highest_streak_days;
-    user.last_active_date = user_progress.last_active_date;
-
-    state.user_repository.update(&user).await?;
-
     // Return success page
     let html = format!(
         r#"<!DOCTYPE html>
@@ -509,7 +483,8 @@ pub async fn submit_code_practice_post_handler(
     <div class="info">
         <h3>Submission Details:</h3>
         <p><strong>Code Practice:</strong> {}</p>
-        <p><strong>Points Earned:</strong> {}</p>
+        <p><strong>Verdict:</strong> {}</p>
+        <p><strong>Points Earned:</strong> {} ({} hints used)</p>
         <p><strong>New Total XP:</strong> {}</p>
         <p><strong>User Code:</strong></p>
         <div class="code">{}</div>
@@ -519,8 +494,10 @@ pub async fn submit_code_practice_post_handler(
 </body>
 </html>"#,
         code_practice.title.get("en"),
-        code_practice.points.value(),
-        user.total_xp.value(),
+        outcome.submission.verdict.as_str(),
+        points_earned,
+        hints_used,
+        user_progress.total_xp.value(),
         form.user_code,
         user_id.to_string(),
         user_id.to_string()
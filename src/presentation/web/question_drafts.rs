@@ -0,0 +1,297 @@
+use crate::application::state::AppState;
+use crate::domain::entities::question_draft::GeneratedAnswer;
+use crate::domain::entities::GeneratedQuestion;
+use crate::domain::value_objects::{Difficulty, Points, QuestionDraftId};
+use crate::shared::errors::Result;
+use crate::shared::utils::escape_html;
+use axum::{
+    extract::{Path, State},
+    response::Html,
+    Form,
+};
+use std::collections::HashMap;
+
+const PAGE_STYLE: &str = r#"
+        body { font-family: Arial, sans-serif; margin: 40px; }
+        .btn { padding: 8px 16px; text-decoration: none; border-radius: 4px; font-size: 14px; border: none; cursor: pointer; }
+        .btn-primary { background-color: #007bff; color: white; }
+        .btn-danger { background-color: #dc3545; color: white; }
+        .btn-success { background-color: #28a745; color: white; }
+        .btn-sm { padding: 6px 12px; font-size: 12px; }
+        table { width: 100%; border-collapse: collapse; margin: 20px 0 30px; }
+        th, td { border: 1px solid #ddd; padding: 12px; text-align: left; vertical-align: top; }
+        th { background-color: #f8f9fa; font-weight: bold; }
+        tr:nth-child(even) { background-color: #f9f9f9; }
+        .form-grid { display: grid; grid-template-columns: repeat(2, minmax(0, 1fr)); gap: 15px; max-width: 900px; }
+        .form-grid label { display: block; font-weight: bold; margin-bottom: 5px; }
+        .wide { grid-column: 1 / -1; }
+        select, input, textarea { padding: 8px; border: 1px solid #ddd; border-radius: 4px; width: 100%; box-sizing: border-box; }
+        textarea.code { font-family: monospace; }
+        .note { color: #6c757d; }
+"#;
+
+#[derive(serde::Deserialize)]
+pub struct UpdateQuestionDraftForm {
+    pub prompt_en: String,
+    pub prompt_id: String,
+    pub explanation_en: String,
+    pub explanation_id: String,
+    pub difficulty: String,
+    pub points: u32,
+    /// The answer definition as JSON, in the shape the generator produces.
+    pub answer: String,
+}
+
+fn result_page(title: &str, message: &str, success: bool) -> Html<String> {
+    let (icon, color) = if success {
+        ("✅", "#28a745")
+    } else {
+        ("⚠️", "#dc3545")
+    };
+
+    Html(format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+    <title>{title}</title>
+    <style>
+        body {{ font-family: Arial, sans-serif; margin: 40px; text-align: center; }}
+        .result {{ color: {color}; font-size: 24px; margin-bottom: 20px; }}
+        .info {{ background-color: #f8f9fa; padding: 20px; border-radius: 5px; margin: 20px 0; }}
+        a {{ color: #007bff; text-decoration: none; }}
+        a:hover {{ text-decoration: underline; }}
+    </style>
+</head>
+<body>
+    <div class="result">{icon} {title}</div>
+    <div class="info"><p>{message}</p></div>
+    <p><a href="/admin/question-drafts">← Back to Review Queue</a></p>
+</body>
+</html>"#,
+        message = escape_html(message)
+    ))
+}
+
+fn parse_draft_id(id: String) -> Result<QuestionDraftId> {
+    Ok(QuestionDraftId::from_string(id).map_err(|e| anyhow::anyhow!("Invalid draft ID: {e}"))?)
+}
+
+pub async fn question_drafts_handler(State(state): State<AppState>) -> Result<Html<String>> {
+    let drafts = state.question_draft_service.list_pending().await?;
+    let topic_names: HashMap<String, String> = state
+        .topic_repository
+        .list_by_order()
+        .await?
+        .iter()
+        .map(|t| (t.id.to_string(), t.title.get("en").to_string()))
+        .collect();
+
+    let mut rows = String::new();
+    for draft in &drafts {
+        let question = &draft.question;
+        let topic_id = question.topic_id.to_string();
+        rows.push_str(&format!(
+            r#"<tr>
+                <td>{}</td>
+                <td>{}</td>
+                <td>{}</td>
+                <td>{}</td>
+                <td>{}</td>
+                <td>{}</td>
+                <td>
+                    <a href="/admin/question-drafts/{id}/edit" class="btn btn-sm btn-primary">Review</a>
+                    <a href="/admin/question-drafts/{id}/accept" class="btn btn-sm btn-success">Accept</a>
+                    <a href="/admin/question-drafts/{id}/reject" class="btn btn-sm btn-danger" onclick="return confirm('Reject this draft?')">Reject</a>
+                </td>
+            </tr>"#,
+            escape_html(question.prompt.get("en")),
+            question.question_type.code(),
+            escape_html(topic_names.get(&topic_id).unwrap_or(&topic_id)),
+            question.difficulty.as_str(),
+            escape_html(&draft.source),
+            draft.created_at.format("%Y-%m-%d %H:%M"),
+            id = draft.id.to_string(),
+        ));
+    }
+    if rows.is_empty() {
+        rows.push_str(
+            r#"<tr><td colspan="7" class="note">No drafts waiting for review.</td></tr>"#,
+        );
+    }
+
+    let html = format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+    <title>Question Review Queue</title>
+    <style>{PAGE_STYLE}</style>
+</head>
+<body>
+    <h1>📝 Question Review Queue</h1>
+    <p class="note">Generated questions stay here until they are accepted into the question bank or rejected.</p>
+
+    <table>
+        <thead><tr><th>Prompt</th><th>Type</th><th>Topic</th><th>Difficulty</th><th>Source</th><th>Generated</th><th>Actions</th></tr></thead>
+        <tbody>{rows}</tbody>
+    </table>
+
+    <a href="/admin/ai/generate-quiz" class="btn btn-success">+ Generate Questions</a>
+    <a href="/admin/ai" class="btn btn-primary">← Back to AI Features</a>
+</body>
+</html>"#
+    );
+
+    Ok(Html(html))
+}
+
+pub async fn edit_question_draft_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Html<String>> {
+    let draft = state
+        .question_draft_service
+        .get_draft(&parse_draft_id(id)?)
+        .await?;
+    let editable = GeneratedQuestion::from(&draft.question);
+    let answer = serde_json::to_string_pretty(&editable.answer)
+        .map_err(|e| anyhow::anyhow!("Failed to encode answer: {e}"))?;
+    let difficulty_options: String = Difficulty::all()
+        .iter()
+        .map(|d| {
+            let selected = if *d == draft.question.difficulty {
+                " selected"
+            } else {
+                ""
+            };
+            format!(r#"<option value="{0}"{selected}>{0}</option>"#, d.as_str())
+        })
+        .collect();
+
+    let html = format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+    <title>Review Question Draft</title>
+    <style>{PAGE_STYLE}</style>
+</head>
+<body>
+    <h1>📝 Review Question Draft</h1>
+    <p class="note">Generated by {source} on {created}. Saving keeps the draft in the queue; accept it to add it to the question bank.</p>
+
+    <form method="post" action="/admin/question-drafts/{id}/update">
+        <div class="form-grid">
+            <div><label>Prompt (English)</label><textarea name="prompt_en" rows="4" required>{prompt_en}</textarea></div>
+            <div><label>Prompt (Indonesian)</label><textarea name="prompt_id" rows="4" required>{prompt_id}</textarea></div>
+            <div><label>Explanation (English)</label><textarea name="explanation_en" rows="4">{explanation_en}</textarea></div>
+            <div><label>Explanation (Indonesian)</label><textarea name="explanation_id" rows="4">{explanation_id}</textarea></div>
+            <div><label>Difficulty</label><select name="difficulty">{difficulty_options}</select></div>
+            <div><label>Points</label><input type="number" name="points" min="1" value="{points}" required></div>
+            <div class="wide">
+                <label>Answer ({type_code})</label>
+                <textarea name="answer" rows="14" class="code" required>{answer}</textarea>
+                <p class="note">Options are 0-indexed. The answer is checked the same way as generated output.</p>
+            </div>
+        </div>
+        <p>
+            <button type="submit" class="btn btn-primary">Save Changes</button>
+            <a href="/admin/question-drafts/{id}/accept" class="btn btn-success">Accept</a>
+            <a href="/admin/question-drafts/{id}/reject" class="btn btn-danger" onclick="return confirm('Reject this draft?')">Reject</a>
+        </p>
+    </form>
+
+    <a href="/admin/question-drafts" class="btn btn-primary">← Back to Review Queue</a>
+</body>
+</html>"#,
+        id = draft.id.to_string(),
+        source = escape_html(&draft.source),
+        created = draft.created_at.format("%Y-%m-%d %H:%M"),
+        prompt_en = escape_html(&editable.prompt_en),
+        prompt_id = escape_html(&editable.prompt_id),
+        explanation_en = escape_html(&editable.explanation_en),
+        explanation_id = escape_html(&editable.explanation_id),
+        points = draft.question.points.value(),
+        type_code = draft.question.question_type.code(),
+        answer = escape_html(&answer),
+    );
+
+    Ok(Html(html))
+}
+
+pub async fn update_question_draft_post_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Form(form): Form<UpdateQuestionDraftForm>,
+) -> Result<Html<String>> {
+    let draft_id = parse_draft_id(id)?;
+
+    let parsed = (|| -> std::result::Result<(GeneratedQuestion, Difficulty), String> {
+        let answer: GeneratedAnswer =
+            serde_json::from_str(&form.answer).map_err(|e| format!("Answer is not valid: {e}"))?;
+        let difficulty = Difficulty::from_str(&form.difficulty)?;
+        Ok((
+            GeneratedQuestion {
+                prompt_en: form.prompt_en.trim().to_string(),
+                prompt_id: form.prompt_id.trim().to_string(),
+                explanation_en: form.explanation_en.trim().to_string(),
+                explanation_id: form.explanation_id.trim().to_string(),
+                answer,
+            },
+            difficulty,
+        ))
+    })();
+
+    let saved = match parsed {
+        Ok((edited, difficulty)) => state
+            .question_draft_service
+            .update_draft(&draft_id, edited, difficulty, Points::new(form.points))
+            .await
+            .map_err(|e| e.to_string()),
+        Err(e) => Err(e),
+    };
+
+    Ok(match saved {
+        Ok(_) => result_page("Draft Saved", "The draft was updated.", true),
+        Err(e) => result_page("Draft Not Saved", &e, false),
+    })
+}
+
+pub async fn accept_question_draft_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Html<String>> {
+    let accepted = state
+        .question_draft_service
+        .accept_draft(&parse_draft_id(id)?)
+        .await;
+
+    Ok(match accepted {
+        Ok(question) => result_page(
+            "Draft Accepted",
+            &format!(
+                "The question was added to the question bank as {}.",
+                question.id.to_string()
+            ),
+            true,
+        ),
+        Err(e) => result_page("Draft Not Accepted", &e.to_string(), false),
+    })
+}
+
+pub async fn reject_question_draft_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Html<String>> {
+    let rejected = state
+        .question_draft_service
+        .reject_draft(&parse_draft_id(id)?)
+        .await;
+
+    Ok(match rejected {
+        Ok(_) => result_page(
+            "Draft Rejected",
+            "The draft was removed from the queue.",
+            true,
+        ),
+        Err(e) => result_page("Draft Not Rejected", &e.to_string(), false),
+    })
+}
@@ -1,8 +1,11 @@
 use crate::application::state::AppState;
-use crate::domain::entities::{Question, QuestionType};
-use crate::domain::value_objects::{Difficulty, LocalizedText, Points, QuestionId, TopicId};
-use crate::shared::errors::Result;
+use crate::domain::entities::{ContentAuthor, Question, QuestionType, TranslatableContent};
+use crate::domain::value_objects::{
+    AssetId, Difficulty, LocalizedText, Points, QuestionId, TopicId, AUTHORED_LOCALES,
+};
+use crate::shared::errors::{AppError, Result};
 use axum::{extract::State, response::Html, Form};
+use std::str::FromStr;
 
 #[derive(serde::Deserialize)]
 pub struct CreateQuestionForm {
@@ -14,6 +17,31 @@ pub struct CreateQuestionForm {
     pub question_type: String,
     pub explanation_en: String,
     pub explanation_id: String,
+    /// ID of an uploaded image to show with the prompt; blank for none.
+    #[serde(default)]
+    pub image_asset_id: String,
+    /// Why the item was changed, kept with its previous version. Only on the edit form.
+    #[serde(default)]
+    pub change_note: String,
+}
+
+/// The uploaded image named on a question form, which must exist.
+async fn parse_image_asset(state: &AppState, image_asset_id: &str) -> Result<Option<AssetId>> {
+    let image_asset_id = image_asset_id.trim();
+    if image_asset_id.is_empty() {
+        return Ok(None);
+    }
+    let asset_id = AssetId::from_str(image_asset_id)
+        .map_err(|_| AppError::Validation(format!("Invalid image asset ID: {image_asset_id}")))?;
+    let asset = state.asset_service.find(&asset_id).await?.ok_or_else(|| {
+        AppError::Validation(format!("No uploaded file with ID {image_asset_id}"))
+    })?;
+    if !asset.asset_type.is_image() {
+        return Err(AppError::Validation(format!(
+            "Uploaded file {image_asset_id} is not an image"
+        )));
+    }
+    Ok(Some(asset_id))
 }
 
 pub async fn questions_handler(State(state): State<AppState>) -> Result<Html<String>> {
@@ -132,6 +160,10 @@ pub async fn create_question_handler(State(state): State<AppState>) -> Result<Ht
             <label for="explanation_id">Explanation (Indonesian):</label>
             <textarea id="explanation_id" name="explanation_id" rows="3"></textarea>
         </div>
+        <div class="form-group">
+            <label for="image_asset_id">Image asset ID (optional, from <a href="/admin/file-upload">File Upload</a>):</label>
+            <input type="text" id="image_asset_id" name="image_asset_id">
+        </div>
         <button type="submit">Create Question</button>
     </form>
     <p><a href="/admin/questions">← Back to Questions</a></p>
@@ -179,14 +211,17 @@ pub async fn create_question_post_handler(
         _ => QuestionType::TrueFalse { answer: true },           // Default fallback
     };
 
+    let image = parse_image_asset(&state, &form.image_asset_id).await?;
+
     // Create new question
-    let question = Question::new(
+    let mut question = Question::new(
         prompt,
         topic_id,
         difficulty,
         Points::new(form.points),
         question_type,
     );
+    question.image = image;
 
     // Save to database
     state.question_repository.create(&question).await?;
@@ -344,6 +379,14 @@ pub async fn edit_question_handler(
             <label for="explanation_id">Explanation (Indonesian):</label>
             <textarea id="explanation_id" name="explanation_id" rows="3">{}</textarea>
         </div>
+        <div class="form-group">
+            <label for="image_asset_id">Image asset ID (optional, from <a href="/admin/file-upload">File Upload</a>):</label>
+            <input type="text" id="image_asset_id" name="image_asset_id" value="{}">
+        </div>
+        <div class="form-group">
+            <label for="change_note">Change note (optional):</label>
+            <input type="text" id="change_note" name="change_note" placeholder="What changed and why">
+        </div>
         <button type="submit">Update Question</button>
         <button type="button" class="delete-btn" onclick="confirmDelete()">Delete Question</button>
     </form>
@@ -416,6 +459,11 @@ pub async fn edit_question_handler(
             Some(exp) => exp.get("id"),
             None => "",
         },
+        question
+            .image
+            .as_ref()
+            .map(|id| id.to_string())
+            .unwrap_or_default(),
         question_id.to_string()
     );
 
@@ -465,15 +513,27 @@ pub async fn update_question_post_handler(
         .ok_or_else(|| anyhow::anyhow!("Question not found"))?;
 
     // Update fields
-    question.prompt = prompt;
+    question.prompt.apply_edit(prompt, AUTHORED_LOCALES);
     question.topic_id = topic_id;
     question.difficulty = difficulty;
     question.points = Points::new(form.points);
     question.question_type = question_type;
-    question.explanation = explanation;
+    question.image = parse_image_asset(&state, &form.image_asset_id).await?;
+    question.explanation = explanation.map(|edited| {
+        let mut text = question.explanation.take().unwrap_or_default();
+        text.apply_edit(edited, AUTHORED_LOCALES);
+        text
+    });
 
     // Save to database
-    state.question_repository.update(&question).await?;
+    state
+        .content_version_service
+        .update_content(
+            &TranslatableContent::Question(question.clone()),
+            ContentAuthor::admin(),
+            &form.change_note,
+        )
+        .await?;
 
     // Return success page
     let html = format!(
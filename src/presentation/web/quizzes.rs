@@ -0,0 +1,258 @@
+use crate::application::state::AppState;
+use crate::domain::entities::quiz::DEFAULT_QUIZ_PASSING_SCORE;
+use crate::domain::entities::Quiz;
+use crate::domain::value_objects::{Difficulty, LocalizedText, QuizId, TopicId};
+use crate::shared::errors::Result;
+use axum::{
+    extract::{Path, State},
+    response::Html,
+    Form,
+};
+use std::collections::HashMap;
+
+const PAGE_STYLE: &str = r#"
+        body { font-family: Arial, sans-serif; margin: 40px; }
+        .btn { padding: 8px 16px; text-decoration: none; border-radius: 4px; font-size: 14px; border: none; cursor: pointer; }
+        .btn-primary { background-color: #007bff; color: white; }
+        .btn-danger { background-color: #dc3545; color: white; }
+        .btn-success { background-color: #28a745; color: white; }
+        .btn-sm { padding: 6px 12px; font-size: 12px; }
+        table { width: 100%; border-collapse: collapse; margin: 20px 0 30px; }
+        th, td { border: 1px solid #ddd; padding: 12px; text-align: left; }
+        th { background-color: #f8f9fa; font-weight: bold; }
+        tr:nth-child(even) { background-color: #f9f9f9; }
+        .form-grid { display: grid; grid-template-columns: repeat(2, minmax(0, 1fr)); gap: 15px; max-width: 900px; }
+        .form-grid label { display: block; font-weight: bold; margin-bottom: 5px; }
+        select, input, textarea { padding: 8px; border: 1px solid #ddd; border-radius: 4px; width: 100%; box-sizing: border-box; }
+        input[type=checkbox] { width: auto; }
+        .note { color: #6c757d; }
+"#;
+
+fn result_page(title: &str, message: &str, success: bool) -> Html<String> {
+    let (icon, color) = if success {
+        ("✅", "#28a745")
+    } else {
+        ("⚠️", "#dc3545")
+    };
+
+    Html(format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+    <title>{title}</title>
+    <style>
+        body {{ font-family: Arial, sans-serif; margin: 40px; text-align: center; }}
+        .result {{ color: {color}; font-size: 24px; margin-bottom: 20px; }}
+        .info {{ background-color: #f8f9fa; padding: 20px; border-radius: 5px; margin: 20px 0; }}
+        a {{ color: #007bff; text-decoration: none; }}
+        a:hover {{ text-decoration: underline; }}
+    </style>
+</head>
+<body>
+    <div class="result">{icon} {title}</div>
+    <div class="info"><p>{message}</p></div>
+    <p><a href="/admin/quizzes">← Back to Quizzes</a></p>
+</body>
+</html>"#
+    ))
+}
+
+pub async fn quizzes_handler(State(state): State<AppState>) -> Result<Html<String>> {
+    let quizzes = state.quiz_service.list_quizzes().await?;
+    let topics = state.topic_repository.list_by_order().await?;
+
+    let topic_names: HashMap<String, String> = topics
+        .iter()
+        .map(|t| (t.id.to_string(), t.title.get("en").to_string()))
+        .collect();
+
+    let mut rows = String::new();
+    for quiz in &quizzes {
+        let topics = quiz
+            .topic_ids
+            .iter()
+            .map(|id| {
+                let id = id.to_string();
+                topic_names.get(&id).cloned().unwrap_or(id)
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let difficulties = if quiz.difficulties.is_empty() {
+            "Any".to_string()
+        } else {
+            quiz.difficulties
+                .iter()
+                .map(|d| d.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let attempts = quiz
+            .max_attempts
+            .map_or("Unlimited".to_string(), |max| max.to_string());
+
+        rows.push_str(&format!(
+            r#"<tr>
+                <td>{}</td>
+                <td>{}</td>
+                <td>{}</td>
+                <td>{}</td>
+                <td>{} min</td>
+                <td>{}%</td>
+                <td>{}</td>
+                <td><a href="/admin/quizzes/{}/delete" class="btn btn-sm btn-danger" onclick="return confirm('Delete this quiz and all its attempts?')">Delete</a></td>
+            </tr>"#,
+            quiz.title.get("en"),
+            topics,
+            difficulties,
+            quiz.question_count,
+            quiz.time_limit_secs / 60,
+            quiz.passing_score,
+            attempts,
+            quiz.id
+        ));
+    }
+
+    let topic_options: String = topics
+        .iter()
+        .map(|t| {
+            format!(
+                r#"<option value="{}">{}</option>"#,
+                t.id.to_string(),
+                t.title.get("en")
+            )
+        })
+        .collect();
+    let difficulty_checkboxes: String = Difficulty::all()
+        .iter()
+        .map(|d| {
+            format!(
+                r#"<label style="display: inline; font-weight: normal; margin-right: 15px;"><input type="checkbox" name="difficulties" value="{0}"> {0}</label>"#,
+                d.as_str()
+            )
+        })
+        .collect();
+
+    let html = format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+    <title>Quizzes</title>
+    <style>{PAGE_STYLE}</style>
+</head>
+<body>
+    <h1>⏱️ Timed Quizzes</h1>
+    <p class="note">Each attempt draws its questions at random from the pool and shuffles the answer options.</p>
+
+    <table>
+        <thead><tr><th>Title</th><th>Topics</th><th>Difficulties</th><th>Questions</th><th>Time limit</th><th>Pass mark</th><th>Attempts</th><th>Actions</th></tr></thead>
+        <tbody>{rows}</tbody>
+    </table>
+
+    <h2>New quiz</h2>
+    <form method="post" action="/admin/quizzes">
+        <div class="form-grid">
+            <div><label>Title (English)</label><input name="title_en" required></div>
+            <div><label>Title (Indonesian)</label><input name="title_id"></div>
+            <div><label>Description (English)</label><textarea name="description_en" rows="3"></textarea></div>
+            <div><label>Description (Indonesian)</label><textarea name="description_id" rows="3"></textarea></div>
+            <div><label>Topics</label><select name="topic_ids" multiple size="6" required>{topic_options}</select></div>
+            <div><label>Difficulties</label>{difficulty_checkboxes}<p class="note">Leave all unchecked to draw from any difficulty.</p></div>
+            <div><label>Questions per attempt</label><input type="number" name="question_count" min="1" value="10" required></div>
+            <div><label>Time limit (minutes)</label><input type="number" name="time_limit_minutes" min="1" value="30" required></div>
+            <div><label>Pass mark (%)</label><input type="number" name="passing_score" min="0" max="100" value="{DEFAULT_QUIZ_PASSING_SCORE}" required></div>
+            <div><label>Attempts allowed</label><input type="number" name="max_attempts" min="1" placeholder="Unlimited"></div>
+        </div>
+        <p><button type="submit" class="btn btn-success">+ Create Quiz</button></p>
+    </form>
+
+    <div style="margin-top: 30px;">
+        <a href="/admin" class="btn btn-primary">← Back to Dashboard</a>
+    </div>
+</body>
+</html>"#
+    );
+
+    Ok(Html(html))
+}
+
+/// Topics and difficulties are multi-valued, so the form is read as raw pairs.
+pub async fn create_quiz_post_handler(
+    State(state): State<AppState>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Result<Html<String>> {
+    let field = |name: &str| {
+        fields
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.trim().to_string())
+            .unwrap_or_default()
+    };
+    let number = |name: &str| -> std::result::Result<Option<u32>, String> {
+        let value = field(name);
+        if value.is_empty() {
+            return Ok(None);
+        }
+        value
+            .parse::<u32>()
+            .map(Some)
+            .map_err(|_| format!("Invalid number for {name}"))
+    };
+
+    let parsed = (|| -> std::result::Result<Quiz, String> {
+        let topic_ids = fields
+            .iter()
+            .filter(|(key, _)| key == "topic_ids")
+            .map(|(_, value)| TopicId::from_str(value))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let difficulties = fields
+            .iter()
+            .filter(|(key, _)| key == "difficulties")
+            .map(|(_, value)| Difficulty::from_str(value))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let title_en = field("title_en");
+        let title_id = Some(field("title_id"))
+            .filter(|t| !t.is_empty())
+            .unwrap_or_else(|| title_en.clone());
+        let mut quiz = Quiz::new(
+            LocalizedText::new(title_en, title_id),
+            LocalizedText::new(field("description_en"), field("description_id")),
+            topic_ids,
+            number("question_count")?.unwrap_or(0),
+            number("time_limit_minutes")?.unwrap_or(0) * 60,
+        );
+        quiz.update_difficulties(difficulties);
+        quiz.update_passing_score(number("passing_score")?.unwrap_or(DEFAULT_QUIZ_PASSING_SCORE));
+        quiz.update_max_attempts(number("max_attempts")?);
+        Ok(quiz)
+    })();
+
+    let saved = match parsed {
+        Ok(quiz) => state
+            .quiz_service
+            .save_quiz(&quiz)
+            .await
+            .map_err(|e| e.to_string()),
+        Err(e) => Err(e),
+    };
+
+    Ok(match saved {
+        Ok(_) => result_page("Quiz Created", "The quiz was created.", true),
+        Err(e) => result_page("Quiz Rejected", &e, false),
+    })
+}
+
+pub async fn delete_quiz_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Html<String>> {
+    let quiz_id = QuizId::from_string(id).map_err(|e| anyhow::anyhow!("Invalid quiz ID: {e}"))?;
+
+    state.quiz_repository.delete(&quiz_id).await?;
+
+    Ok(result_page(
+        "Quiz Deleted",
+        "The quiz and its attempts were deleted.",
+        true,
+    ))
+}
@@ -1,6 +1,6 @@
 use crate::application::state::AppState;
-use crate::domain::entities::Topic;
-use crate::domain::value_objects::{LocalizedText, TopicId};
+use crate::domain::entities::{ContentAuthor, Topic, TranslatableContent};
+use crate::domain::value_objects::{LocalizedText, TopicId, AUTHORED_LOCALES};
 use crate::presentation::web::pagination::{
     generate_pagination_controls, generate_pagination_html, generate_pagination_info,
     get_pagination_css, PaginationParams,
@@ -21,6 +21,9 @@ pub struct CreateTopicForm {
     pub order: u32,
     pub required_skills_en: String,
     pub required_skills_id: String,
+    /// Why the item was changed, kept with its previous version. Only on the edit form.
+    #[serde(default)]
+    pub change_note: String,
 }
 
 pub async fn topics_handler(
@@ -300,6 +303,10 @@ pub async fn edit_topic_handler(
             <label for="required_skills_id">Required Skills (Indonesian):</label>
             <textarea id="required_skills_id" name="required_skills_id" rows="3">{}</textarea>
         </div>
+        <div class="form-group">
+            <label for="change_note">Change note (optional):</label>
+            <input type="text" id="change_note" name="change_note" placeholder="What changed and why">
+        </div>
         <button type="submit">Update Topic</button>
         <button type="button" class="delete-btn" onclick="confirmDelete()">Delete Topic</button>
     </form>
@@ -350,13 +357,22 @@ pub async fn update_topic_post_handler(
         .ok_or_else(|| anyhow::anyhow!("Topic not found"))?;
 
     // Update fields
-    topic.title = title;
-    topic.description = description;
+    topic.title.apply_edit(title, AUTHORED_LOCALES);
+    topic.description.apply_edit(description, AUTHORED_LOCALES);
     topic.order = form.order;
-    topic.required_skills = required_skills;
+    topic
+        .required_skills
+        .apply_edit(required_skills, AUTHORED_LOCALES);
 
     // Save to database
-    state.topic_repository.update(&topic).await?;
+    state
+        .content_version_service
+        .update_content(
+            &TranslatableContent::Topic(topic.clone()),
+            ContentAuthor::admin(),
+            &form.change_note,
+        )
+        .await?;
 
     // Return success page
     let html = format!(
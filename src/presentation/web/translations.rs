@@ -0,0 +1,192 @@
+use crate::application::state::AppState;
+use crate::application::use_cases::translation_use_cases::{
+    TranslationReviewReport, DEFAULT_TRANSLATION_BATCH,
+};
+use crate::shared::errors::Result;
+use crate::shared::utils::escape_html;
+use axum::{extract::State, response::Html, Form};
+
+const PAGE_STYLE: &str = r#"
+        body { font-family: Arial, sans-serif; margin: 40px; }
+        .btn { padding: 8px 16px; text-decoration: none; border-radius: 4px; font-size: 14px; border: none; cursor: pointer; }
+        .btn-primary { background-color: #007bff; color: white; }
+        .btn-danger { background-color: #dc3545; color: white; }
+        .btn-success { background-color: #28a745; color: white; }
+        table { width: 100%; border-collapse: collapse; margin: 20px 0 30px; }
+        th, td { border: 1px solid #ddd; padding: 12px; text-align: left; vertical-align: top; }
+        th { background-color: #f8f9fa; font-weight: bold; }
+        tr:nth-child(even) { background-color: #f9f9f9; }
+        td.text { white-space: pre-wrap; }
+        input[type=number] { padding: 8px; border: 1px solid #ddd; border-radius: 4px; width: 80px; }
+        .note { color: #6c757d; }
+"#;
+
+#[derive(serde::Deserialize)]
+pub struct TranslateMissingForm {
+    pub count: Option<usize>,
+}
+
+fn result_page(title: &str, lines: &[String], success: bool) -> Html<String> {
+    let (icon, color) = if success {
+        ("✅", "#28a745")
+    } else {
+        ("⚠️", "#dc3545")
+    };
+    let items: String = lines
+        .iter()
+        .map(|line| format!("<li>{}</li>", escape_html(line)))
+        .collect();
+
+    Html(format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+    <title>{title}</title>
+    <style>
+        body {{ font-family: Arial, sans-serif; margin: 40px; }}
+        .result {{ color: {color}; font-size: 24px; margin-bottom: 20px; }}
+        .info {{ background-color: #f8f9fa; padding: 20px; border-radius: 5px; margin: 20px 0; }}
+        a {{ color: #007bff; text-decoration: none; }}
+        a:hover {{ text-decoration: underline; }}
+    </style>
+</head>
+<body>
+    <div class="result">{icon} {title}</div>
+    <div class="info"><ul>{items}</ul></div>
+    <p><a href="/admin/translations">← Back to Translations</a></p>
+</body>
+</html>"#
+    ))
+}
+
+pub async fn translations_handler(State(state): State<AppState>) -> Result<Html<String>> {
+    let pending = state.translation_use_cases.pending_translations().await?;
+    let untranslated = state.translation_use_cases.untranslated_fields().await?;
+
+    let mut rows = String::new();
+    for translation in &pending {
+        rows.push_str(&format!(
+            r#"<tr>
+                <td><input type="checkbox" name="ids" value="{}" checked></td>
+                <td>{} <span class="note">{}</span></td>
+                <td>{}</td>
+                <td class="text">{}</td>
+                <td class="text">{}</td>
+                <td>{}</td>
+            </tr>"#,
+            translation.id,
+            translation.kind.label(),
+            translation.content_id,
+            escape_html(&translation.field),
+            escape_html(&translation.source_text),
+            escape_html(&translation.translated_text),
+            escape_html(&translation.provider),
+        ));
+    }
+    if rows.is_empty() {
+        rows.push_str(
+            r#"<tr><td colspan="6" class="note">No translations waiting for review.</td></tr>"#,
+        );
+    }
+
+    let html = format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+    <title>Translations</title>
+    <style>{PAGE_STYLE}</style>
+</head>
+<body>
+    <h1>🌐 Translations</h1>
+    <p class="note">{missing} fields have no Indonesian text or a copy of the English. Machine translations keep code unchanged and reach learners only once approved.</p>
+
+    <form action="/admin/translations/translate" method="POST">
+        <label for="count">Translate the next</label>
+        <input type="number" id="count" name="count" min="1" max="50" value="{DEFAULT_TRANSLATION_BATCH}">
+        <label for="count">fields</label>
+        <button type="submit" class="btn btn-primary">Translate</button>
+    </form>
+
+    <h2>Machine-Translated, Needs Review ({pending_count})</h2>
+    <form action="/admin/translations/review" method="POST">
+        <table>
+            <thead><tr><th></th><th>Content</th><th>Field</th><th>English</th><th>Indonesian</th><th>Provider</th></tr></thead>
+            <tbody>{rows}</tbody>
+        </table>
+        <button type="submit" name="action" value="approve" class="btn btn-success">Approve Selected</button>
+        <button type="submit" name="action" value="reject" class="btn btn-danger" onclick="return confirm('Reject the selected translations?')">Reject Selected</button>
+    </form>
+
+    <p><a href="/admin/ai" class="btn btn-primary">← Back to AI Features</a></p>
+</body>
+</html>"#,
+        missing = untranslated.len(),
+        pending_count = pending.len(),
+    );
+
+    Ok(Html(html))
+}
+
+pub async fn translate_missing_handler(
+    State(state): State<AppState>,
+    Form(form): Form<TranslateMissingForm>,
+) -> Result<Html<String>> {
+    let count = form.count.unwrap_or(DEFAULT_TRANSLATION_BATCH).clamp(1, 50);
+    let report = state.translation_use_cases.translate_missing(count).await?;
+
+    let mut lines = vec![
+        format!(
+            "{} fields translated and queued for review.",
+            report.translated
+        ),
+        format!("{} fields still need a translation.", report.remaining),
+    ];
+    if report.rate_limited {
+        lines.push("Stopped at the AI request limit; run again in a minute.".to_string());
+    }
+    lines.extend(
+        report
+            .failed
+            .iter()
+            .map(|failure| format!("Failed: {failure}")),
+    );
+    let success = report.failed.is_empty() && !report.rate_limited;
+    Ok(result_page("Translation Run Finished", &lines, success))
+}
+
+/// Repeated `ids` checkboxes and the pressed `action` button.
+pub async fn review_translations_handler(
+    State(state): State<AppState>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Result<Html<String>> {
+    let ids: Vec<String> = fields
+        .iter()
+        .filter(|(name, _)| name == "ids")
+        .map(|(_, value)| value.clone())
+        .collect();
+    let action = fields
+        .iter()
+        .find(|(name, _)| name == "action")
+        .map(|(_, value)| value.as_str());
+
+    let (title, report): (&str, TranslationReviewReport) = match action {
+        Some("approve") => (
+            "Translations Approved",
+            state.translation_use_cases.approve(&ids).await?,
+        ),
+        Some("reject") => (
+            "Translations Rejected",
+            state.translation_use_cases.reject(&ids).await?,
+        ),
+        _ => return Err(anyhow::anyhow!("Unknown review action").into()),
+    };
+
+    let mut lines = vec![format!("{} translations reviewed.", report.reviewed)];
+    lines.extend(
+        report
+            .skipped
+            .iter()
+            .map(|skipped| format!("Skipped {skipped}")),
+    );
+    Ok(result_page(title, &lines, report.skipped.is_empty()))
+}
@@ -19,6 +19,12 @@ pub struct UpdateUserForm {
     pub total_xp: u32,
     pub current_streak_days: u32,
     pub highest_streak_days: u32,
+    /// Per-user daily XP cap; blank uses the global default.
+    #[serde(default)]
+    pub daily_xp_cap: String,
+    /// Checkbox; absent when unchecked.
+    #[serde(default)]
+    pub is_content_editor: Option<String>,
 }
 
 pub async fn users_handler(State(state): State<AppState>) -> Result<Html<String>> {
@@ -237,6 +243,14 @@ pub async fn edit_user_handler(
         .await?
         .ok_or_else(|| anyhow::anyhow!("User not found"))?;
 
+    let daily_xp_cap = state
+        .user_progress_repository
+        .find_by_user_id(&user_id)
+        .await?
+        .and_then(|progress| progress.daily_xp_cap)
+        .map(|cap| cap.to_string())
+        .unwrap_or_default();
+
     let html = format!(
         r#"<!DOCTYPE html>
 <html>
@@ -287,6 +301,15 @@ pub async fn edit_user_handler(
                 <input type="number" id="highest_streak_days" name="highest_streak_days" min="0" max="365" value="{}" required>
             </div>
 
+            <div class="form-group">
+                <label for="daily_xp_cap">Daily XP Cap (leave blank for the global default):</label>
+                <input type="number" id="daily_xp_cap" name="daily_xp_cap" min="0" max="100000" value="{}">
+            </div>
+
+            <div class="form-group">
+                <label><input type="checkbox" name="is_content_editor" value="true" style="width: auto;"{}> Content editor (may read answers and solutions through the editor API)</label>
+            </div>
+
             <button type="submit">Update User</button>
         </form>
 
@@ -306,6 +329,12 @@ pub async fn edit_user_handler(
         user.total_xp.value(),
         user.current_streak_days,
         user.highest_streak_days,
+        daily_xp_cap,
+        if user.is_content_editor {
+            " checked"
+        } else {
+            ""
+        },
         user.id.to_string()
     );
 
@@ -324,6 +353,13 @@ pub async fn update_user_post_handler(
     // Parse form data
     let email = Email::new(form.email).map_err(|e| anyhow::anyhow!("Invalid email: {}", e))?;
     let total_xp = Points::new(form.total_xp);
+    let daily_xp_cap = match form.daily_xp_cap.trim() {
+        "" => None,
+        cap => Some(
+            cap.parse::<u32>()
+                .map_err(|e| anyhow::anyhow!("Invalid daily XP cap: {e}"))?,
+        ),
+    };
 
     // Fetch existing user
     let mut user = state
@@ -338,10 +374,27 @@ pub async fn update_user_post_handler(
     user.total_xp = total_xp;
     user.current_streak_days = form.current_streak_days;
     user.highest_streak_days = form.highest_streak_days;
+    user.is_content_editor = form.is_content_editor.is_some();
     user.updated_at = Utc::now();
 
     // Save to database
     state.user_repository.update(&user).await?;
+    state
+        .progress_service
+        .set_daily_xp_cap(&user_id, daily_xp_cap)
+        .await?;
+
+    // XP and streaks are kept on the learner's progress, which `users` mirrors
+    if let Some(mut progress) = state
+        .user_progress_repository
+        .find_by_user_id(&user_id)
+        .await?
+    {
+        progress.total_xp = user.total_xp;
+        progress.current_streak_days = user.current_streak_days;
+        progress.highest_streak_days = user.highest_streak_days;
+        state.user_progress_repository.update(&progress).await?;
+    }
 
     // Return success page
     let html = format!(
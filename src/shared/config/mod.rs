@@ -1,5 +1,52 @@
 use serde::{Deserialize, Serialize};
 use std::env;
+use std::str::FromStr;
+
+/// Backend used for AI features, chosen with `LLM_PROVIDER`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LlmProviderKind {
+    Gemini,
+    /// Any OpenAI-compatible chat completions server, hosted or local.
+    OpenAiCompatible,
+    /// Deterministic offline fixtures; needs no API key.
+    Mock,
+}
+
+impl FromStr for LlmProviderKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "gemini" => Ok(Self::Gemini),
+            "openai" => Ok(Self::OpenAiCompatible),
+            "mock" => Ok(Self::Mock),
+            _ => Err(format!(
+                "Invalid LLM_PROVIDER: {s}. Use gemini, openai or mock"
+            )),
+        }
+    }
+}
+
+/// Where uploaded files are kept, chosen with `ASSET_STORAGE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AssetStorageKind {
+    /// A directory on the server's disk.
+    Local,
+    /// A bucket on any S3-compatible object store.
+    S3,
+}
+
+impl FromStr for AssetStorageKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "local" => Ok(Self::Local),
+            "s3" => Ok(Self::S3),
+            _ => Err(format!("Invalid ASSET_STORAGE: {s}. Use local or s3")),
+        }
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -8,14 +55,44 @@ pub struct Config {
     pub server_host: String,
     pub server_port: u16,
     pub cors_origins: Vec<String>,
-    pub gemini_api_key: String,
+    pub llm_provider: LlmProviderKind,
+    pub gemini_api_key: Option<String>,
     pub gemini_api_url: String,
+    pub gemini_model: String,
+    pub openai_api_url: String,
+    pub openai_api_key: Option<String>,
+    pub openai_model: String,
+    /// JSON fixtures file for the mock provider.
+    pub llm_mock_fixtures: Option<String>,
     pub smtp_host: String,
     pub smtp_port: u16,
     pub smtp_username: String,
     pub smtp_password: String,
     pub from_email: String,
     pub from_name: String,
+    pub account_deletion_grace_days: i64,
+    pub daily_xp_cap: u32,
+    /// AI tutor hints each learner can generate per local day.
+    pub tutor_daily_quota: u32,
+    /// How long identical AI requests are answered from memory; 0 disables.
+    pub ai_cache_ttl_secs: u64,
+    /// AI requests per minute per learner (the admin pages share one limit); 0 disables.
+    pub ai_user_requests_per_minute: u32,
+    /// AI requests per minute across the site; 0 disables.
+    pub ai_global_requests_per_minute: u32,
+    /// Retries after the LLM provider rate limits or fails a request.
+    pub ai_max_retries: u32,
+    pub asset_storage: AssetStorageKind,
+    /// Directory for local asset storage.
+    pub asset_local_dir: String,
+    /// S3-compatible endpoint, e.g. `https://s3.us-east-1.amazonaws.com`.
+    pub s3_endpoint: Option<String>,
+    pub s3_bucket: Option<String>,
+    pub s3_region: String,
+    pub s3_access_key_id: Option<String>,
+    pub s3_secret_access_key: Option<String>,
+    /// Largest file that can be uploaded.
+    pub asset_max_bytes: u64,
 }
 
 impl Config {
@@ -27,9 +104,6 @@ impl Config {
         let jwt_secret =
             env::var("JWT_SECRET").map_err(|_| "JWT_SECRET environment variable is required")?;
 
-        let gemini_api_key = env::var("GEMINI_API_KEY")
-            .map_err(|_| "GEMINI_API_KEY environment variable is required")?;
-
         let smtp_username = env::var("SMTP_USERNAME")
             .map_err(|_| "SMTP_USERNAME environment variable is required")?;
 
@@ -56,6 +130,26 @@ impl Config {
         let gemini_api_url = env::var("GEMINI_API_URL")
             .unwrap_or_else(|_| "https://generativelanguage.googleapis.com/v1beta".to_string());
 
+        let gemini_model = env::var("GEMINI_MODEL").unwrap_or_else(|_| "gemini-pro".to_string());
+
+        // Without a key or an explicit choice, AI features run on the offline mock
+        let gemini_api_key = env::var("GEMINI_API_KEY").ok();
+
+        let llm_provider = match env::var("LLM_PROVIDER") {
+            Ok(value) => LlmProviderKind::from_str(&value)?,
+            Err(_) if gemini_api_key.is_some() => LlmProviderKind::Gemini,
+            Err(_) => LlmProviderKind::Mock,
+        };
+
+        let openai_api_url =
+            env::var("OPENAI_API_URL").unwrap_or_else(|_| "http://localhost:11434/v1".to_string());
+
+        let openai_api_key = env::var("OPENAI_API_KEY").ok();
+
+        let openai_model = env::var("OPENAI_MODEL").unwrap_or_else(|_| "llama3".to_string());
+
+        let llm_mock_fixtures = env::var("LLM_MOCK_FIXTURES").ok();
+
         let smtp_host = env::var("SMTP_HOST").unwrap_or_else(|_| "smtp.gmail.com".to_string());
 
         let smtp_port = env::var("SMTP_PORT")
@@ -66,11 +160,80 @@ impl Config {
         let from_name =
             env::var("FROM_NAME").unwrap_or_else(|_| "Rust Learning Platform".to_string());
 
+        let account_deletion_grace_days = env::var("ACCOUNT_DELETION_GRACE_DAYS")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse()
+            .map_err(|_| "Invalid ACCOUNT_DELETION_GRACE_DAYS format")?;
+
+        let daily_xp_cap = env::var("DAILY_XP_CAP")
+            .unwrap_or_else(|_| "1000".to_string())
+            .parse()
+            .map_err(|_| "Invalid DAILY_XP_CAP format")?;
+
+        let tutor_daily_quota = env::var("TUTOR_DAILY_QUOTA")
+            .unwrap_or_else(|_| "20".to_string())
+            .parse()
+            .map_err(|_| "Invalid TUTOR_DAILY_QUOTA format")?;
+
+        let ai_cache_ttl_secs = env::var("AI_CACHE_TTL_SECS")
+            .unwrap_or_else(|_| "3600".to_string())
+            .parse()
+            .map_err(|_| "Invalid AI_CACHE_TTL_SECS format")?;
+
+        let ai_user_requests_per_minute = env::var("AI_USER_REQUESTS_PER_MINUTE")
+            .unwrap_or_else(|_| "10".to_string())
+            .parse()
+            .map_err(|_| "Invalid AI_USER_REQUESTS_PER_MINUTE format")?;
+
+        let ai_global_requests_per_minute = env::var("AI_GLOBAL_REQUESTS_PER_MINUTE")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse()
+            .map_err(|_| "Invalid AI_GLOBAL_REQUESTS_PER_MINUTE format")?;
+
+        let ai_max_retries = env::var("AI_MAX_RETRIES")
+            .unwrap_or_else(|_| "3".to_string())
+            .parse()
+            .map_err(|_| "Invalid AI_MAX_RETRIES format")?;
+
+        let asset_storage = match env::var("ASSET_STORAGE") {
+            Ok(value) => AssetStorageKind::from_str(&value)?,
+            Err(_) => AssetStorageKind::Local,
+        };
+
+        let asset_local_dir = env::var("ASSET_LOCAL_DIR").unwrap_or_else(|_| "uploads".to_string());
+
+        let s3_endpoint = env::var("S3_ENDPOINT").ok();
+        let s3_bucket = env::var("S3_BUCKET").ok();
+        let s3_region = env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string());
+        let s3_access_key_id = env::var("S3_ACCESS_KEY_ID").ok();
+        let s3_secret_access_key = env::var("S3_SECRET_ACCESS_KEY").ok();
+
+        let asset_max_bytes = env::var("ASSET_MAX_BYTES")
+            .unwrap_or_else(|_| "10485760".to_string())
+            .parse()
+            .map_err(|_| "Invalid ASSET_MAX_BYTES format")?;
+
         // Validate JWT secret strength
         if jwt_secret.len() < 32 {
             return Err("JWT_SECRET must be at least 32 characters long for security".to_string());
         }
 
+        if llm_provider == LlmProviderKind::Gemini && gemini_api_key.is_none() {
+            return Err("GEMINI_API_KEY is required when LLM_PROVIDER is gemini".to_string());
+        }
+
+        if asset_storage == AssetStorageKind::S3
+            && (s3_endpoint.is_none()
+                || s3_bucket.is_none()
+                || s3_access_key_id.is_none()
+                || s3_secret_access_key.is_none())
+        {
+            return Err(
+                "S3_ENDPOINT, S3_BUCKET, S3_ACCESS_KEY_ID and S3_SECRET_ACCESS_KEY are required when ASSET_STORAGE is s3"
+                    .to_string(),
+            );
+        }
+
         // Validate email format
         if !from_email.contains('@') {
             return Err("FROM_EMAIL must be a valid email address".to_string());
@@ -82,14 +245,35 @@ impl Config {
             server_host,
             server_port,
             cors_origins,
+            llm_provider,
             gemini_api_key,
             gemini_api_url,
+            gemini_model,
+            openai_api_url,
+            openai_api_key,
+            openai_model,
+            llm_mock_fixtures,
             smtp_host,
             smtp_port,
             smtp_username,
             smtp_password,
             from_email,
             from_name,
+            account_deletion_grace_days,
+            daily_xp_cap,
+            tutor_daily_quota,
+            ai_cache_ttl_secs,
+            ai_user_requests_per_minute,
+            ai_global_requests_per_minute,
+            ai_max_retries,
+            asset_storage,
+            asset_local_dir,
+            s3_endpoint,
+            s3_bucket,
+            s3_region,
+            s3_access_key_id,
+            s3_secret_access_key,
+            asset_max_bytes,
         })
     }
 }
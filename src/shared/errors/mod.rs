@@ -6,6 +6,17 @@ use axum::{
 use serde_json::json;
 use thiserror::Error;
 
+/// Failures that domain and application code raise through `anyhow` when the
+/// caller, not the server, is at fault. `?` turns them into 404 and 400.
+#[derive(Error, Debug)]
+pub enum DomainError {
+    #[error("{0}")]
+    NotFound(String),
+
+    #[error("{0}")]
+    Invalid(String),
+}
+
 #[derive(Error, Debug)]
 pub enum AppError {
     #[error("Database error: {0}")]
@@ -26,11 +37,14 @@ pub enum AppError {
     #[error("Not found: {0}")]
     NotFound(String),
 
+    #[error("Too many requests: {0}")]
+    TooManyRequests(String),
+
     #[error("Internal server error: {0}")]
     Internal(String),
 
     #[error("Anyhow error: {0}")]
-    Anyhow(#[from] anyhow::Error),
+    Anyhow(anyhow::Error),
 
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
@@ -48,21 +62,41 @@ impl IntoResponse for AppError {
             AppError::Authentication(msg) => (StatusCode::UNAUTHORIZED, msg.as_str()),
             AppError::Authorization(msg) => (StatusCode::FORBIDDEN, msg.as_str()),
             AppError::NotFound(msg) => (StatusCode::NOT_FOUND, msg.as_str()),
+            AppError::TooManyRequests(msg) => (StatusCode::TOO_MANY_REQUESTS, msg.as_str()),
             AppError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg.as_str()),
             AppError::Anyhow(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error"),
             AppError::Json(_) => (StatusCode::BAD_REQUEST, "JSON parsing error"),
             AppError::Generic(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg.as_str()),
         };
 
+        // Wrapped errors carry driver and query text that must not reach clients
+        let message = match &self {
+            AppError::Database(_) | AppError::Anyhow(_) => {
+                tracing::error!("{}", self);
+                error_message.to_string()
+            }
+            _ => self.to_string(),
+        };
+
         let body = Json(json!({
             "error": error_message,
-            "message": self.to_string(),
+            "message": message,
         }));
 
         (status, body).into_response()
     }
 }
 
+impl From<anyhow::Error> for AppError {
+    fn from(error: anyhow::Error) -> Self {
+        match error.downcast::<DomainError>() {
+            Ok(DomainError::NotFound(msg)) => AppError::NotFound(msg),
+            Ok(DomainError::Invalid(msg)) => AppError::Validation(msg),
+            Err(error) => AppError::Anyhow(error),
+        }
+    }
+}
+
 impl From<Box<dyn std::error::Error + Send + Sync>> for AppError {
     fn from(error: Box<dyn std::error::Error + Send + Sync>) -> Self {
         AppError::Generic(error.to_string())
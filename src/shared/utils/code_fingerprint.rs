@@ -0,0 +1,199 @@
+use std::collections::HashSet;
+
+/// Tokens per k-gram; shorter runs of matching code are ignored.
+pub const FINGERPRINT_KGRAM: usize = 5;
+/// k-grams per winnowing window; any match of at least
+/// `FINGERPRINT_KGRAM + FINGERPRINT_WINDOW - 1` tokens is guaranteed to be detected.
+pub const FINGERPRINT_WINDOW: usize = 4;
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+/// Rust source reduced to the tokens that matter for similarity: comments and
+/// whitespace are dropped, identifiers and literals replaced by their kind, so
+/// renaming variables or changing constants does not hide a copy. Keywords,
+/// macro names and punctuation are kept. Lexing is forgiving, so code that does
+/// not compile is still compared.
+pub fn normalized_tokens(source: &str) -> Vec<String> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '/' && next == Some('/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && next == Some('*') {
+            // Block comments nest in Rust
+            let mut depth = 0;
+            while i < chars.len() {
+                if chars[i] == '/' && chars.get(i + 1) == Some(&'*') {
+                    depth += 1;
+                    i += 2;
+                } else if chars[i] == '*' && chars.get(i + 1) == Some(&'/') {
+                    depth -= 1;
+                    i += 2;
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    i += 1;
+                }
+            }
+        } else if c == 'r' && matches!(next, Some('"') | Some('#')) && is_raw_string(&chars, i) {
+            i = skip_raw_string(&chars, i + 1);
+            tokens.push("STR".to_string());
+        } else if c == 'b' && next == Some('"') {
+            i = skip_quoted(&chars, i + 1, '"');
+            tokens.push("STR".to_string());
+        } else if c == '"' {
+            i = skip_quoted(&chars, i, '"');
+            tokens.push("STR".to_string());
+        } else if c == '\'' {
+            // A char literal closes within a few characters; otherwise it is a lifetime
+            if next == Some('\\') || chars.get(i + 2) == Some(&'\'') {
+                i = skip_quoted(&chars, i, '\'');
+                tokens.push("CHAR".to_string());
+            } else {
+                i += 1;
+                while i < chars.len() && is_ident_char(chars[i]) {
+                    i += 1;
+                }
+                tokens.push("LIFETIME".to_string());
+            }
+        } else if c.is_ascii_digit() {
+            while i < chars.len() && (is_ident_char(chars[i]) || chars[i] == '.') {
+                // `1..10` is a range, not a float
+                if chars[i] == '.' && chars.get(i + 1) == Some(&'.') {
+                    break;
+                }
+                i += 1;
+            }
+            tokens.push("NUM".to_string());
+        } else if is_ident_char(c) {
+            let start = i;
+            while i < chars.len() && is_ident_char(chars[i]) {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            let is_macro = chars.get(i) == Some(&'!') && chars.get(i + 1) != Some(&'=');
+            if is_macro || RUST_KEYWORDS.contains(&word.as_str()) {
+                tokens.push(word);
+            } else {
+                tokens.push("ID".to_string());
+            }
+        } else {
+            tokens.push(c.to_string());
+            i += 1;
+        }
+    }
+    tokens
+}
+
+/// Winnowed k-gram hashes of the normalized token stream (Schleimer et al., 2003).
+pub fn fingerprints(source: &str) -> HashSet<u64> {
+    let tokens = normalized_tokens(source);
+    if tokens.is_empty() {
+        return HashSet::new();
+    }
+    if tokens.len() < FINGERPRINT_KGRAM {
+        return HashSet::from([hash_tokens(&tokens)]);
+    }
+
+    let hashes: Vec<u64> = tokens.windows(FINGERPRINT_KGRAM).map(hash_tokens).collect();
+    if hashes.len() <= FINGERPRINT_WINDOW {
+        return hashes.into_iter().min().into_iter().collect();
+    }
+    hashes
+        .windows(FINGERPRINT_WINDOW)
+        .map(|window| {
+            // The rightmost minimum, so equal hashes in a row are picked only once
+            let mut min = window[0];
+            for &hash in window {
+                if hash <= min {
+                    min = hash;
+                }
+            }
+            min
+        })
+        .collect()
+}
+
+/// Jaccard similarity of two fingerprint sets, as a percentage.
+pub fn fingerprint_similarity(a: &HashSet<u64>, b: &HashSet<u64>) -> u32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0;
+    }
+    (a.intersection(b).count() * 100 / union) as u32
+}
+
+/// Percentage similarity of two Rust sources.
+pub fn code_similarity(a: &str, b: &str) -> u32 {
+    fingerprint_similarity(&fingerprints(a), &fingerprints(b))
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn is_raw_string(chars: &[char], start: usize) -> bool {
+    let mut i = start + 1;
+    while chars.get(i) == Some(&'#') {
+        i += 1;
+    }
+    chars.get(i) == Some(&'"')
+}
+
+/// Index just past a raw string whose `#`s start at `start`.
+fn skip_raw_string(chars: &[char], start: usize) -> usize {
+    let mut i = start;
+    let mut hashes = 0;
+    while chars.get(i) == Some(&'#') {
+        hashes += 1;
+        i += 1;
+    }
+    i += 1;
+    while i < chars.len() {
+        if chars[i] == '"' && (1..=hashes).all(|h| chars.get(i + h) == Some(&'#')) {
+            return i + 1 + hashes;
+        }
+        i += 1;
+    }
+    i
+}
+
+/// Index just past a quoted literal opening at `start`, honouring escapes.
+fn skip_quoted(chars: &[char], start: usize, quote: char) -> usize {
+    let mut i = start + 1;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            c if c == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+    i
+}
+
+/// FNV-1a, so fingerprints are stable across runs and builds.
+fn hash_tokens(tokens: &[String]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for token in tokens {
+        for byte in token.bytes().chain(std::iter::once(0)) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
@@ -0,0 +1,62 @@
+/// Text with its fenced code blocks and inline code spans swapped for numbered
+/// placeholders, so it can be rewritten (e.g. translated) without touching code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtectedText {
+    pub text: String,
+    segments: Vec<String>,
+}
+
+impl ProtectedText {
+    pub fn placeholder(index: usize) -> String {
+        format!("⟦C{index}⟧")
+    }
+
+    /// Whether any code was taken out.
+    pub fn has_code(&self) -> bool {
+        !self.segments.is_empty()
+    }
+
+    /// Puts the code back into a rewritten `text`. Fails when a placeholder was
+    /// dropped, altered or repeated.
+    pub fn restore(&self, rewritten: &str) -> Result<String, String> {
+        let mut restored = rewritten.to_string();
+        for (index, segment) in self.segments.iter().enumerate() {
+            let placeholder = Self::placeholder(index);
+            match restored.matches(&placeholder).count() {
+                1 => restored = restored.replacen(&placeholder, segment, 1),
+                0 => return Err(format!("Placeholder {placeholder} is missing")),
+                _ => return Err(format!("Placeholder {placeholder} appears more than once")),
+            }
+        }
+        Ok(restored)
+    }
+}
+
+/// Takes out ```` ``` ```` fenced blocks and `` ` `` inline spans. An unclosed
+/// fence or backtick is left as text.
+pub fn protect_code(text: &str) -> ProtectedText {
+    let mut protected = String::with_capacity(text.len());
+    let mut segments = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('`') {
+        let delimiter = if rest[start..].starts_with("```") {
+            "```"
+        } else {
+            "`"
+        };
+        let body_start = start + delimiter.len();
+        let Some(length) = rest[body_start..].find(delimiter) else {
+            break;
+        };
+        let end = body_start + length + delimiter.len();
+        protected.push_str(&rest[..start]);
+        protected.push_str(&ProtectedText::placeholder(segments.len()));
+        segments.push(rest[start..end].to_string());
+        rest = &rest[end..];
+    }
+    protected.push_str(rest);
+    ProtectedText {
+        text: protected,
+        segments,
+    }
+}
@@ -22,3 +22,16 @@ pub fn hash_string(input: &str) -> String {
     input.hash(&mut hasher);
     format!("{:x}", hasher.finish())
 }
+
+/// 128-bit FNV-1a over `parts`, hex encoded. Unlike `hash_string` the result is
+/// the same across builds and Rust versions, so it can be stored.
+pub fn stable_hash(parts: &[&str]) -> String {
+    let mut hash: u128 = 0x6c62_272e_07bb_0142_62b8_2175_6295_c58d;
+    for part in parts {
+        for byte in part.bytes().chain(std::iter::once(0)) {
+            hash ^= u128::from(byte);
+            hash = hash.wrapping_mul(0x0000_0000_0100_0000_0000_0000_0000_013b);
+        }
+    }
+    format!("{hash:032x}")
+}
@@ -0,0 +1,34 @@
+/// Escapes text for use in HTML content and double-quoted attribute values.
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Renders Markdown to HTML and strips anything unsafe from the result: scripts,
+/// event handlers, `javascript:` links and the like. Raw HTML in the source is
+/// sanitized the same way rather than escaped. Fenced code keeps its
+/// `language-*` class for syntax highlighting.
+pub fn render_markdown(markdown: &str) -> String {
+    use pulldown_cmark::{html, Options, Parser};
+
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, Parser::new_ext(markdown, options));
+
+    ammonia::Builder::default()
+        .add_tag_attributes("code", &["class"])
+        .attribute_filter(|element, attribute, value| {
+            if element == "code" && attribute == "class" {
+                value
+                    .split_whitespace()
+                    .find(|class| class.starts_with("language-"))
+                    .map(Into::into)
+            } else {
+                Some(value.into())
+            }
+        })
+        .clean(&rendered)
+        .to_string()
+}
@@ -0,0 +1,92 @@
+/// Locale content falls back to when nothing closer is available, and the one
+/// every piece of content is written in first.
+pub const DEFAULT_LOCALE: &str = "en";
+
+const MAX_LOCALE_SUBTAGS: usize = 4;
+
+/// Canonical form of a BCP 47 language tag: lower-case language, title-case
+/// script and upper-case region, so `pt_br` becomes `pt-BR` and `zh-hant-tw`
+/// becomes `zh-Hant-TW`. `None` for anything that is not a language tag.
+pub fn normalize_locale(tag: &str) -> Option<String> {
+    let subtags: Vec<&str> = tag.trim().split(['-', '_']).collect();
+    if subtags.len() > MAX_LOCALE_SUBTAGS {
+        return None;
+    }
+    let (language, rest) = subtags.split_first()?;
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let mut normalized = language.to_ascii_lowercase();
+    for subtag in rest {
+        let alphabetic = subtag.chars().all(|c| c.is_ascii_alphabetic());
+        let part = match subtag.len() {
+            // Script, e.g. Hant
+            4 if alphabetic => {
+                let (first, tail) = subtag.split_at(1);
+                format!(
+                    "{}{}",
+                    first.to_ascii_uppercase(),
+                    tail.to_ascii_lowercase()
+                )
+            }
+            // Region, e.g. BR or 419
+            2 if alphabetic => subtag.to_ascii_uppercase(),
+            3 if subtag.chars().all(|c| c.is_ascii_digit()) => subtag.to_string(),
+            // Variant, e.g. valencia
+            5..=8 if subtag.chars().all(|c| c.is_ascii_alphanumeric()) => {
+                subtag.to_ascii_lowercase()
+            }
+            _ => return None,
+        };
+        normalized.push('-');
+        normalized.push_str(&part);
+    }
+    Some(normalized)
+}
+
+/// Locales to look text up in for `locale`, most specific first and ending
+/// with the default: `pt-BR` gives `pt-BR`, `pt`, `en`.
+pub fn locale_fallbacks(locale: &str) -> Vec<String> {
+    accepted_locale_fallbacks(&[locale])
+}
+
+/// Lookup order for several accepted locales, most preferred first: each one
+/// followed by its parents, then the default. `pt-BR, id` gives `pt-BR`, `pt`,
+/// `id`, `en`.
+pub fn accepted_locale_fallbacks(locales: &[&str]) -> Vec<String> {
+    let mut fallbacks: Vec<String> = Vec::new();
+    for locale in locales {
+        let mut current = *locale;
+        while !current.is_empty() {
+            if !fallbacks.iter().any(|tag| tag == current) {
+                fallbacks.push(current.to_string());
+            }
+            current = current.rsplit_once('-').map_or("", |(parent, _)| parent);
+        }
+    }
+    if !fallbacks.iter().any(|tag| tag == DEFAULT_LOCALE) {
+        fallbacks.push(DEFAULT_LOCALE.to_string());
+    }
+    fallbacks
+}
+
+/// Locales listed in an `Accept-Language` header, most preferred first.
+/// Wildcards, malformed tags and entries with `q=0` are left out.
+pub fn parse_accept_language(header: &str) -> Vec<String> {
+    let mut weighted: Vec<(String, f32)> = header
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';');
+            let locale = normalize_locale(parts.next()?)?;
+            let quality = parts
+                .filter_map(|param| param.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            (quality > 0.0).then_some((locale, quality))
+        })
+        .collect();
+    // Stable, so equally weighted locales keep the order they were listed in
+    weighted.sort_by(|a, b| b.1.total_cmp(&a.1));
+    weighted.into_iter().map(|(locale, _)| locale).collect()
+}
@@ -1,7 +1,19 @@
+pub mod code_fingerprint;
+pub mod code_spans;
 pub mod crypto;
 pub mod date_time;
+pub mod diff;
+pub mod html;
+pub mod locale;
+pub mod rate_limit;
 pub mod validation;
 
+pub use code_fingerprint::*;
+pub use code_spans::*;
 pub use crypto::*;
 pub use date_time::*;
+pub use diff::*;
+pub use html::*;
+pub use locale::*;
+pub use rate_limit::*;
 pub use validation::*;
@@ -0,0 +1,53 @@
+use std::time::{Duration, Instant};
+
+/// Token bucket: holds up to `capacity` tokens and refills continuously, so short
+/// bursts are allowed while the long-run rate stays bounded.
+#[derive(Debug, Clone)]
+pub struct TokenBucket {
+    capacity: f64,
+    refill_per_sec: f64,
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    /// A full bucket allowing `requests` per minute.
+    pub fn per_minute(requests: u32, now: Instant) -> Self {
+        let capacity = f64::from(requests.max(1));
+        Self {
+            capacity,
+            refill_per_sec: capacity / 60.0,
+            tokens: capacity,
+            updated_at: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.updated_at = now;
+    }
+
+    /// `Err` with the wait until a token is available when the bucket is empty.
+    pub fn check(&mut self, now: Instant) -> Result<(), Duration> {
+        self.refill(now);
+        if self.tokens >= 1.0 {
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - self.tokens) / self.refill_per_sec,
+            ))
+        }
+    }
+
+    /// Takes a token after a successful `check`.
+    pub fn take(&mut self) {
+        self.tokens -= 1.0;
+    }
+
+    /// Whether the bucket has refilled completely, i.e. has been idle.
+    pub fn is_full(&mut self, now: Instant) -> bool {
+        self.refill(now);
+        self.tokens >= self.capacity
+    }
+}